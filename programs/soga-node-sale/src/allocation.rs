@@ -0,0 +1,215 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::error::SogaNodeSaleError;
+use crate::states::{AllocationMode, TicketRange, WinningBid};
+
+/// Nodes allocated to a commitment holding the `ticket_ranges` positions out of
+/// `total_committed` when `supply` nodes are available. Every commitment is filled
+/// when the tier is not oversubscribed, otherwise the allocations of all commitments
+/// add up to exactly `supply` whatever order they are allocated in.
+pub fn get_allocation(allocation_mode: AllocationMode, lottery_seed: &[u8; 32], ticket_ranges: &[TicketRange],
+                      total_committed: u64, supply: u64) -> Result<u64> {
+    let committed: u64 = ticket_ranges.iter().map(|ticket_range| ticket_range.quantity).sum();
+
+    if total_committed <= supply {
+        return Ok(committed);
+    }
+
+    ticket_ranges.iter().try_fold(0, |allocation: u64, ticket_range| {
+        let range_allocation: u64 = match allocation_mode {
+            AllocationMode::ProRata => get_pro_rata_allocation(ticket_range, total_committed, supply)?,
+            AllocationMode::Lottery => get_lottery_allocation(lottery_seed, ticket_range, total_committed, supply),
+        };
+
+        Ok(allocation + range_allocation)
+    })
+}
+
+/// Share of `supply` for the positions of `ticket_range`, the difference of the
+/// rounded down shares of all positions up to its end and up to its start, so
+/// adjacent ranges never lose a node to rounding.
+pub fn get_pro_rata_allocation(ticket_range: &TicketRange, total_committed: u64, supply: u64) -> Result<u64> {
+    if total_committed == 0 {
+        return Ok(0);
+    }
+
+    let cumulative_share = |position: u64| (position as u128) * (supply as u128) / (total_committed as u128);
+
+    let allocation: u128 = cumulative_share(ticket_range.start + ticket_range.quantity) - cumulative_share(ticket_range.start);
+
+    Ok(u64::try_from(allocation).map_err(|_| SogaNodeSaleError::MathOverflow)?)
+}
+
+/// Positions of `ticket_range` winning a lottery of `supply` nodes. All positions
+/// are shuffled by a permutation drawn from the lottery seed and the first `supply`
+/// shuffled positions win.
+pub fn get_lottery_allocation(lottery_seed: &[u8; 32], ticket_range: &TicketRange, total_committed: u64, supply: u64) -> u64 {
+    let (multiplier, offset): (u128, u128) = get_lottery_permutation(lottery_seed, total_committed);
+    let total_committed: u128 = total_committed as u128;
+
+    (ticket_range.start..ticket_range.start + ticket_range.quantity)
+        .filter(|position| (multiplier * (*position as u128) % total_committed + offset) % total_committed < supply as u128)
+        .count() as u64
+}
+
+/// `position -> (multiplier * position + offset) % total_committed`, a permutation
+/// of the positions because the multiplier is coprime with `total_committed`.
+fn get_lottery_permutation(lottery_seed: &[u8; 32], total_committed: u64) -> (u128, u128) {
+    let seed = hashv(&[lottery_seed.as_ref(), &total_committed.to_le_bytes()]).to_bytes();
+    let total_committed: u128 = total_committed as u128;
+
+    let offset: u128 = u64::from_le_bytes(seed[..8].try_into().unwrap()) as u128 % total_committed;
+    let mut multiplier: u128 = u64::from_le_bytes(seed[8..16].try_into().unwrap()) as u128 % total_committed;
+
+    while get_gcd(multiplier, total_committed) != 1 {
+        multiplier = (multiplier + 1) % total_committed;
+    }
+
+    (multiplier, offset)
+}
+
+fn get_gcd(a: u128, b: u128) -> u128 {
+    if b == 0 { a } else { get_gcd(b, a % b) }
+}
+
+/// Part of a `total_payment` deposit for `committed` nodes spent on `allocated`
+/// nodes. Rounded down, the rest is refunded to the user.
+pub fn get_allocated_payment(total_payment: u64, allocated: u64, committed: u64) -> Result<u64> {
+    if committed == 0 {
+        return Ok(0);
+    }
+
+    let payment: u128 = (total_payment as u128) * (allocated as u128) / (committed as u128);
+
+    Ok(u64::try_from(payment).map_err(|_| SogaNodeSaleError::MathOverflow)?)
+}
+
+/// Hash committed by a sealed bid of `price` lamports per node.
+pub fn get_bid_hash(price: u64, salt: &[u8; 32], user: &Pubkey) -> [u8; 32] {
+    hashv(&[&price.to_le_bytes(), salt.as_ref(), user.as_ref()]).to_bytes()
+}
+
+/// Inserts a revealed bid into `winning_bids`, ordered from the highest price and
+/// limited to `quantity` bids. The lowest bid is dropped when the list is full,
+/// on equal prices the earlier reveal wins. Returns false when the bid does not win.
+pub fn insert_winning_bid(winning_bids: &mut Vec<WinningBid>, bid: WinningBid, quantity: usize) -> bool {
+    let index: usize = winning_bids.iter()
+        .position(|winning_bid| bid.price > winning_bid.price)
+        .unwrap_or(winning_bids.len());
+
+    if index >= quantity {
+        return false;
+    }
+
+    winning_bids.insert(index, bid);
+    winning_bids.truncate(quantity);
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undersubscribed_commitment_is_filled() {
+        let ticket_ranges: Vec<TicketRange> = vec![TicketRange { start: 3, quantity: 5 }, TicketRange { start: 20, quantity: 2 }];
+
+        assert_eq!(get_allocation(AllocationMode::ProRata, &[0; 32], &ticket_ranges, 50, 100).unwrap(), 7);
+        assert_eq!(get_allocation(AllocationMode::Lottery, &[0; 32], &ticket_ranges, 100, 100).unwrap(), 7);
+    }
+
+    #[test]
+    fn pro_rata_allocation_shares_rounding() {
+        // 400 committed nodes for a supply of 100, the 0.75 node share of the first range is carried to the next one
+        assert_eq!(get_pro_rata_allocation(&TicketRange { start: 0, quantity: 3 }, 400, 100).unwrap(), 0);
+        assert_eq!(get_pro_rata_allocation(&TicketRange { start: 3, quantity: 5 }, 400, 100).unwrap(), 2);
+        assert_eq!(get_pro_rata_allocation(&TicketRange { start: 8, quantity: 392 }, 400, 100).unwrap(), 98);
+        assert_eq!(get_pro_rata_allocation(&TicketRange { start: 0, quantity: 400 }, 400, 100).unwrap(), 100);
+    }
+
+    #[test]
+    fn allocations_add_up_to_supply() {
+        let seed: [u8; 32] = [7; 32];
+
+        // 50 users commit twice, all first commits land before the second ones
+        let mut commitments: Vec<Vec<TicketRange>> = vec![Vec::new(); 50];
+        let mut position: u64 = 0;
+
+        for round in 0..2u64 {
+            for (user, ticket_ranges) in commitments.iter_mut().enumerate() {
+                let quantity: u64 = 1 + (user as u64 + round) % 4;
+                ticket_ranges.push(TicketRange { start: position, quantity });
+                position += quantity;
+            }
+        }
+
+        let total_committed: u64 = position;
+
+        for allocation_mode in [AllocationMode::ProRata, AllocationMode::Lottery] {
+            for supply in [1, 17, 100, total_committed - 1] {
+                let allocation: u64 = commitments.iter()
+                    .map(|ticket_ranges| get_allocation(allocation_mode, &seed, ticket_ranges, total_committed, supply).unwrap())
+                    .sum();
+
+                assert_eq!(allocation, supply);
+            }
+        }
+    }
+
+    #[test]
+    fn lottery_allocation() {
+        let seed: [u8; 32] = [7; 32];
+        let ticket_range: TicketRange = TicketRange { start: 1000, quantity: 1000 };
+
+        // deterministic for a seed
+        let allocation: u64 = get_lottery_allocation(&seed, &ticket_range, 4000, 1000);
+        assert_eq!(get_lottery_allocation(&seed, &ticket_range, 4000, 1000), allocation);
+
+        // about a quarter of the committed nodes win
+        assert!(allocation > 150 && allocation < 350);
+    }
+
+    #[test]
+    fn allocated_payment_and_refund() {
+        // 2 of 3 nodes allocated from a 1000 lamport deposit
+        let payment: u64 = get_allocated_payment(1000, 2, 3).unwrap();
+        assert_eq!(payment, 666);
+        assert_eq!(1000 - payment, 334);
+
+        assert_eq!(get_allocated_payment(1000, 3, 3).unwrap(), 1000);
+        assert_eq!(get_allocated_payment(1000, 0, 3).unwrap(), 0);
+    }
+
+    #[test]
+    fn bid_hash_binds_price_and_user() {
+        let user: Pubkey = Pubkey::new_unique();
+        let salt: [u8; 32] = [3; 32];
+
+        assert_eq!(get_bid_hash(100, &salt, &user), get_bid_hash(100, &salt, &user));
+        assert_ne!(get_bid_hash(100, &salt, &user), get_bid_hash(101, &salt, &user));
+        assert_ne!(get_bid_hash(100, &salt, &user), get_bid_hash(100, &salt, &Pubkey::new_unique()));
+    }
+
+    #[test]
+    fn winning_bids_keep_the_highest() {
+        let mut winning_bids: Vec<WinningBid> = Vec::new();
+        let bid = |price: u64| WinningBid { user: Pubkey::new_unique(), price };
+
+        assert!(insert_winning_bid(&mut winning_bids, bid(50), 3));
+        assert!(insert_winning_bid(&mut winning_bids, bid(80), 3));
+        assert!(insert_winning_bid(&mut winning_bids, bid(60), 3));
+
+        // lower than every winning bid of a full list
+        assert!(!insert_winning_bid(&mut winning_bids, bid(40), 3));
+
+        // equal to the lowest winning bid, the earlier reveal wins
+        assert!(!insert_winning_bid(&mut winning_bids, bid(50), 3));
+
+        assert!(insert_winning_bid(&mut winning_bids, bid(70), 3));
+
+        let prices: Vec<u64> = winning_bids.iter().map(|winning_bid| winning_bid.price).collect();
+        assert_eq!(prices, vec![80, 70, 60]);
+    }
+}
\ No newline at end of file
@@ -91,4 +91,289 @@ pub enum SogaNodeSaleError {
 
     #[msg("Invalid whitelist quantity")]
     InvalidWhitelistQuantity,
+
+    #[msg("Invalid mint mode")]
+    InvalidMintMode,
+
+    #[msg("Phase tier is compressed")]
+    PhaseTierIsCompressed,
+
+    #[msg("Phase tier is not compressed")]
+    PhaseTierIsNotCompressed,
+
+    #[msg("Invalid merkle tree address")]
+    InvalidMerkleTreeAddress,
+
+    #[msg("Invalid program address")]
+    InvalidProgramAddress,
+
+    #[msg("Node is locked")]
+    NodeIsLocked,
+
+    #[msg("Order token id revoked")]
+    OrderTokenIdRevoked,
+
+    #[msg("Invalid node mint account")]
+    InvalidNodeMintAccount,
+
+    #[msg("Invalid user token account")]
+    InvalidUserTokenAccount,
+
+    #[msg("Node is not revoked")]
+    NodeIsNotRevoked,
+
+    #[msg("Invalid upgrade tier")]
+    InvalidUpgradeTier,
+
+    #[msg("Invalid oracle price")]
+    InvalidOraclePrice,
+
+    #[msg("Oracle confidence too wide")]
+    OracleConfidenceTooWide,
+
+    #[msg("Oracle price out of range")]
+    OraclePriceOutOfRange,
+
+    #[msg("Math overflow")]
+    MathOverflow,
+
+    #[msg("Payment amount exceeds max payment amount")]
+    PaymentAmountExceedsMax,
+
+    #[msg("Price update required")]
+    PriceUpdateRequired,
+
+    #[msg("Invalid fixed rate")]
+    InvalidFixedRate,
+
+    #[msg("Stablecoin depegged")]
+    StablecoinDepegged,
+
+    #[msg("Invalid oracle account")]
+    InvalidOracleAccount,
+
+    #[msg("Oracle price too old")]
+    OraclePriceTooOld,
+
+    #[msg("Oracle quorum not reached")]
+    OracleQuorumNotReached,
+
+    #[msg("Too many price sources")]
+    TooManyPriceSources,
+
+    #[msg("Invalid price source quorum")]
+    InvalidPriceSourceQuorum,
+
+    #[msg("Invalid price schedule")]
+    InvalidPriceSchedule,
+
+    #[msg("Invalid dutch auction")]
+    InvalidDutchAuction,
+
+    #[msg("Auction not ended")]
+    AuctionNotEnded,
+
+    #[msg("Auction order settled")]
+    AuctionOrderSettled,
+
+    #[msg("Invalid auction escrow")]
+    InvalidAuctionEscrow,
+
+    #[msg("Invalid fair launch")]
+    InvalidFairLaunch,
+
+    #[msg("Phase tier is a fair launch")]
+    PhaseTierIsFairLaunch,
+
+    #[msg("Commit window closed")]
+    CommitWindowClosed,
+
+    #[msg("Commit window not ended")]
+    CommitWindowNotEnded,
+
+    #[msg("Fair launch not finalized")]
+    FairLaunchNotFinalized,
+
+    #[msg("Fair launch finalized")]
+    FairLaunchFinalized,
+
+    #[msg("Commitment allocated")]
+    CommitmentAllocated,
+
+    #[msg("Commitment not allocated")]
+    CommitmentNotAllocated,
+
+    #[msg("Commitment refunded")]
+    CommitmentRefunded,
+
+    #[msg("Invalid allocation quantity")]
+    InvalidAllocationQuantity,
+
+    #[msg("Invalid commitment escrow")]
+    InvalidCommitmentEscrow,
+
+    #[msg("Invalid sealed bid auction")]
+    InvalidSealedBidAuction,
+
+    #[msg("Phase tier is a sealed bid auction")]
+    PhaseTierIsSealedBidAuction,
+
+    #[msg("Bidding closed")]
+    BiddingClosed,
+
+    #[msg("Reveal closed")]
+    RevealClosed,
+
+    #[msg("Reveal not ended")]
+    RevealNotEnded,
+
+    #[msg("Invalid bid reveal")]
+    InvalidBidReveal,
+
+    #[msg("Invalid bid price")]
+    InvalidBidPrice,
+
+    #[msg("Bid revealed")]
+    BidRevealed,
+
+    #[msg("Bid settled")]
+    BidSettled,
+
+    #[msg("Bid not winning")]
+    BidNotWinning,
+
+    #[msg("Bid winning")]
+    BidWinning,
+
+    #[msg("Invalid phase caps")]
+    InvalidPhaseCaps,
+
+    #[msg("Hard cap exceeded")]
+    HardCapExceeded,
+
+    #[msg("Phase ended")]
+    PhaseEnded,
+
+    #[msg("Soft cap pending")]
+    SoftCapPending,
+
+    #[msg("Cap escrow settled")]
+    CapEscrowSettled,
+
+    #[msg("Order voided")]
+    OrderVoided,
+
+    #[msg("Invalid cap escrow")]
+    InvalidCapEscrow,
+
+    #[msg("Invalid installment config")]
+    InvalidInstallmentConfig,
+
+    #[msg("Installment disabled")]
+    InstallmentDisabled,
+
+    #[msg("Invalid installment")]
+    InvalidInstallment,
+
+    #[msg("Not installment order")]
+    NotInstallmentOrder,
+
+    #[msg("Invalid installment payment")]
+    InvalidInstallmentPayment,
+
+    #[msg("Installment overdue")]
+    InstallmentOverdue,
+
+    #[msg("Installment not overdue")]
+    InstallmentNotOverdue,
+
+    #[msg("Installment not paid")]
+    InstallmentNotPaid,
+
+    #[msg("Invalid volume brackets")]
+    InvalidVolumeBrackets,
+
+    #[msg("Invalid loyalty collections")]
+    InvalidLoyaltyCollections,
+
+    #[msg("Invalid loyalty node")]
+    InvalidLoyaltyNode,
+
+    #[msg("Invalid whitelist pass")]
+    InvalidWhitelistPass,
+
+    #[msg("Invalid whitelist pass escrow")]
+    InvalidWhitelistPassEscrow,
+
+    #[msg("Whitelist mint limit exceeded")]
+    WhitelistMintLimitExceeded,
+
+    #[msg("Reservation exceeds supply")]
+    ReservationExceedsSupply,
+
+    #[msg("Reservation claimed")]
+    ReservationClaimed,
+
+    #[msg("Airdrop claim started")]
+    AirdropClaimStarted,
+
+    #[msg("Invalid airdrop claim")]
+    InvalidAirdropClaim,
+
+    #[msg("Airdrop claimed")]
+    AirdropClaimed,
+
+    #[msg("Insufficient fill fee vault balance")]
+    InsufficientFillFeeVaultBalance,
+
+    #[msg("Invalid realloc account")]
+    InvalidReallocAccount,
+
+    #[msg("Order layout outdated")]
+    OrderLayoutOutdated,
+
+    #[msg("Cap escrow pending")]
+    CapEscrowPending,
+
+    #[msg("Invalid price curve")]
+    InvalidPriceCurve,
+
+    #[msg("Invalid payment token program")]
+    InvalidPaymentTokenProgram,
+
+    #[msg("Allocation ended")]
+    AllocationEnded,
+
+    #[msg("Too many commits")]
+    TooManyCommits,
+
+    #[msg("Invalid referral receiver")]
+    InvalidReferralReceiver,
+
+    #[msg("Loyalty node already used in this phase")]
+    LoyaltyNodeUsed,
+
+    #[msg("Reservations can not change once the fair launch is finalized or bids are revealed")]
+    PublicQuantityLocked,
+
+    #[msg("Airdrop claim exceeds the airdrop claim quantity")]
+    AirdropClaimExceedsQuantity,
+
+    #[msg("Order token id upgraded")]
+    OrderTokenIdUpgraded,
+
+    #[msg("Order token id reissued")]
+    OrderTokenIdReissued,
+
+    #[msg("Caps can not change once the phase has payments or has ended")]
+    PhaseCapsLocked,
+
+    #[msg("Order has no cap escrow")]
+    CapEscrowEmpty,
+
+    #[msg("Order token ids minted")]
+    OrderTokenIdsMinted,
+
+    #[msg("Phase not started")]
+    PhaseNotStarted,
 }
\ No newline at end of file
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct AllocateCommitmentEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub tier_id: String,
+
+    pub order_id: String,
+
+    pub user: Pubkey,
+
+    pub payment_receiver: Pubkey,
+
+    pub payment_token_mint_account: Option<Pubkey>,
+
+    pub committed_quantity: u64,
+
+    pub allocated_quantity: u64,
+
+    pub payment_in_usd: u64,
+
+    pub payment_amount: u64,
+
+    pub refund_amount: u64,
+}
\ No newline at end of file
@@ -67,4 +67,26 @@ pub struct BuyWithTokenEvent {
     pub quantity: u64,
 
     pub is_whitelist: bool,
+
+    pub payment_amount: u64,
+
+    pub max_payment_amount: u64,
+
+    pub is_installment: bool,
+
+    pub volume_discount: u16,
+
+    pub volume_discount_in_usd: u64,
+
+    pub volume_discount_in_lamport: u64,
+
+    pub loyalty_node_mint_account: Option<Pubkey>,
+
+    pub loyalty_discount: u16,
+
+    pub loyalty_discount_in_usd: u64,
+
+    pub loyalty_discount_in_lamport: u64,
+
+    pub whitelist_pass_amount: u64,
 }
\ No newline at end of file
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ClaimAirdropEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub tier_id: String,
+
+    pub order_id: String,
+
+    pub user: Pubkey,
+
+    pub index: u32,
+
+    pub quantity: u64,
+
+    pub merkle_root: [u8; 32],
+}
\ No newline at end of file
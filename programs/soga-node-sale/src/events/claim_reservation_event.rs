@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ClaimReservationEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub tier_id: String,
+
+    pub order_id: String,
+
+    pub partner: Pubkey,
+
+    pub payment_receiver: Pubkey,
+
+    pub quantity: u64,
+
+    pub pyth_expo: u64,
+
+    pub pyth_price: u64,
+
+    pub total_price_in_usd: u64,
+
+    pub total_price_in_lamport: u64,
+
+    pub max_payment_amount: u64,
+}
\ No newline at end of file
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct CommitEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub tier_id: String,
+
+    pub user: Pubkey,
+
+    pub user_payer: Pubkey,
+
+    /// none for sol commitments
+    pub payment_token_mint_account: Option<Pubkey>,
+
+    pub quantity: u64,
+
+    /// nodes requested by the commitment
+    pub total_quantity: u64,
+
+    pub price_in_usd: u64,
+
+    pub payment_amount: u64,
+
+    pub max_payment_amount: u64,
+}
\ No newline at end of file
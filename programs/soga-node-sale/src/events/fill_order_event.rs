@@ -19,4 +19,10 @@ pub struct FillOrderEvent {
     pub node_mint_account: Pubkey,
 
     pub is_completed: bool,
+
+    /// signer paying the rent of the fill, the user itself or a crank
+    pub filler: Pubkey,
+
+    /// lamports paid to the filler from the fill fee vault
+    pub fill_reward: u64,
 }
\ No newline at end of file
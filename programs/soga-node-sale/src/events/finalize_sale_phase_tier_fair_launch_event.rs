@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+use crate::states::AllocationMode;
+
+#[event]
+pub struct FinalizeSalePhaseTierFairLaunchEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub tier_id: String,
+
+    pub total_committed: u64,
+
+    pub fair_launch_supply: u64,
+
+    pub allocation_mode: AllocationMode,
+
+    pub lottery_seed: [u8; 32],
+}
\ No newline at end of file
@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct FundFillFeeVaultEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub funder: Pubkey,
+
+    pub amount: u64,
+
+    pub total_funded: u64,
+}
\ No newline at end of file
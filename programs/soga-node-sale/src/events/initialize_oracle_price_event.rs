@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct InitializeOraclePriceEvent {
+    pub timestamp: i64,
+
+    pub name: String,
+
+    pub oracle_price: Pubkey,
+
+    pub authority: Pubkey,
+
+    pub price: i64,
+
+    pub conf: u64,
+
+    pub exponent: i32,
+}
\ No newline at end of file
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::states::NodeMintMode;
+
 #[event]
 pub struct InitializeSalePhaseEvent {
     pub timestamp: i64,
@@ -15,4 +17,6 @@ pub struct InitializeSalePhaseEvent {
     pub payment_receiver: Pubkey,
 
     pub price_feed_id: String,
+
+    pub mint_mode: NodeMintMode,
 }
\ No newline at end of file
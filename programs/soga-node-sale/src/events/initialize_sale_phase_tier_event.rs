@@ -17,4 +17,8 @@ pub struct InitializeSalePhaseTierEvent {
     pub mint_limit: u64,
 
     pub whitelist_quantity: u64,
+
+    pub is_compressed: bool,
+
+    pub merkle_tree_address: Pubkey,
 }
\ No newline at end of file
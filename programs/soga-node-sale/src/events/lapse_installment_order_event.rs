@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct LapseInstallmentOrderEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub tier_id: String,
+
+    pub order_id: String,
+
+    pub user: Pubkey,
+
+    pub payment_receiver: Pubkey,
+
+    pub payment_token_mint_account: Option<Pubkey>,
+
+    pub installment_paid_in_usd: u64,
+
+    pub installment_owed_in_usd: u64,
+
+    /// share of the order price no longer counted toward the phase caps
+    pub paid_price_in_usd: u64,
+
+    pub escrow_amount: u64,
+
+    pub total_revoked: u64,
+
+    pub is_refunded: bool,
+}
\ No newline at end of file
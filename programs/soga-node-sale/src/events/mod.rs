@@ -9,6 +9,49 @@ pub use update_sale_phase_payment_token_event::*;
 pub use buy_with_token_event::*;
 pub use fill_order_event::*;
 pub use create_order_receipt_event::*;
+pub use update_sale_phase_unlock_event::*;
+pub use update_sale_phase_tier_unlock_event::*;
+pub use thaw_node_event::*;
+pub use revoke_node_event::*;
+pub use reissue_node_event::*;
+pub use upgrade_node_event::*;
+pub use update_sale_phase_oracle_config_event::*;
+pub use update_sale_phase_payment_token_fixed_rate_event::*;
+pub use initialize_oracle_price_event::*;
+pub use update_oracle_price_event::*;
+pub use update_sale_phase_price_sources_event::*;
+pub use update_sale_phase_tier_price_schedule_event::*;
+pub use update_sale_phase_tier_price_curve_event::*;
+pub use quote_event::*;
+pub use settle_auction_order_event::*;
+pub use update_sale_phase_tier_dutch_auction_event::*;
+pub use update_sale_phase_tier_fair_launch_event::*;
+pub use commit_event::*;
+pub use finalize_sale_phase_tier_fair_launch_event::*;
+pub use allocate_commitment_event::*;
+pub use refund_commitment_event::*;
+pub use update_sale_phase_tier_sealed_bid_auction_event::*;
+pub use place_bid_event::*;
+pub use reveal_bid_event::*;
+pub use settle_winning_bid_event::*;
+pub use refund_bid_event::*;
+pub use update_sale_phase_caps_event::*;
+pub use settle_order_cap_escrow_event::*;
+pub use update_sale_phase_installment_event::*;
+pub use pay_installment_event::*;
+pub use lapse_installment_order_event::*;
+pub use update_sale_phase_tier_volume_brackets_event::*;
+pub use update_sale_phase_loyalty_event::*;
+pub use update_sale_phase_tier_whitelist_pass_event::*;
+pub use update_sale_phase_tier_whitelist_event::*;
+pub use update_sale_phase_tier_reservation_event::*;
+pub use claim_reservation_event::*;
+pub use update_sale_phase_tier_airdrop_claim_event::*;
+pub use claim_airdrop_event::*;
+pub use update_sale_phase_fill_reward_event::*;
+pub use fund_fill_fee_vault_event::*;
+pub use withdraw_fill_fee_vault_event::*;
+pub use realloc_account_event::*;
 
 pub mod initialize_sale_phase_event;
 pub mod initialize_sale_phase_tier_event;
@@ -21,4 +64,47 @@ pub mod update_sale_phase_payment_token_event;
 pub mod buy_with_token_event;
 
 pub mod fill_order_event;
-pub mod create_order_receipt_event;
\ No newline at end of file
+pub mod create_order_receipt_event;
+pub mod update_sale_phase_unlock_event;
+pub mod update_sale_phase_tier_unlock_event;
+pub mod thaw_node_event;
+pub mod revoke_node_event;
+pub mod reissue_node_event;
+pub mod upgrade_node_event;
+pub mod update_sale_phase_oracle_config_event;
+pub mod update_sale_phase_payment_token_fixed_rate_event;
+pub mod initialize_oracle_price_event;
+pub mod update_oracle_price_event;
+pub mod update_sale_phase_price_sources_event;
+pub mod update_sale_phase_tier_price_schedule_event;
+pub mod update_sale_phase_tier_price_curve_event;
+pub mod quote_event;
+pub mod settle_auction_order_event;
+pub mod update_sale_phase_tier_dutch_auction_event;
+pub mod update_sale_phase_tier_fair_launch_event;
+pub mod commit_event;
+pub mod finalize_sale_phase_tier_fair_launch_event;
+pub mod allocate_commitment_event;
+pub mod refund_commitment_event;
+pub mod update_sale_phase_tier_sealed_bid_auction_event;
+pub mod place_bid_event;
+pub mod reveal_bid_event;
+pub mod settle_winning_bid_event;
+pub mod refund_bid_event;
+pub mod update_sale_phase_caps_event;
+pub mod settle_order_cap_escrow_event;
+pub mod update_sale_phase_installment_event;
+pub mod pay_installment_event;
+pub mod lapse_installment_order_event;
+pub mod update_sale_phase_tier_volume_brackets_event;
+pub mod update_sale_phase_loyalty_event;
+pub mod update_sale_phase_tier_whitelist_pass_event;
+pub mod update_sale_phase_tier_whitelist_event;
+pub mod update_sale_phase_tier_reservation_event;
+pub mod claim_reservation_event;
+pub mod update_sale_phase_tier_airdrop_claim_event;
+pub mod claim_airdrop_event;
+pub mod update_sale_phase_fill_reward_event;
+pub mod fund_fill_fee_vault_event;
+pub mod withdraw_fill_fee_vault_event;
+pub mod realloc_account_event;
\ No newline at end of file
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct PayInstallmentEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub tier_id: String,
+
+    pub order_id: String,
+
+    pub user: Pubkey,
+
+    pub user_payer: Pubkey,
+
+    pub payment_token_mint_account: Option<Pubkey>,
+
+    pub pyth_expo: u64,
+
+    pub pyth_price: u64,
+
+    pub payment_in_usd: u64,
+
+    /// share of the order price counted toward the phase caps by this payment
+    pub paid_price_in_usd: u64,
+
+    pub payment_amount: u64,
+
+    pub max_payment_amount: u64,
+
+    pub installment_paid_in_usd: u64,
+
+    pub installment_owed_in_usd: u64,
+
+    pub is_paid: bool,
+}
\ No newline at end of file
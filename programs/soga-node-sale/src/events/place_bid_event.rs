@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct PlaceBidEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub tier_id: String,
+
+    pub user: Pubkey,
+
+    pub bid_hash: [u8; 32],
+
+    pub deposit: u64,
+}
\ No newline at end of file
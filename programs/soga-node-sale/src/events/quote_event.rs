@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct QuoteEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub tier_id: String,
+
+    pub quantity: u64,
+
+    pub unit_prices_in_usd: Vec<u64>,
+
+    pub total_price_in_usd: u64,
+}
\ No newline at end of file
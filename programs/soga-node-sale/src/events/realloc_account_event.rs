@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ReallocAccountEvent {
+    pub timestamp: i64,
+
+    pub account: Pubkey,
+
+    pub layout_version: u8,
+
+    pub previous_space: u64,
+
+    pub space: u64,
+}
\ No newline at end of file
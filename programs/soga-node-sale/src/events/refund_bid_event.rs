@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct RefundBidEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub tier_id: String,
+
+    pub user: Pubkey,
+
+    pub is_revealed: bool,
+
+    pub refund_amount: u64,
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct RefundCommitmentEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub tier_id: String,
+
+    pub user: Pubkey,
+
+    pub payment_token_mint_account: Option<Pubkey>,
+
+    pub refund_amount: u64,
+}
\ No newline at end of file
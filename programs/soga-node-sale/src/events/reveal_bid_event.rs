@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct RevealBidEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub tier_id: String,
+
+    pub user: Pubkey,
+
+    pub price: u64,
+
+    pub is_winning: bool,
+
+    pub lowest_winning_bid: u64,
+}
\ No newline at end of file
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct RevokeNodeEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub tier_id: String,
+
+    pub order_id: String,
+
+    pub token_id: String,
+
+    pub user: Pubkey,
+
+    pub node_mint_account: Option<Pubkey>,
+
+    pub is_completed: bool,
+}
\ No newline at end of file
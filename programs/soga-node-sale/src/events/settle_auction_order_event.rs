@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct SettleAuctionOrderEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub tier_id: String,
+
+    pub order_id: String,
+
+    pub user: Pubkey,
+
+    pub payment_receiver: Pubkey,
+
+    pub payment_token_mint_account: Option<Pubkey>,
+
+    pub clearing_price_in_usd: u64,
+
+    pub escrow_amount: u64,
+
+    pub rebate_amount: u64,
+
+    pub payment_receiver_amount: u64,
+}
\ No newline at end of file
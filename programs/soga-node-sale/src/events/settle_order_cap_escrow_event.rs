@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct SettleOrderCapEscrowEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub order_id: String,
+
+    pub user: Pubkey,
+
+    pub payment_receiver: Pubkey,
+
+    pub payment_token_mint_account: Option<Pubkey>,
+
+    pub escrow_amount: u64,
+
+    /// referral shares released from the escrow
+    pub full_discount_amount: u64,
+
+    pub half_discount_amount: u64,
+
+    /// the phase missed its soft cap, the escrow is refunded and the order voided
+    pub is_refunded: bool,
+}
\ No newline at end of file
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct SettleWinningBidEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub tier_id: String,
+
+    pub order_id: String,
+
+    pub user: Pubkey,
+
+    pub payment_receiver: Pubkey,
+
+    pub bid_price: u64,
+
+    pub price: u64,
+
+    pub price_in_usd: u64,
+
+    pub cap_escrow_amount: u64,
+
+    pub refund_amount: u64,
+}
\ No newline at end of file
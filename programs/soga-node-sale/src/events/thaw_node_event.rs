@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ThawNodeEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub tier_id: String,
+
+    pub token_id: String,
+
+    pub user: Pubkey,
+
+    pub node_mint_account: Pubkey,
+}
\ No newline at end of file
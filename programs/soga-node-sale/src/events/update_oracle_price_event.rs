@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct UpdateOraclePriceEvent {
+    pub timestamp: i64,
+
+    pub name: String,
+
+    pub oracle_price: Pubkey,
+
+    pub price: i64,
+
+    pub conf: u64,
+
+    pub exponent: i32,
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct UpdateSalePhaseCapsEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub soft_cap: u64,
+
+    pub hard_cap: u64,
+
+    pub start_timestamp: i64,
+
+    pub end_timestamp: i64,
+}
\ No newline at end of file
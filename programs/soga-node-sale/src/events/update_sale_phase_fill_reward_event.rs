@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct UpdateSalePhaseFillRewardEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub fill_reward: u64,
+
+    pub fill_fee_vault: Pubkey,
+}
\ No newline at end of file
@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct UpdateSalePhaseInstallmentEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub installment_deposit_bps: u16,
+
+    pub installment_period: i64,
+
+    pub is_installment_deposit_refundable: bool,
+}
\ No newline at end of file
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct UpdateSalePhaseLoyaltyEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub loyalty_discount: u16,
+
+    pub loyalty_collections: Vec<Pubkey>,
+}
\ No newline at end of file
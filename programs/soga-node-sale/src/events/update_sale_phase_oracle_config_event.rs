@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+use crate::oracle::OracleConfig;
+
+#[event]
+pub struct UpdateSalePhaseOracleConfigEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub payment_token_mint_account: Option<Pubkey>,
+
+    pub oracle_config: OracleConfig,
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct UpdateSalePhasePaymentTokenFixedRateEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub mint: Pubkey,
+
+    pub is_fixed_rate: bool,
+
+    pub fixed_rate: u64,
+
+    pub max_depeg_bps: u16,
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+use crate::oracle::PriceSource;
+
+#[event]
+pub struct UpdateSalePhasePriceSourcesEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub payment_token_mint_account: Option<Pubkey>,
+
+    pub price_sources: Vec<PriceSource>,
+
+    pub price_source_quorum: u8,
+}
\ No newline at end of file
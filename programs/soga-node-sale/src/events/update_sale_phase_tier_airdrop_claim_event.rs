@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct UpdateSalePhaseTierAirdropClaimEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub tier_id: String,
+
+    pub merkle_root: [u8; 32],
+
+    pub total_claims: u32,
+
+    pub total_quantity: u64,
+
+    pub total_reserved: u64,
+}
\ No newline at end of file
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct UpdateSalePhaseTierDutchAuctionEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub tier_id: String,
+
+    pub start_timestamp: i64,
+
+    pub end_timestamp: i64,
+
+    pub start_price: u64,
+
+    pub floor_price: u64,
+
+    pub is_rebate: bool,
+}
\ No newline at end of file
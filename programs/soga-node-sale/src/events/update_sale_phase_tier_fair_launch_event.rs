@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::states::AllocationMode;
+
+#[event]
+pub struct UpdateSalePhaseTierFairLaunchEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub tier_id: String,
+
+    pub is_fair_launch: bool,
+
+    pub commit_start_timestamp: i64,
+
+    pub commit_end_timestamp: i64,
+
+    pub allocation_mode: AllocationMode,
+
+    pub allocation_end_timestamp: i64,
+}
\ No newline at end of file
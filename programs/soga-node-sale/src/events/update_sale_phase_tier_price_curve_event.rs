@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+use crate::states::PriceCurve;
+
+#[event]
+pub struct UpdateSalePhaseTierPriceCurveEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub tier_id: String,
+
+    pub price_curve: PriceCurve,
+}
\ No newline at end of file
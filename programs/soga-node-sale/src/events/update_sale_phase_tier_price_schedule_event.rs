@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+use crate::states::PriceSchedule;
+
+#[event]
+pub struct UpdateSalePhaseTierPriceScheduleEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub tier_id: String,
+
+    pub price_schedule: PriceSchedule,
+}
\ No newline at end of file
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct UpdateSalePhaseTierReservationEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub tier_id: String,
+
+    pub partner: Pubkey,
+
+    pub quantity: u64,
+
+    pub price: u64,
+
+    pub total_reserved: u64,
+}
\ No newline at end of file
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct UpdateSalePhaseTierSealedBidAuctionEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub tier_id: String,
+
+    pub is_sealed_bid_auction: bool,
+
+    pub bid_end_timestamp: i64,
+
+    pub reveal_end_timestamp: i64,
+
+    pub reserve_price: u64,
+
+    pub is_uniform_price: bool,
+}
\ No newline at end of file
@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct UpdateSalePhaseTierUnlockEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub tier_id: String,
+
+    pub unlock_timestamp: i64,
+
+    pub is_unlocked: bool,
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+use crate::states::VolumeBracket;
+
+#[event]
+pub struct UpdateSalePhaseTierVolumeBracketsEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub tier_id: String,
+
+    pub volume_brackets: Vec<VolumeBracket>,
+
+    pub is_volume_lifetime: bool,
+}
\ No newline at end of file
@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct UpdateSalePhaseTierWhitelistEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub tier_id: String,
+
+    pub whitelist_price: u64,
+
+    pub whitelist_mint_limit: u64,
+}
\ No newline at end of file
@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct UpdateSalePhaseTierWhitelistPassEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub tier_id: String,
+
+    pub whitelist_pass_mint: Option<Pubkey>,
+
+    pub is_whitelist_pass_burned: bool,
+}
\ No newline at end of file
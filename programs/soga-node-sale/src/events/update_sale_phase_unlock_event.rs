@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct UpdateSalePhaseUnlockEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub unlock_timestamp: i64,
+}
\ No newline at end of file
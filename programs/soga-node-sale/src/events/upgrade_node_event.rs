@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct UpgradeNodeEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub source_tier_id: String,
+
+    pub target_tier_id: String,
+
+    pub source_order_id: String,
+
+    pub token_id: String,
+
+    pub order_id: String,
+
+    pub user: Pubkey,
+
+    pub user_payer: Pubkey,
+
+    pub price_feed: Pubkey,
+
+    pub payment_receiver: Pubkey,
+
+    pub payment_token_mint_account: Option<Pubkey>,
+
+    pub node_mint_account: Option<Pubkey>,
+
+    pub total_price_in_lamport: u64,
+
+    pub total_price_in_usd: u64,
+
+    pub pyth_expo: u64,
+
+    pub pyth_price: u64,
+}
\ No newline at end of file
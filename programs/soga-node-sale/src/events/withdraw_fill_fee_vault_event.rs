@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct WithdrawFillFeeVaultEvent {
+    pub timestamp: i64,
+
+    pub sale_phase_name: String,
+
+    pub payment_receiver: Pubkey,
+
+    pub amount: u64,
+
+    pub total_withdrawn: u64,
+}
\ No newline at end of file
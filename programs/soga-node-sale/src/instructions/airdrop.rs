@@ -21,6 +21,7 @@ use crate::states::{
     UserTierDetailAccount,
     COLLECTION_ACCOUNT_PREFIX,
     NODE_ACCOUNT_PREFIX,
+    NodeMintMode,
 };
 
 use crate::events::{AirdropEvent};
@@ -33,8 +34,12 @@ use crate::utils::{
     check_mint_limit,
     check_phase_airdrop,
     check_phase_tier_airdrop,
-    check_token_id_out_of_range,
-    check_tier_id
+    check_token_quantity_out_of_range,
+    check_tier_id,
+    check_mint_mode,
+    check_phase_tier_is_compressed,
+    get_unlock_timestamp,
+    is_node_locked,
 };
 
 #[derive(Accounts)]
@@ -166,10 +171,14 @@ pub fn handle_airdrop<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, Airdrop
 
     check_signing_authority(sale_phase_detail.signing_authority, ctx.accounts.signing_authority.key())?;
 
+    check_mint_mode(sale_phase_detail.mint_mode, NodeMintMode::Metaplex)?;
+
     check_tier_id(sale_phase_detail.total_completed_tiers + 1, tier_id_int)?;
 
     check_phase_tier_collection(sale_phase_tier_detail.collection_mint_address, ctx.accounts.collection_mint_account.key())?;
 
+    check_phase_tier_is_compressed(sale_phase_tier_detail.is_compressed)?;
+
     check_phase_tier_is_completed(sale_phase_tier_detail.is_completed)?;
 
     let token_id_int: u64 = token_id.clone().parse().unwrap();
@@ -177,7 +186,7 @@ pub fn handle_airdrop<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, Airdrop
 
     check_token_id(current_token_id, token_id_int)?;
 
-    check_token_id_out_of_range(sale_phase_tier_detail.total_mint, token_id_int, sale_phase_tier_detail.quantity)?;
+    check_token_quantity_out_of_range(sale_phase_tier_detail.minted_supply() + 1, sale_phase_tier_detail.public_quantity())?;
 
     let user_tier_detail: &Box<Account<UserTierDetailAccount>> = &ctx.accounts.user_tier_detail;
 
@@ -207,18 +216,23 @@ pub fn handle_airdrop<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, Airdrop
 
     mint_to(mint_to_cpi_context, 1)?;
 
-    // freeze delegate account
-    let freeze_account_cpi_context = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
-        FreezeAccount {
-            mint: ctx.accounts.node_mint_account.to_account_info(),
-            account: ctx.accounts.user_token_account.to_account_info(),
-            authority: ctx.accounts.sale_phase_tier_detail.to_account_info(),
-        },
-        signer,
-    );
-
-    freeze_account(freeze_account_cpi_context)?;
+    // freeze delegate account, the master edition takes over the freeze authority so nodes
+    // frozen here can not be thawed, nodes minted after unlock are left transferable
+    let unlock_timestamp: i64 = get_unlock_timestamp(sale_phase_detail.unlock_timestamp, sale_phase_tier_detail.unlock_timestamp);
+
+    if is_node_locked(sale_phase_tier_detail.is_unlocked, unlock_timestamp, timestamp) {
+        let freeze_account_cpi_context = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            FreezeAccount {
+                mint: ctx.accounts.node_mint_account.to_account_info(),
+                account: ctx.accounts.user_token_account.to_account_info(),
+                authority: ctx.accounts.sale_phase_tier_detail.to_account_info(),
+            },
+            signer,
+        );
+
+        freeze_account(freeze_account_cpi_context)?;
+    }
 
     // create metadata account
     let data_v2 = DataV2 {
@@ -304,7 +318,7 @@ pub fn handle_airdrop<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, Airdrop
     sale_phase_tier_detail.total_airdrop += 1;
     sale_phase_tier_detail.last_block_timestamp = timestamp;
 
-    if sale_phase_tier_detail.total_mint >= sale_phase_tier_detail.quantity {
+    if sale_phase_tier_detail.minted_supply() >= sale_phase_tier_detail.public_quantity() {
         sale_phase_tier_detail.is_completed = true;
         sale_phase_detail.total_completed_tiers += 1;
     }
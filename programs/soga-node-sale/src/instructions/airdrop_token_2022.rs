@@ -0,0 +1,300 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    associated_token::{AssociatedToken, Create, create},
+    token_2022::{Token2022, MintTo, mint_to, FreezeAccount, freeze_account},
+};
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+    USER_DETAIL_ACCOUNT_PREFIX,
+    UserDetailAccount,
+    USER_TIER_DETAIL_ACCOUNT_PREFIX,
+    UserTierDetailAccount,
+    COLLECTION_ACCOUNT_PREFIX,
+    NODE_ACCOUNT_PREFIX,
+    NodeMintMode,
+};
+
+use crate::events::{AirdropEvent};
+
+use crate::token_extensions::create_node_member_mint;
+
+use crate::utils::{
+    check_signing_authority,
+    check_phase_tier_collection,
+    check_phase_tier_is_completed,
+    check_token_id,
+    check_mint_limit,
+    check_phase_airdrop,
+    check_phase_tier_airdrop,
+    check_token_quantity_out_of_range,
+    check_tier_id,
+    check_mint_mode,
+    get_unlock_timestamp,
+    is_node_locked,
+};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8,
+_collection_mint_account_bump: u8, sale_phase_name: String, tier_id: String, token_id: String)]
+pub struct AirdropToken2022InputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub signing_authority: Signer<'info>,
+
+    /// CHECK: user
+    #[account(mut)]
+    pub user: AccountInfo<'info>,
+
+    #[account(
+    mut,
+    seeds = [
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_name.as_ref(),
+    ],
+    bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    tier_id.as_ref()
+    ],
+    bump = _sale_phase_tier_detail_bump,
+    )]
+    pub sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+
+    #[account(
+    init_if_needed,
+    payer = payer,
+    space = UserDetailAccount::space(),
+    seeds = [
+    USER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    user.key().as_ref(),
+    ],
+    bump,
+    )]
+    pub user_detail: Box<Account<'info, UserDetailAccount>>,
+
+    #[account(
+    init_if_needed,
+    payer = payer,
+    space = UserTierDetailAccount::space(),
+    seeds = [
+    USER_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    user_detail.key().as_ref(),
+    sale_phase_tier_detail.key().as_ref(),
+    ],
+    bump,
+    )]
+    pub user_tier_detail: Box<Account<'info, UserTierDetailAccount>>,
+
+    /// CHECK: token-2022 collection group mint
+    #[account(
+    mut,
+    seeds = [
+    COLLECTION_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_tier_detail.key().as_ref(),
+    ],
+    bump = _collection_mint_account_bump,
+    owner = token_program.key(),
+    )]
+    pub collection_mint_account: UncheckedAccount<'info>,
+
+    /// CHECK: node mint, created with token-2022 extensions in the instruction
+    #[account(
+    mut,
+    seeds = [
+    NODE_ACCOUNT_PREFIX.as_ref(),
+    collection_mint_account.key().as_ref(),
+    token_id.as_ref()
+    ],
+    bump,
+    )]
+    pub node_mint_account: UncheckedAccount<'info>,
+
+    /// CHECK: user associated token account, created in the instruction
+    #[account(mut)]
+    pub user_token_account: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handle_airdrop_token_2022<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, AirdropToken2022InputAccounts<'info>>,
+                                                    _sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8,
+                                                    _collection_mint_account_bump: u8, sale_phase_name: String, tier_id: String, token_id: String,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let tier_id_int: u32 = tier_id.clone().parse().unwrap();
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+    let sale_phase_tier_detail: &Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &ctx.accounts.sale_phase_tier_detail;
+
+    // Checks
+    check_phase_airdrop(sale_phase_detail.airdrop_enable)?;
+    check_phase_tier_airdrop(sale_phase_tier_detail.airdrop_enable)?;
+
+    check_signing_authority(sale_phase_detail.signing_authority, ctx.accounts.signing_authority.key())?;
+
+    check_mint_mode(sale_phase_detail.mint_mode, NodeMintMode::Token2022)?;
+
+    check_tier_id(sale_phase_detail.total_completed_tiers + 1, tier_id_int)?;
+
+    check_phase_tier_collection(sale_phase_tier_detail.collection_mint_address, ctx.accounts.collection_mint_account.key())?;
+
+    check_phase_tier_is_completed(sale_phase_tier_detail.is_completed)?;
+
+    let token_id_int: u64 = token_id.clone().parse().unwrap();
+    let current_token_id: u64 = sale_phase_tier_detail.total_mint + 1;
+
+    check_token_id(current_token_id, token_id_int)?;
+
+    check_token_quantity_out_of_range(sale_phase_tier_detail.minted_supply() + 1, sale_phase_tier_detail.public_quantity())?;
+
+    let user_tier_detail: &Box<Account<UserTierDetailAccount>> = &ctx.accounts.user_tier_detail;
+
+    check_mint_limit(sale_phase_tier_detail.mint_limit, user_tier_detail.total_mint)?;
+
+    let sale_phase_detail_key: Pubkey = ctx.accounts.sale_phase_detail.key();
+    let collection_mint_account_key: Pubkey = ctx.accounts.collection_mint_account.key();
+
+    let signer_seeds = &[
+        SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail_key.as_ref(),
+        tier_id.as_ref(),
+        &[_sale_phase_tier_detail_bump],
+    ];
+
+    let signer = &[&signer_seeds[..]];
+
+    let node_mint_signer_seeds = &[
+        NODE_ACCOUNT_PREFIX.as_ref(),
+        collection_mint_account_key.as_ref(),
+        token_id.as_ref(),
+        &[ctx.bumps.node_mint_account],
+    ];
+
+    let node_mint_signer = &[&node_mint_signer_seeds[..]];
+
+    // nodes are frozen until the unlock, thaw_node thaws them once a schedule is set and elapsed
+    let unlock_timestamp: i64 = get_unlock_timestamp(sale_phase_detail.unlock_timestamp, sale_phase_tier_detail.unlock_timestamp);
+    let is_locked: bool = is_node_locked(sale_phase_tier_detail.is_unlocked, unlock_timestamp, timestamp);
+
+    // create node member mint
+    create_node_member_mint(
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.node_mint_account.to_account_info(),
+        &ctx.accounts.collection_mint_account.to_account_info(),
+        &ctx.accounts.sale_phase_tier_detail.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        node_mint_signer,
+        signer,
+        sale_phase_detail.name.clone(),
+        sale_phase_detail.symbol.clone(),
+        sale_phase_detail.metadata_base_uri.clone(),
+    )?;
+
+    // create user token account
+    let create_cpi_context = CpiContext::new(
+        ctx.accounts.associated_token_program.to_account_info(),
+        Create {
+            payer: ctx.accounts.payer.to_account_info(),
+            associated_token: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.user.to_account_info(),
+            mint: ctx.accounts.node_mint_account.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+    );
+
+    create(create_cpi_context)?;
+
+    // mint node
+    let mint_to_cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        MintTo {
+            mint: ctx.accounts.node_mint_account.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.sale_phase_tier_detail.to_account_info(),
+        },
+        signer,
+    );
+
+    mint_to(mint_to_cpi_context, 1)?;
+
+    // freeze user token account until unlock
+    if is_locked {
+        let freeze_account_cpi_context = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            FreezeAccount {
+                mint: ctx.accounts.node_mint_account.to_account_info(),
+                account: ctx.accounts.user_token_account.to_account_info(),
+                authority: ctx.accounts.sale_phase_tier_detail.to_account_info(),
+            },
+            signer,
+        );
+
+        freeze_account(freeze_account_cpi_context)?;
+    }
+
+
+    // Update
+    let sale_phase_detail: &mut Box<Account<SogaNodeSalePhaseDetailAccount>> = &mut ctx.accounts.sale_phase_detail;
+    sale_phase_detail.total_mint += 1;
+    sale_phase_detail.total_airdrop += 1;
+    sale_phase_detail.last_block_timestamp = timestamp;
+
+
+    let sale_phase_tier_detail: &mut Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &mut ctx.accounts.sale_phase_tier_detail;
+    sale_phase_tier_detail.total_mint += 1;
+    sale_phase_tier_detail.total_airdrop += 1;
+    sale_phase_tier_detail.last_block_timestamp = timestamp;
+
+    if sale_phase_tier_detail.minted_supply() >= sale_phase_tier_detail.public_quantity() {
+        sale_phase_tier_detail.is_completed = true;
+        sale_phase_detail.total_completed_tiers += 1;
+    }
+
+    let user_detail: &mut Box<Account<UserDetailAccount>> = &mut ctx.accounts.user_detail;
+    user_detail.total_mint += 1;
+    user_detail.total_airdrop += 1;
+    user_detail.last_block_timestamp = timestamp;
+
+    let user_tier_detail: &mut Box<Account<UserTierDetailAccount>> = &mut ctx.accounts.user_tier_detail;
+    user_tier_detail.total_mint += 1;
+    user_tier_detail.total_airdrop += 1;
+    user_tier_detail.last_block_timestamp = timestamp;
+
+    // Event
+
+    let event: AirdropEvent = AirdropEvent {
+        timestamp,
+        sale_phase_name,
+        tier_id,
+        token_id,
+        user: ctx.accounts.user.key(),
+        collection_mint_account: ctx.accounts.collection_mint_account.key(),
+        node_mint_account: ctx.accounts.node_mint_account.key(),
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,328 @@
+use std::ops::{Mul, Sub};
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    token_interface::{Mint, TokenAccount, TransferChecked, transfer_checked},
+};
+
+use crate::error::SogaNodeSaleError;
+
+use crate::allocation::{get_allocation, get_allocated_payment};
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+    USER_DETAIL_ACCOUNT_PREFIX,
+    UserDetailAccount,
+    USER_TIER_DETAIL_ACCOUNT_PREFIX,
+    UserTierDetailAccount,
+    ORDER_DETAIL_ACCOUNT_PREFIX,
+    OrderDetailAccount,
+    COMMITMENT_DETAIL_ACCOUNT_PREFIX,
+    CommitmentDetailAccount,
+};
+
+use crate::events::{
+    AllocateCommitmentEvent
+};
+
+use crate::utils::{check_payment_receiver, check_order_id, check_payment_token_mint_account, check_fair_launch_finalized, check_commitment_allocated, check_allocation_quantity, check_commitment_escrow, check_allocation_ended, check_payment_token_program, check_hard_cap, check_cap_escrow};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8,
+sale_phase_name: String, tier_id: String, order_id: String, quantity: u64)]
+pub struct AllocateCommitmentInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: user
+    pub user: AccountInfo<'info>,
+
+    /// CHECK: payment receiver, checked against the sale phase detail
+    #[account(mut)]
+    pub payment_receiver: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+        SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_name.as_ref(),
+        ],
+        bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+        SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail.key().as_ref(),
+        tier_id.as_ref()
+        ],
+        bump = _sale_phase_tier_detail_bump,
+    )]
+    pub sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+        COMMITMENT_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_tier_detail.key().as_ref(),
+        user.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub commitment_detail: Box<Account<'info, CommitmentDetailAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserDetailAccount::space(),
+        seeds = [
+        USER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail.key().as_ref(),
+        user.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub user_detail: Box<Account<'info, UserDetailAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserTierDetailAccount::space(),
+        seeds = [
+        USER_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        user_detail.key().as_ref(),
+        sale_phase_tier_detail.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub user_tier_detail: Box<Account<'info, UserTierDetailAccount>>,
+
+    /// empty when the commitment is not allocated any node
+    #[account(
+        init,
+        payer = payer,
+        space = OrderDetailAccount::space(quantity),
+        seeds = [
+        ORDER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail.key().as_ref(),
+        user_detail.key().as_ref(),
+        order_id.as_ref(),
+        ],
+        bump,
+    )]
+    pub order_detail: Box<Account<'info, OrderDetailAccount>>,
+
+    /// required for payment token commitments until the soft cap is reached
+    /// CHECK: token account owned by the sale phase detail, checked in the handler
+    #[account(mut)]
+    pub cap_escrow: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_allocate_commitment<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, AllocateCommitmentInputAccounts<'info>>,
+                                                     _sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8,
+                                                     sale_phase_name: String, tier_id: String, order_id: String, quantity: u64,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let order_id_int: u64 = order_id.clone().parse().unwrap();
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+    let sale_phase_tier_detail: &Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &ctx.accounts.sale_phase_tier_detail;
+    let commitment_detail: &Box<Account<CommitmentDetailAccount>> = &ctx.accounts.commitment_detail;
+    let user_detail: &Box<Account<UserDetailAccount>> = &ctx.accounts.user_detail;
+
+    // Checks
+    check_payment_receiver(sale_phase_detail.payment_receiver, ctx.accounts.payment_receiver.key())?;
+
+    check_fair_launch_finalized(sale_phase_tier_detail.is_fair_launch_finalized)?;
+
+    check_commitment_allocated(commitment_detail.is_allocated)?;
+
+    check_allocation_ended(sale_phase_tier_detail.is_allocation_ended(timestamp))?;
+
+    check_order_id(user_detail.total_orders + 1, order_id_int)?;
+
+    let allocated_quantity: u64 = get_allocation(sale_phase_tier_detail.allocation_mode, &sale_phase_tier_detail.lottery_seed, &commitment_detail.ticket_ranges,
+                                                 sale_phase_tier_detail.total_committed, sale_phase_tier_detail.fair_launch_supply)?;
+
+    check_allocation_quantity(allocated_quantity, quantity)?;
+
+    // Make Payment
+    let payment_in_usd: u64 = sale_phase_tier_detail.price.mul(allocated_quantity);
+    let payment: u64 = get_allocated_payment(commitment_detail.total_payment, allocated_quantity, commitment_detail.quantity)?;
+    let refund_amount: u64 = commitment_detail.total_payment.sub(payment);
+
+    check_hard_cap(sale_phase_detail.committed_payment() + payment_in_usd, sale_phase_detail.hard_cap)?;
+
+    // the phase holds the payment until the soft cap is reached
+    let cap_escrow_amount: u64 = if sale_phase_detail.is_soft_cap_reached() { 0 } else { payment };
+
+    match commitment_detail.payment_token_mint_account {
+        None => {
+            // the sol deposit is held as lamports of the sale phase tier detail
+            let sale_phase_tier_detail_info: AccountInfo = sale_phase_tier_detail.to_account_info();
+            let deposit_receiver: AccountInfo = if cap_escrow_amount > 0 { sale_phase_detail.to_account_info() } else { ctx.accounts.payment_receiver.to_account_info() };
+
+            **sale_phase_tier_detail_info.try_borrow_mut_lamports()? -= payment;
+            **deposit_receiver.try_borrow_mut_lamports()? += payment;
+        }
+        Some(payment_token_mint) => {
+            let payment_token_mint_account = &ctx.remaining_accounts[0];
+            let payment_token_program = &ctx.remaining_accounts[1];
+            let commitment_escrow = &ctx.remaining_accounts[2];
+            let payment_token_payment_receiver_token_account = &ctx.remaining_accounts[3];
+
+            check_payment_token_mint_account(payment_token_mint, payment_token_mint_account.key())?;
+
+            check_payment_token_program(payment_token_program.key(), *payment_token_mint_account.owner, *commitment_escrow.owner)?;
+
+            let commitment_escrow_token: TokenAccount = TokenAccount::try_deserialize(&mut &commitment_escrow.try_borrow_data()?[..])?;
+            check_commitment_escrow(commitment_escrow_token.owner, sale_phase_tier_detail.key(), commitment_escrow_token.mint, payment_token_mint)?;
+
+            let payment_receiver_token: TokenAccount = TokenAccount::try_deserialize(&mut &payment_token_payment_receiver_token_account.try_borrow_data()?[..])?;
+            check_payment_receiver(sale_phase_detail.payment_receiver, payment_receiver_token.owner)?;
+
+            let cap_escrow: Option<&AccountInfo> = if cap_escrow_amount > 0 {
+                let cap_escrow = ctx.accounts.cap_escrow.as_deref().ok_or(SogaNodeSaleError::InvalidCapEscrow)?;
+                let cap_escrow_token: TokenAccount = TokenAccount::try_deserialize(&mut &cap_escrow.try_borrow_data()?[..])?;
+
+                check_cap_escrow(cap_escrow_token.owner, sale_phase_detail.key(), cap_escrow_token.mint, payment_token_mint)?;
+
+                Some(cap_escrow)
+            } else { None };
+
+            let decimals: u8 = Mint::try_deserialize(&mut &payment_token_mint_account.try_borrow_data()?[..])?.decimals;
+
+            let sale_phase_detail_key: Pubkey = sale_phase_detail.key();
+
+            let signer_seeds = &[
+                SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+                sale_phase_detail_key.as_ref(),
+                tier_id.as_ref(),
+                &[_sale_phase_tier_detail_bump],
+            ];
+
+            let signer = &[&signer_seeds[..]];
+
+            if payment > 0 {
+                let cpi_accounts = TransferChecked {
+                    from: commitment_escrow.to_account_info(),
+                    mint: payment_token_mint_account.to_account_info(),
+                    to: cap_escrow.unwrap_or(payment_token_payment_receiver_token_account).to_account_info(),
+                    authority: sale_phase_tier_detail.to_account_info(),
+                };
+                let cpi_program = payment_token_program.to_account_info();
+                let cpi_context = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+                transfer_checked(cpi_context, payment, decimals)?;
+            }
+        }
+    }
+
+    // Update
+    let payment_token_mint_account: Option<Pubkey> = commitment_detail.payment_token_mint_account;
+
+    let order_detail: &mut Box<Account<OrderDetailAccount>> = &mut ctx.accounts.order_detail;
+    order_detail.last_block_timestamp = timestamp;
+    order_detail.tier_id = tier_id.clone().parse().unwrap();
+    order_detail.is_completed = false;
+    order_detail.quantity = allocated_quantity;
+    order_detail.total_payment_in_usd = payment_in_usd;
+    order_detail.total_user_discount_in_usd = payment_in_usd;
+    order_detail.total_discount_in_usd = 0;
+    order_detail.total_payment = payment;
+    order_detail.total_user_discount = payment;
+    order_detail.total_discount = 0;
+    order_detail.payment_token_mint_account = payment_token_mint_account;
+    order_detail.token_ids = Vec::with_capacity(allocated_quantity as usize);
+    order_detail.is_token_ids_minted = Vec::with_capacity(allocated_quantity as usize);
+    order_detail.is_token_ids_revoked = Vec::with_capacity(allocated_quantity as usize);
+    order_detail.is_token_ids_upgraded = Vec::with_capacity(allocated_quantity as usize);
+    order_detail.is_token_ids_reissued = Vec::with_capacity(allocated_quantity as usize);
+    order_detail.cap_escrow_amount = cap_escrow_amount;
+
+    let mut current_token_id: u64 = ctx.accounts.sale_phase_tier_detail.total_mint;
+
+    for _i in 0..allocated_quantity {
+        current_token_id += 1;
+        order_detail.token_ids.push(current_token_id);
+        order_detail.is_token_ids_minted.push(false);
+        order_detail.is_token_ids_revoked.push(false);
+        order_detail.is_token_ids_upgraded.push(false);
+        order_detail.is_token_ids_reissued.push(false);
+    };
+
+    let commitment_detail: &mut Box<Account<CommitmentDetailAccount>> = &mut ctx.accounts.commitment_detail;
+    commitment_detail.last_block_timestamp = timestamp;
+    commitment_detail.is_allocated = true;
+    commitment_detail.allocated_quantity = allocated_quantity;
+    commitment_detail.order_id = order_id_int;
+    commitment_detail.refund_amount = refund_amount;
+
+    let sale_phase_detail: &mut Box<Account<SogaNodeSalePhaseDetailAccount>> = &mut ctx.accounts.sale_phase_detail;
+    let sale_phase_tier_detail: &mut Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &mut ctx.accounts.sale_phase_tier_detail;
+    let user_detail: &mut Box<Account<UserDetailAccount>> = &mut ctx.accounts.user_detail;
+    let user_tier_detail: &mut Box<Account<UserTierDetailAccount>> = &mut ctx.accounts.user_tier_detail;
+
+    if payment_token_mint_account.is_some() {
+        sale_phase_detail.total_buy_with_token += allocated_quantity;
+        sale_phase_tier_detail.total_buy_with_token += allocated_quantity;
+        user_detail.total_buy_with_token += allocated_quantity;
+        user_tier_detail.total_buy_with_token += allocated_quantity;
+    } else {
+        sale_phase_detail.total_buy += allocated_quantity;
+        sale_phase_tier_detail.total_buy += allocated_quantity;
+        user_detail.total_buy += allocated_quantity;
+        user_tier_detail.total_buy += allocated_quantity;
+    }
+
+    sale_phase_detail.total_mint += allocated_quantity;
+    sale_phase_detail.total_payment += payment_in_usd;
+    sale_phase_detail.latch_soft_cap();
+    sale_phase_detail.last_block_timestamp = timestamp;
+
+    sale_phase_tier_detail.total_mint += allocated_quantity;
+    sale_phase_tier_detail.total_allocated += allocated_quantity;
+    sale_phase_tier_detail.total_payment += payment_in_usd;
+    sale_phase_tier_detail.last_block_timestamp = timestamp;
+
+    if !sale_phase_tier_detail.is_completed && sale_phase_tier_detail.minted_supply() >= sale_phase_tier_detail.public_quantity() {
+        sale_phase_tier_detail.is_completed = true;
+        sale_phase_detail.total_completed_tiers += 1;
+    }
+
+    user_detail.total_mint += allocated_quantity;
+    user_detail.total_payment += payment_in_usd;
+    user_detail.total_orders += 1;
+    user_detail.last_block_timestamp = timestamp;
+
+    user_tier_detail.total_mint += allocated_quantity;
+    user_tier_detail.total_payment += payment_in_usd;
+    user_tier_detail.last_block_timestamp = timestamp;
+
+    // Event
+    let event: AllocateCommitmentEvent = AllocateCommitmentEvent {
+        timestamp,
+        sale_phase_name,
+        tier_id,
+        order_id,
+        user: ctx.accounts.user.key(),
+        payment_receiver: ctx.accounts.payment_receiver.key(),
+        payment_token_mint_account,
+        committed_quantity: commitment_detail.quantity,
+        allocated_quantity,
+        payment_in_usd,
+        payment_amount: payment,
+        refund_amount,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
@@ -1,8 +1,13 @@
 use std::ops::{Add, Mul, Sub};
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
 
-use pyth_solana_receiver_sdk::price_update::{PriceUpdateV2, Price, get_feed_id_from_hex};
+use crate::oracle::{get_quorum_oracle_price, get_expo_base, OraclePrice};
+
+use crate::pricing::{usd_to_token_amount, bps_of, get_total_price, get_auction_escrow_amount, get_paid_price_in_usd, SOL_DECIMALS};
+
+use crate::loyalty::get_loyalty_collection_mint;
+
+use crate::whitelist::consume_whitelist_pass;
 
 use crate::states::{
     SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
@@ -15,13 +20,17 @@ use crate::states::{
     UserTierDetailAccount,
     ORDER_DETAIL_ACCOUNT_PREFIX,
     OrderDetailAccount,
+    LOYALTY_DETAIL_ACCOUNT_PREFIX,
+    LoyaltyDetailAccount,
 };
 
+use crate::error::SogaNodeSaleError;
+
 use crate::events::{
     BuyEvent
 };
 
-use crate::utils::{check_signing_authority, check_price_feed, check_payment_receiver, check_phase_tier_is_completed, check_token_quantity_out_of_range, check_phase_buy, check_phase_tier_buy, check_invalid_discount, check_quantity, check_tier_id, check_order_id, check_mint_limit_with_quantity, check_value_is_zero, check_invalid_user_discount, check_token_whitelist_quantity_out_of_range};
+use crate::utils::{check_signing_authority, check_price_feed, check_payment_receiver, check_phase_tier_is_completed, check_token_quantity_out_of_range, check_phase_buy, check_phase_tier_buy, check_invalid_discount, check_quantity, check_tier_id, check_order_id, check_mint_limit_with_quantity, check_value_is_zero, check_invalid_user_discount, check_token_whitelist_quantity_out_of_range, check_max_payment_amount, check_phase_tier_is_fair_launch, check_phase_tier_is_sealed_bid_auction, check_phase_started, check_phase_ended, check_hard_cap, check_installment_enabled, check_installment, check_loyalty_collection, check_loyalty_node_used, check_whitelist_pass_mint, check_whitelist_mint_limit};
 
 #[derive(Accounts)]
 #[instruction(_sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8,
@@ -99,7 +108,47 @@ pub struct BuyInputAccounts<'info> {
     )]
     pub order_detail: Box<Account<'info, OrderDetailAccount>>,
 
-    pub price_update: Account<'info, PriceUpdateV2>,
+    /// CHECK: price feed, owner and layout checked by the oracle source
+    pub price_update: UncheckedAccount<'info>,
+
+    /// CHECK: token-2022 or metaplex node mint of a loyalty collection held by the user, checked in the handler
+    pub loyalty_node_mint: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: token account of the user holding the loyalty node, checked in the handler
+    pub loyalty_token_account: Option<UncheckedAccount<'info>>,
+
+    /// required for metaplex loyalty nodes
+    /// CHECK: metadata account of the loyalty node mint, checked in the handler
+    pub loyalty_node_metadata: Option<UncheckedAccount<'info>>,
+
+    /// required with a loyalty node, each node grants the loyalty discount on one order of the phase
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = LoyaltyDetailAccount::space(),
+        seeds = [
+        LOYALTY_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail.key().as_ref(),
+        loyalty_node_mint.as_ref().map(|loyalty_node_mint| loyalty_node_mint.key()).unwrap_or_default().as_ref(),
+        ],
+        bump,
+    )]
+    pub loyalty_detail: Option<Box<Account<'info, LoyaltyDetailAccount>>>,
+
+    /// CHECK: pass mint of the tier, checked in the handler
+    #[account(mut)]
+    pub whitelist_pass_mint: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: token account of the user payer holding the passes, checked in the handler
+    #[account(mut)]
+    pub whitelist_pass_token_account: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: token account owned by the sale phase tier detail receiving the passes, checked in the handler
+    #[account(mut)]
+    pub whitelist_pass_escrow: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: token program of the pass mint, checked in the handler
+    pub whitelist_pass_token_program: Option<UncheckedAccount<'info>>,
 
     pub system_program: Program<'info, System>,
 
@@ -110,7 +159,7 @@ pub fn handle_buy<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, BuyInputAcc
                                      _sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8,
                                      sale_phase_name: String, tier_id: String, order_id: String, quantity: u64,
                                      allow_full_discount: bool, full_discount: u16, allow_half_discount: bool, half_discount: u16,
-                                     is_whitelist: bool, allow_user_discount: bool, user_discount: u16,
+                                     is_whitelist: bool, allow_user_discount: bool, user_discount: u16, max_payment_amount: u64, is_installment: bool,
 ) -> Result<()> {
     let timestamp = Clock::get().unwrap().unix_timestamp;
 
@@ -120,6 +169,12 @@ pub fn handle_buy<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, BuyInputAcc
     let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
     let sale_phase_tier_detail: &Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &ctx.accounts.sale_phase_tier_detail;
 
+    // tiers with a whitelist pass take the whitelist status from the pass instead of the signed flag
+    let is_whitelist: bool = match sale_phase_tier_detail.whitelist_pass_mint {
+        Some(_) => ctx.accounts.whitelist_pass_mint.is_some(),
+        None => is_whitelist,
+    };
+
     let payment_receiver = &ctx.remaining_accounts[0];
     let full_discount_receiver = &ctx.remaining_accounts[1];
     let half_discount_receiver = &ctx.remaining_accounts[2];
@@ -131,6 +186,14 @@ pub fn handle_buy<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, BuyInputAcc
 
     check_phase_tier_buy(sale_phase_tier_detail.buy_enable)?;
 
+    check_phase_started(sale_phase_detail.is_started(timestamp))?;
+
+    check_phase_ended(sale_phase_detail.is_ended(timestamp))?;
+
+    check_phase_tier_is_fair_launch(sale_phase_tier_detail.is_fair_launch_running())?;
+
+    check_phase_tier_is_sealed_bid_auction(sale_phase_tier_detail.is_sealed_bid_auction)?;
+
     check_signing_authority(sale_phase_detail.signing_authority, ctx.accounts.signing_authority.key())?;
 
     check_price_feed(sale_phase_detail.price_feed_address, ctx.accounts.price_update.key())?;
@@ -147,7 +210,7 @@ pub fn handle_buy<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, BuyInputAcc
 
     check_quantity(sale_phase_tier_detail.mint_limit, quantity)?;
 
-    check_token_quantity_out_of_range(sale_phase_tier_detail.total_mint + quantity, sale_phase_tier_detail.quantity)?;
+    check_token_quantity_out_of_range(sale_phase_tier_detail.minted_supply() + quantity, sale_phase_tier_detail.public_quantity())?;
 
     let user_detail: &Box<Account<UserDetailAccount>> = &ctx.accounts.user_detail;
 
@@ -157,30 +220,95 @@ pub fn handle_buy<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, BuyInputAcc
 
     check_mint_limit_with_quantity(sale_phase_tier_detail.mint_limit, user_tier_detail.total_mint + quantity)?;
 
+    if is_whitelist {
+        check_whitelist_mint_limit(sale_phase_tier_detail.whitelist_mint_limit, user_tier_detail.total_whitelist_mint + quantity)?;
+    }
+
     check_invalid_discount(full_discount, half_discount)?;
 
+    if is_installment {
+        check_installment_enabled(sale_phase_detail.installment_deposit_bps)?;
+
+        check_installment(allow_full_discount, allow_half_discount, sale_phase_tier_detail.is_auction_running(timestamp))?;
+    }
+
+    // Consume Whitelist Pass
+    let mut whitelist_pass_amount: u64 = 0;
+
+    if let Some(whitelist_pass_mint) = sale_phase_tier_detail.whitelist_pass_mint.filter(|_| is_whitelist) {
+        let whitelist_pass_mint_account = ctx.accounts.whitelist_pass_mint.as_deref().ok_or(SogaNodeSaleError::InvalidWhitelistPass)?;
+        let whitelist_pass_token_account = ctx.accounts.whitelist_pass_token_account.as_deref().ok_or(SogaNodeSaleError::InvalidWhitelistPass)?;
+        let whitelist_pass_token_program = ctx.accounts.whitelist_pass_token_program.as_deref().ok_or(SogaNodeSaleError::InvalidWhitelistPass)?;
+
+        check_whitelist_pass_mint(whitelist_pass_mint, whitelist_pass_mint_account.key())?;
+
+        let whitelist_pass_escrow = if sale_phase_tier_detail.is_whitelist_pass_burned { None } else {
+            Some(ctx.accounts.whitelist_pass_escrow.as_deref().ok_or(SogaNodeSaleError::InvalidWhitelistPassEscrow)?)
+        };
+
+        whitelist_pass_amount = consume_whitelist_pass(whitelist_pass_mint_account, whitelist_pass_token_account, whitelist_pass_escrow,
+                                                       whitelist_pass_token_program, &ctx.accounts.user_payer, sale_phase_tier_detail.key(), quantity)?;
+    }
 
     // Make Payment
-    let price_in_usd: u64 = sale_phase_tier_detail.price.mul(quantity);
+    // whitelist buys at the whitelist price are not part of a rebate auction
+    let is_whitelist_price: bool = is_whitelist && sale_phase_tier_detail.whitelist_price != 0;
 
-    let price_update = &mut ctx.accounts.price_update;
+    let unit_prices: Vec<u64> = if is_whitelist {
+        sale_phase_tier_detail.quote_whitelist(timestamp, quantity)?
+    } else {
+        sale_phase_tier_detail.quote(timestamp, quantity)?
+    };
+    let price_in_usd: u64 = get_total_price(&unit_prices)?;
 
-    let feed_id: [u8; 32] = get_feed_id_from_hex(sale_phase_detail.price_feed_id.as_str())?;
+    check_hard_cap(sale_phase_detail.committed_payment() + price_in_usd, sale_phase_detail.hard_cap)?;
 
-    let price: Price = price_update.get_price_no_older_than(&Clock::get()?,
-                                                            120,
-                                                            &feed_id,
-    )?;
+    // registered price sources follow the receivers in the remaining accounts
+    let price: OraclePrice = get_quorum_oracle_price(&ctx.accounts.price_update, &ctx.remaining_accounts[3..], sale_phase_detail.price_feed_id.as_str(),
+                                                     &sale_phase_detail.oracle_config, &sale_phase_detail.price_sources, sale_phase_detail.price_source_quorum)?;
 
-    let pyth_expo: u64 = 10_u64.pow(price.exponent.abs().try_into().unwrap());
-    let pyth_price: u64 = u64::try_from(price.price).unwrap();
-    let price_in_lamport: u64 = LAMPORTS_PER_SOL.checked_mul(pyth_expo).unwrap().checked_div(pyth_price).unwrap().checked_mul(price_in_usd).unwrap();
+    let pyth_expo: u64 = get_expo_base(&price);
+    let pyth_price: u64 = price.price as u64;
+    let price_in_lamport: u64 = usd_to_token_amount(price_in_usd, SOL_DECIMALS, pyth_price, price.exponent)?;
+
+    // volume discount of the tier brackets, applied before the user discount
+    let volume_discount: u16 = sale_phase_tier_detail.volume_discount(quantity, ctx.accounts.user_tier_detail.total_mint);
+
+    let volume_discount_in_usd: u64 = bps_of(price_in_usd, volume_discount)?;
+    let volume_discount_in_lamport: u64 = bps_of(price_in_lamport, volume_discount)?;
+
+    let after_volume_discount_in_usd: u64 = price_in_usd - volume_discount_in_usd;
+    let after_volume_discount_in_lamport: u64 = price_in_lamport - volume_discount_in_lamport;
+
+    // loyalty discount of a node held from a tier of an earlier phase, applied after the volume discount
+    let mut loyalty_discount: u16 = 0;
+    let mut loyalty_node_mint_account: Option<Pubkey> = None;
+
+    if let (Some(loyalty_node_mint), Some(loyalty_token_account)) = (ctx.accounts.loyalty_node_mint.as_deref(), ctx.accounts.loyalty_token_account.as_deref()) {
+        let loyalty_collection_mint: Pubkey = get_loyalty_collection_mint(ctx.accounts.user.key(), loyalty_node_mint, loyalty_token_account,
+                                                                          ctx.accounts.loyalty_node_metadata.as_deref())?;
+
+        check_loyalty_collection(sale_phase_detail.loyalty_collections.contains(&loyalty_collection_mint))?;
+
+        let loyalty_detail = ctx.accounts.loyalty_detail.as_deref().ok_or(SogaNodeSaleError::InvalidLoyaltyNode)?;
+
+        check_loyalty_node_used(loyalty_detail.is_used)?;
+
+        loyalty_discount = sale_phase_detail.loyalty_discount;
+        loyalty_node_mint_account = Some(loyalty_node_mint.key());
+    }
+
+    let loyalty_discount_in_usd: u64 = bps_of(after_volume_discount_in_usd, loyalty_discount)?;
+    let loyalty_discount_in_lamport: u64 = bps_of(after_volume_discount_in_lamport, loyalty_discount)?;
+
+    let after_loyalty_discount_in_usd: u64 = after_volume_discount_in_usd - loyalty_discount_in_usd;
+    let after_loyalty_discount_in_lamport: u64 = after_volume_discount_in_lamport - loyalty_discount_in_lamport;
 
     let mut user_discount_in_usd: u64 = 0;
     let mut user_discount_in_lamport: u64 = 0;
 
-    let mut after_user_discount_in_usd: u64 = price_in_usd;
-    let mut after_user_discount_in_lamport: u64 = price_in_lamport;
+    let mut after_user_discount_in_usd: u64 = after_loyalty_discount_in_usd;
+    let mut after_user_discount_in_lamport: u64 = after_loyalty_discount_in_lamport;
 
     let mut full_discount_amount_in_lamport: u64 = 0;
     let mut full_discount_amount_in_usd: u64 = 0;
@@ -190,22 +318,44 @@ pub fn handle_buy<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, BuyInputAcc
 
         check_invalid_user_discount(user_discount)?;
 
-        user_discount_in_lamport = (user_discount as u64 * price_in_lamport) / 10000;
-        user_discount_in_usd = (user_discount as u64 * price_in_usd) / 10000;
+        user_discount_in_lamport = bps_of(after_loyalty_discount_in_lamport, user_discount)?;
+        user_discount_in_usd = bps_of(after_loyalty_discount_in_usd, user_discount)?;
+
+        after_user_discount_in_usd = after_loyalty_discount_in_usd - user_discount_in_usd;
+        after_user_discount_in_lamport = after_loyalty_discount_in_lamport - user_discount_in_lamport;
+    }
+
+    // installment orders pay the deposit now and the balance with pay_installment
+    let mut payment_amount_in_lamport: u64 = after_user_discount_in_lamport;
+    let mut installment_paid_in_usd: u64 = 0;
 
-        after_user_discount_in_usd = price_in_usd - user_discount_in_usd;
-        after_user_discount_in_lamport = price_in_lamport - user_discount_in_lamport;
+    if is_installment {
+        payment_amount_in_lamport = bps_of(after_user_discount_in_lamport, sale_phase_detail.installment_deposit_bps)?;
+        installment_paid_in_usd = bps_of(after_user_discount_in_usd, sale_phase_detail.installment_deposit_bps)?;
     }
 
+    // installment orders count toward the phase caps as they are paid
+    let paid_price_in_usd: u64 = if is_installment {
+        get_paid_price_in_usd(price_in_usd, installment_paid_in_usd, after_user_discount_in_usd)?
+    } else { price_in_usd };
+
+    // slippage protection, the buyer pays the price after the user discount
+    check_max_payment_amount(payment_amount_in_lamport, max_payment_amount)?;
+
+    // the phase account holds the payment and the referral shares until the soft cap is reached and the installments are paid
+    let is_cap_escrowed: bool = !sale_phase_detail.is_soft_cap_reached() || is_installment;
+
     if allow_full_discount {
         check_value_is_zero(full_discount as usize)?;
 
-        full_discount_amount_in_lamport = (full_discount as u64 * after_user_discount_in_lamport) / 10000;
-        full_discount_amount_in_usd = (full_discount as u64 * after_user_discount_in_usd) / 10000;
+        full_discount_amount_in_lamport = bps_of(after_user_discount_in_lamport, full_discount)?;
+        full_discount_amount_in_usd = bps_of(after_user_discount_in_usd, full_discount)?;
+
+        let full_discount_deposit_receiver: AccountInfo = if is_cap_escrowed { sale_phase_detail.to_account_info() } else { full_discount_receiver.to_account_info() };
 
         let deposit_full_discount_amount_ix = anchor_lang::solana_program::system_instruction::transfer(
             &ctx.accounts.user_payer.key(),
-            &full_discount_receiver.key(),
+            &full_discount_deposit_receiver.key(),
             full_discount_amount_in_lamport,
         );
 
@@ -213,7 +363,7 @@ pub fn handle_buy<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, BuyInputAcc
             &deposit_full_discount_amount_ix,
             &[
                 ctx.accounts.user_payer.to_account_info(),
-                full_discount_receiver.to_account_info(),
+                full_discount_deposit_receiver,
             ],
         )?;
     };
@@ -224,12 +374,14 @@ pub fn handle_buy<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, BuyInputAcc
     if allow_half_discount {
         check_value_is_zero(half_discount as usize)?;
 
-        half_discount_amount_in_lamport = (half_discount as u64 * after_user_discount_in_lamport) / 10000;
-        half_discount_amount_in_usd = (half_discount as u64 * after_user_discount_in_usd) / 10000;
+        half_discount_amount_in_lamport = bps_of(after_user_discount_in_lamport, half_discount)?;
+        half_discount_amount_in_usd = bps_of(after_user_discount_in_usd, half_discount)?;
+
+        let half_discount_deposit_receiver: AccountInfo = if is_cap_escrowed { sale_phase_detail.to_account_info() } else { half_discount_receiver.to_account_info() };
 
         let deposit_half_discount_amount_ix = anchor_lang::solana_program::system_instruction::transfer(
             &ctx.accounts.user_payer.key(),
-            &half_discount_receiver.key(),
+            &half_discount_deposit_receiver.key(),
             half_discount_amount_in_lamport,
         );
 
@@ -237,22 +389,50 @@ pub fn handle_buy<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, BuyInputAcc
             &deposit_half_discount_amount_ix,
             &[
                 ctx.accounts.user_payer.to_account_info(),
-                half_discount_receiver.to_account_info(),
+                half_discount_deposit_receiver,
             ],
         )?;
     }
 
+    let receiver_amount_in_lamport: u64 = payment_amount_in_lamport.sub(full_discount_amount_in_lamport).sub(half_discount_amount_in_lamport);
+
+    let mut auction_escrow_amount: u64 = 0;
+
+    if sale_phase_tier_detail.is_auction_running(timestamp) && !is_whitelist_price {
+        auction_escrow_amount = get_auction_escrow_amount(receiver_amount_in_lamport, price_in_usd, sale_phase_tier_detail.auction_floor_price.mul(quantity))?;
+
+        // the tier account holds the sol escrow until the order is settled
+        let deposit_escrow_amount_ix = anchor_lang::solana_program::system_instruction::transfer(
+            &ctx.accounts.user_payer.key(),
+            &sale_phase_tier_detail.key(),
+            auction_escrow_amount,
+        );
+
+        anchor_lang::solana_program::program::invoke(
+            &deposit_escrow_amount_ix,
+            &[
+                ctx.accounts.user_payer.to_account_info(),
+                sale_phase_tier_detail.to_account_info(),
+            ],
+        )?;
+    }
+
+    let cap_escrow_amount: u64 = if is_cap_escrowed {
+        receiver_amount_in_lamport.sub(auction_escrow_amount) + full_discount_amount_in_lamport + half_discount_amount_in_lamport
+    } else { 0 };
+    let deposit_receiver: AccountInfo = if is_cap_escrowed { sale_phase_detail.to_account_info() } else { payment_receiver.to_account_info() };
+
     let deposit_amount_ix = anchor_lang::solana_program::system_instruction::transfer(
         &ctx.accounts.user_payer.key(),
-        &payment_receiver.key(),
-        after_user_discount_in_lamport.sub(full_discount_amount_in_lamport).sub(half_discount_amount_in_lamport),
+        &deposit_receiver.key(),
+        receiver_amount_in_lamport.sub(auction_escrow_amount),
     );
 
     anchor_lang::solana_program::program::invoke(
         &deposit_amount_ix,
         &[
             ctx.accounts.user_payer.to_account_info(),
-            payment_receiver.to_account_info(),
+            deposit_receiver,
         ],
     )?;
 
@@ -272,20 +452,50 @@ pub fn handle_buy<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, BuyInputAcc
     order_detail.payment_token_mint_account = None;
     order_detail.token_ids = Vec::with_capacity(quantity as usize);
     order_detail.is_token_ids_minted = Vec::with_capacity(quantity as usize);
+    order_detail.is_token_ids_revoked = Vec::with_capacity(quantity as usize);
+    order_detail.is_token_ids_upgraded = Vec::with_capacity(quantity as usize);
+    order_detail.is_token_ids_reissued = Vec::with_capacity(quantity as usize);
+    order_detail.auction_escrow_amount = auction_escrow_amount;
+    order_detail.cap_escrow_amount = cap_escrow_amount;
+    order_detail.volume_discount = volume_discount;
+    order_detail.total_volume_discount_in_usd = volume_discount_in_usd;
+    order_detail.total_volume_discount = volume_discount_in_lamport;
+    order_detail.loyalty_discount = loyalty_discount;
+    order_detail.total_loyalty_discount_in_usd = loyalty_discount_in_usd;
+    order_detail.total_loyalty_discount = loyalty_discount_in_lamport;
     order_detail.is_whitelist = is_whitelist;
 
+    if is_cap_escrowed {
+        order_detail.full_discount_receiver = full_discount_receiver.key();
+        order_detail.full_discount_escrow_amount = full_discount_amount_in_lamport;
+        order_detail.half_discount_receiver = half_discount_receiver.key();
+        order_detail.half_discount_escrow_amount = half_discount_amount_in_lamport;
+    }
+
+    if is_installment {
+        order_detail.is_installment = true;
+        order_detail.installment_owed_in_usd = after_user_discount_in_usd;
+        order_detail.installment_paid_in_usd = installment_paid_in_usd;
+        order_detail.installment_deadline = timestamp + sale_phase_detail.installment_period;
+    }
+
     let mut current_token_id: u64 = sale_phase_tier_detail.total_mint;
 
     for _i in 0..quantity {
         current_token_id += 1;
         order_detail.token_ids.push(current_token_id);
         order_detail.is_token_ids_minted.push(false);
+        order_detail.is_token_ids_revoked.push(false);
+        order_detail.is_token_ids_upgraded.push(false);
+        order_detail.is_token_ids_reissued.push(false);
     };
 
     let sale_phase_detail: &mut Box<Account<SogaNodeSalePhaseDetailAccount>> = &mut ctx.accounts.sale_phase_detail;
     sale_phase_detail.total_buy += quantity;
     sale_phase_detail.total_mint += quantity;
-    sale_phase_detail.total_payment += price_in_usd;
+    sale_phase_detail.total_payment += paid_price_in_usd;
+    sale_phase_detail.latch_soft_cap();
+    sale_phase_detail.total_installment_pending += price_in_usd - paid_price_in_usd;
     sale_phase_detail.total_discount += full_discount_amount_in_usd;
     sale_phase_detail.total_discount += half_discount_amount_in_usd;
     sale_phase_detail.last_block_timestamp = timestamp;
@@ -294,12 +504,20 @@ pub fn handle_buy<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, BuyInputAcc
     let sale_phase_tier_detail: &mut Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &mut ctx.accounts.sale_phase_tier_detail;
     sale_phase_tier_detail.total_mint += quantity;
     sale_phase_tier_detail.total_buy += quantity;
-    sale_phase_tier_detail.total_payment += price_in_usd;
+    sale_phase_tier_detail.total_payment += paid_price_in_usd;
     sale_phase_tier_detail.total_discount += full_discount_amount_in_usd;
     sale_phase_tier_detail.total_discount += half_discount_amount_in_usd;
     sale_phase_tier_detail.last_block_timestamp = timestamp;
 
-    if sale_phase_tier_detail.total_mint >= sale_phase_tier_detail.quantity {
+    if sale_phase_tier_detail.is_auction_rebate && !is_whitelist_price {
+        let lowest_unit_price: u64 = *unit_prices.iter().min().unwrap();
+
+        if sale_phase_tier_detail.clearing_price == 0 || lowest_unit_price < sale_phase_tier_detail.clearing_price {
+            sale_phase_tier_detail.clearing_price = lowest_unit_price;
+        }
+    }
+
+    if sale_phase_tier_detail.minted_supply() >= sale_phase_tier_detail.public_quantity() {
         sale_phase_tier_detail.is_completed = true;
         sale_phase_detail.total_completed_tiers += 1;
     }
@@ -307,16 +525,23 @@ pub fn handle_buy<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, BuyInputAcc
     let user_detail: &mut Box<Account<UserDetailAccount>> = &mut ctx.accounts.user_detail;
     user_detail.total_mint += quantity;
     user_detail.total_buy += quantity;
-    user_detail.total_payment += price_in_usd;
+    user_detail.total_payment += paid_price_in_usd;
     user_detail.total_discount += full_discount_amount_in_usd;
     user_detail.total_discount += half_discount_amount_in_usd;
     user_detail.total_orders += 1;
     user_detail.last_block_timestamp = timestamp;
 
+    if let (Some(loyalty_detail), Some(_)) = (ctx.accounts.loyalty_detail.as_deref_mut(), loyalty_node_mint_account) {
+        loyalty_detail.last_block_timestamp = timestamp;
+        loyalty_detail.is_used = true;
+        loyalty_detail.user = ctx.accounts.user.key();
+        loyalty_detail.order_id = order_id_int;
+    }
+
     let user_tier_detail: &mut Box<Account<UserTierDetailAccount>> = &mut ctx.accounts.user_tier_detail;
     user_tier_detail.total_mint += quantity;
     user_tier_detail.total_buy += quantity;
-    user_tier_detail.total_payment += price_in_usd;
+    user_tier_detail.total_payment += paid_price_in_usd;
     user_tier_detail.total_discount += full_discount_amount_in_usd;
     user_tier_detail.total_discount += half_discount_amount_in_usd;
     user_tier_detail.last_block_timestamp = timestamp;
@@ -326,6 +551,7 @@ pub fn handle_buy<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, BuyInputAcc
         sale_phase_tier_detail.total_whitelist_mint += quantity;
         user_detail.total_whitelist_mint += quantity;
         user_tier_detail.total_whitelist_mint += quantity;
+        user_tier_detail.total_whitelist_payment += price_in_usd;
     }
 
     // Event
@@ -358,6 +584,17 @@ pub fn handle_buy<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, BuyInputAcc
         user_discount_in_usd,
         quantity,
         is_whitelist,
+        payment_amount: payment_amount_in_lamport,
+        max_payment_amount,
+        is_installment,
+        volume_discount,
+        volume_discount_in_usd,
+        volume_discount_in_lamport,
+        loyalty_node_mint_account,
+        loyalty_discount,
+        loyalty_discount_in_usd,
+        loyalty_discount_in_lamport,
+        whitelist_pass_amount,
     };
 
     emit!(event);
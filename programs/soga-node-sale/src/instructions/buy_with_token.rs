@@ -1,10 +1,12 @@
 use std::ops::{Add, Mul, Sub};
 use anchor_lang::prelude::*;
 
-use pyth_solana_receiver_sdk::price_update::{PriceUpdateV2, Price, get_feed_id_from_hex};
+use crate::oracle::{get_expo_base, get_payment_token_amount, OraclePrice};
+
+use crate::pricing::{bps_of, get_total_price, get_auction_escrow_amount, get_paid_price_in_usd};
 
 use anchor_spl::{
-    token_interface::{TransferChecked, transfer_checked},
+    token_interface::{TokenAccount, TransferChecked, transfer_checked},
 };
 
 use crate::states::{
@@ -19,13 +21,21 @@ use crate::states::{
     UserTierDetailAccount,
     ORDER_DETAIL_ACCOUNT_PREFIX,
     OrderDetailAccount,
+    LOYALTY_DETAIL_ACCOUNT_PREFIX,
+    LoyaltyDetailAccount,
 };
 
+use crate::error::SogaNodeSaleError;
+
+use crate::loyalty::get_loyalty_collection_mint;
+
+use crate::whitelist::consume_whitelist_pass;
+
 use crate::events::{
     BuyWithTokenEvent
 };
 
-use crate::utils::{check_signing_authority, check_price_feed, check_payment_receiver, check_phase_tier_is_completed, check_token_quantity_out_of_range, check_invalid_discount, check_payment_token_mint_account, check_payment_token, check_phase_buy_with_token, check_phase_tier_buy_with_token, check_quantity, check_tier_id, check_order_id, check_mint_limit_with_quantity, check_value_is_zero, check_invalid_user_discount, check_token_whitelist_quantity_out_of_range};
+use crate::utils::{check_signing_authority, check_payment_receiver, check_phase_tier_is_completed, check_token_quantity_out_of_range, check_invalid_discount, check_payment_token_mint_account, check_payment_token, check_phase_buy_with_token, check_phase_tier_buy_with_token, check_quantity, check_tier_id, check_order_id, check_mint_limit_with_quantity, check_value_is_zero, check_invalid_user_discount, check_token_whitelist_quantity_out_of_range, check_max_payment_amount, check_auction_escrow, check_phase_tier_is_fair_launch, check_phase_tier_is_sealed_bid_auction, check_phase_started, check_phase_ended, check_hard_cap, check_cap_escrow, check_installment_enabled, check_installment, check_loyalty_collection, check_loyalty_node_used, check_whitelist_pass_mint, check_whitelist_mint_limit};
 
 #[derive(Accounts)]
 #[instruction(_sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8,
@@ -103,7 +113,58 @@ pub struct BuyWithTokenInputAccounts<'info> {
     )]
     pub order_detail: Box<Account<'info, OrderDetailAccount>>,
 
-    pub price_update: Account<'info, PriceUpdateV2>,
+    /// not required for fixed-rate payment tokens without a depeg guard
+    /// CHECK: price feed, owner and layout checked by the oracle source
+    pub price_update: Option<UncheckedAccount<'info>>,
+
+    /// required while a rebate auction is running
+    /// CHECK: token account owned by the sale phase tier detail, checked in the handler
+    #[account(mut)]
+    pub auction_escrow: Option<UncheckedAccount<'info>>,
+
+    /// required until the phase soft cap is reached
+    /// CHECK: token account owned by the sale phase detail, checked in the handler
+    #[account(mut)]
+    pub cap_escrow: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: token-2022 or metaplex node mint of a loyalty collection held by the user, checked in the handler
+    pub loyalty_node_mint: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: token account of the user holding the loyalty node, checked in the handler
+    pub loyalty_token_account: Option<UncheckedAccount<'info>>,
+
+    /// required for metaplex loyalty nodes
+    /// CHECK: metadata account of the loyalty node mint, checked in the handler
+    pub loyalty_node_metadata: Option<UncheckedAccount<'info>>,
+
+    /// required with a loyalty node, each node grants the loyalty discount on one order of the phase
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = LoyaltyDetailAccount::space(),
+        seeds = [
+        LOYALTY_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail.key().as_ref(),
+        loyalty_node_mint.as_ref().map(|loyalty_node_mint| loyalty_node_mint.key()).unwrap_or_default().as_ref(),
+        ],
+        bump,
+    )]
+    pub loyalty_detail: Option<Box<Account<'info, LoyaltyDetailAccount>>>,
+
+    /// CHECK: pass mint of the tier, checked in the handler
+    #[account(mut)]
+    pub whitelist_pass_mint: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: token account of the user payer holding the passes, checked in the handler
+    #[account(mut)]
+    pub whitelist_pass_token_account: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: token account owned by the sale phase tier detail receiving the passes, checked in the handler
+    #[account(mut)]
+    pub whitelist_pass_escrow: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: token program of the pass mint, checked in the handler
+    pub whitelist_pass_token_program: Option<UncheckedAccount<'info>>,
 
     pub system_program: Program<'info, System>,
 
@@ -113,7 +174,7 @@ pub struct BuyWithTokenInputAccounts<'info> {
 pub fn handle_buy_with_token<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, BuyWithTokenInputAccounts<'info>>,
                                                 _sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, sale_phase_name: String, tier_id: String,
                                                 order_id: String, quantity: u64, allow_full_discount: bool, full_discount: u16, allow_half_discount: bool, half_discount: u16,
-                                                is_whitelist: bool, allow_user_discount: bool, user_discount: u16,
+                                                is_whitelist: bool, allow_user_discount: bool, user_discount: u16, max_payment_amount: u64, is_installment: bool,
 ) -> Result<()> {
     let timestamp = Clock::get().unwrap().unix_timestamp;
 
@@ -123,6 +184,12 @@ pub fn handle_buy_with_token<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info,
     let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
     let sale_phase_tier_detail: &Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &ctx.accounts.sale_phase_tier_detail;
 
+    // tiers with a whitelist pass take the whitelist status from the pass instead of the signed flag
+    let is_whitelist: bool = match sale_phase_tier_detail.whitelist_pass_mint {
+        Some(_) => ctx.accounts.whitelist_pass_mint.is_some(),
+        None => is_whitelist,
+    };
+
     let payment_receiver = &ctx.remaining_accounts[0];
     let full_discount_receiver = &ctx.remaining_accounts[1];
     let half_discount_receiver = &ctx.remaining_accounts[2];
@@ -145,14 +212,20 @@ pub fn handle_buy_with_token<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info,
 
     check_phase_tier_buy_with_token(sale_phase_tier_detail.buy_with_token_enable)?;
 
+    check_phase_started(sale_phase_detail.is_started(timestamp))?;
+
+    check_phase_ended(sale_phase_detail.is_ended(timestamp))?;
+
+    check_phase_tier_is_fair_launch(sale_phase_tier_detail.is_fair_launch_running())?;
+
+    check_phase_tier_is_sealed_bid_auction(sale_phase_tier_detail.is_sealed_bid_auction)?;
+
     check_payment_token(sale_phase_payment_token_detail.enable)?;
 
     check_payment_token_mint_account(sale_phase_payment_token_detail.mint, payment_token_mint_account.key())?;
 
     check_signing_authority(sale_phase_detail.signing_authority, ctx.accounts.signing_authority.key())?;
 
-    check_price_feed(sale_phase_payment_token_detail.price_feed_address, ctx.accounts.price_update.key())?;
-
     check_payment_receiver(sale_phase_detail.payment_receiver, payment_receiver.key())?;
 
     if is_whitelist {
@@ -165,7 +238,7 @@ pub fn handle_buy_with_token<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info,
 
     check_quantity(sale_phase_tier_detail.mint_limit, quantity)?;
 
-    check_token_quantity_out_of_range(sale_phase_tier_detail.total_mint + quantity, sale_phase_tier_detail.quantity)?;
+    check_token_quantity_out_of_range(sale_phase_tier_detail.minted_supply() + quantity, sale_phase_tier_detail.public_quantity())?;
 
     let user_detail: &Box<Account<UserDetailAccount>> = &ctx.accounts.user_detail;
 
@@ -175,30 +248,93 @@ pub fn handle_buy_with_token<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info,
 
     check_mint_limit_with_quantity(sale_phase_tier_detail.mint_limit, user_tier_detail.total_mint + quantity)?;
 
+    if is_whitelist {
+        check_whitelist_mint_limit(sale_phase_tier_detail.whitelist_mint_limit, user_tier_detail.total_whitelist_mint + quantity)?;
+    }
+
     check_invalid_discount(full_discount, half_discount)?;
 
+    if is_installment {
+        check_installment_enabled(sale_phase_detail.installment_deposit_bps)?;
+
+        check_installment(allow_full_discount, allow_half_discount, sale_phase_tier_detail.is_auction_running(timestamp))?;
+    }
+
+    // Consume Whitelist Pass
+    let mut whitelist_pass_amount: u64 = 0;
+
+    if let Some(whitelist_pass_mint) = sale_phase_tier_detail.whitelist_pass_mint.filter(|_| is_whitelist) {
+        let whitelist_pass_mint_account = ctx.accounts.whitelist_pass_mint.as_deref().ok_or(SogaNodeSaleError::InvalidWhitelistPass)?;
+        let whitelist_pass_token_account = ctx.accounts.whitelist_pass_token_account.as_deref().ok_or(SogaNodeSaleError::InvalidWhitelistPass)?;
+        let whitelist_pass_token_program = ctx.accounts.whitelist_pass_token_program.as_deref().ok_or(SogaNodeSaleError::InvalidWhitelistPass)?;
+
+        check_whitelist_pass_mint(whitelist_pass_mint, whitelist_pass_mint_account.key())?;
+
+        let whitelist_pass_escrow = if sale_phase_tier_detail.is_whitelist_pass_burned { None } else {
+            Some(ctx.accounts.whitelist_pass_escrow.as_deref().ok_or(SogaNodeSaleError::InvalidWhitelistPassEscrow)?)
+        };
+
+        whitelist_pass_amount = consume_whitelist_pass(whitelist_pass_mint_account, whitelist_pass_token_account, whitelist_pass_escrow,
+                                                       whitelist_pass_token_program, &ctx.accounts.user_payer, sale_phase_tier_detail.key(), quantity)?;
+    }
+
     // Make Payment
-    let price_in_usd: u64 = sale_phase_tier_detail.price.mul(quantity);
+    // whitelist buys at the whitelist price are not part of a rebate auction
+    let is_whitelist_price: bool = is_whitelist && sale_phase_tier_detail.whitelist_price != 0;
 
-    let price_update = &mut ctx.accounts.price_update;
+    let unit_prices: Vec<u64> = if is_whitelist {
+        sale_phase_tier_detail.quote_whitelist(timestamp, quantity)?
+    } else {
+        sale_phase_tier_detail.quote(timestamp, quantity)?
+    };
+    let price_in_usd: u64 = get_total_price(&unit_prices)?;
+
+    check_hard_cap(sale_phase_detail.committed_payment() + price_in_usd, sale_phase_detail.hard_cap)?;
+
+    let (price_in_lamport, price): (u64, Option<OraclePrice>) = get_payment_token_amount(price_in_usd, &sale_phase_payment_token_detail,
+                                                                                            ctx.accounts.price_update.as_deref(), &ctx.remaining_accounts[10..])?;
+
+    let pyth_expo: u64 = price.as_ref().map_or(0, get_expo_base);
+    let pyth_price: u64 = price.as_ref().map_or(0, |price| price.price as u64);
+
+    // volume discount of the tier brackets, applied before the user discount
+    let volume_discount: u16 = sale_phase_tier_detail.volume_discount(quantity, ctx.accounts.user_tier_detail.total_mint);
 
-    let feed_id: [u8; 32] = get_feed_id_from_hex(sale_phase_payment_token_detail.price_feed_id.as_str())?;
+    let volume_discount_in_usd: u64 = bps_of(price_in_usd, volume_discount)?;
+    let volume_discount_in_lamport: u64 = bps_of(price_in_lamport, volume_discount)?;
 
-    let price: Price = price_update.get_price_no_older_than(&Clock::get()?,
-                                                            120,
-                                                            &feed_id,
-    )?;
+    let after_volume_discount_in_usd: u64 = price_in_usd - volume_discount_in_usd;
+    let after_volume_discount_in_lamport: u64 = price_in_lamport - volume_discount_in_lamport;
 
-    let pyth_expo: u64 = 10_u64.pow(price.exponent.abs().try_into().unwrap());
-    let pyth_price: u64 = u64::try_from(price.price).unwrap();
-    let decimal_base_lamport: u64 = 10_u64.pow(sale_phase_payment_token_detail.decimals.try_into().unwrap());
-    let price_in_lamport: u64 = decimal_base_lamport.checked_mul(pyth_expo).unwrap().checked_div(pyth_price).unwrap().checked_mul(price_in_usd).unwrap();
+    // loyalty discount of a node held from a tier of an earlier phase, applied after the volume discount
+    let mut loyalty_discount: u16 = 0;
+    let mut loyalty_node_mint_account: Option<Pubkey> = None;
+
+    if let (Some(loyalty_node_mint), Some(loyalty_token_account)) = (ctx.accounts.loyalty_node_mint.as_deref(), ctx.accounts.loyalty_token_account.as_deref()) {
+        let loyalty_collection_mint: Pubkey = get_loyalty_collection_mint(ctx.accounts.user.key(), loyalty_node_mint, loyalty_token_account,
+                                                                          ctx.accounts.loyalty_node_metadata.as_deref())?;
+
+        check_loyalty_collection(sale_phase_detail.loyalty_collections.contains(&loyalty_collection_mint))?;
+
+        let loyalty_detail = ctx.accounts.loyalty_detail.as_deref().ok_or(SogaNodeSaleError::InvalidLoyaltyNode)?;
+
+        check_loyalty_node_used(loyalty_detail.is_used)?;
+
+        loyalty_discount = sale_phase_detail.loyalty_discount;
+        loyalty_node_mint_account = Some(loyalty_node_mint.key());
+    }
+
+    let loyalty_discount_in_usd: u64 = bps_of(after_volume_discount_in_usd, loyalty_discount)?;
+    let loyalty_discount_in_lamport: u64 = bps_of(after_volume_discount_in_lamport, loyalty_discount)?;
+
+    let after_loyalty_discount_in_usd: u64 = after_volume_discount_in_usd - loyalty_discount_in_usd;
+    let after_loyalty_discount_in_lamport: u64 = after_volume_discount_in_lamport - loyalty_discount_in_lamport;
 
     let mut user_discount_in_usd: u64 = 0;
     let mut user_discount_in_lamport: u64 = 0;
 
-    let mut after_user_discount_in_usd: u64 = price_in_usd;
-    let mut after_user_discount_in_lamport: u64 = price_in_lamport;
+    let mut after_user_discount_in_usd: u64 = after_loyalty_discount_in_usd;
+    let mut after_user_discount_in_lamport: u64 = after_loyalty_discount_in_lamport;
 
     let mut full_discount_amount_in_lamport: u64 = 0;
     let mut full_discount_amount_in_usd: u64 = 0;
@@ -208,23 +344,52 @@ pub fn handle_buy_with_token<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info,
 
         check_invalid_user_discount(user_discount)?;
 
-        user_discount_in_lamport = (user_discount as u64 * price_in_lamport) / 10000;
-        user_discount_in_usd = (user_discount as u64 * price_in_usd) / 10000;
+        user_discount_in_lamport = bps_of(after_loyalty_discount_in_lamport, user_discount)?;
+        user_discount_in_usd = bps_of(after_loyalty_discount_in_usd, user_discount)?;
+
+        after_user_discount_in_usd = after_loyalty_discount_in_usd - user_discount_in_usd;
+        after_user_discount_in_lamport = after_loyalty_discount_in_lamport - user_discount_in_lamport;
+    }
+
+    // installment orders pay the deposit now and the balance with pay_installment
+    let mut payment_amount_in_lamport: u64 = after_user_discount_in_lamport;
+    let mut installment_paid_in_usd: u64 = 0;
 
-        after_user_discount_in_usd = price_in_usd - user_discount_in_usd;
-        after_user_discount_in_lamport = price_in_lamport - user_discount_in_lamport;
+    if is_installment {
+        payment_amount_in_lamport = bps_of(after_user_discount_in_lamport, sale_phase_detail.installment_deposit_bps)?;
+        installment_paid_in_usd = bps_of(after_user_discount_in_usd, sale_phase_detail.installment_deposit_bps)?;
     }
 
+    // installment orders count toward the phase caps as they are paid
+    let paid_price_in_usd: u64 = if is_installment {
+        get_paid_price_in_usd(price_in_usd, installment_paid_in_usd, after_user_discount_in_usd)?
+    } else { price_in_usd };
+
+    // slippage protection, the buyer pays the price after the user discount
+    check_max_payment_amount(payment_amount_in_lamport, max_payment_amount)?;
+
+    // the phase escrow holds the payment and the referral shares until the soft cap is reached and the installments are paid
+    let is_cap_escrowed: bool = !sale_phase_detail.is_soft_cap_reached() || is_installment;
+
+    let cap_escrow: Option<&AccountInfo> = if is_cap_escrowed {
+        let cap_escrow = ctx.accounts.cap_escrow.as_deref().ok_or(SogaNodeSaleError::InvalidCapEscrow)?;
+        let cap_escrow_token: TokenAccount = TokenAccount::try_deserialize(&mut &cap_escrow.try_borrow_data()?[..])?;
+
+        check_cap_escrow(cap_escrow_token.owner, sale_phase_detail.key(), cap_escrow_token.mint, payment_token_mint_account.key())?;
+
+        Some(cap_escrow)
+    } else { None };
+
     if allow_full_discount {
         check_value_is_zero(full_discount as usize)?;
 
-        full_discount_amount_in_lamport = (full_discount as u64 * after_user_discount_in_lamport) / 10000;
-        full_discount_amount_in_usd = (full_discount as u64 * after_user_discount_in_usd) / 10000;
+        full_discount_amount_in_lamport = bps_of(after_user_discount_in_lamport, full_discount)?;
+        full_discount_amount_in_usd = bps_of(after_user_discount_in_usd, full_discount)?;
 
         let cpi_accounts = TransferChecked {
             from: payment_token_user_payer_token_account.to_account_info(),
             mint: payment_token_mint_account.to_account_info(),
-            to: payment_token_full_discount_receiver_token_account.to_account_info(),
+            to: cap_escrow.unwrap_or(payment_token_full_discount_receiver_token_account).to_account_info(),
             authority: ctx.accounts.user_payer.to_account_info(),
         };
         let cpi_program = payment_token_program.to_account_info();
@@ -238,13 +403,13 @@ pub fn handle_buy_with_token<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info,
     if allow_half_discount {
         check_value_is_zero(half_discount as usize)?;
 
-        half_discount_amount_in_lamport = (half_discount as u64 * after_user_discount_in_lamport) / 10000;
-        half_discount_amount_in_usd = (half_discount as u64 * after_user_discount_in_usd) / 10000;
+        half_discount_amount_in_lamport = bps_of(after_user_discount_in_lamport, half_discount)?;
+        half_discount_amount_in_usd = bps_of(after_user_discount_in_usd, half_discount)?;
 
         let cpi_accounts = TransferChecked {
             from: payment_token_user_payer_token_account.to_account_info(),
             mint: payment_token_mint_account.to_account_info(),
-            to: payment_token_half_discount_receiver_token_account.to_account_info(),
+            to: cap_escrow.unwrap_or(payment_token_half_discount_receiver_token_account).to_account_info(),
             authority: ctx.accounts.user_payer.to_account_info(),
         };
         let cpi_program = payment_token_program.to_account_info();
@@ -252,16 +417,44 @@ pub fn handle_buy_with_token<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info,
         transfer_checked(cpi_context, half_discount_amount_in_lamport, sale_phase_payment_token_detail.decimals)?;
     }
 
+    let receiver_amount_in_lamport: u64 = payment_amount_in_lamport.sub(full_discount_amount_in_lamport).sub(half_discount_amount_in_lamport);
+
+    let mut auction_escrow_amount: u64 = 0;
+
+    if sale_phase_tier_detail.is_auction_running(timestamp) && !is_whitelist_price {
+        let auction_escrow = ctx.accounts.auction_escrow.as_deref().ok_or(SogaNodeSaleError::InvalidAuctionEscrow)?;
+        let auction_escrow_token: TokenAccount = TokenAccount::try_deserialize(&mut &auction_escrow.try_borrow_data()?[..])?;
+
+        check_auction_escrow(auction_escrow_token.owner, sale_phase_tier_detail.key(), auction_escrow_token.mint, payment_token_mint_account.key())?;
+
+        auction_escrow_amount = get_auction_escrow_amount(receiver_amount_in_lamport, price_in_usd, sale_phase_tier_detail.auction_floor_price.mul(quantity))?;
+
+        let cpi_accounts = TransferChecked {
+            from: payment_token_user_payer_token_account.to_account_info(),
+            mint: payment_token_mint_account.to_account_info(),
+            to: auction_escrow.to_account_info(),
+            authority: ctx.accounts.user_payer.to_account_info(),
+        };
+        let cpi_program = payment_token_program.to_account_info();
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+        transfer_checked(cpi_context, auction_escrow_amount, sale_phase_payment_token_detail.decimals)?;
+    }
+
+    let cap_escrow_amount: u64 = if is_cap_escrowed {
+        receiver_amount_in_lamport.sub(auction_escrow_amount) + full_discount_amount_in_lamport + half_discount_amount_in_lamport
+    } else { 0 };
+    let deposit_receiver: AccountInfo = cap_escrow.unwrap_or(payment_token_payment_receiver_token_account).to_account_info();
+
     let cpi_accounts = TransferChecked {
         from: payment_token_user_payer_token_account.to_account_info(),
         mint: payment_token_mint_account.to_account_info(),
-        to: payment_token_payment_receiver_token_account.to_account_info(),
+        to: deposit_receiver,
         authority: ctx.accounts.user_payer.to_account_info(),
     };
     let cpi_program = payment_token_program.to_account_info();
     let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
     transfer_checked(cpi_context,
-                     after_user_discount_in_lamport.sub(full_discount_amount_in_lamport).sub(half_discount_amount_in_lamport),
+                     receiver_amount_in_lamport.sub(auction_escrow_amount),
                      sale_phase_payment_token_detail.decimals)?;
 
 
@@ -280,6 +473,31 @@ pub fn handle_buy_with_token<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info,
     order_detail.payment_token_mint_account = Some(payment_token_mint_account.key());
     order_detail.token_ids = Vec::with_capacity(quantity as usize);
     order_detail.is_token_ids_minted = Vec::with_capacity(quantity as usize);
+    order_detail.is_token_ids_revoked = Vec::with_capacity(quantity as usize);
+    order_detail.is_token_ids_upgraded = Vec::with_capacity(quantity as usize);
+    order_detail.is_token_ids_reissued = Vec::with_capacity(quantity as usize);
+    order_detail.auction_escrow_amount = auction_escrow_amount;
+    order_detail.cap_escrow_amount = cap_escrow_amount;
+    order_detail.volume_discount = volume_discount;
+    order_detail.total_volume_discount_in_usd = volume_discount_in_usd;
+    order_detail.total_volume_discount = volume_discount_in_lamport;
+    order_detail.loyalty_discount = loyalty_discount;
+    order_detail.total_loyalty_discount_in_usd = loyalty_discount_in_usd;
+    order_detail.total_loyalty_discount = loyalty_discount_in_lamport;
+
+    if is_cap_escrowed {
+        order_detail.full_discount_receiver = payment_token_full_discount_receiver_token_account.key();
+        order_detail.full_discount_escrow_amount = full_discount_amount_in_lamport;
+        order_detail.half_discount_receiver = payment_token_half_discount_receiver_token_account.key();
+        order_detail.half_discount_escrow_amount = half_discount_amount_in_lamport;
+    }
+
+    if is_installment {
+        order_detail.is_installment = true;
+        order_detail.installment_owed_in_usd = after_user_discount_in_usd;
+        order_detail.installment_paid_in_usd = installment_paid_in_usd;
+        order_detail.installment_deadline = timestamp + sale_phase_detail.installment_period;
+    }
 
     let mut current_token_id: u64 = sale_phase_tier_detail.total_mint;
 
@@ -287,12 +505,17 @@ pub fn handle_buy_with_token<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info,
         current_token_id += 1;
         order_detail.token_ids.push(current_token_id);
         order_detail.is_token_ids_minted.push(false);
+        order_detail.is_token_ids_revoked.push(false);
+        order_detail.is_token_ids_upgraded.push(false);
+        order_detail.is_token_ids_reissued.push(false);
     };
 
     let sale_phase_detail: &mut Box<Account<SogaNodeSalePhaseDetailAccount>> = &mut ctx.accounts.sale_phase_detail;
     sale_phase_detail.total_mint += quantity;
     sale_phase_detail.total_buy_with_token += quantity;
-    sale_phase_detail.total_payment += price_in_usd;
+    sale_phase_detail.total_payment += paid_price_in_usd;
+    sale_phase_detail.latch_soft_cap();
+    sale_phase_detail.total_installment_pending += price_in_usd - paid_price_in_usd;
     sale_phase_detail.total_discount += full_discount_amount_in_usd;
     sale_phase_detail.total_discount += half_discount_amount_in_usd;
     sale_phase_detail.last_block_timestamp = timestamp;
@@ -301,12 +524,20 @@ pub fn handle_buy_with_token<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info,
     let sale_phase_tier_detail: &mut Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &mut ctx.accounts.sale_phase_tier_detail;
     sale_phase_tier_detail.total_mint += quantity;
     sale_phase_tier_detail.total_buy_with_token += quantity;
-    sale_phase_tier_detail.total_payment += price_in_usd;
+    sale_phase_tier_detail.total_payment += paid_price_in_usd;
     sale_phase_tier_detail.total_discount += full_discount_amount_in_usd;
     sale_phase_tier_detail.total_discount += half_discount_amount_in_usd;
     sale_phase_tier_detail.last_block_timestamp = timestamp;
 
-    if sale_phase_tier_detail.total_mint >= sale_phase_tier_detail.quantity {
+    if sale_phase_tier_detail.is_auction_rebate && !is_whitelist_price {
+        let lowest_unit_price: u64 = *unit_prices.iter().min().unwrap();
+
+        if sale_phase_tier_detail.clearing_price == 0 || lowest_unit_price < sale_phase_tier_detail.clearing_price {
+            sale_phase_tier_detail.clearing_price = lowest_unit_price;
+        }
+    }
+
+    if sale_phase_tier_detail.minted_supply() >= sale_phase_tier_detail.public_quantity() {
         sale_phase_tier_detail.is_completed = true;
         sale_phase_detail.total_completed_tiers += 1;
     }
@@ -314,16 +545,23 @@ pub fn handle_buy_with_token<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info,
     let user_detail: &mut Box<Account<UserDetailAccount>> = &mut ctx.accounts.user_detail;
     user_detail.total_mint += quantity;
     user_detail.total_buy_with_token += quantity;
-    user_detail.total_payment += price_in_usd;
+    user_detail.total_payment += paid_price_in_usd;
     user_detail.total_discount += full_discount_amount_in_usd;
     user_detail.total_discount += half_discount_amount_in_usd;
     user_detail.total_orders += 1;
     user_detail.last_block_timestamp = timestamp;
 
+    if let (Some(loyalty_detail), Some(_)) = (ctx.accounts.loyalty_detail.as_deref_mut(), loyalty_node_mint_account) {
+        loyalty_detail.last_block_timestamp = timestamp;
+        loyalty_detail.is_used = true;
+        loyalty_detail.user = ctx.accounts.user.key();
+        loyalty_detail.order_id = order_id_int;
+    }
+
     let user_tier_detail: &mut Box<Account<UserTierDetailAccount>> = &mut ctx.accounts.user_tier_detail;
     user_tier_detail.total_mint += quantity;
     user_tier_detail.total_buy_with_token += quantity;
-    user_tier_detail.total_payment += price_in_usd;
+    user_tier_detail.total_payment += paid_price_in_usd;
     user_tier_detail.total_discount += full_discount_amount_in_usd;
     user_tier_detail.total_discount += half_discount_amount_in_usd;
     user_tier_detail.last_block_timestamp = timestamp;
@@ -333,6 +571,7 @@ pub fn handle_buy_with_token<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info,
         sale_phase_tier_detail.total_whitelist_mint += quantity;
         user_detail.total_whitelist_mint += quantity;
         user_tier_detail.total_whitelist_mint += quantity;
+        user_tier_detail.total_whitelist_payment += price_in_usd;
     }
 
     // Event
@@ -343,7 +582,7 @@ pub fn handle_buy_with_token<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info,
         order_id,
         user: ctx.accounts.user.key(),
         user_payer: ctx.accounts.user_payer.key(),
-        price_feed: ctx.accounts.price_update.key(),
+        price_feed: ctx.accounts.price_update.as_ref().map_or(Pubkey::default(), |price_update| price_update.key()),
         payment_receiver: payment_receiver.key(),
         full_discount_receiver: full_discount_receiver.key(),
         half_discount_receiver: half_discount_receiver.key(),
@@ -370,6 +609,17 @@ pub fn handle_buy_with_token<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info,
         payment_token_half_discount_receiver_token_account: payment_token_half_discount_receiver_token_account.key(),
         quantity,
         is_whitelist,
+        payment_amount: payment_amount_in_lamport,
+        max_payment_amount,
+        is_installment,
+        volume_discount,
+        volume_discount_in_usd,
+        volume_discount_in_lamport,
+        loyalty_node_mint_account,
+        loyalty_discount,
+        loyalty_discount_in_usd,
+        loyalty_discount_in_lamport,
+        whitelist_pass_amount,
     };
 
     emit!(event);
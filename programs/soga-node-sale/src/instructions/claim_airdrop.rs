@@ -0,0 +1,224 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+    USER_DETAIL_ACCOUNT_PREFIX,
+    UserDetailAccount,
+    USER_TIER_DETAIL_ACCOUNT_PREFIX,
+    UserTierDetailAccount,
+    ORDER_DETAIL_ACCOUNT_PREFIX,
+    OrderDetailAccount,
+    AIRDROP_CLAIM_BITMAP_ACCOUNT_PREFIX,
+    AirdropClaimBitmapAccount,
+};
+
+use crate::events::{
+    ClaimAirdropEvent
+};
+
+use crate::merkle::{get_airdrop_leaf, verify_merkle_proof};
+
+use crate::utils::{
+    check_phase_airdrop,
+    check_phase_tier_airdrop,
+    check_value_is_zero,
+    check_order_id,
+    check_airdrop_claim_index,
+    check_airdrop_claimed, check_airdrop_claim_quantity,
+    check_airdrop_proof,
+};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8,
+sale_phase_name: String, tier_id: String, order_id: String, index: u32, quantity: u64)]
+pub struct ClaimAirdropInputAccounts<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+    mut,
+    seeds = [
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_name.as_ref(),
+    ],
+    bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    tier_id.as_ref()
+    ],
+    bump = _sale_phase_tier_detail_bump,
+    )]
+    pub sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    AIRDROP_CLAIM_BITMAP_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_tier_detail.key().as_ref(),
+    sale_phase_tier_detail.airdrop_merkle_root.as_ref(),
+    ],
+    bump,
+    )]
+    pub airdrop_claim_bitmap: Box<Account<'info, AirdropClaimBitmapAccount>>,
+
+    #[account(
+    init_if_needed,
+    payer = user,
+    space = UserDetailAccount::space(),
+    seeds = [
+    USER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    user.key().as_ref(),
+    ],
+    bump,
+    )]
+    pub user_detail: Box<Account<'info, UserDetailAccount>>,
+
+    #[account(
+    init_if_needed,
+    payer = user,
+    space = UserTierDetailAccount::space(),
+    seeds = [
+    USER_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    user_detail.key().as_ref(),
+    sale_phase_tier_detail.key().as_ref(),
+    ],
+    bump,
+    )]
+    pub user_tier_detail: Box<Account<'info, UserTierDetailAccount>>,
+
+    #[account(
+    init,
+    payer = user,
+    space = OrderDetailAccount::space(quantity),
+    seeds = [
+    ORDER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    user_detail.key().as_ref(),
+    order_id.as_ref(),
+    ],
+    bump,
+    )]
+    pub order_detail: Box<Account<'info, OrderDetailAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Claims the airdrop snapshot entry `index` of the user as a free order, the user pays
+/// the rent of the claim and the order is filled like a bought order.
+pub fn handle_claim_airdrop(ctx: Context<ClaimAirdropInputAccounts>,
+                            _sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8,
+                            sale_phase_name: String, tier_id: String, order_id: String,
+                            index: u32, quantity: u64, proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let order_id_int: u64 = order_id.clone().parse().unwrap();
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+    let sale_phase_tier_detail: &Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &ctx.accounts.sale_phase_tier_detail;
+    let airdrop_claim_bitmap: &Box<Account<AirdropClaimBitmapAccount>> = &ctx.accounts.airdrop_claim_bitmap;
+    let user_detail: &Box<Account<UserDetailAccount>> = &ctx.accounts.user_detail;
+
+    // Checks
+    check_phase_airdrop(sale_phase_detail.airdrop_enable)?;
+    check_phase_tier_airdrop(sale_phase_tier_detail.airdrop_enable)?;
+
+    check_value_is_zero(quantity as usize)?;
+
+    check_airdrop_claim_index(index, airdrop_claim_bitmap.total_claims)?;
+
+    check_airdrop_claimed(airdrop_claim_bitmap.is_claimed(index))?;
+
+    let leaf: [u8; 32] = get_airdrop_leaf(index, &ctx.accounts.user.key(), &sale_phase_tier_detail.key(), quantity);
+
+    check_airdrop_proof(verify_merkle_proof(&proof, &airdrop_claim_bitmap.merkle_root, leaf))?;
+
+    check_airdrop_claim_quantity(sale_phase_tier_detail.airdrop_claimed_quantity + quantity, sale_phase_tier_detail.airdrop_claim_quantity)?;
+
+    check_order_id(user_detail.total_orders + 1, order_id_int)?;
+
+    // Update
+    let order_detail: &mut Box<Account<OrderDetailAccount>> = &mut ctx.accounts.order_detail;
+    order_detail.last_block_timestamp = timestamp;
+    order_detail.tier_id = tier_id.clone().parse().unwrap();
+    order_detail.is_completed = false;
+    order_detail.quantity = quantity;
+    order_detail.payment_token_mint_account = None;
+    order_detail.token_ids = Vec::with_capacity(quantity as usize);
+    order_detail.is_token_ids_minted = Vec::with_capacity(quantity as usize);
+    order_detail.is_token_ids_revoked = Vec::with_capacity(quantity as usize);
+    order_detail.is_token_ids_upgraded = Vec::with_capacity(quantity as usize);
+    order_detail.is_token_ids_reissued = Vec::with_capacity(quantity as usize);
+
+    let mut current_token_id: u64 = ctx.accounts.sale_phase_tier_detail.total_mint;
+
+    for _i in 0..quantity {
+        current_token_id += 1;
+        order_detail.token_ids.push(current_token_id);
+        order_detail.is_token_ids_minted.push(false);
+        order_detail.is_token_ids_revoked.push(false);
+        order_detail.is_token_ids_upgraded.push(false);
+        order_detail.is_token_ids_reissued.push(false);
+    };
+
+    let airdrop_claim_bitmap: &mut Box<Account<AirdropClaimBitmapAccount>> = &mut ctx.accounts.airdrop_claim_bitmap;
+    airdrop_claim_bitmap.last_block_timestamp = timestamp;
+    airdrop_claim_bitmap.total_claimed += 1;
+    airdrop_claim_bitmap.set_claimed(index);
+
+    let merkle_root: [u8; 32] = airdrop_claim_bitmap.merkle_root;
+
+    let sale_phase_detail: &mut Box<Account<SogaNodeSalePhaseDetailAccount>> = &mut ctx.accounts.sale_phase_detail;
+    sale_phase_detail.total_mint += quantity;
+    sale_phase_detail.total_airdrop += quantity;
+    sale_phase_detail.last_block_timestamp = timestamp;
+
+    let sale_phase_tier_detail: &mut Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &mut ctx.accounts.sale_phase_tier_detail;
+    sale_phase_tier_detail.total_mint += quantity;
+    sale_phase_tier_detail.total_airdrop += quantity;
+    sale_phase_tier_detail.total_reserved_claimed += quantity;
+    sale_phase_tier_detail.airdrop_claimed_quantity += quantity;
+    sale_phase_tier_detail.last_block_timestamp = timestamp;
+
+    if !sale_phase_tier_detail.is_completed && sale_phase_tier_detail.minted_supply() >= sale_phase_tier_detail.public_quantity() {
+        sale_phase_tier_detail.is_completed = true;
+        sale_phase_detail.total_completed_tiers += 1;
+    }
+
+    let user_detail: &mut Box<Account<UserDetailAccount>> = &mut ctx.accounts.user_detail;
+    user_detail.total_mint += quantity;
+    user_detail.total_airdrop += quantity;
+    user_detail.total_orders += 1;
+    user_detail.last_block_timestamp = timestamp;
+
+    let user_tier_detail: &mut Box<Account<UserTierDetailAccount>> = &mut ctx.accounts.user_tier_detail;
+    user_tier_detail.total_mint += quantity;
+    user_tier_detail.total_airdrop += quantity;
+    user_tier_detail.last_block_timestamp = timestamp;
+
+    // Event
+    let event: ClaimAirdropEvent = ClaimAirdropEvent {
+        timestamp,
+        sale_phase_name,
+        tier_id,
+        order_id,
+        user: ctx.accounts.user.key(),
+        index,
+        quantity,
+        merkle_root,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,283 @@
+use std::ops::Mul;
+use anchor_lang::prelude::*;
+
+use crate::oracle::{get_quorum_oracle_price, get_expo_base, get_price_update, OraclePrice};
+
+use crate::pricing::{usd_to_token_amount, SOL_DECIMALS};
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+    USER_DETAIL_ACCOUNT_PREFIX,
+    UserDetailAccount,
+    USER_TIER_DETAIL_ACCOUNT_PREFIX,
+    UserTierDetailAccount,
+    ORDER_DETAIL_ACCOUNT_PREFIX,
+    OrderDetailAccount,
+    RESERVATION_DETAIL_ACCOUNT_PREFIX,
+    ReservationDetailAccount,
+};
+
+use crate::events::{
+    ClaimReservationEvent
+};
+
+use crate::utils::{check_payment_receiver, check_price_feed, check_value_is_zero, check_order_id, check_max_payment_amount, check_reservation_claimed, check_hard_cap};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8,
+sale_phase_name: String, tier_id: String, order_id: String)]
+pub struct ClaimReservationInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(mut)]
+    pub partner: Signer<'info>,
+
+    /// CHECK: payment receiver, checked against the sale phase detail
+    #[account(mut)]
+    pub payment_receiver: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+        SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_name.as_ref(),
+        ],
+        bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+        SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail.key().as_ref(),
+        tier_id.as_ref()
+        ],
+        bump = _sale_phase_tier_detail_bump,
+    )]
+    pub sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+        RESERVATION_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_tier_detail.key().as_ref(),
+        partner.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub reservation_detail: Box<Account<'info, ReservationDetailAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserDetailAccount::space(),
+        seeds = [
+        USER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail.key().as_ref(),
+        partner.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub user_detail: Box<Account<'info, UserDetailAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserTierDetailAccount::space(),
+        seeds = [
+        USER_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        user_detail.key().as_ref(),
+        sale_phase_tier_detail.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub user_tier_detail: Box<Account<'info, UserTierDetailAccount>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = OrderDetailAccount::space(reservation_detail.quantity),
+        seeds = [
+        ORDER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail.key().as_ref(),
+        user_detail.key().as_ref(),
+        order_id.as_ref(),
+        ],
+        bump,
+    )]
+    pub order_detail: Box<Account<'info, OrderDetailAccount>>,
+
+    /// not required for free reservations
+    /// CHECK: price feed, owner and layout checked by the oracle source
+    pub price_update: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Claims the nodes reserved for the partner as an order that is filled like a bought
+/// order. Reservations with a price are paid in sol, remaining accounts are the
+/// registered price sources of the phase.
+pub fn handle_claim_reservation<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, ClaimReservationInputAccounts<'info>>,
+                                                   _sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8,
+                                                   sale_phase_name: String, tier_id: String, order_id: String, max_payment_amount: u64,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let order_id_int: u64 = order_id.clone().parse().unwrap();
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+    let reservation_detail: &Box<Account<ReservationDetailAccount>> = &ctx.accounts.reservation_detail;
+    let user_detail: &Box<Account<UserDetailAccount>> = &ctx.accounts.user_detail;
+
+    let quantity: u64 = reservation_detail.quantity;
+
+    // Checks
+    check_payment_receiver(sale_phase_detail.payment_receiver, ctx.accounts.payment_receiver.key())?;
+
+    check_reservation_claimed(reservation_detail.is_claimed)?;
+
+    check_value_is_zero(quantity as usize)?;
+
+    check_order_id(user_detail.total_orders + 1, order_id_int)?;
+
+    // Make Payment
+    let price_in_usd: u64 = reservation_detail.price.mul(quantity);
+
+    check_hard_cap(sale_phase_detail.committed_payment() + price_in_usd, sale_phase_detail.hard_cap)?;
+
+    let mut price_in_lamport: u64 = 0;
+    let mut pyth_expo: u64 = 0;
+    let mut pyth_price: u64 = 0;
+
+    let mut cap_escrow_amount: u64 = 0;
+
+    if price_in_usd > 0 {
+        let price_update = get_price_update(ctx.accounts.price_update.as_deref())?;
+
+        check_price_feed(sale_phase_detail.price_feed_address, price_update.key())?;
+
+        let price: OraclePrice = get_quorum_oracle_price(price_update, ctx.remaining_accounts, sale_phase_detail.price_feed_id.as_str(),
+                                                         &sale_phase_detail.oracle_config, &sale_phase_detail.price_sources, sale_phase_detail.price_source_quorum)?;
+
+        pyth_expo = get_expo_base(&price);
+        pyth_price = price.price as u64;
+
+        price_in_lamport = usd_to_token_amount(price_in_usd, SOL_DECIMALS, pyth_price, price.exponent)?;
+
+        check_max_payment_amount(price_in_lamport, max_payment_amount)?;
+
+        // the phase account holds the payment until the soft cap is reached
+        if !sale_phase_detail.is_soft_cap_reached() {
+            cap_escrow_amount = price_in_lamport;
+        }
+
+        let deposit_receiver: AccountInfo = if cap_escrow_amount > 0 { sale_phase_detail.to_account_info() } else { ctx.accounts.payment_receiver.to_account_info() };
+
+        let deposit_amount_ix = anchor_lang::solana_program::system_instruction::transfer(
+            &ctx.accounts.partner.key(),
+            &deposit_receiver.key(),
+            price_in_lamport,
+        );
+
+        anchor_lang::solana_program::program::invoke(
+            &deposit_amount_ix,
+            &[
+                ctx.accounts.partner.to_account_info(),
+                deposit_receiver,
+            ],
+        )?;
+    }
+
+    // Update
+    let order_detail: &mut Box<Account<OrderDetailAccount>> = &mut ctx.accounts.order_detail;
+    order_detail.last_block_timestamp = timestamp;
+    order_detail.tier_id = tier_id.clone().parse().unwrap();
+    order_detail.is_completed = false;
+    order_detail.quantity = quantity;
+    order_detail.total_payment_in_usd = price_in_usd;
+    order_detail.total_user_discount_in_usd = price_in_usd;
+    order_detail.total_discount_in_usd = 0;
+    order_detail.total_payment = price_in_lamport;
+    order_detail.total_user_discount = price_in_lamport;
+    order_detail.total_discount = 0;
+    order_detail.payment_token_mint_account = None;
+    order_detail.token_ids = Vec::with_capacity(quantity as usize);
+    order_detail.is_token_ids_minted = Vec::with_capacity(quantity as usize);
+    order_detail.is_token_ids_revoked = Vec::with_capacity(quantity as usize);
+    order_detail.is_token_ids_upgraded = Vec::with_capacity(quantity as usize);
+    order_detail.is_token_ids_reissued = Vec::with_capacity(quantity as usize);
+    order_detail.cap_escrow_amount = cap_escrow_amount;
+
+    let mut current_token_id: u64 = ctx.accounts.sale_phase_tier_detail.total_mint;
+
+    for _i in 0..quantity {
+        current_token_id += 1;
+        order_detail.token_ids.push(current_token_id);
+        order_detail.is_token_ids_minted.push(false);
+        order_detail.is_token_ids_revoked.push(false);
+        order_detail.is_token_ids_upgraded.push(false);
+        order_detail.is_token_ids_reissued.push(false);
+    };
+
+    let reservation_detail: &mut Box<Account<ReservationDetailAccount>> = &mut ctx.accounts.reservation_detail;
+    reservation_detail.last_block_timestamp = timestamp;
+    reservation_detail.is_claimed = true;
+    reservation_detail.order_id = order_id_int;
+
+    let sale_phase_detail: &mut Box<Account<SogaNodeSalePhaseDetailAccount>> = &mut ctx.accounts.sale_phase_detail;
+    sale_phase_detail.total_mint += quantity;
+    sale_phase_detail.total_buy += quantity;
+    sale_phase_detail.total_payment += price_in_usd;
+    sale_phase_detail.latch_soft_cap();
+    sale_phase_detail.last_block_timestamp = timestamp;
+
+    let sale_phase_tier_detail: &mut Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &mut ctx.accounts.sale_phase_tier_detail;
+    sale_phase_tier_detail.total_mint += quantity;
+    sale_phase_tier_detail.total_buy += quantity;
+    sale_phase_tier_detail.total_payment += price_in_usd;
+    sale_phase_tier_detail.total_reserved_claimed += quantity;
+    sale_phase_tier_detail.last_block_timestamp = timestamp;
+
+    if !sale_phase_tier_detail.is_completed && sale_phase_tier_detail.minted_supply() >= sale_phase_tier_detail.public_quantity() {
+        sale_phase_tier_detail.is_completed = true;
+        sale_phase_detail.total_completed_tiers += 1;
+    }
+
+    let user_detail: &mut Box<Account<UserDetailAccount>> = &mut ctx.accounts.user_detail;
+    user_detail.total_mint += quantity;
+    user_detail.total_buy += quantity;
+    user_detail.total_payment += price_in_usd;
+    user_detail.total_orders += 1;
+    user_detail.last_block_timestamp = timestamp;
+
+    let user_tier_detail: &mut Box<Account<UserTierDetailAccount>> = &mut ctx.accounts.user_tier_detail;
+    user_tier_detail.total_mint += quantity;
+    user_tier_detail.total_buy += quantity;
+    user_tier_detail.total_payment += price_in_usd;
+    user_tier_detail.last_block_timestamp = timestamp;
+
+    // Event
+    let event: ClaimReservationEvent = ClaimReservationEvent {
+        timestamp,
+        sale_phase_name,
+        tier_id,
+        order_id,
+        partner: ctx.accounts.partner.key(),
+        payment_receiver: ctx.accounts.payment_receiver.key(),
+        quantity,
+        pyth_expo,
+        pyth_price,
+        total_price_in_usd: price_in_usd,
+        total_price_in_lamport: price_in_lamport,
+        max_payment_amount,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
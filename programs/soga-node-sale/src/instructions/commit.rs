@@ -0,0 +1,162 @@
+use std::ops::Mul;
+use anchor_lang::prelude::*;
+
+use crate::oracle::{get_quorum_oracle_price, OraclePrice};
+
+use crate::pricing::{usd_to_token_amount, SOL_DECIMALS};
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+    COMMITMENT_DETAIL_ACCOUNT_PREFIX,
+    CommitmentDetailAccount,
+};
+
+use crate::events::{
+    CommitEvent
+};
+
+use crate::utils::{check_signing_authority, check_price_feed, check_phase_buy, check_phase_tier_buy, check_value_is_zero, check_mint_limit_with_quantity, check_max_payment_amount, check_commit_window_open, check_commitment_payment_token, check_ticket_ranges};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, sale_phase_name: String, tier_id: String)]
+pub struct CommitInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub signing_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub user_payer: Signer<'info>,
+
+    /// CHECK: user
+    pub user: AccountInfo<'info>,
+
+    #[account(
+        seeds = [
+        SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_name.as_ref(),
+        ],
+        bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+        SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail.key().as_ref(),
+        tier_id.as_ref()
+        ],
+        bump = _sale_phase_tier_detail_bump,
+    )]
+    pub sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = CommitmentDetailAccount::space(),
+        seeds = [
+        COMMITMENT_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_tier_detail.key().as_ref(),
+        user.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub commitment_detail: Box<Account<'info, CommitmentDetailAccount>>,
+
+    /// CHECK: price feed, owner and layout checked by the oracle source
+    pub price_update: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_commit<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, CommitInputAccounts<'info>>,
+                                        _sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8,
+                                        sale_phase_name: String, tier_id: String, quantity: u64, max_payment_amount: u64,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+    let sale_phase_tier_detail: &Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &ctx.accounts.sale_phase_tier_detail;
+    let commitment_detail: &Box<Account<CommitmentDetailAccount>> = &ctx.accounts.commitment_detail;
+
+    // Checks
+    check_value_is_zero(quantity as usize)?;
+
+    check_phase_buy(sale_phase_detail.buy_enable)?;
+
+    check_phase_tier_buy(sale_phase_tier_detail.buy_enable)?;
+
+    check_signing_authority(sale_phase_detail.signing_authority, ctx.accounts.signing_authority.key())?;
+
+    check_price_feed(sale_phase_detail.price_feed_address, ctx.accounts.price_update.key())?;
+
+    check_commit_window_open(sale_phase_tier_detail.is_commit_open(timestamp))?;
+
+    check_mint_limit_with_quantity(sale_phase_tier_detail.mint_limit, commitment_detail.quantity + quantity)?;
+
+    check_commitment_payment_token(commitment_detail.quantity, commitment_detail.payment_token_mint_account, None)?;
+
+    check_ticket_ranges(commitment_detail.ticket_ranges.len(), commitment_detail.is_adjacent(sale_phase_tier_detail.total_committed))?;
+
+    // Make Payment
+    let price_in_usd: u64 = sale_phase_tier_detail.price.mul(quantity);
+
+    let price: OraclePrice = get_quorum_oracle_price(&ctx.accounts.price_update, ctx.remaining_accounts, sale_phase_detail.price_feed_id.as_str(),
+                                                     &sale_phase_detail.oracle_config, &sale_phase_detail.price_sources, sale_phase_detail.price_source_quorum)?;
+
+    let price_in_lamport: u64 = usd_to_token_amount(price_in_usd, SOL_DECIMALS, price.price as u64, price.exponent)?;
+
+    check_max_payment_amount(price_in_lamport, max_payment_amount)?;
+
+    // the tier account holds the deposit until the commitment is allocated
+    let deposit_amount_ix = anchor_lang::solana_program::system_instruction::transfer(
+        &ctx.accounts.user_payer.key(),
+        &sale_phase_tier_detail.key(),
+        price_in_lamport,
+    );
+
+    anchor_lang::solana_program::program::invoke(
+        &deposit_amount_ix,
+        &[
+            ctx.accounts.user_payer.to_account_info(),
+            sale_phase_tier_detail.to_account_info(),
+        ],
+    )?;
+
+    // Update
+    let commitment_detail: &mut Box<Account<CommitmentDetailAccount>> = &mut ctx.accounts.commitment_detail;
+    commitment_detail.last_block_timestamp = timestamp;
+    commitment_detail.user = ctx.accounts.user.key();
+    commitment_detail.tier_id = tier_id.clone().parse().unwrap();
+    commitment_detail.quantity += quantity;
+    commitment_detail.total_payment_in_usd += price_in_usd;
+    commitment_detail.total_payment += price_in_lamport;
+    commitment_detail.add_tickets(ctx.accounts.sale_phase_tier_detail.total_committed, quantity);
+
+    let sale_phase_tier_detail: &mut Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &mut ctx.accounts.sale_phase_tier_detail;
+    sale_phase_tier_detail.total_committed += quantity;
+    sale_phase_tier_detail.last_block_timestamp = timestamp;
+
+    // Event
+    let event: CommitEvent = CommitEvent {
+        timestamp,
+        sale_phase_name,
+        tier_id,
+        user: ctx.accounts.user.key(),
+        user_payer: ctx.accounts.user_payer.key(),
+        payment_token_mint_account: None,
+        quantity,
+        total_quantity: commitment_detail.quantity,
+        price_in_usd,
+        payment_amount: price_in_lamport,
+        max_payment_amount,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
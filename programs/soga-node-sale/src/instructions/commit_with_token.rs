@@ -0,0 +1,176 @@
+use std::ops::Mul;
+use anchor_lang::prelude::*;
+
+use crate::oracle::{get_payment_token_amount, OraclePrice};
+
+use anchor_spl::{
+    token_interface::{TokenAccount, TransferChecked, transfer_checked},
+};
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+    COMMITMENT_DETAIL_ACCOUNT_PREFIX,
+    CommitmentDetailAccount,
+    SogaNodeSalePhasePaymentTokenDetailAccount,
+};
+
+use crate::events::{
+    CommitEvent
+};
+
+use crate::utils::{check_signing_authority, check_phase_buy_with_token, check_phase_tier_buy_with_token, check_payment_token, check_payment_token_mint_account, check_value_is_zero, check_mint_limit_with_quantity, check_max_payment_amount, check_commit_window_open, check_commitment_payment_token, check_commitment_escrow, check_ticket_ranges};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, sale_phase_name: String, tier_id: String)]
+pub struct CommitWithTokenInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub signing_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub user_payer: Signer<'info>,
+
+    /// CHECK: user
+    pub user: AccountInfo<'info>,
+
+    #[account(
+        seeds = [
+        SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_name.as_ref(),
+        ],
+        bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+        SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail.key().as_ref(),
+        tier_id.as_ref()
+        ],
+        bump = _sale_phase_tier_detail_bump,
+    )]
+    pub sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = CommitmentDetailAccount::space(),
+        seeds = [
+        COMMITMENT_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_tier_detail.key().as_ref(),
+        user.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub commitment_detail: Box<Account<'info, CommitmentDetailAccount>>,
+
+    /// not required for fixed-rate payment tokens without a depeg guard
+    /// CHECK: price feed, owner and layout checked by the oracle source
+    pub price_update: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_commit_with_token<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, CommitWithTokenInputAccounts<'info>>,
+                                                   _sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8,
+                                                   sale_phase_name: String, tier_id: String, quantity: u64, max_payment_amount: u64,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+    let sale_phase_tier_detail: &Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &ctx.accounts.sale_phase_tier_detail;
+    let commitment_detail: &Box<Account<CommitmentDetailAccount>> = &ctx.accounts.commitment_detail;
+
+    let sale_phase_payment_token_detail_info = &ctx.remaining_accounts[0];
+    let payment_token_mint_account = &ctx.remaining_accounts[1];
+    let payment_token_program = &ctx.remaining_accounts[2];
+
+    let payment_token_user_payer_token_account = &ctx.remaining_accounts[3];
+    let commitment_escrow = &ctx.remaining_accounts[4];
+
+    let sale_phase_payment_token_detail = SogaNodeSalePhasePaymentTokenDetailAccount::try_deserialize(&mut &**sale_phase_payment_token_detail_info.try_borrow_mut_data()?).unwrap();
+
+    // Checks
+    check_value_is_zero(quantity as usize)?;
+
+    check_phase_buy_with_token(sale_phase_detail.buy_with_token_enable)?;
+
+    check_phase_tier_buy_with_token(sale_phase_tier_detail.buy_with_token_enable)?;
+
+    check_payment_token(sale_phase_payment_token_detail.enable)?;
+
+    check_payment_token_mint_account(sale_phase_payment_token_detail.mint, payment_token_mint_account.key())?;
+
+    check_signing_authority(sale_phase_detail.signing_authority, ctx.accounts.signing_authority.key())?;
+
+    check_commit_window_open(sale_phase_tier_detail.is_commit_open(timestamp))?;
+
+    check_mint_limit_with_quantity(sale_phase_tier_detail.mint_limit, commitment_detail.quantity + quantity)?;
+
+    check_commitment_payment_token(commitment_detail.quantity, commitment_detail.payment_token_mint_account, Some(payment_token_mint_account.key()))?;
+
+    check_ticket_ranges(commitment_detail.ticket_ranges.len(), commitment_detail.is_adjacent(sale_phase_tier_detail.total_committed))?;
+
+    let commitment_escrow_token: TokenAccount = TokenAccount::try_deserialize(&mut &commitment_escrow.try_borrow_data()?[..])?;
+
+    check_commitment_escrow(commitment_escrow_token.owner, sale_phase_tier_detail.key(), commitment_escrow_token.mint, payment_token_mint_account.key())?;
+
+    // Make Payment
+    let price_in_usd: u64 = sale_phase_tier_detail.price.mul(quantity);
+
+    let (price_in_lamport, _price): (u64, Option<OraclePrice>) = get_payment_token_amount(price_in_usd, &sale_phase_payment_token_detail,
+                                                                                             ctx.accounts.price_update.as_deref(), &ctx.remaining_accounts[5..])?;
+
+    check_max_payment_amount(price_in_lamport, max_payment_amount)?;
+
+    // the escrow owned by the tier account holds the deposit until the commitment is allocated
+    let cpi_accounts = TransferChecked {
+        from: payment_token_user_payer_token_account.to_account_info(),
+        mint: payment_token_mint_account.to_account_info(),
+        to: commitment_escrow.to_account_info(),
+        authority: ctx.accounts.user_payer.to_account_info(),
+    };
+    let cpi_program = payment_token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+    transfer_checked(cpi_context, price_in_lamport, sale_phase_payment_token_detail.decimals)?;
+
+    // Update
+    let commitment_detail: &mut Box<Account<CommitmentDetailAccount>> = &mut ctx.accounts.commitment_detail;
+    commitment_detail.last_block_timestamp = timestamp;
+    commitment_detail.user = ctx.accounts.user.key();
+    commitment_detail.tier_id = tier_id.clone().parse().unwrap();
+    commitment_detail.quantity += quantity;
+    commitment_detail.total_payment_in_usd += price_in_usd;
+    commitment_detail.total_payment += price_in_lamport;
+    commitment_detail.add_tickets(ctx.accounts.sale_phase_tier_detail.total_committed, quantity);
+    commitment_detail.payment_token_mint_account = Some(payment_token_mint_account.key());
+
+    let sale_phase_tier_detail: &mut Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &mut ctx.accounts.sale_phase_tier_detail;
+    sale_phase_tier_detail.total_committed += quantity;
+    sale_phase_tier_detail.last_block_timestamp = timestamp;
+
+    // Event
+    let event: CommitEvent = CommitEvent {
+        timestamp,
+        sale_phase_name,
+        tier_id,
+        user: ctx.accounts.user.key(),
+        user_payer: ctx.accounts.user_payer.key(),
+        payment_token_mint_account: Some(payment_token_mint_account.key()),
+        quantity,
+        total_quantity: commitment_detail.quantity,
+        price_in_usd,
+        payment_amount: price_in_lamport,
+        max_payment_amount,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
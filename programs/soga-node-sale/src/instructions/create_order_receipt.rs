@@ -122,7 +122,7 @@ pub fn handle_create_order_receipt(ctx: Context<CreateOrderReceiptInputAccounts>
 
     check_quantity(sale_phase_tier_detail.mint_limit, quantity)?;
 
-    check_token_quantity_out_of_range(sale_phase_tier_detail.total_mint + quantity, sale_phase_tier_detail.quantity)?;
+    check_token_quantity_out_of_range(sale_phase_tier_detail.minted_supply() + quantity, sale_phase_tier_detail.public_quantity())?;
 
     let user_detail: &Box<Account<UserDetailAccount>> = &ctx.accounts.user_detail;
 
@@ -148,6 +148,9 @@ pub fn handle_create_order_receipt(ctx: Context<CreateOrderReceiptInputAccounts>
     order_detail.payment_token_mint_account = None;
     order_detail.token_ids = Vec::with_capacity(quantity as usize);
     order_detail.is_token_ids_minted = Vec::with_capacity(quantity as usize);
+    order_detail.is_token_ids_revoked = Vec::with_capacity(quantity as usize);
+    order_detail.is_token_ids_upgraded = Vec::with_capacity(quantity as usize);
+    order_detail.is_token_ids_reissued = Vec::with_capacity(quantity as usize);
     order_detail.is_whitelist = false;
 
     let mut current_token_id: u64 = sale_phase_tier_detail.total_mint;
@@ -156,6 +159,9 @@ pub fn handle_create_order_receipt(ctx: Context<CreateOrderReceiptInputAccounts>
         current_token_id += 1;
         order_detail.token_ids.push(current_token_id);
         order_detail.is_token_ids_minted.push(false);
+        order_detail.is_token_ids_revoked.push(false);
+        order_detail.is_token_ids_upgraded.push(false);
+        order_detail.is_token_ids_reissued.push(false);
     };
 
     let sale_phase_detail: &mut Box<Account<SogaNodeSalePhaseDetailAccount>> = &mut ctx.accounts.sale_phase_detail;
@@ -169,7 +175,7 @@ pub fn handle_create_order_receipt(ctx: Context<CreateOrderReceiptInputAccounts>
     sale_phase_tier_detail.total_airdrop += quantity;
     sale_phase_tier_detail.last_block_timestamp = timestamp;
 
-    if sale_phase_tier_detail.total_mint >= sale_phase_tier_detail.quantity {
+    if sale_phase_tier_detail.minted_supply() >= sale_phase_tier_detail.public_quantity() {
         sale_phase_tier_detail.is_completed = true;
         sale_phase_detail.total_completed_tiers += 1;
     }
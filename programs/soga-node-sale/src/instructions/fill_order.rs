@@ -21,16 +21,29 @@ use crate::states::{
     NODE_ACCOUNT_PREFIX,
     ORDER_DETAIL_ACCOUNT_PREFIX,
     OrderDetailAccount,
+    FILL_FEE_VAULT_ACCOUNT_PREFIX,
+    FillFeeVaultAccount,
+    NodeMintMode,
 };
 
 use crate::events::{FillOrderEvent};
 
 use crate::utils::{
-    check_signing_authority,
     check_phase_tier_collection,
     check_order_token_id_filled,
     check_order_token_id,
     check_order_is_filled,
+    check_order_voided,
+    check_soft_cap_reached,
+    check_installment_paid,
+    check_cap_escrow_pending,
+    check_order_token_id_revoked,
+    check_order_token_id_upgraded,
+    check_mint_mode,
+    check_phase_tier_is_compressed,
+    get_fill_reward,
+    get_unlock_timestamp,
+    is_node_locked,
 };
 
 #[derive(Accounts)]
@@ -40,8 +53,6 @@ pub struct FillOrderInputAccounts<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    pub signing_authority: Signer<'info>,
-
     /// CHECK: user
     #[account(mut)]
     pub user: AccountInfo<'info>,
@@ -130,6 +141,17 @@ pub struct FillOrderInputAccounts<'info> {
 
     pub associated_token_program: Program<'info, AssociatedToken>,
 
+    /// not required when the phase pays no fill reward
+    #[account(
+    mut,
+    seeds = [
+    FILL_FEE_VAULT_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    ],
+    bump,
+    )]
+    pub fill_fee_vault: Option<Box<Account<'info, FillFeeVaultAccount>>>,
+
     pub system_program: Program<'info, System>,
 
     pub rent: Sysvar<'info, Rent>,
@@ -153,17 +175,32 @@ pub fn handle_file_order<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, Fill
     let order_detail: &Box<Account<OrderDetailAccount>> = &ctx.accounts.order_detail;
 
     // Checks
-    check_signing_authority(sale_phase_detail.signing_authority, ctx.accounts.signing_authority.key())?;
+    check_mint_mode(sale_phase_detail.mint_mode, NodeMintMode::Metaplex)?;
     check_phase_tier_collection(sale_phase_tier_detail.collection_mint_address, ctx.accounts.collection_mint_account.key())?;
+    check_phase_tier_is_compressed(sale_phase_tier_detail.is_compressed)?;
 
     check_order_is_filled(order_detail.is_completed)?;
 
+    check_order_voided(order_detail.is_voided)?;
+
+    // orders are filled once the phase soft cap is reached
+    check_soft_cap_reached(sale_phase_detail.is_soft_cap_reached())?;
+
+    check_installment_paid(order_detail.is_installment_paid())?;
+
+    // the escrowed payment is released before the order is filled, a refund can not void a filled order
+    check_cap_escrow_pending(order_detail.cap_escrow_amount > 0 && !order_detail.is_cap_settled)?;
+
     check_order_token_id(order_detail.token_ids.contains(&token_id_int))?;
 
     let index = order_detail.token_ids.iter().position(|r| r == &token_id_int).unwrap();
 
     check_order_token_id_filled(order_detail.is_token_ids_minted[index])?;
 
+    check_order_token_id_revoked(order_detail.is_token_id_revoked(index))?;
+
+    check_order_token_id_upgraded(order_detail.is_token_id_upgraded(index))?;
+
     let sale_phase_detail_key: Pubkey = ctx.accounts.sale_phase_detail.key();
 
     let signer_seeds = &[
@@ -188,18 +225,23 @@ pub fn handle_file_order<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, Fill
 
     mint_to(mint_to_cpi_context, 1)?;
 
-    // freeze delegate account
-    let freeze_account_cpi_context = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
-        FreezeAccount {
-            mint: ctx.accounts.node_mint_account.to_account_info(),
-            account: ctx.accounts.user_token_account.to_account_info(),
-            authority: ctx.accounts.sale_phase_tier_detail.to_account_info(),
-        },
-        signer,
-    );
-
-    freeze_account(freeze_account_cpi_context)?;
+    // freeze delegate account, the master edition takes over the freeze authority so nodes
+    // frozen here can not be thawed, nodes minted after unlock are left transferable
+    let unlock_timestamp: i64 = get_unlock_timestamp(sale_phase_detail.unlock_timestamp, sale_phase_tier_detail.unlock_timestamp);
+
+    if is_node_locked(sale_phase_tier_detail.is_unlocked, unlock_timestamp, timestamp) {
+        let freeze_account_cpi_context = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            FreezeAccount {
+                mint: ctx.accounts.node_mint_account.to_account_info(),
+                account: ctx.accounts.user_token_account.to_account_info(),
+                authority: ctx.accounts.sale_phase_tier_detail.to_account_info(),
+            },
+            signer,
+        );
+
+        freeze_account(freeze_account_cpi_context)?;
+    }
 
     // create metadata account
     let data_v2 = DataV2 {
@@ -273,13 +315,31 @@ pub fn handle_file_order<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, Fill
     verify_collection(verify_collection_cpi_context, None)?;
 
 
+    // Fill Reward
+    let mut fill_reward: u64 = 0;
+
+    if let Some(fill_fee_vault) = ctx.accounts.fill_fee_vault.as_mut() {
+        let fill_fee_vault_info: AccountInfo = fill_fee_vault.to_account_info();
+        let min_balance: u64 = Rent::get()?.minimum_balance(fill_fee_vault_info.data_len());
+
+        fill_reward = get_fill_reward(ctx.accounts.sale_phase_detail.fill_reward, fill_fee_vault_info.lamports(), min_balance);
+
+        if fill_reward > 0 {
+            **fill_fee_vault_info.try_borrow_mut_lamports()? -= fill_reward;
+            **ctx.accounts.payer.try_borrow_mut_lamports()? += fill_reward;
+
+            fill_fee_vault.last_block_timestamp = timestamp;
+            fill_fee_vault.total_rewarded += fill_reward;
+        }
+    }
+
     // Update
     let order_detail: &mut Box<Account<OrderDetailAccount>> = &mut ctx.accounts.order_detail;
     order_detail.last_block_timestamp = timestamp;
 
     order_detail.is_token_ids_minted[index] = true;
 
-    if !order_detail.is_token_ids_minted.contains(&false) {
+    if order_detail.is_filled() {
         order_detail.is_completed = true;
     }
 
@@ -294,6 +354,8 @@ pub fn handle_file_order<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, Fill
         collection_mint_account: ctx.accounts.collection_mint_account.key(),
         node_mint_account: ctx.accounts.node_mint_account.key(),
         is_completed: order_detail.is_completed,
+        filler: ctx.accounts.payer.key(),
+        fill_reward,
     };
 
     emit!(event);
@@ -0,0 +1,311 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    metadata::Metadata,
+    token_interface::{Mint, TokenInterface},
+};
+
+use mpl_bubblegum::{
+    instructions::{MintToCollectionV1Cpi, MintToCollectionV1CpiAccounts, MintToCollectionV1InstructionArgs},
+    programs::{SPL_ACCOUNT_COMPRESSION_ID, SPL_NOOP_ID},
+    types::{Collection, MetadataArgs, TokenProgramVersion, TokenStandard},
+};
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+    USER_DETAIL_ACCOUNT_PREFIX,
+    UserDetailAccount,
+    COLLECTION_ACCOUNT_PREFIX,
+    ORDER_DETAIL_ACCOUNT_PREFIX,
+    OrderDetailAccount,
+    FILL_FEE_VAULT_ACCOUNT_PREFIX,
+    FillFeeVaultAccount,
+    NodeMintMode,
+};
+
+use crate::events::{FillOrderEvent};
+
+use crate::utils::{
+    check_phase_tier_collection,
+    check_order_token_id_filled,
+    check_order_token_id,
+    check_order_is_filled,
+    check_order_voided,
+    check_soft_cap_reached,
+    check_installment_paid,
+    check_cap_escrow_pending,
+    check_order_token_id_revoked,
+    check_order_token_id_upgraded,
+    check_mint_mode,
+    check_phase_tier_is_not_compressed,
+    check_merkle_tree,
+    check_program_address,
+    get_fill_reward,
+};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, _collection_mint_account_bump: u8,
+_user_detail_bump: u8, _order_detail_bump: u8, sale_phase_name: String, tier_id: String, token_id: String, order_id: String)]
+pub struct FillOrderCompressedInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: user
+    #[account(mut)]
+    pub user: AccountInfo<'info>,
+
+    #[account(
+    seeds = [
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_name.as_ref(),
+    ],
+    bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    tier_id.as_ref()
+    ],
+    bump = _sale_phase_tier_detail_bump,
+    )]
+    pub sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+
+    #[account(
+    seeds = [
+    USER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    user.key().as_ref(),
+    ],
+    bump = _user_detail_bump,
+    )]
+    pub user_detail: Box<Account<'info, UserDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    ORDER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    user_detail.key().as_ref(),
+    order_id.as_ref(),
+    ],
+    bump = _order_detail_bump,
+    )]
+    pub order_detail: Box<Account<'info, OrderDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    COLLECTION_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_tier_detail.key().as_ref(),
+    ],
+    bump = _collection_mint_account_bump,
+    mint::token_program = token_program,
+    )]
+    pub collection_mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: collection metadata, checked by token metadata program
+    #[account(mut)]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: collection master edition, checked by token metadata program
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    /// CHECK: merkle tree of the tier, checked against the tier in the instruction
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: tree config, checked by bubblegum program
+    #[account(mut)]
+    pub tree_config: UncheckedAccount<'info>,
+
+    /// CHECK: bubblegum collection cpi signer, checked by bubblegum program
+    pub bubblegum_signer: UncheckedAccount<'info>,
+
+    /// CHECK: bubblegum program, checked in the instruction
+    pub bubblegum_program: UncheckedAccount<'info>,
+
+    /// CHECK: noop program, checked in the instruction
+    pub log_wrapper: UncheckedAccount<'info>,
+
+    /// CHECK: account compression program, checked in the instruction
+    pub compression_program: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub token_metadata_program: Program<'info, Metadata>,
+
+    /// not required when the phase pays no fill reward
+    #[account(
+    mut,
+    seeds = [
+    FILL_FEE_VAULT_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    ],
+    bump,
+    )]
+    pub fill_fee_vault: Option<Box<Account<'info, FillFeeVaultAccount>>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_file_order_compressed<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, FillOrderCompressedInputAccounts<'info>>,
+                                                       _sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, _collection_mint_account_bump: u8,
+                                                       _user_detail_bump: u8, _order_detail_bump: u8, sale_phase_name: String, tier_id: String, token_id: String, order_id: String,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+    let sale_phase_tier_detail: &Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &ctx.accounts.sale_phase_tier_detail;
+
+    let token_id_int: u64 = token_id.clone().parse().unwrap();
+    let order_detail: &Box<Account<OrderDetailAccount>> = &ctx.accounts.order_detail;
+
+    // Checks
+    check_mint_mode(sale_phase_detail.mint_mode, NodeMintMode::Metaplex)?;
+    check_phase_tier_collection(sale_phase_tier_detail.collection_mint_address, ctx.accounts.collection_mint_account.key())?;
+    check_phase_tier_is_not_compressed(sale_phase_tier_detail.is_compressed)?;
+    check_merkle_tree(sale_phase_tier_detail.merkle_tree_address, ctx.accounts.merkle_tree.key())?;
+
+    check_program_address(mpl_bubblegum::ID, ctx.accounts.bubblegum_program.key())?;
+    check_program_address(SPL_NOOP_ID, ctx.accounts.log_wrapper.key())?;
+    check_program_address(SPL_ACCOUNT_COMPRESSION_ID, ctx.accounts.compression_program.key())?;
+
+    check_order_is_filled(order_detail.is_completed)?;
+
+    check_order_voided(order_detail.is_voided)?;
+
+    // orders are filled once the phase soft cap is reached
+    check_soft_cap_reached(sale_phase_detail.is_soft_cap_reached())?;
+
+    check_installment_paid(order_detail.is_installment_paid())?;
+
+    // the escrowed payment is released before the order is filled, a refund can not void a filled order
+    check_cap_escrow_pending(order_detail.cap_escrow_amount > 0 && !order_detail.is_cap_settled)?;
+
+    check_order_token_id(order_detail.token_ids.contains(&token_id_int))?;
+
+    let index = order_detail.token_ids.iter().position(|r| r == &token_id_int).unwrap();
+
+    check_order_token_id_filled(order_detail.is_token_ids_minted[index])?;
+
+    check_order_token_id_revoked(order_detail.is_token_id_revoked(index))?;
+
+    check_order_token_id_upgraded(order_detail.is_token_id_upgraded(index))?;
+
+    let sale_phase_detail_key: Pubkey = ctx.accounts.sale_phase_detail.key();
+
+    let signer_seeds = &[
+        SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail_key.as_ref(),
+        tier_id.as_ref(),
+        &[_sale_phase_tier_detail_bump],
+    ];
+
+    let signer = &[&signer_seeds[..]];
+
+    // mint compressed node into the tier merkle tree
+    let metadata = MetadataArgs {
+        name: sale_phase_detail.name.clone(),
+        symbol: sale_phase_detail.symbol.clone(),
+        uri: sale_phase_detail.metadata_base_uri.clone(),
+        seller_fee_basis_points: 0,
+        primary_sale_happened: true,
+        is_mutable: true,
+        edition_nonce: None,
+        token_standard: Some(TokenStandard::NonFungible),
+        collection: Some(Collection {
+            verified: false,
+            key: ctx.accounts.collection_mint_account.key(),
+        }),
+        uses: None,
+        token_program_version: TokenProgramVersion::Original,
+        creators: vec![],
+    };
+
+    let tier_info = ctx.accounts.sale_phase_tier_detail.to_account_info();
+    let payer_info = ctx.accounts.payer.to_account_info();
+    let user_info = ctx.accounts.user.to_account_info();
+    let collection_mint_info = ctx.accounts.collection_mint_account.to_account_info();
+    let token_metadata_program_info = ctx.accounts.token_metadata_program.to_account_info();
+    let system_program_info = ctx.accounts.system_program.to_account_info();
+
+    MintToCollectionV1Cpi::new(
+        &ctx.accounts.bubblegum_program,
+        MintToCollectionV1CpiAccounts {
+            tree_config: &ctx.accounts.tree_config,
+            leaf_owner: &user_info,
+            leaf_delegate: &user_info,
+            merkle_tree: &ctx.accounts.merkle_tree,
+            payer: &payer_info,
+            tree_creator_or_delegate: &tier_info,
+            collection_authority: &tier_info,
+            collection_authority_record_pda: None,
+            collection_mint: &collection_mint_info,
+            collection_metadata: &ctx.accounts.collection_metadata,
+            collection_edition: &ctx.accounts.collection_master_edition,
+            bubblegum_signer: &ctx.accounts.bubblegum_signer,
+            log_wrapper: &ctx.accounts.log_wrapper,
+            compression_program: &ctx.accounts.compression_program,
+            token_metadata_program: &token_metadata_program_info,
+            system_program: &system_program_info,
+        },
+        MintToCollectionV1InstructionArgs {
+            metadata,
+        },
+    ).invoke_signed(signer)?;
+
+
+    // Fill Reward
+    let mut fill_reward: u64 = 0;
+
+    if let Some(fill_fee_vault) = ctx.accounts.fill_fee_vault.as_mut() {
+        let fill_fee_vault_info: AccountInfo = fill_fee_vault.to_account_info();
+        let min_balance: u64 = Rent::get()?.minimum_balance(fill_fee_vault_info.data_len());
+
+        fill_reward = get_fill_reward(ctx.accounts.sale_phase_detail.fill_reward, fill_fee_vault_info.lamports(), min_balance);
+
+        if fill_reward > 0 {
+            **fill_fee_vault_info.try_borrow_mut_lamports()? -= fill_reward;
+            **ctx.accounts.payer.try_borrow_mut_lamports()? += fill_reward;
+
+            fill_fee_vault.last_block_timestamp = timestamp;
+            fill_fee_vault.total_rewarded += fill_reward;
+        }
+    }
+
+    // Update
+    let order_detail: &mut Box<Account<OrderDetailAccount>> = &mut ctx.accounts.order_detail;
+    order_detail.last_block_timestamp = timestamp;
+
+    order_detail.is_token_ids_minted[index] = true;
+
+    if order_detail.is_filled() {
+        order_detail.is_completed = true;
+    }
+
+    // Event
+    let event: FillOrderEvent = FillOrderEvent {
+        timestamp,
+        sale_phase_name,
+        tier_id,
+        order_id,
+        token_id,
+        user: ctx.accounts.user.key(),
+        collection_mint_account: ctx.accounts.collection_mint_account.key(),
+        node_mint_account: ctx.accounts.merkle_tree.key(),
+        is_completed: order_detail.is_completed,
+        filler: ctx.accounts.payer.key(),
+        fill_reward,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,314 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    associated_token::{AssociatedToken, Create, create},
+    token_2022::{Token2022, MintTo, mint_to, FreezeAccount, freeze_account},
+};
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+    USER_DETAIL_ACCOUNT_PREFIX,
+    UserDetailAccount,
+    COLLECTION_ACCOUNT_PREFIX,
+    NODE_ACCOUNT_PREFIX,
+    ORDER_DETAIL_ACCOUNT_PREFIX,
+    OrderDetailAccount,
+    FILL_FEE_VAULT_ACCOUNT_PREFIX,
+    FillFeeVaultAccount,
+    NodeMintMode,
+};
+
+use crate::events::{FillOrderEvent};
+
+use crate::token_extensions::create_node_member_mint;
+
+use crate::utils::{
+    check_phase_tier_collection,
+    check_order_token_id_filled,
+    check_order_token_id,
+    check_order_is_filled,
+    check_order_voided,
+    check_soft_cap_reached,
+    check_installment_paid,
+    check_cap_escrow_pending,
+    check_order_token_id_revoked,
+    check_order_token_id_upgraded,
+    check_mint_mode,
+    get_fill_reward,
+    get_unlock_timestamp,
+    is_node_locked,
+};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, _collection_mint_account_bump: u8,
+_user_detail_bump: u8, _order_detail_bump: u8, sale_phase_name: String, tier_id: String, token_id: String, order_id: String)]
+pub struct FillOrderToken2022InputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: user
+    #[account(mut)]
+    pub user: AccountInfo<'info>,
+
+    #[account(
+    seeds = [
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_name.as_ref(),
+    ],
+    bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    tier_id.as_ref()
+    ],
+    bump = _sale_phase_tier_detail_bump,
+    )]
+    pub sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+
+    #[account(
+    seeds = [
+    USER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    user.key().as_ref(),
+    ],
+    bump = _user_detail_bump,
+    )]
+    pub user_detail: Box<Account<'info, UserDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    ORDER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    user_detail.key().as_ref(),
+    order_id.as_ref(),
+    ],
+    bump = _order_detail_bump,
+    )]
+    pub order_detail: Box<Account<'info, OrderDetailAccount>>,
+
+    /// CHECK: token-2022 collection group mint
+    #[account(
+    mut,
+    seeds = [
+    COLLECTION_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_tier_detail.key().as_ref(),
+    ],
+    bump = _collection_mint_account_bump,
+    owner = token_program.key(),
+    )]
+    pub collection_mint_account: UncheckedAccount<'info>,
+
+    /// CHECK: node mint, created with token-2022 extensions in the instruction
+    #[account(
+    mut,
+    seeds = [
+    NODE_ACCOUNT_PREFIX.as_ref(),
+    collection_mint_account.key().as_ref(),
+    token_id.as_ref()
+    ],
+    bump,
+    )]
+    pub node_mint_account: UncheckedAccount<'info>,
+
+    /// CHECK: user associated token account, created in the instruction
+    #[account(mut)]
+    pub user_token_account: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// not required when the phase pays no fill reward
+    #[account(
+    mut,
+    seeds = [
+    FILL_FEE_VAULT_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    ],
+    bump,
+    )]
+    pub fill_fee_vault: Option<Box<Account<'info, FillFeeVaultAccount>>>,
+
+    pub system_program: Program<'info, System>,
+
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handle_file_order_token_2022<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, FillOrderToken2022InputAccounts<'info>>,
+                                                       _sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, _collection_mint_account_bump: u8,
+                                                       _user_detail_bump: u8, _order_detail_bump: u8, sale_phase_name: String, tier_id: String, token_id: String, order_id: String,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+    let sale_phase_tier_detail: &Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &ctx.accounts.sale_phase_tier_detail;
+
+    let token_id_int: u64 = token_id.clone().parse().unwrap();
+    let order_detail: &Box<Account<OrderDetailAccount>> = &ctx.accounts.order_detail;
+
+    // Checks
+    check_mint_mode(sale_phase_detail.mint_mode, NodeMintMode::Token2022)?;
+    check_phase_tier_collection(sale_phase_tier_detail.collection_mint_address, ctx.accounts.collection_mint_account.key())?;
+
+    check_order_is_filled(order_detail.is_completed)?;
+
+    check_order_voided(order_detail.is_voided)?;
+
+    // orders are filled once the phase soft cap is reached
+    check_soft_cap_reached(sale_phase_detail.is_soft_cap_reached())?;
+
+    check_installment_paid(order_detail.is_installment_paid())?;
+
+    // the escrowed payment is released before the order is filled, a refund can not void a filled order
+    check_cap_escrow_pending(order_detail.cap_escrow_amount > 0 && !order_detail.is_cap_settled)?;
+
+    check_order_token_id(order_detail.token_ids.contains(&token_id_int))?;
+
+    let index = order_detail.token_ids.iter().position(|r| r == &token_id_int).unwrap();
+
+    check_order_token_id_filled(order_detail.is_token_ids_minted[index])?;
+
+    check_order_token_id_revoked(order_detail.is_token_id_revoked(index))?;
+
+    check_order_token_id_upgraded(order_detail.is_token_id_upgraded(index))?;
+
+    let sale_phase_detail_key: Pubkey = ctx.accounts.sale_phase_detail.key();
+    let collection_mint_account_key: Pubkey = ctx.accounts.collection_mint_account.key();
+
+    let signer_seeds = &[
+        SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail_key.as_ref(),
+        tier_id.as_ref(),
+        &[_sale_phase_tier_detail_bump],
+    ];
+
+    let signer = &[&signer_seeds[..]];
+
+    let node_mint_signer_seeds = &[
+        NODE_ACCOUNT_PREFIX.as_ref(),
+        collection_mint_account_key.as_ref(),
+        token_id.as_ref(),
+        &[ctx.bumps.node_mint_account],
+    ];
+
+    let node_mint_signer = &[&node_mint_signer_seeds[..]];
+
+    // nodes are frozen until the unlock, thaw_node thaws them once a schedule is set and elapsed
+    let unlock_timestamp: i64 = get_unlock_timestamp(sale_phase_detail.unlock_timestamp, sale_phase_tier_detail.unlock_timestamp);
+    let is_locked: bool = is_node_locked(sale_phase_tier_detail.is_unlocked, unlock_timestamp, timestamp);
+
+    // create node member mint
+    create_node_member_mint(
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.node_mint_account.to_account_info(),
+        &ctx.accounts.collection_mint_account.to_account_info(),
+        &ctx.accounts.sale_phase_tier_detail.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        node_mint_signer,
+        signer,
+        sale_phase_detail.name.clone(),
+        sale_phase_detail.symbol.clone(),
+        sale_phase_detail.metadata_base_uri.clone(),
+    )?;
+
+    // create user token account
+    let create_cpi_context = CpiContext::new(
+        ctx.accounts.associated_token_program.to_account_info(),
+        Create {
+            payer: ctx.accounts.payer.to_account_info(),
+            associated_token: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.user.to_account_info(),
+            mint: ctx.accounts.node_mint_account.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+    );
+
+    create(create_cpi_context)?;
+
+    // mint node
+    let mint_to_cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        MintTo {
+            mint: ctx.accounts.node_mint_account.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.sale_phase_tier_detail.to_account_info(),
+        },
+        signer,
+    );
+
+    mint_to(mint_to_cpi_context, 1)?;
+
+    // freeze user token account until unlock
+    if is_locked {
+        let freeze_account_cpi_context = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            FreezeAccount {
+                mint: ctx.accounts.node_mint_account.to_account_info(),
+                account: ctx.accounts.user_token_account.to_account_info(),
+                authority: ctx.accounts.sale_phase_tier_detail.to_account_info(),
+            },
+            signer,
+        );
+
+        freeze_account(freeze_account_cpi_context)?;
+    }
+
+
+    // Fill Reward
+    let mut fill_reward: u64 = 0;
+
+    if let Some(fill_fee_vault) = ctx.accounts.fill_fee_vault.as_mut() {
+        let fill_fee_vault_info: AccountInfo = fill_fee_vault.to_account_info();
+        let min_balance: u64 = Rent::get()?.minimum_balance(fill_fee_vault_info.data_len());
+
+        fill_reward = get_fill_reward(ctx.accounts.sale_phase_detail.fill_reward, fill_fee_vault_info.lamports(), min_balance);
+
+        if fill_reward > 0 {
+            **fill_fee_vault_info.try_borrow_mut_lamports()? -= fill_reward;
+            **ctx.accounts.payer.try_borrow_mut_lamports()? += fill_reward;
+
+            fill_fee_vault.last_block_timestamp = timestamp;
+            fill_fee_vault.total_rewarded += fill_reward;
+        }
+    }
+
+    // Update
+    let order_detail: &mut Box<Account<OrderDetailAccount>> = &mut ctx.accounts.order_detail;
+    order_detail.last_block_timestamp = timestamp;
+
+    order_detail.is_token_ids_minted[index] = true;
+
+    if order_detail.is_filled() {
+        order_detail.is_completed = true;
+    }
+
+    // Event
+    let event: FillOrderEvent = FillOrderEvent {
+        timestamp,
+        sale_phase_name,
+        tier_id,
+        order_id,
+        token_id,
+        user: ctx.accounts.user.key(),
+        collection_mint_account: ctx.accounts.collection_mint_account.key(),
+        node_mint_account: ctx.accounts.node_mint_account.key(),
+        is_completed: order_detail.is_completed,
+        filler: ctx.accounts.payer.key(),
+        fill_reward,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
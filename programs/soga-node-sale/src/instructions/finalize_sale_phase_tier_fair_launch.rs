@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{hash::hashv, sysvar::slot_hashes};
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+};
+
+use crate::events::{
+    FinalizeSalePhaseTierFairLaunchEvent
+};
+
+use crate::utils::{check_signing_authority, check_fair_launch_enabled, check_fair_launch_not_finalized, check_commit_window_ended, check_allocation_ended};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, sale_phase_name: String, tier_id: String)]
+pub struct FinalizeSalePhaseTierFairLaunchInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub signing_authority: Signer<'info>,
+
+    #[account(
+    seeds = [
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_name.as_ref(),
+    ],
+    bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    tier_id.as_ref()
+    ],
+    bump = _sale_phase_tier_detail_bump,
+    )]
+    pub sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+
+    /// CHECK: slot hashes sysvar, the most recent hash seeds the lottery
+    #[account(address = slot_hashes::ID)]
+    pub recent_slot_hashes: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_finalize_sale_phase_tier_fair_launch(ctx: Context<FinalizeSalePhaseTierFairLaunchInputAccounts>,
+                                                   _sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8,
+                                                   sale_phase_name: String, tier_id: String,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+    let sale_phase_tier_detail: &Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &ctx.accounts.sale_phase_tier_detail;
+
+    // Checks
+    check_signing_authority(sale_phase_detail.signing_authority, ctx.accounts.signing_authority.key())?;
+
+    check_fair_launch_enabled(sale_phase_tier_detail.is_fair_launch)?;
+
+    check_fair_launch_not_finalized(sale_phase_tier_detail.is_fair_launch_finalized)?;
+
+    check_commit_window_ended(sale_phase_tier_detail.commit_end_timestamp, timestamp)?;
+
+    check_allocation_ended(sale_phase_tier_detail.is_allocation_ended(timestamp))?;
+
+    // first entry of the slot hashes after the entry count
+    let slot_hashes_data = ctx.accounts.recent_slot_hashes.try_borrow_data()?;
+    let lottery_seed: [u8; 32] = hashv(&[&slot_hashes_data[16..48], sale_phase_tier_detail.key().as_ref()]).to_bytes();
+    drop(slot_hashes_data);
+
+    // Update
+    let sale_phase_tier_detail: &mut Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &mut ctx.accounts.sale_phase_tier_detail;
+    sale_phase_tier_detail.last_block_timestamp = timestamp;
+    sale_phase_tier_detail.fair_launch_supply = sale_phase_tier_detail.public_quantity().saturating_sub(sale_phase_tier_detail.minted_supply());
+    sale_phase_tier_detail.lottery_seed = lottery_seed;
+    sale_phase_tier_detail.is_fair_launch_finalized = true;
+
+    // Event
+    let event: FinalizeSalePhaseTierFairLaunchEvent = FinalizeSalePhaseTierFairLaunchEvent {
+        timestamp,
+        sale_phase_name,
+        tier_id,
+        total_committed: sale_phase_tier_detail.total_committed,
+        fair_launch_supply: sale_phase_tier_detail.fair_launch_supply,
+        allocation_mode: sale_phase_tier_detail.allocation_mode,
+        lottery_seed,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    FILL_FEE_VAULT_ACCOUNT_PREFIX,
+    FillFeeVaultAccount,
+};
+
+use crate::events::{
+    FundFillFeeVaultEvent
+};
+
+use crate::utils::{check_value_is_zero};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, sale_phase_name: String)]
+pub struct FundFillFeeVaultInputAccounts<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+    seeds = [
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_name.as_ref(),
+    ],
+    bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    FILL_FEE_VAULT_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    ],
+    bump,
+    )]
+    pub fill_fee_vault: Box<Account<'info, FillFeeVaultAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Anyone can fund the fill rewards of a phase.
+pub fn handle_fund_fill_fee_vault(ctx: Context<FundFillFeeVaultInputAccounts>,
+                                  _sale_phase_detail_bump: u8, sale_phase_name: String, amount: u64,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    // Checks
+    check_value_is_zero(amount as usize)?;
+
+    // Make Payment
+    let deposit_amount_ix = anchor_lang::solana_program::system_instruction::transfer(
+        &ctx.accounts.funder.key(),
+        &ctx.accounts.fill_fee_vault.key(),
+        amount,
+    );
+
+    anchor_lang::solana_program::program::invoke(
+        &deposit_amount_ix,
+        &[
+            ctx.accounts.funder.to_account_info(),
+            ctx.accounts.fill_fee_vault.to_account_info(),
+        ],
+    )?;
+
+    // Update
+    let fill_fee_vault: &mut Box<Account<FillFeeVaultAccount>> = &mut ctx.accounts.fill_fee_vault;
+    fill_fee_vault.last_block_timestamp = timestamp;
+    fill_fee_vault.total_funded += amount;
+
+    // Event
+    let event: FundFillFeeVaultEvent = FundFillFeeVaultEvent {
+        timestamp,
+        sale_phase_name,
+        funder: ctx.accounts.funder.key(),
+        amount,
+        total_funded: fill_fee_vault.total_funded,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{
+    SOGA_NODE_SALE_CONFIG_ACCOUNT_PREFIX,
+    SogaNodeSaleConfigAccount,
+    ORACLE_PRICE_ACCOUNT_PREFIX,
+    OraclePriceAccount,
+};
+
+use crate::events::{
+    InitializeOraclePriceEvent
+};
+
+use crate::utils::{check_main_signing_authority};
+
+#[derive(Accounts)]
+#[instruction(_sale_config_bump: u8, name: String)]
+pub struct InitializeOraclePriceInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub main_signing_authority: Signer<'info>,
+
+    /// CHECK: authority allowed to update the price
+    pub signing_authority: AccountInfo<'info>,
+
+    #[account(
+    seeds = [
+    SOGA_NODE_SALE_CONFIG_ACCOUNT_PREFIX.as_ref()
+    ],
+    bump = _sale_config_bump,
+    )]
+    pub sale_config: Box<Account<'info, SogaNodeSaleConfigAccount>>,
+
+    #[account(
+    init,
+    payer = payer,
+    space = OraclePriceAccount::space(),
+    seeds = [
+    ORACLE_PRICE_ACCOUNT_PREFIX.as_ref(),
+    name.as_ref(),
+    ],
+    bump,
+    )]
+    pub oracle_price: Box<Account<'info, OraclePriceAccount>>,
+
+    pub system_program: Program<'info, System>,
+
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Creates an admin controlled price account for phases and payment tokens using
+/// the manual oracle source, e.g. for local tests and private deployments.
+pub fn handle_initialize_oracle_price(ctx: Context<InitializeOraclePriceInputAccounts>,
+                                      _sale_config_bump: u8, name: String, price: i64, conf: u64, exponent: i32,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let sale_config: &Box<Account<SogaNodeSaleConfigAccount>> = &ctx.accounts.sale_config;
+
+    // Checks
+    check_main_signing_authority(sale_config.main_signing_authority, ctx.accounts.main_signing_authority.key())?;
+
+    // Update
+    let oracle_price: &mut Box<Account<OraclePriceAccount>> = &mut ctx.accounts.oracle_price;
+    oracle_price.last_block_timestamp = timestamp;
+    oracle_price.authority = ctx.accounts.signing_authority.key();
+    oracle_price.name = name.clone();
+    oracle_price.price = price;
+    oracle_price.conf = conf;
+    oracle_price.exponent = exponent;
+    oracle_price.publish_time = timestamp;
+
+    // Event
+    let event: InitializeOraclePriceEvent = InitializeOraclePriceEvent {
+        timestamp,
+        name,
+        oracle_price: ctx.accounts.oracle_price.key(),
+        authority: ctx.accounts.signing_authority.key(),
+        price,
+        conf,
+        exponent,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
@@ -1,11 +1,11 @@
 use anchor_lang::prelude::*;
-use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 
 use crate::states::{
     SOGA_NODE_SALE_CONFIG_ACCOUNT_PREFIX,
     SogaNodeSaleConfigAccount,
     SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
     SogaNodeSalePhaseDetailAccount,
+    NodeMintMode,
 };
 
 use crate::events::{
@@ -24,7 +24,8 @@ pub struct InitializeSalePhaseInputAccounts<'info> {
 
     pub signing_authority: Signer<'info>,
 
-    pub price_feed: Account<'info, PriceUpdateV2>,
+    /// CHECK: price feed, owner and layout checked by the oracle source when a price is read
+    pub price_feed: UncheckedAccount<'info>,
 
     /// CHECK: payment receiver
     pub payment_receiver: AccountInfo<'info>,
@@ -57,7 +58,7 @@ pub struct InitializeSalePhaseInputAccounts<'info> {
 pub fn handle_initialize_sale_phase(ctx: Context<InitializeSalePhaseInputAccounts>,
                                     _sale_config_bump: u8, sale_phase_name: String,
                                     total_tiers: u32, name: String, symbol: String, metadata_base_uri: String,
-                                    price_feed_id: String
+                                    price_feed_id: String, mint_mode: NodeMintMode,
 ) -> Result<()> {
     let timestamp = Clock::get().unwrap().unix_timestamp;
 
@@ -72,6 +73,7 @@ pub fn handle_initialize_sale_phase(ctx: Context<InitializeSalePhaseInputAccount
     let sale_phase_detail: &mut Box<Account<SogaNodeSalePhaseDetailAccount>> = &mut ctx.accounts.sale_phase_detail;
 
     sale_phase_detail.last_block_timestamp = timestamp;
+    sale_phase_detail.initialize_timestamp = timestamp;
     sale_phase_detail.signing_authority = ctx.accounts.signing_authority.key();
     sale_phase_detail.price_feed_address = ctx.accounts.price_feed.key();
     sale_phase_detail.price_feed_id = price_feed_id.clone();
@@ -87,6 +89,8 @@ pub fn handle_initialize_sale_phase(ctx: Context<InitializeSalePhaseInputAccount
     sale_phase_detail.symbol = symbol;
     sale_phase_detail.metadata_base_uri = metadata_base_uri;
 
+    sale_phase_detail.mint_mode = mint_mode;
+
     // Event
     let event: InitializeSalePhaseEvent = InitializeSalePhaseEvent {
         timestamp,
@@ -96,6 +100,7 @@ pub fn handle_initialize_sale_phase(ctx: Context<InitializeSalePhaseInputAccount
         price_feed: ctx.accounts.price_feed.key(),
         price_feed_id,
         payment_receiver: ctx.accounts.payment_receiver.key(),
+        mint_mode,
     };
 
     emit!(event);
@@ -1,5 +1,4 @@
 use anchor_lang::prelude::*;
-use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 use anchor_spl::{
     token_interface::{Mint, TokenInterface},
 };
@@ -28,7 +27,8 @@ pub struct InitializeSalePhasePaymentTokenInputAccounts<'info> {
 
     pub signing_authority: Signer<'info>,
 
-    pub price_feed: Account<'info, PriceUpdateV2>,
+    /// CHECK: price feed, owner and layout checked by the oracle source when a price is read
+    pub price_feed: UncheckedAccount<'info>,
 
     #[account(
         seeds = [
@@ -1,5 +1,10 @@
 use anchor_lang::prelude::*;
 
+use mpl_bubblegum::{
+    instructions::{CreateTreeConfigCpi, CreateTreeConfigCpiAccounts, CreateTreeConfigInstructionArgs},
+    programs::{SPL_ACCOUNT_COMPRESSION_ID, SPL_NOOP_ID},
+};
+
 use anchor_spl::{
     associated_token::AssociatedToken,
     metadata::{
@@ -15,13 +20,14 @@ use crate::states::{
     SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
     SogaNodeSalePhaseTierDetailAccount,
     COLLECTION_ACCOUNT_PREFIX,
+    NodeMintMode,
 };
 
 use crate::events::{
     InitializeSalePhaseTierEvent
 };
 
-use crate::utils::{check_signing_authority, check_tier_id, check_tier_id_out_of_range, check_value_is_zero, check_whitelist_quantity};
+use crate::utils::{check_mint_mode, check_program_address, check_signing_authority, check_tier_id, check_tier_id_out_of_range, check_value_is_zero, check_whitelist_quantity};
 
 #[derive(Accounts)]
 #[instruction(_sale_phase_detail_bump: u8, sale_phase_name: String, tier_id: String)]
@@ -97,11 +103,11 @@ pub struct InitializeSalePhaseTierInputAccounts<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
-pub fn handle_initialize_sale_phase_tier(ctx: Context<InitializeSalePhaseTierInputAccounts>,
-                                         _sale_phase_detail_bump: u8, sale_phase_name: String,
-                                         tier_id: String, price: u64, quantity: u64, mint_limit: u64,
-                                         collection_name: String, collection_symbol: String, collection_url: String,
-                                         whitelist_quantity: u64,
+pub fn handle_initialize_sale_phase_tier<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, InitializeSalePhaseTierInputAccounts<'info>>,
+                                                            _sale_phase_detail_bump: u8, sale_phase_name: String,
+                                                            tier_id: String, price: u64, quantity: u64, mint_limit: u64,
+                                                            collection_name: String, collection_symbol: String, collection_url: String,
+                                                            whitelist_quantity: u64, is_compressed: bool, max_depth: u32, max_buffer_size: u32,
 ) -> Result<()> {
     let timestamp = Clock::get().unwrap().unix_timestamp;
 
@@ -121,6 +127,8 @@ pub fn handle_initialize_sale_phase_tier(ctx: Context<InitializeSalePhaseTierInp
 
     check_signing_authority(sale_phase_detail.signing_authority, ctx.accounts.signing_authority.key())?;
 
+    check_mint_mode(sale_phase_detail.mint_mode, NodeMintMode::Metaplex)?;
+
     check_tier_id_out_of_range(sale_phase_detail.total_initialize_tiers, tier_id_int, sale_phase_detail.total_tiers)?;
 
     let current_tier: u32 = sale_phase_detail.total_initialize_tiers + 1;
@@ -217,6 +225,44 @@ pub fn handle_initialize_sale_phase_tier(ctx: Context<InitializeSalePhaseTierInp
 
     create_master_edition_v3(create_master_edition_v3_cpi_context, Some(0))?;
 
+    // create merkle tree config, the tree account itself is allocated by the client
+    let mut merkle_tree_address: Pubkey = Pubkey::default();
+
+    if is_compressed {
+        let merkle_tree = &ctx.remaining_accounts[0];
+        let tree_config = &ctx.remaining_accounts[1];
+        let bubblegum_program = &ctx.remaining_accounts[2];
+        let log_wrapper = &ctx.remaining_accounts[3];
+        let compression_program = &ctx.remaining_accounts[4];
+
+        check_program_address(mpl_bubblegum::ID, bubblegum_program.key())?;
+        check_program_address(SPL_NOOP_ID, log_wrapper.key())?;
+        check_program_address(SPL_ACCOUNT_COMPRESSION_ID, compression_program.key())?;
+
+        let payer = ctx.accounts.payer.to_account_info();
+        let tree_creator = ctx.accounts.sale_phase_tier_detail.to_account_info();
+        let system_program = ctx.accounts.system_program.to_account_info();
+
+        CreateTreeConfigCpi::new(
+            bubblegum_program,
+            CreateTreeConfigCpiAccounts {
+                tree_config,
+                merkle_tree,
+                payer: &payer,
+                tree_creator: &tree_creator,
+                log_wrapper,
+                compression_program,
+                system_program: &system_program,
+            },
+            CreateTreeConfigInstructionArgs {
+                max_depth,
+                max_buffer_size,
+                public: Some(false),
+            },
+        ).invoke_signed(signer)?;
+
+        merkle_tree_address = merkle_tree.key();
+    }
 
     let sale_phase_tier_detail: &mut Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &mut ctx.accounts.sale_phase_tier_detail;
 
@@ -229,6 +275,8 @@ pub fn handle_initialize_sale_phase_tier(ctx: Context<InitializeSalePhaseTierInp
     sale_phase_tier_detail.buy_enable = true;
     sale_phase_tier_detail.buy_with_token_enable = true;
     sale_phase_tier_detail.airdrop_enable = true;
+    sale_phase_tier_detail.is_compressed = is_compressed;
+    sale_phase_tier_detail.merkle_tree_address = merkle_tree_address;
 
     let sale_phase_detail: &mut Box<Account<SogaNodeSalePhaseDetailAccount>> = &mut ctx.accounts.sale_phase_detail;
     sale_phase_detail.total_initialize_tiers += 1;
@@ -244,6 +292,8 @@ pub fn handle_initialize_sale_phase_tier(ctx: Context<InitializeSalePhaseTierInp
         quantity,
         mint_limit,
         whitelist_quantity,
+        is_compressed,
+        merkle_tree_address,
     };
 
     emit!(event);
@@ -0,0 +1,172 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_2022::Token2022;
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+    COLLECTION_ACCOUNT_PREFIX,
+    NodeMintMode,
+};
+
+use crate::events::{
+    InitializeSalePhaseTierEvent
+};
+
+use crate::token_extensions::create_collection_group_mint;
+
+use crate::utils::{check_mint_mode, check_signing_authority, check_tier_id, check_tier_id_out_of_range, check_value_is_zero, check_whitelist_quantity};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, sale_phase_name: String, tier_id: String)]
+pub struct InitializeSalePhaseTierToken2022InputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub signing_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+        SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_name.as_ref(),
+        ],
+        bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = SogaNodeSalePhaseTierDetailAccount::space(),
+        seeds = [
+        SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail.key().as_ref(),
+        tier_id.as_ref()
+        ],
+        bump,
+    )]
+    pub sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+
+    /// CHECK: collection mint, created with token-2022 extensions in the instruction
+    #[account(
+        mut,
+        seeds = [
+        COLLECTION_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_tier_detail.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub collection_mint_account: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+
+    pub system_program: Program<'info, System>,
+
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handle_initialize_sale_phase_tier_token_2022(ctx: Context<InitializeSalePhaseTierToken2022InputAccounts>,
+                                                    _sale_phase_detail_bump: u8, sale_phase_name: String,
+                                                    tier_id: String, price: u64, quantity: u64, mint_limit: u64,
+                                                    collection_name: String, collection_symbol: String, collection_url: String,
+                                                    whitelist_quantity: u64,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let tier_id_int: u32 = tier_id.clone().parse().unwrap();
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+
+    // Checks
+
+    check_value_is_zero(price as usize)?;
+
+    check_value_is_zero(quantity as usize)?;
+
+    check_value_is_zero(mint_limit as usize)?;
+
+    check_whitelist_quantity(whitelist_quantity, quantity)?;
+
+    check_signing_authority(sale_phase_detail.signing_authority, ctx.accounts.signing_authority.key())?;
+
+    check_mint_mode(sale_phase_detail.mint_mode, NodeMintMode::Token2022)?;
+
+    check_tier_id_out_of_range(sale_phase_detail.total_initialize_tiers, tier_id_int, sale_phase_detail.total_tiers)?;
+
+    let current_tier: u32 = sale_phase_detail.total_initialize_tiers + 1;
+
+    check_tier_id(current_tier, tier_id_int)?;
+
+    let sale_phase_detail_key: Pubkey = ctx.accounts.sale_phase_detail.key();
+    let sale_phase_tier_detail_key: Pubkey = ctx.accounts.sale_phase_tier_detail.key();
+
+    let signer_seeds = &[
+        SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail_key.as_ref(),
+        tier_id.as_ref(),
+        &[ctx.bumps.sale_phase_tier_detail],
+    ];
+
+    let signer = &[&signer_seeds[..]];
+
+    let collection_mint_signer_seeds = &[
+        COLLECTION_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_tier_detail_key.as_ref(),
+        &[ctx.bumps.collection_mint_account],
+    ];
+
+    let collection_mint_signer = &[&collection_mint_signer_seeds[..]];
+
+    // create collection group mint
+    create_collection_group_mint(
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.collection_mint_account.to_account_info(),
+        &ctx.accounts.sale_phase_tier_detail.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        collection_mint_signer,
+        signer,
+        collection_name,
+        collection_symbol,
+        collection_url,
+        u32::try_from(quantity).unwrap_or(u32::MAX),
+    )?;
+
+
+    let sale_phase_tier_detail: &mut Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &mut ctx.accounts.sale_phase_tier_detail;
+
+    sale_phase_tier_detail.last_block_timestamp = timestamp;
+    sale_phase_tier_detail.collection_mint_address = ctx.accounts.collection_mint_account.key();
+    sale_phase_tier_detail.price = price;
+    sale_phase_tier_detail.quantity = quantity;
+    sale_phase_tier_detail.whitelist_quantity = whitelist_quantity;
+    sale_phase_tier_detail.mint_limit = mint_limit;
+    sale_phase_tier_detail.buy_enable = true;
+    sale_phase_tier_detail.buy_with_token_enable = true;
+    sale_phase_tier_detail.airdrop_enable = true;
+
+    let sale_phase_detail: &mut Box<Account<SogaNodeSalePhaseDetailAccount>> = &mut ctx.accounts.sale_phase_detail;
+    sale_phase_detail.total_initialize_tiers += 1;
+    sale_phase_detail.last_block_timestamp = timestamp;
+
+    // Event
+    let event: InitializeSalePhaseTierEvent = InitializeSalePhaseTierEvent {
+        timestamp,
+        sale_phase_name,
+        tier_id,
+        collection_mint_address: ctx.accounts.collection_mint_account.key(),
+        price,
+        quantity,
+        mint_limit,
+        whitelist_quantity,
+        is_compressed: false,
+        merkle_tree_address: Pubkey::default(),
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,259 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    token_interface::{Mint, TokenAccount, TransferChecked, transfer_checked},
+};
+
+use crate::pricing::get_paid_price_in_usd;
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+    USER_DETAIL_ACCOUNT_PREFIX,
+    UserDetailAccount,
+    USER_TIER_DETAIL_ACCOUNT_PREFIX,
+    UserTierDetailAccount,
+    ORDER_DETAIL_ACCOUNT_PREFIX,
+    OrderDetailAccount,
+};
+
+use crate::events::{
+    LapseInstallmentOrderEvent
+};
+
+use crate::utils::{check_payment_receiver, check_tier_id, check_payment_token_mint_account, check_user_token_account, check_cap_escrow, check_cap_escrow_settled, check_order_voided, check_installment_order, check_installment_not_overdue, check_payment_token_program};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, _user_detail_bump: u8, _user_tier_detail_bump: u8,
+_order_detail_bump: u8, sale_phase_name: String, tier_id: String, order_id: String)]
+pub struct LapseInstallmentOrderInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: user
+    #[account(mut)]
+    pub user: AccountInfo<'info>,
+
+    /// CHECK: payment receiver, checked against the sale phase detail
+    #[account(mut)]
+    pub payment_receiver: AccountInfo<'info>,
+
+    #[account(
+    mut,
+    seeds = [
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_name.as_ref(),
+    ],
+    bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    tier_id.as_ref()
+    ],
+    bump = _sale_phase_tier_detail_bump,
+    )]
+    pub sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    USER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    user.key().as_ref(),
+    ],
+    bump = _user_detail_bump,
+    )]
+    pub user_detail: Box<Account<'info, UserDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    USER_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    user_detail.key().as_ref(),
+    sale_phase_tier_detail.key().as_ref(),
+    ],
+    bump = _user_tier_detail_bump,
+    )]
+    pub user_tier_detail: Box<Account<'info, UserTierDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    ORDER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    user_detail.key().as_ref(),
+    order_id.as_ref(),
+    ],
+    bump = _order_detail_bump,
+    )]
+    pub order_detail: Box<Account<'info, OrderDetailAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Lapses an installment order that was not paid in full before its deadline. The
+/// escrowed payments, including the escrowed referral shares, are refunded to the user or
+/// forfeited to the payment receiver per phase policy and the reserved token ids are revoked.
+///
+/// Remaining accounts are `[payment_token_mint_account, payment_token_program, cap_escrow,
+/// payment_token_receiver_token_account]` when the order was paid with a payment token.
+pub fn handle_lapse_installment_order<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, LapseInstallmentOrderInputAccounts<'info>>,
+                                                         _sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, _user_detail_bump: u8,
+                                                         _user_tier_detail_bump: u8, _order_detail_bump: u8,
+                                                         sale_phase_name: String, tier_id: String, order_id: String,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let tier_id_int: u32 = tier_id.clone().parse().unwrap();
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+    let order_detail: &Box<Account<OrderDetailAccount>> = &ctx.accounts.order_detail;
+
+    // Checks
+    check_payment_receiver(sale_phase_detail.payment_receiver, ctx.accounts.payment_receiver.key())?;
+
+    check_tier_id(order_detail.tier_id, tier_id_int)?;
+
+    check_installment_order(order_detail.is_installment)?;
+
+    check_order_voided(order_detail.is_voided)?;
+
+    check_cap_escrow_settled(order_detail.is_cap_settled)?;
+
+    check_installment_not_overdue(order_detail.is_installment_overdue(timestamp))?;
+
+    let is_refunded: bool = sale_phase_detail.is_installment_deposit_refundable;
+
+    let escrow_amount: u64 = order_detail.cap_escrow_amount;
+
+    match order_detail.payment_token_mint_account {
+        None => {
+            // the sol escrow is held as lamports of the sale phase detail
+            let sale_phase_detail_info: AccountInfo = sale_phase_detail.to_account_info();
+            let receiver: &AccountInfo = if is_refunded { &ctx.accounts.user } else { &ctx.accounts.payment_receiver };
+
+            **sale_phase_detail_info.try_borrow_mut_lamports()? -= escrow_amount;
+            **receiver.try_borrow_mut_lamports()? += escrow_amount;
+        }
+        Some(payment_token_mint) => {
+            let payment_token_mint_account = &ctx.remaining_accounts[0];
+            let payment_token_program = &ctx.remaining_accounts[1];
+            let cap_escrow = &ctx.remaining_accounts[2];
+            let payment_token_receiver_token_account = &ctx.remaining_accounts[3];
+
+            check_payment_token_mint_account(payment_token_mint, payment_token_mint_account.key())?;
+
+            check_payment_token_program(payment_token_program.key(), *payment_token_mint_account.owner, *cap_escrow.owner)?;
+
+            let cap_escrow_token: TokenAccount = TokenAccount::try_deserialize(&mut &cap_escrow.try_borrow_data()?[..])?;
+            check_cap_escrow(cap_escrow_token.owner, sale_phase_detail.key(), cap_escrow_token.mint, payment_token_mint)?;
+
+            let receiver_token: TokenAccount = TokenAccount::try_deserialize(&mut &payment_token_receiver_token_account.try_borrow_data()?[..])?;
+
+            if is_refunded {
+                check_user_token_account(ctx.accounts.user.key(), receiver_token.owner)?;
+            } else {
+                check_payment_receiver(sale_phase_detail.payment_receiver, receiver_token.owner)?;
+            }
+
+            let decimals: u8 = Mint::try_deserialize(&mut &payment_token_mint_account.try_borrow_data()?[..])?.decimals;
+
+            let signer_seeds: &[&[u8]] = &[
+                SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_bytes(),
+                sale_phase_name.as_bytes(),
+                &[_sale_phase_detail_bump],
+            ];
+
+            let signer = &[signer_seeds];
+
+            if escrow_amount > 0 {
+                let cpi_accounts = TransferChecked {
+                    from: cap_escrow.to_account_info(),
+                    mint: payment_token_mint_account.to_account_info(),
+                    to: payment_token_receiver_token_account.to_account_info(),
+                    authority: sale_phase_detail.to_account_info(),
+                };
+                let cpi_program = payment_token_program.to_account_info();
+                let cpi_context = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+                transfer_checked(cpi_context, escrow_amount, decimals)?;
+            }
+        }
+    }
+
+    // Update
+    let order_detail: &mut Box<Account<OrderDetailAccount>> = &mut ctx.accounts.order_detail;
+
+    // the paid share of the order price is no longer counted toward the phase caps
+    let paid_price_in_usd: u64 = get_paid_price_in_usd(order_detail.total_payment_in_usd, order_detail.installment_paid_in_usd, order_detail.installment_owed_in_usd)?;
+    let pending_price_in_usd: u64 = order_detail.total_payment_in_usd - paid_price_in_usd;
+
+    order_detail.last_block_timestamp = timestamp;
+    order_detail.is_cap_settled = true;
+    order_detail.is_voided = true;
+    order_detail.is_installment_lapsed = true;
+
+    // the order can not be filled before it is paid, none of its token ids are minted
+    let mut total_revoked: u64 = 0;
+
+    for is_revoked in order_detail.is_token_ids_revoked.iter_mut() {
+        if !*is_revoked {
+            *is_revoked = true;
+            total_revoked += 1;
+        }
+    }
+
+    order_detail.is_completed = true;
+
+    let sale_phase_detail: &mut Box<Account<SogaNodeSalePhaseDetailAccount>> = &mut ctx.accounts.sale_phase_detail;
+    sale_phase_detail.total_revoked += total_revoked;
+    // a soft cap reached before the lapse stays reached
+    sale_phase_detail.latch_soft_cap();
+    sale_phase_detail.total_payment -= paid_price_in_usd;
+    sale_phase_detail.total_installment_pending -= pending_price_in_usd;
+    sale_phase_detail.last_block_timestamp = timestamp;
+
+    let sale_phase_tier_detail: &mut Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &mut ctx.accounts.sale_phase_tier_detail;
+    // the revoked token ids return to the supply of the tier, a completed tier is not reopened so the
+    // tiers after it keep their sequence
+    sale_phase_tier_detail.total_revoked += total_revoked;
+    sale_phase_tier_detail.total_payment -= paid_price_in_usd;
+    sale_phase_tier_detail.last_block_timestamp = timestamp;
+
+    let user_detail: &mut Box<Account<UserDetailAccount>> = &mut ctx.accounts.user_detail;
+    user_detail.total_mint -= total_revoked;
+    user_detail.total_payment -= paid_price_in_usd;
+    user_detail.last_block_timestamp = timestamp;
+
+    let user_tier_detail: &mut Box<Account<UserTierDetailAccount>> = &mut ctx.accounts.user_tier_detail;
+    user_tier_detail.total_mint -= total_revoked;
+    user_tier_detail.total_payment -= paid_price_in_usd;
+    user_tier_detail.last_block_timestamp = timestamp;
+
+    // Event
+    let event: LapseInstallmentOrderEvent = LapseInstallmentOrderEvent {
+        timestamp,
+        sale_phase_name,
+        tier_id,
+        order_id,
+        user: ctx.accounts.user.key(),
+        payment_receiver: ctx.accounts.payment_receiver.key(),
+        payment_token_mint_account: order_detail.payment_token_mint_account,
+        installment_paid_in_usd: order_detail.installment_paid_in_usd,
+        installment_owed_in_usd: order_detail.installment_owed_in_usd,
+        paid_price_in_usd,
+        escrow_amount,
+        total_revoked,
+        is_refunded,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
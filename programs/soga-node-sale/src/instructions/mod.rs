@@ -11,7 +11,56 @@ pub use buy_with_token::*;
 pub use fill_order::*;
 pub use create_order_receipt::*;
 pub use update_key::*;
-
+pub use initialize_sale_phase_tier_token_2022::*;
+pub use fill_order_token_2022::*;
+pub use airdrop_token_2022::*;
+pub use fill_order_compressed::*;
+pub use update_sale_phase_unlock::*;
+pub use update_sale_phase_tier_unlock::*;
+pub use thaw_node::*;
+pub use revoke_node::*;
+pub use reissue_node::*;
+pub use upgrade_node::*;
+pub use update_sale_phase_oracle_config::*;
+pub use update_sale_phase_payment_token_oracle_config::*;
+pub use update_sale_phase_payment_token_fixed_rate::*;
+pub use initialize_oracle_price::*;
+pub use update_oracle_price::*;
+pub use update_sale_phase_price_sources::*;
+pub use update_sale_phase_payment_token_price_sources::*;
+pub use update_sale_phase_tier_price_schedule::*;
+pub use update_sale_phase_tier_price_curve::*;
+pub use quote::*;
+pub use settle_auction_order::*;
+pub use update_sale_phase_tier_dutch_auction::*;
+pub use update_sale_phase_tier_fair_launch::*;
+pub use commit::*;
+pub use commit_with_token::*;
+pub use finalize_sale_phase_tier_fair_launch::*;
+pub use allocate_commitment::*;
+pub use refund_commitment::*;
+pub use update_sale_phase_tier_sealed_bid_auction::*;
+pub use place_bid::*;
+pub use reveal_bid::*;
+pub use settle_winning_bid::*;
+pub use refund_bid::*;
+pub use update_sale_phase_caps::*;
+pub use settle_order_cap_escrow::*;
+pub use update_sale_phase_installment::*;
+pub use pay_installment::*;
+pub use lapse_installment_order::*;
+pub use update_sale_phase_tier_volume_brackets::*;
+pub use update_sale_phase_loyalty::*;
+pub use update_sale_phase_tier_whitelist_pass::*;
+pub use update_sale_phase_tier_whitelist::*;
+pub use update_sale_phase_tier_reservation::*;
+pub use claim_reservation::*;
+pub use update_sale_phase_tier_airdrop_claim::*;
+pub use claim_airdrop::*;
+pub use update_sale_phase_fill_reward::*;
+pub use fund_fill_fee_vault::*;
+pub use withdraw_fill_fee_vault::*;
+pub use realloc_account::*;
 
 pub mod initialize;
 pub mod initialize_sale_phase;
@@ -26,4 +75,54 @@ pub mod buy_with_token;
 
 pub mod fill_order;
 pub mod create_order_receipt;
-pub mod update_key;
\ No newline at end of file
+pub mod update_key;
+pub mod initialize_sale_phase_tier_token_2022;
+pub mod fill_order_token_2022;
+pub mod airdrop_token_2022;
+pub mod fill_order_compressed;
+pub mod update_sale_phase_unlock;
+pub mod update_sale_phase_tier_unlock;
+pub mod thaw_node;
+pub mod revoke_node;
+pub mod reissue_node;
+pub mod upgrade_node;
+pub mod update_sale_phase_oracle_config;
+pub mod update_sale_phase_payment_token_oracle_config;
+pub mod update_sale_phase_payment_token_fixed_rate;
+pub mod initialize_oracle_price;
+pub mod update_oracle_price;
+pub mod update_sale_phase_price_sources;
+pub mod update_sale_phase_payment_token_price_sources;
+pub mod update_sale_phase_tier_price_schedule;
+pub mod update_sale_phase_tier_price_curve;
+pub mod quote;
+pub mod settle_auction_order;
+pub mod update_sale_phase_tier_dutch_auction;
+pub mod update_sale_phase_tier_fair_launch;
+pub mod commit;
+pub mod commit_with_token;
+pub mod finalize_sale_phase_tier_fair_launch;
+pub mod allocate_commitment;
+pub mod refund_commitment;
+pub mod update_sale_phase_tier_sealed_bid_auction;
+pub mod place_bid;
+pub mod reveal_bid;
+pub mod settle_winning_bid;
+pub mod refund_bid;
+pub mod update_sale_phase_caps;
+pub mod settle_order_cap_escrow;
+pub mod update_sale_phase_installment;
+pub mod pay_installment;
+pub mod lapse_installment_order;
+pub mod update_sale_phase_tier_volume_brackets;
+pub mod update_sale_phase_loyalty;
+pub mod update_sale_phase_tier_whitelist_pass;
+pub mod update_sale_phase_tier_whitelist;
+pub mod update_sale_phase_tier_reservation;
+pub mod claim_reservation;
+pub mod update_sale_phase_tier_airdrop_claim;
+pub mod claim_airdrop;
+pub mod update_sale_phase_fill_reward;
+pub mod fund_fill_fee_vault;
+pub mod withdraw_fill_fee_vault;
+pub mod realloc_account;
\ No newline at end of file
@@ -0,0 +1,285 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    token_interface::{TokenAccount, TransferChecked, transfer_checked},
+};
+
+use crate::oracle::{get_quorum_oracle_price, get_expo_base, get_price_update, get_payment_token_amount, OraclePrice};
+
+use crate::pricing::{usd_to_token_amount, get_paid_price_in_usd, SOL_DECIMALS};
+
+use crate::error::SogaNodeSaleError;
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+    SOGA_NODE_SALE_PHASE_PAYMENT_TOKEN_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhasePaymentTokenDetailAccount,
+    USER_DETAIL_ACCOUNT_PREFIX,
+    UserDetailAccount,
+    USER_TIER_DETAIL_ACCOUNT_PREFIX,
+    UserTierDetailAccount,
+    ORDER_DETAIL_ACCOUNT_PREFIX,
+    OrderDetailAccount,
+};
+
+use crate::events::{
+    PayInstallmentEvent
+};
+
+use crate::utils::{check_signing_authority, check_price_feed, check_tier_id, check_payment_token, check_payment_token_mint_account, check_payment_token_program, check_max_payment_amount, check_cap_escrow, check_order_voided, check_installment_order, check_installment_payment, check_installment_overdue};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, _user_detail_bump: u8, _user_tier_detail_bump: u8,
+_order_detail_bump: u8, sale_phase_name: String, tier_id: String, order_id: String)]
+pub struct PayInstallmentInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub signing_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub user_payer: Signer<'info>,
+
+    /// CHECK: user
+    pub user: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+        SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_name.as_ref(),
+        ],
+        bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+        SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail.key().as_ref(),
+        tier_id.as_ref()
+        ],
+        bump = _sale_phase_tier_detail_bump,
+    )]
+    pub sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+        USER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail.key().as_ref(),
+        user.key().as_ref(),
+        ],
+        bump = _user_detail_bump,
+    )]
+    pub user_detail: Box<Account<'info, UserDetailAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+        USER_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        user_detail.key().as_ref(),
+        sale_phase_tier_detail.key().as_ref(),
+        ],
+        bump = _user_tier_detail_bump,
+    )]
+    pub user_tier_detail: Box<Account<'info, UserTierDetailAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+        ORDER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail.key().as_ref(),
+        user_detail.key().as_ref(),
+        order_id.as_ref(),
+        ],
+        bump = _order_detail_bump,
+    )]
+    pub order_detail: Box<Account<'info, OrderDetailAccount>>,
+
+    /// required when the order was paid with a payment token
+    #[account(
+        seeds = [
+        SOGA_NODE_SALE_PHASE_PAYMENT_TOKEN_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail.key().as_ref(),
+        sale_phase_payment_token_detail.mint.as_ref()
+        ],
+        bump,
+    )]
+    pub sale_phase_payment_token_detail: Option<Box<Account<'info, SogaNodeSalePhasePaymentTokenDetailAccount>>>,
+
+    /// not required when paying with a fixed-rate payment token without a depeg guard
+    /// CHECK: price feed, owner and layout checked by the oracle source
+    pub price_update: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Pays `payment_in_usd` of the balance of an installment order in the currency of the
+/// order. The payment is held in the phase escrow until the order is paid in full and
+/// its share of the order price is counted toward the phase caps.
+///
+/// Remaining accounts are `[payment_token_mint_account, payment_token_program,
+/// payment_token_user_payer_token_account, cap_escrow]` when the order was paid with a
+/// payment token, followed by the registered price sources of the phase or payment token.
+pub fn handle_pay_installment<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, PayInstallmentInputAccounts<'info>>,
+                                                 _sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, _user_detail_bump: u8,
+                                                 _user_tier_detail_bump: u8, _order_detail_bump: u8, sale_phase_name: String, tier_id: String,
+                                                 order_id: String, payment_in_usd: u64, max_payment_amount: u64,
+) -> Result<()> {
+    let tier_id_int: u32 = tier_id.clone().parse().unwrap();
+
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+    let order_detail: &Box<Account<OrderDetailAccount>> = &ctx.accounts.order_detail;
+
+    // Checks
+    check_signing_authority(sale_phase_detail.signing_authority, ctx.accounts.signing_authority.key())?;
+
+    check_tier_id(order_detail.tier_id, tier_id_int)?;
+
+    check_installment_order(order_detail.is_installment)?;
+
+    check_order_voided(order_detail.is_voided)?;
+
+    check_installment_overdue(order_detail.is_installment_overdue(timestamp))?;
+
+    check_installment_payment(payment_in_usd, order_detail.installment_paid_in_usd, order_detail.installment_owed_in_usd)?;
+
+    // Make Payment
+    let price_update = ctx.accounts.price_update.as_deref();
+
+    let payment_amount: u64;
+    let pyth_expo: u64;
+    let pyth_price: u64;
+
+    match order_detail.payment_token_mint_account {
+        Some(payment_token_mint) => {
+            let payment_token_mint_account = &ctx.remaining_accounts[0];
+            let payment_token_program = &ctx.remaining_accounts[1];
+            let payment_token_user_payer_token_account = &ctx.remaining_accounts[2];
+            let cap_escrow = &ctx.remaining_accounts[3];
+
+            let sale_phase_payment_token_detail = ctx.accounts.sale_phase_payment_token_detail.as_deref().ok_or(SogaNodeSaleError::InvalidPaymentTokenMintAccount)?;
+
+            check_payment_token(sale_phase_payment_token_detail.enable)?;
+
+            check_payment_token_mint_account(payment_token_mint, sale_phase_payment_token_detail.mint)?;
+
+            check_payment_token_mint_account(payment_token_mint, payment_token_mint_account.key())?;
+
+            check_payment_token_program(payment_token_program.key(), *payment_token_mint_account.owner, *cap_escrow.owner)?;
+
+            let cap_escrow_token: TokenAccount = TokenAccount::try_deserialize(&mut &cap_escrow.try_borrow_data()?[..])?;
+            check_cap_escrow(cap_escrow_token.owner, sale_phase_detail.key(), cap_escrow_token.mint, payment_token_mint)?;
+
+            let (amount, price): (u64, Option<OraclePrice>) = get_payment_token_amount(payment_in_usd, sale_phase_payment_token_detail, price_update, &ctx.remaining_accounts[4..])?;
+
+            pyth_expo = price.as_ref().map_or(0, get_expo_base);
+            pyth_price = price.as_ref().map_or(0, |price| price.price as u64);
+
+            payment_amount = amount;
+
+            check_max_payment_amount(payment_amount, max_payment_amount)?;
+
+            let cpi_accounts = TransferChecked {
+                from: payment_token_user_payer_token_account.to_account_info(),
+                mint: payment_token_mint_account.to_account_info(),
+                to: cap_escrow.to_account_info(),
+                authority: ctx.accounts.user_payer.to_account_info(),
+            };
+            let cpi_program = payment_token_program.to_account_info();
+            let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+            transfer_checked(cpi_context, payment_amount, sale_phase_payment_token_detail.decimals)?;
+        }
+        None => {
+            let price_update = get_price_update(price_update)?;
+
+            check_price_feed(sale_phase_detail.price_feed_address, price_update.key())?;
+
+            let price: OraclePrice = get_quorum_oracle_price(price_update, ctx.remaining_accounts, sale_phase_detail.price_feed_id.as_str(),
+                                                             &sale_phase_detail.oracle_config, &sale_phase_detail.price_sources, sale_phase_detail.price_source_quorum)?;
+
+            pyth_expo = get_expo_base(&price);
+            pyth_price = price.price as u64;
+
+            payment_amount = usd_to_token_amount(payment_in_usd, SOL_DECIMALS, pyth_price, price.exponent)?;
+
+            check_max_payment_amount(payment_amount, max_payment_amount)?;
+
+            // the sol escrow is held as lamports of the sale phase detail
+            let deposit_amount_ix = anchor_lang::solana_program::system_instruction::transfer(
+                &ctx.accounts.user_payer.key(),
+                &sale_phase_detail.key(),
+                payment_amount,
+            );
+
+            anchor_lang::solana_program::program::invoke(
+                &deposit_amount_ix,
+                &[
+                    ctx.accounts.user_payer.to_account_info(),
+                    sale_phase_detail.to_account_info(),
+                ],
+            )?;
+        }
+    }
+
+    // Update
+    let order_detail: &mut Box<Account<OrderDetailAccount>> = &mut ctx.accounts.order_detail;
+    let previous_paid_price_in_usd: u64 = get_paid_price_in_usd(order_detail.total_payment_in_usd, order_detail.installment_paid_in_usd, order_detail.installment_owed_in_usd)?;
+
+    order_detail.last_block_timestamp = timestamp;
+    order_detail.installment_paid_in_usd += payment_in_usd;
+    order_detail.cap_escrow_amount += payment_amount;
+
+    // the share of the order price paid by this installment
+    let paid_price_in_usd: u64 = get_paid_price_in_usd(order_detail.total_payment_in_usd, order_detail.installment_paid_in_usd, order_detail.installment_owed_in_usd)?
+        - previous_paid_price_in_usd;
+
+    let sale_phase_detail: &mut Box<Account<SogaNodeSalePhaseDetailAccount>> = &mut ctx.accounts.sale_phase_detail;
+    sale_phase_detail.total_payment += paid_price_in_usd;
+    sale_phase_detail.latch_soft_cap();
+    sale_phase_detail.total_installment_pending -= paid_price_in_usd;
+    sale_phase_detail.last_block_timestamp = timestamp;
+
+    let sale_phase_tier_detail: &mut Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &mut ctx.accounts.sale_phase_tier_detail;
+    sale_phase_tier_detail.total_payment += paid_price_in_usd;
+    sale_phase_tier_detail.last_block_timestamp = timestamp;
+
+    let user_detail: &mut Box<Account<UserDetailAccount>> = &mut ctx.accounts.user_detail;
+    user_detail.total_payment += paid_price_in_usd;
+    user_detail.last_block_timestamp = timestamp;
+
+    let user_tier_detail: &mut Box<Account<UserTierDetailAccount>> = &mut ctx.accounts.user_tier_detail;
+    user_tier_detail.total_payment += paid_price_in_usd;
+    user_tier_detail.last_block_timestamp = timestamp;
+
+    // Event
+    let event: PayInstallmentEvent = PayInstallmentEvent {
+        timestamp,
+        sale_phase_name,
+        tier_id,
+        order_id,
+        user: ctx.accounts.user.key(),
+        user_payer: ctx.accounts.user_payer.key(),
+        payment_token_mint_account: order_detail.payment_token_mint_account,
+        pyth_expo,
+        pyth_price,
+        payment_in_usd,
+        paid_price_in_usd,
+        payment_amount,
+        max_payment_amount,
+        installment_paid_in_usd: order_detail.installment_paid_in_usd,
+        installment_owed_in_usd: order_detail.installment_owed_in_usd,
+        is_paid: order_detail.is_installment_paid(),
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
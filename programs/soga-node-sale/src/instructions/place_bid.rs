@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+    BID_DETAIL_ACCOUNT_PREFIX,
+    BidDetailAccount,
+};
+
+use crate::events::{
+    PlaceBidEvent
+};
+
+use crate::utils::{check_signing_authority, check_phase_buy, check_phase_tier_buy, check_value_is_zero, check_bidding_open};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, sale_phase_name: String, tier_id: String)]
+pub struct PlaceBidInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub signing_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+    seeds = [
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_name.as_ref(),
+    ],
+    bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    tier_id.as_ref()
+    ],
+    bump = _sale_phase_tier_detail_bump,
+    )]
+    pub sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+
+    #[account(
+    init,
+    payer = payer,
+    space = BidDetailAccount::space(),
+    seeds = [
+    BID_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_tier_detail.key().as_ref(),
+    user.key().as_ref(),
+    ],
+    bump,
+    )]
+    pub bid_detail: Box<Account<'info, BidDetailAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_place_bid(ctx: Context<PlaceBidInputAccounts>,
+                        _sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8,
+                        sale_phase_name: String, tier_id: String, bid_hash: [u8; 32], deposit: u64,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+    let sale_phase_tier_detail: &Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &ctx.accounts.sale_phase_tier_detail;
+
+    // Checks
+    check_value_is_zero(deposit as usize)?;
+
+    check_phase_buy(sale_phase_detail.buy_enable)?;
+
+    check_phase_tier_buy(sale_phase_tier_detail.buy_enable)?;
+
+    check_signing_authority(sale_phase_detail.signing_authority, ctx.accounts.signing_authority.key())?;
+
+    check_bidding_open(sale_phase_tier_detail.is_sealed_bid_auction, sale_phase_tier_detail.bid_end_timestamp, timestamp)?;
+
+    // the tier account holds the deposit until the bid is settled
+    let deposit_amount_ix = anchor_lang::solana_program::system_instruction::transfer(
+        &ctx.accounts.user.key(),
+        &sale_phase_tier_detail.key(),
+        deposit,
+    );
+
+    anchor_lang::solana_program::program::invoke(
+        &deposit_amount_ix,
+        &[
+            ctx.accounts.user.to_account_info(),
+            sale_phase_tier_detail.to_account_info(),
+        ],
+    )?;
+
+    // Update
+    let bid_detail: &mut Box<Account<BidDetailAccount>> = &mut ctx.accounts.bid_detail;
+    bid_detail.last_block_timestamp = timestamp;
+    bid_detail.user = ctx.accounts.user.key();
+    bid_detail.tier_id = tier_id.clone().parse().unwrap();
+    bid_detail.bid_hash = bid_hash;
+    bid_detail.deposit = deposit;
+
+    let sale_phase_tier_detail: &mut Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &mut ctx.accounts.sale_phase_tier_detail;
+    sale_phase_tier_detail.total_bids += 1;
+    sale_phase_tier_detail.last_block_timestamp = timestamp;
+
+    // Event
+    let event: PlaceBidEvent = PlaceBidEvent {
+        timestamp,
+        sale_phase_name,
+        tier_id,
+        user: ctx.accounts.user.key(),
+        bid_hash,
+        deposit,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+};
+
+use crate::events::{
+    QuoteEvent
+};
+
+use crate::pricing::get_total_price;
+
+use crate::utils::{check_value_is_zero};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, sale_phase_name: String, tier_id: String)]
+pub struct QuoteInputAccounts<'info> {
+    #[account(
+    seeds = [
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_name.as_ref(),
+    ],
+    bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+    seeds = [
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    tier_id.as_ref()
+    ],
+    bump = _sale_phase_tier_detail_bump,
+    )]
+    pub sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+}
+
+/// Emits the usd price of each of the next `quantity` nodes of a tier, meant to be simulated by clients.
+pub fn handle_quote(ctx: Context<QuoteInputAccounts>,
+                    _sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8,
+                    sale_phase_name: String, tier_id: String, quantity: u64,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    // Checks
+    check_value_is_zero(quantity as usize)?;
+
+    let unit_prices_in_usd: Vec<u64> = ctx.accounts.sale_phase_tier_detail.quote(timestamp, quantity)?;
+    let total_price_in_usd: u64 = get_total_price(&unit_prices_in_usd)?;
+
+    // Event
+    let event: QuoteEvent = QuoteEvent {
+        timestamp,
+        sale_phase_name,
+        tier_id,
+        quantity,
+        unit_prices_in_usd,
+        total_price_in_usd,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,240 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::states::{
+    SogaNodeSalePhaseDetailAccount,
+    SogaNodeSalePhaseTierDetailAccount,
+    SogaNodeSalePhasePaymentTokenDetailAccount,
+    UserTierDetailAccount,
+    OrderDetailAccount,
+    ACCOUNT_LAYOUT_VERSION,
+};
+
+use crate::events::{
+    ReallocAccountEvent
+};
+
+use crate::utils::{check_realloc_account_owner};
+
+use crate::error::SogaNodeSaleError;
+
+#[derive(Accounts)]
+pub struct ReallocAccountInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: phase, tier, payment token, user tier or order account of this program, the layout
+    /// is picked by its discriminator
+    #[account(mut)]
+    pub account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grows an account created before a layout change to the current layout. Fields are only
+/// appended, the zeroed bytes read as the default of every new field. Accounts already at the
+/// current layout are left untouched.
+pub fn handle_realloc_account(ctx: Context<ReallocAccountInputAccounts>) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let account_info: AccountInfo = ctx.accounts.account.to_account_info();
+
+    // Checks
+    check_realloc_account_owner(*account_info.owner, crate::ID)?;
+
+    let previous_space: usize = account_info.data_len();
+    let space: usize = get_layout_space(&account_info.try_borrow_data()?)?;
+
+    // Make Payment
+    if space > previous_space {
+        let rent_amount: u64 = Rent::get()?.minimum_balance(space).saturating_sub(account_info.lamports());
+
+        if rent_amount > 0 {
+            let deposit_amount_ix = anchor_lang::solana_program::system_instruction::transfer(
+                &ctx.accounts.payer.key(),
+                &account_info.key(),
+                rent_amount,
+            );
+
+            anchor_lang::solana_program::program::invoke(
+                &deposit_amount_ix,
+                &[
+                    ctx.accounts.payer.to_account_info(),
+                    account_info.clone(),
+                ],
+            )?;
+        }
+
+        account_info.realloc(space, true)?;
+    }
+
+    // Update
+    migrate_layout(&mut account_info.try_borrow_mut_data()?)?;
+
+    // Event
+    let event: ReallocAccountEvent = ReallocAccountEvent {
+        timestamp,
+        account: account_info.key(),
+        layout_version: ACCOUNT_LAYOUT_VERSION,
+        previous_space: previous_space as u64,
+        space: account_info.data_len() as u64,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
+
+/// Space of the current layout of the account, orders are sized by their token ids.
+fn get_layout_space(data: &[u8]) -> Result<usize> {
+    if data.len() < 8 {
+        return Err(SogaNodeSaleError::InvalidReallocAccount.into());
+    }
+
+    let discriminator: &[u8] = &data[..8];
+
+    if discriminator == SogaNodeSalePhaseDetailAccount::DISCRIMINATOR {
+        return Ok(SogaNodeSalePhaseDetailAccount::space());
+    }
+
+    if discriminator == SogaNodeSalePhaseTierDetailAccount::DISCRIMINATOR {
+        return Ok(SogaNodeSalePhaseTierDetailAccount::space());
+    }
+
+    if discriminator == SogaNodeSalePhasePaymentTokenDetailAccount::DISCRIMINATOR {
+        return Ok(SogaNodeSalePhasePaymentTokenDetailAccount::space());
+    }
+
+    if discriminator == UserTierDetailAccount::DISCRIMINATOR {
+        return Ok(UserTierDetailAccount::space());
+    }
+
+    if discriminator == OrderDetailAccount::DISCRIMINATOR {
+        // discriminator, last_block_timestamp, tier_id and is_completed come before token_ids
+        let offset: usize = 8 + 8 + 4 + 1;
+        let length: [u8; 4] = data.get(offset..offset + 4)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(SogaNodeSaleError::InvalidReallocAccount)?;
+
+        return Ok(OrderDetailAccount::space(u32::from_le_bytes(length) as u64));
+    }
+
+    Err(SogaNodeSaleError::InvalidReallocAccount.into())
+}
+
+/// Fills the new fields of a grown account that do not read as their default.
+fn migrate_layout(data: &mut [u8]) -> Result<()> {
+    // orders created before node revocation, upgrades or reissues carry no flags for them
+    if data[..8] == OrderDetailAccount::DISCRIMINATOR {
+        let mut order_detail: OrderDetailAccount = OrderDetailAccount::try_deserialize(&mut &data[..])?;
+
+        if order_detail.token_flags_len() < order_detail.token_ids.len() {
+            order_detail.is_token_ids_revoked.resize(order_detail.token_ids.len(), false);
+            order_detail.is_token_ids_upgraded.resize(order_detail.token_ids.len(), false);
+            order_detail.is_token_ids_reissued.resize(order_detail.token_ids.len(), false);
+            order_detail.try_serialize(&mut &mut data[..])?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// order layout before the token-2022 mint mode
+    #[derive(AnchorSerialize)]
+    struct BaselineOrderDetailAccount {
+        last_block_timestamp: i64,
+        tier_id: u32,
+        is_completed: bool,
+        token_ids: Vec<u64>,
+        is_token_ids_minted: Vec<bool>,
+        quantity: u64,
+        total_payment_in_usd: u64,
+        total_discount_in_usd: u64,
+        total_user_discount_in_usd: u64,
+        total_payment: u64,
+        total_discount: u64,
+        total_user_discount: u64,
+        payment_token_mint_account: Option<Pubkey>,
+        is_whitelist: bool,
+    }
+
+    fn baseline_order_data(token_ids: Vec<u64>) -> Vec<u8> {
+        let quantity: usize = token_ids.len();
+
+        let order_detail = BaselineOrderDetailAccount {
+            last_block_timestamp: 1700000000,
+            tier_id: 2,
+            is_completed: false,
+            token_ids,
+            is_token_ids_minted: vec![true; quantity],
+            quantity: quantity as u64,
+            total_payment_in_usd: 300,
+            total_discount_in_usd: 30,
+            total_user_discount_in_usd: 0,
+            total_payment: 2_000_000_000,
+            total_discount: 200_000_000,
+            total_user_discount: 0,
+            payment_token_mint_account: None,
+            is_whitelist: true,
+        };
+
+        let mut data: Vec<u8> = OrderDetailAccount::DISCRIMINATOR.to_vec();
+        order_detail.serialize(&mut data).unwrap();
+
+        // space allocated by the baseline buy
+        data.resize(126 + 9 * quantity, 0);
+        data
+    }
+
+    #[test]
+    fn baseline_order_is_grown_to_the_current_layout() {
+        let mut data: Vec<u8> = baseline_order_data(vec![7, 8, 9]);
+
+        let space: usize = get_layout_space(&data).unwrap();
+        assert_eq!(space, OrderDetailAccount::space(3));
+        assert!(space > data.len());
+
+        // realloc zero fills the grown bytes
+        data.resize(space, 0);
+        migrate_layout(&mut data).unwrap();
+
+        let order_detail: OrderDetailAccount = OrderDetailAccount::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(order_detail.last_block_timestamp, 1700000000);
+        assert_eq!(order_detail.tier_id, 2);
+        assert_eq!(order_detail.token_ids, vec![7, 8, 9]);
+        assert_eq!(order_detail.is_token_ids_minted, vec![true; 3]);
+        assert_eq!(order_detail.total_payment, 2_000_000_000);
+        assert!(order_detail.is_whitelist);
+
+        assert_eq!(order_detail.is_token_ids_revoked, vec![false; 3]);
+        assert_eq!(order_detail.is_token_ids_upgraded, vec![false; 3]);
+        assert_eq!(order_detail.is_token_ids_reissued, vec![false; 3]);
+        assert_eq!(order_detail.cap_escrow_amount, 0);
+        assert!(!order_detail.is_installment);
+        assert_eq!(order_detail.full_discount_receiver, Pubkey::default());
+        assert!(order_detail.is_filled());
+    }
+
+    #[test]
+    fn current_order_is_left_untouched() {
+        let mut data: Vec<u8> = baseline_order_data(vec![1]);
+        data.resize(get_layout_space(&data).unwrap(), 0);
+        migrate_layout(&mut data).unwrap();
+
+        let migrated: Vec<u8> = data.clone();
+        assert_eq!(get_layout_space(&data).unwrap(), data.len());
+
+        migrate_layout(&mut data).unwrap();
+        assert_eq!(data, migrated);
+    }
+
+    #[test]
+    fn unknown_accounts_are_rejected() {
+        assert!(get_layout_space(&[0; 4]).is_err());
+        assert!(get_layout_space(&[0; 64]).is_err());
+    }
+}
\ No newline at end of file
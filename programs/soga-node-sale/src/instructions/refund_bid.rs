@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+    BID_DETAIL_ACCOUNT_PREFIX,
+    BidDetailAccount,
+};
+
+use crate::events::{
+    RefundBidEvent
+};
+
+use crate::utils::{check_reveal_ended, check_bid_settled, check_bid_not_winning};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, sale_phase_name: String, tier_id: String)]
+pub struct RefundBidInputAccounts<'info> {
+    pub payer: Signer<'info>,
+
+    /// CHECK: user
+    #[account(mut)]
+    pub user: AccountInfo<'info>,
+
+    #[account(
+    seeds = [
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_name.as_ref(),
+    ],
+    bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    tier_id.as_ref()
+    ],
+    bump = _sale_phase_tier_detail_bump,
+    )]
+    pub sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    BID_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_tier_detail.key().as_ref(),
+    user.key().as_ref(),
+    ],
+    bump,
+    )]
+    pub bid_detail: Box<Account<'info, BidDetailAccount>>,
+}
+
+pub fn handle_refund_bid(ctx: Context<RefundBidInputAccounts>,
+                         _sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8,
+                         sale_phase_name: String, tier_id: String,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let sale_phase_tier_detail: &Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &ctx.accounts.sale_phase_tier_detail;
+    let bid_detail: &Box<Account<BidDetailAccount>> = &ctx.accounts.bid_detail;
+
+    // Checks
+    check_reveal_ended(sale_phase_tier_detail.reveal_end_timestamp, timestamp)?;
+
+    check_bid_settled(bid_detail.is_settled)?;
+
+    // unrevealed bids lose and are refunded as well
+    check_bid_not_winning(bid_detail.is_revealed && sale_phase_tier_detail.is_winning_bid(&bid_detail.user))?;
+
+    let refund_amount: u64 = bid_detail.deposit;
+
+    // the deposit is held as lamports of the sale phase tier detail
+    let sale_phase_tier_detail_info: AccountInfo = sale_phase_tier_detail.to_account_info();
+
+    **sale_phase_tier_detail_info.try_borrow_mut_lamports()? -= refund_amount;
+    **ctx.accounts.user.try_borrow_mut_lamports()? += refund_amount;
+
+    // Update
+    let bid_detail: &mut Box<Account<BidDetailAccount>> = &mut ctx.accounts.bid_detail;
+    bid_detail.last_block_timestamp = timestamp;
+    bid_detail.is_settled = true;
+
+    // Event
+    let event: RefundBidEvent = RefundBidEvent {
+        timestamp,
+        sale_phase_name,
+        tier_id,
+        user: ctx.accounts.user.key(),
+        is_revealed: bid_detail.is_revealed,
+        refund_amount,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    token_interface::{Mint, TokenAccount, TransferChecked, transfer_checked},
+};
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+    COMMITMENT_DETAIL_ACCOUNT_PREFIX,
+    CommitmentDetailAccount,
+};
+
+use crate::events::{
+    RefundCommitmentEvent
+};
+
+use crate::allocation::get_allocation;
+
+use crate::utils::{check_payment_token_mint_account, check_user_token_account, check_commitment_refundable, check_commitment_escrow, check_payment_token_program};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, sale_phase_name: String, tier_id: String)]
+pub struct RefundCommitmentInputAccounts<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+    seeds = [
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_name.as_ref(),
+    ],
+    bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    tier_id.as_ref()
+    ],
+    bump = _sale_phase_tier_detail_bump,
+    )]
+    pub sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    COMMITMENT_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_tier_detail.key().as_ref(),
+    user.key().as_ref(),
+    ],
+    bump,
+    )]
+    pub commitment_detail: Box<Account<'info, CommitmentDetailAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_refund_commitment<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, RefundCommitmentInputAccounts<'info>>,
+                                                   _sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8,
+                                                   sale_phase_name: String, tier_id: String,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+    let sale_phase_tier_detail: &Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &ctx.accounts.sale_phase_tier_detail;
+    let commitment_detail: &Box<Account<CommitmentDetailAccount>> = &ctx.accounts.commitment_detail;
+
+    // Checks
+    check_commitment_refundable(commitment_detail.is_allocated, sale_phase_tier_detail.is_allocation_ended(timestamp), commitment_detail.is_refunded)?;
+
+    // a commitment not allocated before the allocation window ended gets its whole deposit back
+    let refund_amount: u64 = if commitment_detail.is_allocated { commitment_detail.refund_amount } else { commitment_detail.total_payment };
+
+    // the nodes it would have been allocated are released to public buys
+    let forfeited_quantity: u64 = if commitment_detail.is_allocated || !sale_phase_tier_detail.is_fair_launch_finalized { 0 } else {
+        get_allocation(sale_phase_tier_detail.allocation_mode, &sale_phase_tier_detail.lottery_seed, &commitment_detail.ticket_ranges,
+                       sale_phase_tier_detail.total_committed, sale_phase_tier_detail.fair_launch_supply)?
+    };
+
+    match commitment_detail.payment_token_mint_account {
+        None => {
+            // the sol deposit is held as lamports of the sale phase tier detail
+            let sale_phase_tier_detail_info: AccountInfo = sale_phase_tier_detail.to_account_info();
+
+            **sale_phase_tier_detail_info.try_borrow_mut_lamports()? -= refund_amount;
+            **ctx.accounts.user.try_borrow_mut_lamports()? += refund_amount;
+        }
+        Some(payment_token_mint) => {
+            let payment_token_mint_account = &ctx.remaining_accounts[0];
+            let payment_token_program = &ctx.remaining_accounts[1];
+            let commitment_escrow = &ctx.remaining_accounts[2];
+            let payment_token_user_token_account = &ctx.remaining_accounts[3];
+
+            check_payment_token_mint_account(payment_token_mint, payment_token_mint_account.key())?;
+
+            check_payment_token_program(payment_token_program.key(), *payment_token_mint_account.owner, *commitment_escrow.owner)?;
+
+            let commitment_escrow_token: TokenAccount = TokenAccount::try_deserialize(&mut &commitment_escrow.try_borrow_data()?[..])?;
+            check_commitment_escrow(commitment_escrow_token.owner, sale_phase_tier_detail.key(), commitment_escrow_token.mint, payment_token_mint)?;
+
+            let user_token: TokenAccount = TokenAccount::try_deserialize(&mut &payment_token_user_token_account.try_borrow_data()?[..])?;
+            check_user_token_account(ctx.accounts.user.key(), user_token.owner)?;
+
+            let decimals: u8 = Mint::try_deserialize(&mut &payment_token_mint_account.try_borrow_data()?[..])?.decimals;
+
+            let sale_phase_detail_key: Pubkey = sale_phase_detail.key();
+
+            let signer_seeds = &[
+                SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+                sale_phase_detail_key.as_ref(),
+                tier_id.as_ref(),
+                &[_sale_phase_tier_detail_bump],
+            ];
+
+            let signer = &[&signer_seeds[..]];
+
+            if refund_amount > 0 {
+                let cpi_accounts = TransferChecked {
+                    from: commitment_escrow.to_account_info(),
+                    mint: payment_token_mint_account.to_account_info(),
+                    to: payment_token_user_token_account.to_account_info(),
+                    authority: sale_phase_tier_detail.to_account_info(),
+                };
+                let cpi_program = payment_token_program.to_account_info();
+                let cpi_context = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+                transfer_checked(cpi_context, refund_amount, decimals)?;
+            }
+        }
+    }
+
+    // Update
+    let commitment_detail: &mut Box<Account<CommitmentDetailAccount>> = &mut ctx.accounts.commitment_detail;
+    commitment_detail.last_block_timestamp = timestamp;
+    commitment_detail.is_refunded = true;
+    commitment_detail.refund_amount = refund_amount;
+
+    let sale_phase_tier_detail: &mut Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &mut ctx.accounts.sale_phase_tier_detail;
+    sale_phase_tier_detail.total_forfeited += forfeited_quantity;
+    sale_phase_tier_detail.last_block_timestamp = timestamp;
+
+    // Event
+    let event: RefundCommitmentEvent = RefundCommitmentEvent {
+        timestamp,
+        sale_phase_name,
+        tier_id,
+        user: ctx.accounts.user.key(),
+        payment_token_mint_account: commitment_detail.payment_token_mint_account,
+        refund_amount,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
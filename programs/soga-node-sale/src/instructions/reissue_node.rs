@@ -0,0 +1,328 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    associated_token::{AssociatedToken, Create, create_idempotent},
+    token_2022::{Token2022, MintTo, mint_to, FreezeAccount, freeze_account},
+    token_interface::Mint,
+};
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+    COLLECTION_ACCOUNT_PREFIX,
+    NODE_ACCOUNT_PREFIX,
+    USER_DETAIL_ACCOUNT_PREFIX,
+    UserDetailAccount,
+    USER_TIER_DETAIL_ACCOUNT_PREFIX,
+    UserTierDetailAccount,
+    ORDER_DETAIL_ACCOUNT_PREFIX,
+    OrderDetailAccount,
+    NodeMintMode,
+};
+
+use crate::events::{ReissueNodeEvent};
+
+use crate::utils::{
+    check_signing_authority,
+    check_mint_mode,
+    check_node_is_revoked,
+    check_tier_id,
+    check_order_id,
+    check_order_token_id,
+    check_order_token_id_not_revoked,
+    check_order_token_id_upgraded,
+    check_order_token_id_reissued,
+    check_order_layout,
+    check_token_quantity_out_of_range,
+    get_unlock_timestamp,
+    is_node_locked,
+};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, _collection_mint_account_bump: u8,
+_revoked_user_detail_bump: u8, _revoked_order_detail_bump: u8, sale_phase_name: String, tier_id: String, token_id: String,
+revoked_order_id: String, order_id: String)]
+pub struct ReissueNodeInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub signing_authority: Signer<'info>,
+
+    /// CHECK: user receiving the revoked node
+    pub user: AccountInfo<'info>,
+
+    /// CHECK: holder of the order the node was revoked from
+    pub revoked_user: AccountInfo<'info>,
+
+    #[account(
+    mut,
+    seeds = [
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_name.as_ref(),
+    ],
+    bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    tier_id.as_ref()
+    ],
+    bump = _sale_phase_tier_detail_bump,
+    )]
+    pub sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserDetailAccount::space(),
+        seeds = [
+        USER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail.key().as_ref(),
+        user.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub user_detail: Box<Account<'info, UserDetailAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserTierDetailAccount::space(),
+        seeds = [
+        USER_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        user_detail.key().as_ref(),
+        sale_phase_tier_detail.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub user_tier_detail: Box<Account<'info, UserTierDetailAccount>>,
+
+    #[account(
+        seeds = [
+        USER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail.key().as_ref(),
+        revoked_user.key().as_ref(),
+        ],
+        bump = _revoked_user_detail_bump,
+    )]
+    pub revoked_user_detail: Box<Account<'info, UserDetailAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+        ORDER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail.key().as_ref(),
+        revoked_user_detail.key().as_ref(),
+        revoked_order_id.as_ref(),
+        ],
+        bump = _revoked_order_detail_bump,
+    )]
+    pub revoked_order_detail: Box<Account<'info, OrderDetailAccount>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = OrderDetailAccount::space(1),
+        seeds = [
+        ORDER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail.key().as_ref(),
+        user_detail.key().as_ref(),
+        order_id.as_ref(),
+        ],
+        bump,
+    )]
+    pub order_detail: Box<Account<'info, OrderDetailAccount>>,
+
+    /// CHECK: token-2022 collection group mint
+    #[account(
+    seeds = [
+    COLLECTION_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_tier_detail.key().as_ref(),
+    ],
+    bump = _collection_mint_account_bump,
+    owner = token_program.key(),
+    )]
+    pub collection_mint_account: UncheckedAccount<'info>,
+
+    #[account(
+    mut,
+    seeds = [
+    NODE_ACCOUNT_PREFIX.as_ref(),
+    collection_mint_account.key().as_ref(),
+    token_id.as_ref()
+    ],
+    bump,
+    mint::authority = sale_phase_tier_detail.key(),
+    mint::token_program = token_program,
+    )]
+    pub node_mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: user associated token account, created in the instruction
+    #[account(mut)]
+    pub user_token_account: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Mints a node revoked by `revoke_node` again to `user` in a new single node order. Each
+/// revocation of the revoked order is reissued once, the new order holds the node from then on
+/// so the reissued node can be revoked again.
+pub fn handle_reissue_node(ctx: Context<ReissueNodeInputAccounts>,
+                           _sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, _collection_mint_account_bump: u8,
+                           _revoked_user_detail_bump: u8, _revoked_order_detail_bump: u8, sale_phase_name: String, tier_id: String,
+                           token_id: String, revoked_order_id: String, order_id: String,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let tier_id_int: u32 = tier_id.clone().parse().unwrap();
+    let token_id_int: u64 = token_id.clone().parse().unwrap();
+    let order_id_int: u64 = order_id.clone().parse().unwrap();
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+    let sale_phase_tier_detail: &Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &ctx.accounts.sale_phase_tier_detail;
+    let revoked_order_detail: &Box<Account<OrderDetailAccount>> = &ctx.accounts.revoked_order_detail;
+
+    // Checks
+    check_signing_authority(sale_phase_detail.signing_authority, ctx.accounts.signing_authority.key())?;
+    check_mint_mode(sale_phase_detail.mint_mode, NodeMintMode::Token2022)?;
+
+    check_tier_id(revoked_order_detail.tier_id, tier_id_int)?;
+
+    check_order_token_id(revoked_order_detail.token_ids.contains(&token_id_int))?;
+
+    let index = revoked_order_detail.token_ids.iter().position(|r| r == &token_id_int).unwrap();
+
+    check_order_layout(revoked_order_detail.token_flags_len(), revoked_order_detail.token_ids.len())?;
+
+    // only a node revoked by `revoke_node` is reissued, an upgraded node was exchanged by its holder
+    check_order_token_id_not_revoked(revoked_order_detail.is_token_id_revoked(index))?;
+
+    check_order_token_id_upgraded(revoked_order_detail.is_token_id_upgraded(index))?;
+
+    check_order_token_id_reissued(revoked_order_detail.is_token_id_reissued(index))?;
+
+    // a burned node is the only way for the supply of a node mint to be 0
+    check_node_is_revoked(ctx.accounts.node_mint_account.supply)?;
+
+    check_order_id(ctx.accounts.user_detail.total_orders + 1, order_id_int)?;
+
+    check_token_quantity_out_of_range(sale_phase_tier_detail.minted_supply() + 1, sale_phase_tier_detail.public_quantity())?;
+
+    let sale_phase_detail_key: Pubkey = ctx.accounts.sale_phase_detail.key();
+
+    let signer_seeds = &[
+        SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail_key.as_ref(),
+        tier_id.as_ref(),
+        &[_sale_phase_tier_detail_bump],
+    ];
+
+    let signer = &[&signer_seeds[..]];
+
+    let unlock_timestamp: i64 = get_unlock_timestamp(sale_phase_detail.unlock_timestamp, sale_phase_tier_detail.unlock_timestamp);
+    let is_locked: bool = is_node_locked(sale_phase_tier_detail.is_unlocked, unlock_timestamp, timestamp);
+
+    // create user token account
+    let create_idempotent_cpi_context = CpiContext::new(
+        ctx.accounts.associated_token_program.to_account_info(),
+        Create {
+            payer: ctx.accounts.payer.to_account_info(),
+            associated_token: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.user.to_account_info(),
+            mint: ctx.accounts.node_mint_account.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+    );
+
+    create_idempotent(create_idempotent_cpi_context)?;
+
+    // mint node
+    let mint_to_cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        MintTo {
+            mint: ctx.accounts.node_mint_account.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.sale_phase_tier_detail.to_account_info(),
+        },
+        signer,
+    );
+
+    mint_to(mint_to_cpi_context, 1)?;
+
+    // freeze user token account until unlock
+    if is_locked {
+        let freeze_account_cpi_context = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            FreezeAccount {
+                mint: ctx.accounts.node_mint_account.to_account_info(),
+                account: ctx.accounts.user_token_account.to_account_info(),
+                authority: ctx.accounts.sale_phase_tier_detail.to_account_info(),
+            },
+            signer,
+        );
+
+        freeze_account(freeze_account_cpi_context)?;
+    }
+
+    // Update
+    let revoked_order_detail: &mut Box<Account<OrderDetailAccount>> = &mut ctx.accounts.revoked_order_detail;
+    revoked_order_detail.last_block_timestamp = timestamp;
+    revoked_order_detail.is_token_ids_reissued[index] = true;
+
+    let order_detail: &mut Box<Account<OrderDetailAccount>> = &mut ctx.accounts.order_detail;
+    order_detail.last_block_timestamp = timestamp;
+    order_detail.tier_id = tier_id_int;
+    order_detail.is_completed = true;
+    order_detail.quantity = 1;
+    order_detail.token_ids = vec![token_id_int];
+    order_detail.is_token_ids_minted = vec![true];
+    order_detail.is_token_ids_revoked = vec![false];
+    order_detail.is_token_ids_upgraded = vec![false];
+    order_detail.is_token_ids_reissued = vec![false];
+    order_detail.is_whitelist = false;
+
+    let sale_phase_detail: &mut Box<Account<SogaNodeSalePhaseDetailAccount>> = &mut ctx.accounts.sale_phase_detail;
+    sale_phase_detail.total_reissued += 1;
+    sale_phase_detail.last_block_timestamp = timestamp;
+
+    let sale_phase_tier_detail: &mut Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &mut ctx.accounts.sale_phase_tier_detail;
+    sale_phase_tier_detail.total_reissued += 1;
+    sale_phase_tier_detail.last_block_timestamp = timestamp;
+
+    let user_detail: &mut Box<Account<UserDetailAccount>> = &mut ctx.accounts.user_detail;
+    user_detail.total_mint += 1;
+    user_detail.total_orders += 1;
+    user_detail.last_block_timestamp = timestamp;
+
+    let user_tier_detail: &mut Box<Account<UserTierDetailAccount>> = &mut ctx.accounts.user_tier_detail;
+    user_tier_detail.total_mint += 1;
+    user_tier_detail.last_block_timestamp = timestamp;
+
+    // Event
+    let event: ReissueNodeEvent = ReissueNodeEvent {
+        timestamp,
+        sale_phase_name,
+        tier_id,
+        token_id,
+        revoked_order_id,
+        order_id,
+        user: ctx.accounts.user.key(),
+        revoked_user: ctx.accounts.revoked_user.key(),
+        node_mint_account: ctx.accounts.node_mint_account.key(),
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+
+use crate::allocation::{get_bid_hash, insert_winning_bid};
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+    BID_DETAIL_ACCOUNT_PREFIX,
+    BidDetailAccount,
+    WinningBid,
+};
+
+use crate::events::{
+    RevealBidEvent
+};
+
+use crate::utils::{check_reveal_open, check_bid_revealed, check_bid_hash, check_bid_price};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, sale_phase_name: String, tier_id: String)]
+pub struct RevealBidInputAccounts<'info> {
+    pub user: Signer<'info>,
+
+    #[account(
+    seeds = [
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_name.as_ref(),
+    ],
+    bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    tier_id.as_ref()
+    ],
+    bump = _sale_phase_tier_detail_bump,
+    )]
+    pub sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    BID_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_tier_detail.key().as_ref(),
+    user.key().as_ref(),
+    ],
+    bump,
+    )]
+    pub bid_detail: Box<Account<'info, BidDetailAccount>>,
+}
+
+pub fn handle_reveal_bid(ctx: Context<RevealBidInputAccounts>,
+                         _sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8,
+                         sale_phase_name: String, tier_id: String, price: u64, salt: [u8; 32],
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let sale_phase_tier_detail: &Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &ctx.accounts.sale_phase_tier_detail;
+    let bid_detail: &Box<Account<BidDetailAccount>> = &ctx.accounts.bid_detail;
+
+    // Checks
+    check_reveal_open(sale_phase_tier_detail.bid_end_timestamp, sale_phase_tier_detail.reveal_end_timestamp, timestamp)?;
+
+    check_bid_revealed(bid_detail.is_revealed)?;
+
+    check_bid_hash(bid_detail.bid_hash, get_bid_hash(price, &salt, &ctx.accounts.user.key()))?;
+
+    check_bid_price(price, sale_phase_tier_detail.reserve_price, bid_detail.deposit)?;
+
+    // Update
+    let bid_detail: &mut Box<Account<BidDetailAccount>> = &mut ctx.accounts.bid_detail;
+    bid_detail.last_block_timestamp = timestamp;
+    bid_detail.is_revealed = true;
+    bid_detail.price = price;
+
+    let sale_phase_tier_detail: &mut Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &mut ctx.accounts.sale_phase_tier_detail;
+    let winner_quantity: usize = sale_phase_tier_detail.public_quantity().saturating_sub(sale_phase_tier_detail.minted_supply()) as usize;
+
+    let is_winning: bool = insert_winning_bid(&mut sale_phase_tier_detail.winning_bids, WinningBid {
+        user: ctx.accounts.user.key(),
+        price,
+    }, winner_quantity);
+
+    sale_phase_tier_detail.last_block_timestamp = timestamp;
+
+    // Event
+    let event: RevealBidEvent = RevealBidEvent {
+        timestamp,
+        sale_phase_name,
+        tier_id,
+        user: ctx.accounts.user.key(),
+        price,
+        is_winning,
+        lowest_winning_bid: sale_phase_tier_detail.lowest_winning_bid(),
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
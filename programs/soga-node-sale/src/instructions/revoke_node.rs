@@ -0,0 +1,236 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    token_2022::Token2022,
+    token_interface::TokenAccount,
+};
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+    USER_DETAIL_ACCOUNT_PREFIX,
+    UserDetailAccount,
+    USER_TIER_DETAIL_ACCOUNT_PREFIX,
+    UserTierDetailAccount,
+    NODE_ACCOUNT_PREFIX,
+    ORDER_DETAIL_ACCOUNT_PREFIX,
+    OrderDetailAccount,
+    NodeMintMode,
+};
+
+use crate::events::{RevokeNodeEvent};
+
+use crate::token_extensions::burn_node;
+
+use crate::utils::{
+    check_signing_authority,
+    check_order_token_id,
+    check_order_token_id_revoked,
+    check_order_token_id_upgraded,
+    check_order_layout,
+    check_mint_mode,
+    check_phase_tier_collection,
+    check_node_mint_account,
+    check_user_token_account,
+};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, _user_detail_bump: u8, _user_tier_detail_bump: u8,
+_order_detail_bump: u8, sale_phase_name: String, tier_id: String, token_id: String, order_id: String)]
+pub struct RevokeNodeInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub signing_authority: Signer<'info>,
+
+    /// CHECK: user
+    pub user: AccountInfo<'info>,
+
+    #[account(
+    mut,
+    seeds = [
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_name.as_ref(),
+    ],
+    bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    tier_id.as_ref()
+    ],
+    bump = _sale_phase_tier_detail_bump,
+    )]
+    pub sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    USER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    user.key().as_ref(),
+    ],
+    bump = _user_detail_bump,
+    )]
+    pub user_detail: Box<Account<'info, UserDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    USER_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    user_detail.key().as_ref(),
+    sale_phase_tier_detail.key().as_ref(),
+    ],
+    bump = _user_tier_detail_bump,
+    )]
+    pub user_tier_detail: Box<Account<'info, UserTierDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    ORDER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    user_detail.key().as_ref(),
+    order_id.as_ref(),
+    ],
+    bump = _order_detail_bump,
+    )]
+    pub order_detail: Box<Account<'info, OrderDetailAccount>>,
+
+    pub token_program: Program<'info, Token2022>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Revokes a token id of an order. Token ids that are already minted are burned
+/// through the permanent delegate of the token-2022 node mint, remaining accounts
+/// `[collection_mint_account, node_mint_account, holder_token_account]` are required
+/// in that case. Unminted token ids are only marked revoked so they are never filled.
+pub fn handle_revoke_node<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, RevokeNodeInputAccounts<'info>>,
+                                             _sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, _user_detail_bump: u8, _user_tier_detail_bump: u8,
+                                             _order_detail_bump: u8, sale_phase_name: String, tier_id: String, token_id: String, order_id: String,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+    let sale_phase_tier_detail: &Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &ctx.accounts.sale_phase_tier_detail;
+
+    let token_id_int: u64 = token_id.clone().parse().unwrap();
+    let order_detail: &Box<Account<OrderDetailAccount>> = &ctx.accounts.order_detail;
+
+    // Checks
+    check_signing_authority(sale_phase_detail.signing_authority, ctx.accounts.signing_authority.key())?;
+
+    check_order_token_id(order_detail.token_ids.contains(&token_id_int))?;
+
+    let index = order_detail.token_ids.iter().position(|r| r == &token_id_int).unwrap();
+
+    check_order_token_id_revoked(order_detail.is_token_id_revoked(index))?;
+
+    check_order_token_id_upgraded(order_detail.is_token_id_upgraded(index))?;
+
+    check_order_layout(order_detail.token_flags_len(), order_detail.token_ids.len())?;
+
+    let is_minted: bool = order_detail.is_token_ids_minted[index];
+
+    let mut node_mint_account: Option<Pubkey> = None;
+
+    if is_minted {
+        check_mint_mode(sale_phase_detail.mint_mode, NodeMintMode::Token2022)?;
+
+        let collection_mint_account = &ctx.remaining_accounts[0];
+        let node_mint = &ctx.remaining_accounts[1];
+        let holder_token_account = &ctx.remaining_accounts[2];
+
+        check_phase_tier_collection(sale_phase_tier_detail.collection_mint_address, collection_mint_account.key())?;
+
+        let (node_mint_address, _node_mint_bump) = Pubkey::find_program_address(
+            &[
+                NODE_ACCOUNT_PREFIX.as_ref(),
+                collection_mint_account.key().as_ref(),
+                token_id.as_ref(),
+            ],
+            ctx.program_id,
+        );
+
+        check_node_mint_account(node_mint_address, node_mint.key())?;
+
+        // the node may have been transferred after unlock, any holder token account of the node mint is accepted
+        check_user_token_account(ctx.accounts.token_program.key(), *holder_token_account.owner)?;
+
+        let holder_token: TokenAccount = TokenAccount::try_deserialize(&mut &holder_token_account.try_borrow_data()?[..])?;
+
+        check_user_token_account(node_mint.key(), holder_token.mint)?;
+
+        let sale_phase_detail_key: Pubkey = ctx.accounts.sale_phase_detail.key();
+
+        let signer_seeds = &[
+            SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+            sale_phase_detail_key.as_ref(),
+            tier_id.as_ref(),
+            &[_sale_phase_tier_detail_bump],
+        ];
+
+        let signer = &[&signer_seeds[..]];
+
+        // burn node as permanent delegate
+        burn_node(
+            &ctx.accounts.token_program.to_account_info(),
+            node_mint,
+            holder_token_account,
+            &ctx.accounts.sale_phase_tier_detail.to_account_info(),
+            signer,
+        )?;
+
+        node_mint_account = Some(node_mint.key());
+    }
+
+
+    // Update
+    let order_detail: &mut Box<Account<OrderDetailAccount>> = &mut ctx.accounts.order_detail;
+    order_detail.last_block_timestamp = timestamp;
+
+    order_detail.is_token_ids_revoked[index] = true;
+
+    if order_detail.is_filled() {
+        order_detail.is_completed = true;
+    }
+
+    let sale_phase_detail: &mut Box<Account<SogaNodeSalePhaseDetailAccount>> = &mut ctx.accounts.sale_phase_detail;
+    sale_phase_detail.total_revoked += 1;
+    sale_phase_detail.last_block_timestamp = timestamp;
+
+    let sale_phase_tier_detail: &mut Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &mut ctx.accounts.sale_phase_tier_detail;
+    sale_phase_tier_detail.total_revoked += 1;
+    sale_phase_tier_detail.last_block_timestamp = timestamp;
+
+    let user_detail: &mut Box<Account<UserDetailAccount>> = &mut ctx.accounts.user_detail;
+    user_detail.total_mint -= 1;
+    user_detail.last_block_timestamp = timestamp;
+
+    let user_tier_detail: &mut Box<Account<UserTierDetailAccount>> = &mut ctx.accounts.user_tier_detail;
+    user_tier_detail.total_mint -= 1;
+    user_tier_detail.last_block_timestamp = timestamp;
+
+    // Event
+    let event: RevokeNodeEvent = RevokeNodeEvent {
+        timestamp,
+        sale_phase_name,
+        tier_id,
+        order_id,
+        token_id,
+        user: ctx.accounts.user.key(),
+        node_mint_account,
+        is_completed: order_detail.is_completed,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
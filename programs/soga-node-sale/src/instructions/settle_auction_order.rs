@@ -0,0 +1,215 @@
+use std::ops::{Mul, Sub};
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    token_interface::{Mint, TokenAccount, TransferChecked, transfer_checked},
+};
+
+use crate::pricing::get_auction_rebate_amount;
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+    USER_DETAIL_ACCOUNT_PREFIX,
+    UserDetailAccount,
+    ORDER_DETAIL_ACCOUNT_PREFIX,
+    OrderDetailAccount,
+};
+
+use crate::events::{
+    SettleAuctionOrderEvent
+};
+
+use crate::utils::{check_payment_receiver, check_tier_id, check_payment_token_mint_account, check_user_token_account, check_auction_escrow, check_auction_ended, check_auction_order_settled, check_soft_cap_reached, check_payment_token_program};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, _user_detail_bump: u8, _order_detail_bump: u8,
+sale_phase_name: String, tier_id: String, order_id: String)]
+pub struct SettleAuctionOrderInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: user
+    #[account(mut)]
+    pub user: AccountInfo<'info>,
+
+    /// CHECK: payment receiver, checked against the sale phase detail
+    #[account(mut)]
+    pub payment_receiver: AccountInfo<'info>,
+
+    #[account(
+    seeds = [
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_name.as_ref(),
+    ],
+    bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    tier_id.as_ref()
+    ],
+    bump = _sale_phase_tier_detail_bump,
+    )]
+    pub sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+
+    #[account(
+    seeds = [
+    USER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    user.key().as_ref(),
+    ],
+    bump = _user_detail_bump,
+    )]
+    pub user_detail: Box<Account<'info, UserDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    ORDER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    user_detail.key().as_ref(),
+    order_id.as_ref(),
+    ],
+    bump = _order_detail_bump,
+    )]
+    pub order_detail: Box<Account<'info, OrderDetailAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_settle_auction_order<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, SettleAuctionOrderInputAccounts<'info>>,
+                                                      _sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, _user_detail_bump: u8, _order_detail_bump: u8,
+                                                      sale_phase_name: String, tier_id: String, order_id: String,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let tier_id_int: u32 = tier_id.clone().parse().unwrap();
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+    let sale_phase_tier_detail: &Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &ctx.accounts.sale_phase_tier_detail;
+    let order_detail: &Box<Account<OrderDetailAccount>> = &ctx.accounts.order_detail;
+
+    // Checks
+    check_payment_receiver(sale_phase_detail.payment_receiver, ctx.accounts.payment_receiver.key())?;
+
+    check_tier_id(order_detail.tier_id, tier_id_int)?;
+
+    check_auction_order_settled(order_detail.is_auction_settled)?;
+
+    check_auction_ended(sale_phase_tier_detail.is_auction_ended(timestamp))?;
+
+    let is_soft_cap_missed: bool = sale_phase_detail.is_soft_cap_missed(timestamp);
+
+    check_soft_cap_reached(sale_phase_detail.is_soft_cap_reached() || is_soft_cap_missed)?;
+
+    let clearing_price_in_usd: u64 = sale_phase_tier_detail.auction_clearing_price();
+
+    let escrow_amount: u64 = order_detail.auction_escrow_amount;
+    // the whole escrow is refunded when the phase missed its soft cap
+    let rebate_amount: u64 = if is_soft_cap_missed { escrow_amount } else {
+        get_auction_rebate_amount(escrow_amount,
+                                  order_detail.total_payment_in_usd,
+                                  sale_phase_tier_detail.auction_floor_price.mul(order_detail.quantity),
+                                  clearing_price_in_usd.mul(order_detail.quantity))?
+    };
+    let payment_receiver_amount: u64 = escrow_amount.sub(rebate_amount);
+
+    match order_detail.payment_token_mint_account {
+        None => {
+            // the sol escrow is held as lamports of the sale phase tier detail
+            let sale_phase_tier_detail_info: AccountInfo = sale_phase_tier_detail.to_account_info();
+
+            **sale_phase_tier_detail_info.try_borrow_mut_lamports()? -= escrow_amount;
+            **ctx.accounts.user.try_borrow_mut_lamports()? += rebate_amount;
+            **ctx.accounts.payment_receiver.try_borrow_mut_lamports()? += payment_receiver_amount;
+        }
+        Some(payment_token_mint) => {
+            let payment_token_mint_account = &ctx.remaining_accounts[0];
+            let payment_token_program = &ctx.remaining_accounts[1];
+            let auction_escrow = &ctx.remaining_accounts[2];
+            let payment_token_user_token_account = &ctx.remaining_accounts[3];
+            let payment_token_payment_receiver_token_account = &ctx.remaining_accounts[4];
+
+            check_payment_token_mint_account(payment_token_mint, payment_token_mint_account.key())?;
+
+            check_payment_token_program(payment_token_program.key(), *payment_token_mint_account.owner, *auction_escrow.owner)?;
+
+            let auction_escrow_token: TokenAccount = TokenAccount::try_deserialize(&mut &auction_escrow.try_borrow_data()?[..])?;
+            check_auction_escrow(auction_escrow_token.owner, sale_phase_tier_detail.key(), auction_escrow_token.mint, payment_token_mint)?;
+
+            let user_token: TokenAccount = TokenAccount::try_deserialize(&mut &payment_token_user_token_account.try_borrow_data()?[..])?;
+            check_user_token_account(ctx.accounts.user.key(), user_token.owner)?;
+
+            let payment_receiver_token: TokenAccount = TokenAccount::try_deserialize(&mut &payment_token_payment_receiver_token_account.try_borrow_data()?[..])?;
+            check_payment_receiver(sale_phase_detail.payment_receiver, payment_receiver_token.owner)?;
+
+            let decimals: u8 = Mint::try_deserialize(&mut &payment_token_mint_account.try_borrow_data()?[..])?.decimals;
+
+            let sale_phase_detail_key: Pubkey = sale_phase_detail.key();
+
+            let signer_seeds = &[
+                SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+                sale_phase_detail_key.as_ref(),
+                tier_id.as_ref(),
+                &[_sale_phase_tier_detail_bump],
+            ];
+
+            let signer = &[&signer_seeds[..]];
+
+            if rebate_amount > 0 {
+                let cpi_accounts = TransferChecked {
+                    from: auction_escrow.to_account_info(),
+                    mint: payment_token_mint_account.to_account_info(),
+                    to: payment_token_user_token_account.to_account_info(),
+                    authority: sale_phase_tier_detail.to_account_info(),
+                };
+                let cpi_program = payment_token_program.to_account_info();
+                let cpi_context = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+                transfer_checked(cpi_context, rebate_amount, decimals)?;
+            }
+
+            if payment_receiver_amount > 0 {
+                let cpi_accounts = TransferChecked {
+                    from: auction_escrow.to_account_info(),
+                    mint: payment_token_mint_account.to_account_info(),
+                    to: payment_token_payment_receiver_token_account.to_account_info(),
+                    authority: sale_phase_tier_detail.to_account_info(),
+                };
+                let cpi_program = payment_token_program.to_account_info();
+                let cpi_context = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+                transfer_checked(cpi_context, payment_receiver_amount, decimals)?;
+            }
+        }
+    }
+
+    // Update
+    let order_detail: &mut Box<Account<OrderDetailAccount>> = &mut ctx.accounts.order_detail;
+    order_detail.is_auction_settled = true;
+    order_detail.last_block_timestamp = timestamp;
+
+    // Event
+    let event: SettleAuctionOrderEvent = SettleAuctionOrderEvent {
+        timestamp,
+        sale_phase_name,
+        tier_id,
+        order_id,
+        user: ctx.accounts.user.key(),
+        payment_receiver: ctx.accounts.payment_receiver.key(),
+        payment_token_mint_account: order_detail.payment_token_mint_account,
+        clearing_price_in_usd,
+        escrow_amount,
+        rebate_amount,
+        payment_receiver_amount,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
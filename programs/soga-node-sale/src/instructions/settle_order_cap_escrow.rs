@@ -0,0 +1,229 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    token_interface::{Mint, TokenAccount, TransferChecked, transfer_checked},
+};
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    USER_DETAIL_ACCOUNT_PREFIX,
+    UserDetailAccount,
+    ORDER_DETAIL_ACCOUNT_PREFIX,
+    OrderDetailAccount,
+};
+
+use crate::events::{
+    SettleOrderCapEscrowEvent
+};
+
+use crate::error::SogaNodeSaleError;
+
+use crate::utils::{check_payment_receiver, check_payment_token_mint_account, check_user_token_account, check_cap_escrow, check_cap_escrow_settled, check_cap_escrow_empty, check_soft_cap_reached, check_installment_paid, check_order_token_ids_minted, check_payment_token_program, check_referral_receiver};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, _user_detail_bump: u8, _order_detail_bump: u8, sale_phase_name: String, order_id: String)]
+pub struct SettleOrderCapEscrowInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: user
+    #[account(mut)]
+    pub user: AccountInfo<'info>,
+
+    /// CHECK: payment receiver, checked against the sale phase detail
+    #[account(mut)]
+    pub payment_receiver: AccountInfo<'info>,
+
+    #[account(
+    mut,
+    seeds = [
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_name.as_ref(),
+    ],
+    bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+    seeds = [
+    USER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    user.key().as_ref(),
+    ],
+    bump = _user_detail_bump,
+    )]
+    pub user_detail: Box<Account<'info, UserDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    ORDER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    user_detail.key().as_ref(),
+    order_id.as_ref(),
+    ],
+    bump = _order_detail_bump,
+    )]
+    pub order_detail: Box<Account<'info, OrderDetailAccount>>,
+
+    /// required when the escrow holds a full discount share and is released
+    /// CHECK: wallet or token account, checked against the order detail
+    #[account(mut)]
+    pub full_discount_receiver: Option<UncheckedAccount<'info>>,
+
+    /// required when the escrow holds a half discount share and is released
+    /// CHECK: wallet or token account, checked against the order detail
+    #[account(mut)]
+    pub half_discount_receiver: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Releases the cap escrow of an order to the payment receiver and the referral receivers
+/// once the soft cap is reached, or refunds it to the user when the soft cap is missed.
+///
+/// Remaining accounts are `[payment_token_mint_account, payment_token_program, cap_escrow,
+/// payment_token_receiver_token_account]` when the order was paid with a payment token.
+pub fn handle_settle_order_cap_escrow<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, SettleOrderCapEscrowInputAccounts<'info>>,
+                                                         _sale_phase_detail_bump: u8, _user_detail_bump: u8, _order_detail_bump: u8,
+                                                         sale_phase_name: String, order_id: String,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+    let order_detail: &Box<Account<OrderDetailAccount>> = &ctx.accounts.order_detail;
+
+    // Checks
+    check_payment_receiver(sale_phase_detail.payment_receiver, ctx.accounts.payment_receiver.key())?;
+
+    check_cap_escrow_settled(order_detail.is_cap_settled)?;
+
+    check_cap_escrow_empty(order_detail.cap_escrow_amount)?;
+
+    let is_soft_cap_missed: bool = sale_phase_detail.is_soft_cap_missed(timestamp);
+
+    check_soft_cap_reached(sale_phase_detail.is_soft_cap_reached() || is_soft_cap_missed)?;
+
+    // installment payments are released once the order is paid in full, or refunded with the soft cap
+    if !is_soft_cap_missed {
+        check_installment_paid(order_detail.is_installment_paid())?;
+    } else {
+        // a minted node is kept by its holder, its payment is not refunded
+        check_order_token_ids_minted(order_detail.is_token_ids_minted.contains(&true))?;
+    }
+
+    // refunded to the user when the soft cap is missed, released to the payment receiver and the referral receivers otherwise
+    let escrow_amount: u64 = order_detail.cap_escrow_amount;
+
+    let full_discount_amount: u64 = if is_soft_cap_missed { 0 } else { order_detail.full_discount_escrow_amount };
+    let half_discount_amount: u64 = if is_soft_cap_missed { 0 } else { order_detail.half_discount_escrow_amount };
+    let receiver_amount: u64 = escrow_amount - full_discount_amount - half_discount_amount;
+
+    let full_discount_receiver: Option<&AccountInfo> = if full_discount_amount > 0 {
+        let full_discount_receiver = ctx.accounts.full_discount_receiver.as_deref().ok_or(SogaNodeSaleError::InvalidReferralReceiver)?;
+        check_referral_receiver(order_detail.full_discount_receiver, full_discount_receiver.key())?;
+        Some(full_discount_receiver)
+    } else { None };
+
+    let half_discount_receiver: Option<&AccountInfo> = if half_discount_amount > 0 {
+        let half_discount_receiver = ctx.accounts.half_discount_receiver.as_deref().ok_or(SogaNodeSaleError::InvalidReferralReceiver)?;
+        check_referral_receiver(order_detail.half_discount_receiver, half_discount_receiver.key())?;
+        Some(half_discount_receiver)
+    } else { None };
+
+    match order_detail.payment_token_mint_account {
+        None => {
+            // the sol escrow is held as lamports of the sale phase detail
+            let sale_phase_detail_info: AccountInfo = sale_phase_detail.to_account_info();
+            let receiver: &AccountInfo = if is_soft_cap_missed { &ctx.accounts.user } else { &ctx.accounts.payment_receiver };
+
+            **sale_phase_detail_info.try_borrow_mut_lamports()? -= escrow_amount;
+            **receiver.try_borrow_mut_lamports()? += receiver_amount;
+
+            if let Some(full_discount_receiver) = full_discount_receiver {
+                **full_discount_receiver.try_borrow_mut_lamports()? += full_discount_amount;
+            }
+
+            if let Some(half_discount_receiver) = half_discount_receiver {
+                **half_discount_receiver.try_borrow_mut_lamports()? += half_discount_amount;
+            }
+        }
+        Some(payment_token_mint) => {
+            let payment_token_mint_account = &ctx.remaining_accounts[0];
+            let payment_token_program = &ctx.remaining_accounts[1];
+            let cap_escrow = &ctx.remaining_accounts[2];
+            let payment_token_receiver_token_account = &ctx.remaining_accounts[3];
+
+            check_payment_token_mint_account(payment_token_mint, payment_token_mint_account.key())?;
+
+            check_payment_token_program(payment_token_program.key(), *payment_token_mint_account.owner, *cap_escrow.owner)?;
+
+            let cap_escrow_token: TokenAccount = TokenAccount::try_deserialize(&mut &cap_escrow.try_borrow_data()?[..])?;
+            check_cap_escrow(cap_escrow_token.owner, sale_phase_detail.key(), cap_escrow_token.mint, payment_token_mint)?;
+
+            let receiver_token: TokenAccount = TokenAccount::try_deserialize(&mut &payment_token_receiver_token_account.try_borrow_data()?[..])?;
+
+            if is_soft_cap_missed {
+                check_user_token_account(ctx.accounts.user.key(), receiver_token.owner)?;
+            } else {
+                check_payment_receiver(sale_phase_detail.payment_receiver, receiver_token.owner)?;
+            }
+
+            let decimals: u8 = Mint::try_deserialize(&mut &payment_token_mint_account.try_borrow_data()?[..])?.decimals;
+
+            let signer_seeds: &[&[u8]] = &[
+                SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_bytes(),
+                sale_phase_name.as_bytes(),
+                &[_sale_phase_detail_bump],
+            ];
+
+            let signer = &[signer_seeds];
+
+            // the referral receivers are only resolved when their share is released
+            let transfers = [
+                (Some(payment_token_receiver_token_account), receiver_amount),
+                (full_discount_receiver, full_discount_amount),
+                (half_discount_receiver, half_discount_amount),
+            ];
+
+            for (receiver_token_account, amount) in transfers {
+                if let Some(receiver_token_account) = receiver_token_account.filter(|_| amount > 0) {
+                    let cpi_accounts = TransferChecked {
+                        from: cap_escrow.to_account_info(),
+                        mint: payment_token_mint_account.to_account_info(),
+                        to: receiver_token_account.to_account_info(),
+                        authority: sale_phase_detail.to_account_info(),
+                    };
+                    let cpi_program = payment_token_program.to_account_info();
+                    let cpi_context = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+                    transfer_checked(cpi_context, amount, decimals)?;
+                }
+            }
+        }
+    }
+
+    // Update
+    let order_detail: &mut Box<Account<OrderDetailAccount>> = &mut ctx.accounts.order_detail;
+    order_detail.last_block_timestamp = timestamp;
+    order_detail.is_cap_settled = true;
+    order_detail.is_voided = is_soft_cap_missed;
+
+    // Event
+    let event: SettleOrderCapEscrowEvent = SettleOrderCapEscrowEvent {
+        timestamp,
+        sale_phase_name,
+        order_id,
+        user: ctx.accounts.user.key(),
+        payment_receiver: ctx.accounts.payment_receiver.key(),
+        payment_token_mint_account: order_detail.payment_token_mint_account,
+        escrow_amount,
+        full_discount_amount,
+        half_discount_amount,
+        is_refunded: is_soft_cap_missed,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
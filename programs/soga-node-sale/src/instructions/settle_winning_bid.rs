@@ -0,0 +1,249 @@
+use std::ops::Sub;
+use anchor_lang::prelude::*;
+
+use crate::oracle::{get_quorum_oracle_price, OraclePrice};
+
+use crate::pricing::{token_amount_to_usd, SOL_DECIMALS};
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+    USER_DETAIL_ACCOUNT_PREFIX,
+    UserDetailAccount,
+    USER_TIER_DETAIL_ACCOUNT_PREFIX,
+    UserTierDetailAccount,
+    ORDER_DETAIL_ACCOUNT_PREFIX,
+    OrderDetailAccount,
+    BID_DETAIL_ACCOUNT_PREFIX,
+    BidDetailAccount,
+};
+
+use crate::events::{
+    SettleWinningBidEvent
+};
+
+use crate::utils::{check_payment_receiver, check_order_id, check_reveal_ended, check_bid_settled, check_bid_winning, check_price_feed, check_hard_cap};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8,
+sale_phase_name: String, tier_id: String, order_id: String)]
+pub struct SettleWinningBidInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: user
+    #[account(mut)]
+    pub user: AccountInfo<'info>,
+
+    /// CHECK: payment receiver, checked against the sale phase detail
+    #[account(mut)]
+    pub payment_receiver: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+        SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_name.as_ref(),
+        ],
+        bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+        SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail.key().as_ref(),
+        tier_id.as_ref()
+        ],
+        bump = _sale_phase_tier_detail_bump,
+    )]
+    pub sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+        BID_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_tier_detail.key().as_ref(),
+        user.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub bid_detail: Box<Account<'info, BidDetailAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserDetailAccount::space(),
+        seeds = [
+        USER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail.key().as_ref(),
+        user.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub user_detail: Box<Account<'info, UserDetailAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserTierDetailAccount::space(),
+        seeds = [
+        USER_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        user_detail.key().as_ref(),
+        sale_phase_tier_detail.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub user_tier_detail: Box<Account<'info, UserTierDetailAccount>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = OrderDetailAccount::space(1),
+        seeds = [
+        ORDER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail.key().as_ref(),
+        user_detail.key().as_ref(),
+        order_id.as_ref(),
+        ],
+        bump,
+    )]
+    pub order_detail: Box<Account<'info, OrderDetailAccount>>,
+
+    /// CHECK: price feed, owner and layout checked by the oracle source
+    pub price_update: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Settles a winning sealed bid as an order. Bids are priced in lamports, the usd value
+/// counted toward the phase caps is read from the price feed, remaining accounts are the
+/// registered price sources of the phase.
+pub fn handle_settle_winning_bid<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, SettleWinningBidInputAccounts<'info>>,
+                                                    _sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8,
+                                                    sale_phase_name: String, tier_id: String, order_id: String,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let order_id_int: u64 = order_id.clone().parse().unwrap();
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+    let sale_phase_tier_detail: &Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &ctx.accounts.sale_phase_tier_detail;
+    let bid_detail: &Box<Account<BidDetailAccount>> = &ctx.accounts.bid_detail;
+    let user_detail: &Box<Account<UserDetailAccount>> = &ctx.accounts.user_detail;
+
+    // Checks
+    check_payment_receiver(sale_phase_detail.payment_receiver, ctx.accounts.payment_receiver.key())?;
+
+    check_price_feed(sale_phase_detail.price_feed_address, ctx.accounts.price_update.key())?;
+
+    check_reveal_ended(sale_phase_tier_detail.reveal_end_timestamp, timestamp)?;
+
+    check_bid_settled(bid_detail.is_settled)?;
+
+    check_bid_winning(bid_detail.is_revealed && sale_phase_tier_detail.is_winning_bid(&bid_detail.user))?;
+
+    check_order_id(user_detail.total_orders + 1, order_id_int)?;
+
+    // Make Payment
+    let price: u64 = if sale_phase_tier_detail.is_uniform_price { sale_phase_tier_detail.lowest_winning_bid() } else { bid_detail.price };
+    let refund_amount: u64 = bid_detail.deposit.sub(price);
+
+    let oracle_price: OraclePrice = get_quorum_oracle_price(&ctx.accounts.price_update, ctx.remaining_accounts, sale_phase_detail.price_feed_id.as_str(),
+                                                            &sale_phase_detail.oracle_config, &sale_phase_detail.price_sources, sale_phase_detail.price_source_quorum)?;
+
+    let price_in_usd: u64 = token_amount_to_usd(price, SOL_DECIMALS, oracle_price.price as u64, oracle_price.exponent)?;
+
+    check_hard_cap(sale_phase_detail.committed_payment() + price_in_usd, sale_phase_detail.hard_cap)?;
+
+    // the phase account holds the payment until the soft cap is reached
+    let cap_escrow_amount: u64 = if sale_phase_detail.is_soft_cap_reached() { 0 } else { price };
+
+    // the deposit is held as lamports of the sale phase tier detail
+    let sale_phase_tier_detail_info: AccountInfo = sale_phase_tier_detail.to_account_info();
+    let deposit_receiver: AccountInfo = if cap_escrow_amount > 0 { sale_phase_detail.to_account_info() } else { ctx.accounts.payment_receiver.to_account_info() };
+
+    **sale_phase_tier_detail_info.try_borrow_mut_lamports()? -= bid_detail.deposit;
+    **deposit_receiver.try_borrow_mut_lamports()? += price;
+    **ctx.accounts.user.try_borrow_mut_lamports()? += refund_amount;
+
+    // Update
+    let bid_price: u64 = bid_detail.price;
+
+    let order_detail: &mut Box<Account<OrderDetailAccount>> = &mut ctx.accounts.order_detail;
+    order_detail.last_block_timestamp = timestamp;
+    order_detail.tier_id = tier_id.clone().parse().unwrap();
+    order_detail.is_completed = false;
+    order_detail.quantity = 1;
+    order_detail.total_payment_in_usd = price_in_usd;
+    order_detail.total_user_discount_in_usd = price_in_usd;
+    order_detail.total_discount_in_usd = 0;
+    order_detail.total_payment = price;
+    order_detail.total_user_discount = price;
+    order_detail.total_discount = 0;
+    order_detail.payment_token_mint_account = None;
+    order_detail.token_ids = vec![ctx.accounts.sale_phase_tier_detail.total_mint + 1];
+    order_detail.is_token_ids_minted = vec![false];
+    order_detail.is_token_ids_revoked = vec![false];
+    order_detail.is_token_ids_upgraded = vec![false];
+    order_detail.is_token_ids_reissued = vec![false];
+    order_detail.cap_escrow_amount = cap_escrow_amount;
+
+    let bid_detail: &mut Box<Account<BidDetailAccount>> = &mut ctx.accounts.bid_detail;
+    bid_detail.last_block_timestamp = timestamp;
+    bid_detail.is_settled = true;
+    bid_detail.order_id = order_id_int;
+
+    let sale_phase_detail: &mut Box<Account<SogaNodeSalePhaseDetailAccount>> = &mut ctx.accounts.sale_phase_detail;
+    sale_phase_detail.total_mint += 1;
+    sale_phase_detail.total_buy += 1;
+    sale_phase_detail.total_payment += price_in_usd;
+    sale_phase_detail.latch_soft_cap();
+    sale_phase_detail.last_block_timestamp = timestamp;
+
+    let sale_phase_tier_detail: &mut Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &mut ctx.accounts.sale_phase_tier_detail;
+    sale_phase_tier_detail.total_mint += 1;
+    sale_phase_tier_detail.total_buy += 1;
+    sale_phase_tier_detail.total_payment += price_in_usd;
+    sale_phase_tier_detail.last_block_timestamp = timestamp;
+
+    if !sale_phase_tier_detail.is_completed && sale_phase_tier_detail.minted_supply() >= sale_phase_tier_detail.public_quantity() {
+        sale_phase_tier_detail.is_completed = true;
+        sale_phase_detail.total_completed_tiers += 1;
+    }
+
+    let user_detail: &mut Box<Account<UserDetailAccount>> = &mut ctx.accounts.user_detail;
+    user_detail.total_mint += 1;
+    user_detail.total_buy += 1;
+    user_detail.total_payment += price_in_usd;
+    user_detail.total_orders += 1;
+    user_detail.last_block_timestamp = timestamp;
+
+    let user_tier_detail: &mut Box<Account<UserTierDetailAccount>> = &mut ctx.accounts.user_tier_detail;
+    user_tier_detail.total_mint += 1;
+    user_tier_detail.total_buy += 1;
+    user_tier_detail.total_payment += price_in_usd;
+    user_tier_detail.last_block_timestamp = timestamp;
+
+    // Event
+    let event: SettleWinningBidEvent = SettleWinningBidEvent {
+        timestamp,
+        sale_phase_name,
+        tier_id,
+        order_id,
+        user: ctx.accounts.user.key(),
+        payment_receiver: ctx.accounts.payment_receiver.key(),
+        bid_price,
+        price,
+        price_in_usd,
+        cap_escrow_amount,
+        refund_amount,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, ThawAccount, thaw_account};
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+    COLLECTION_ACCOUNT_PREFIX,
+    NODE_ACCOUNT_PREFIX,
+    NodeMintMode,
+};
+
+use crate::events::{ThawNodeEvent};
+
+use crate::utils::{
+    check_mint_mode,
+    check_node_is_locked,
+    get_unlock_timestamp,
+};
+
+/// Permissionless, thaws a holder node token account once the tier is unlocked.
+/// Only token-2022 nodes can be thawed, the master edition of a metaplex node takes over
+/// the freeze authority of its mint. Metaplex nodes filled before the unlock stay frozen,
+/// so metaplex tiers have to be unlocked before their orders are filled.
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, _collection_mint_account_bump: u8,
+sale_phase_name: String, tier_id: String, token_id: String)]
+pub struct ThawNodeInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: user
+    pub user: AccountInfo<'info>,
+
+    #[account(
+    seeds = [
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_name.as_ref(),
+    ],
+    bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+    seeds = [
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    tier_id.as_ref()
+    ],
+    bump = _sale_phase_tier_detail_bump,
+    )]
+    pub sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+
+    /// CHECK: collection mint of the tier, only used as seed
+    #[account(
+    seeds = [
+    COLLECTION_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_tier_detail.key().as_ref(),
+    ],
+    bump = _collection_mint_account_bump,
+    )]
+    pub collection_mint_account: UncheckedAccount<'info>,
+
+    #[account(
+    seeds = [
+    NODE_ACCOUNT_PREFIX.as_ref(),
+    collection_mint_account.key().as_ref(),
+    token_id.as_ref()
+    ],
+    bump,
+    mint::freeze_authority = sale_phase_tier_detail.key(),
+    mint::token_program = token_program,
+    )]
+    pub node_mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+    mut,
+    associated_token::mint = node_mint_account,
+    associated_token::authority = user,
+    associated_token::token_program = token_program,
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handle_thaw_node(ctx: Context<ThawNodeInputAccounts>,
+                        _sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, _collection_mint_account_bump: u8,
+                        sale_phase_name: String, tier_id: String, token_id: String,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+    let sale_phase_tier_detail: &Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &ctx.accounts.sale_phase_tier_detail;
+
+    let unlock_timestamp: i64 = get_unlock_timestamp(sale_phase_detail.unlock_timestamp, sale_phase_tier_detail.unlock_timestamp);
+
+    // Checks
+    check_mint_mode(sale_phase_detail.mint_mode, NodeMintMode::Token2022)?;
+
+    check_node_is_locked(sale_phase_tier_detail.is_unlocked, unlock_timestamp, timestamp)?;
+
+    let sale_phase_detail_key: Pubkey = ctx.accounts.sale_phase_detail.key();
+
+    let signer_seeds = &[
+        SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail_key.as_ref(),
+        tier_id.as_ref(),
+        &[_sale_phase_tier_detail_bump],
+    ];
+
+    let signer = &[&signer_seeds[..]];
+
+    // thaw user token account
+    let thaw_account_cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        ThawAccount {
+            account: ctx.accounts.user_token_account.to_account_info(),
+            mint: ctx.accounts.node_mint_account.to_account_info(),
+            authority: ctx.accounts.sale_phase_tier_detail.to_account_info(),
+        },
+        signer,
+    );
+
+    thaw_account(thaw_account_cpi_context)?;
+
+    // Event
+    let event: ThawNodeEvent = ThawNodeEvent {
+        timestamp,
+        sale_phase_name,
+        tier_id,
+        token_id,
+        user: ctx.accounts.user.key(),
+        node_mint_account: ctx.accounts.node_mint_account.key(),
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{
+    ORACLE_PRICE_ACCOUNT_PREFIX,
+    OraclePriceAccount,
+};
+
+use crate::events::{
+    UpdateOraclePriceEvent
+};
+
+use crate::utils::{check_signing_authority};
+
+#[derive(Accounts)]
+#[instruction(_oracle_price_bump: u8, name: String)]
+pub struct UpdateOraclePriceInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub signing_authority: Signer<'info>,
+
+    #[account(
+    mut,
+    seeds = [
+    ORACLE_PRICE_ACCOUNT_PREFIX.as_ref(),
+    name.as_ref(),
+    ],
+    bump = _oracle_price_bump,
+    )]
+    pub oracle_price: Box<Account<'info, OraclePriceAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_update_oracle_price(ctx: Context<UpdateOraclePriceInputAccounts>,
+                                  _oracle_price_bump: u8, name: String, price: i64, conf: u64, exponent: i32,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    // Checks
+    check_signing_authority(ctx.accounts.oracle_price.authority, ctx.accounts.signing_authority.key())?;
+
+    // Update
+    let oracle_price: &mut Box<Account<OraclePriceAccount>> = &mut ctx.accounts.oracle_price;
+    oracle_price.last_block_timestamp = timestamp;
+    oracle_price.price = price;
+    oracle_price.conf = conf;
+    oracle_price.exponent = exponent;
+    oracle_price.publish_time = timestamp;
+
+    // Event
+    let event: UpdateOraclePriceEvent = UpdateOraclePriceEvent {
+        timestamp,
+        name,
+        oracle_price: ctx.accounts.oracle_price.key(),
+        price,
+        conf,
+        exponent,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
@@ -1,5 +1,4 @@
 use anchor_lang::prelude::*;
-use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 
 use crate::states::{
     SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
@@ -20,7 +19,8 @@ pub struct UpdateSalePhaseInputAccounts<'info> {
 
     pub signing_authority: Signer<'info>,
 
-    pub price_feed: Account<'info, PriceUpdateV2>,
+    /// CHECK: price feed, owner and layout checked by the oracle source when a price is read
+    pub price_feed: UncheckedAccount<'info>,
 
     /// CHECK: payment receiver
     pub payment_receiver: AccountInfo<'info>,
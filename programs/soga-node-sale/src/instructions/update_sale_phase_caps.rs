@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+};
+
+use crate::events::{
+    UpdateSalePhaseCapsEvent
+};
+
+use crate::utils::{check_signing_authority, check_phase_caps, check_phase_caps_locked};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, sale_phase_name: String)]
+pub struct UpdateSalePhaseCapsInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub signing_authority: Signer<'info>,
+
+    #[account(
+    mut,
+    seeds = [
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_name.as_ref(),
+    ],
+    bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_update_sale_phase_caps(ctx: Context<UpdateSalePhaseCapsInputAccounts>,
+                                     _sale_phase_detail_bump: u8, sale_phase_name: String, soft_cap: u64, hard_cap: u64, start_timestamp: i64, end_timestamp: i64,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+
+    // Checks
+    check_signing_authority(sale_phase_detail.signing_authority, ctx.accounts.signing_authority.key())?;
+
+    check_phase_caps(soft_cap, hard_cap, start_timestamp, end_timestamp)?;
+
+    // escrowed orders are settled against the caps they were placed under
+    check_phase_caps_locked(sale_phase_detail.committed_payment() > 0 || sale_phase_detail.is_ended(timestamp))?;
+
+    // Update
+    let sale_phase_detail: &mut Box<Account<SogaNodeSalePhaseDetailAccount>> = &mut ctx.accounts.sale_phase_detail;
+    sale_phase_detail.last_block_timestamp = timestamp;
+    sale_phase_detail.soft_cap = soft_cap;
+    sale_phase_detail.hard_cap = hard_cap;
+    sale_phase_detail.start_timestamp = start_timestamp;
+    sale_phase_detail.end_timestamp = end_timestamp;
+
+    // Event
+    let event: UpdateSalePhaseCapsEvent = UpdateSalePhaseCapsEvent {
+        timestamp,
+        sale_phase_name,
+        soft_cap,
+        hard_cap,
+        start_timestamp,
+        end_timestamp,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    FILL_FEE_VAULT_ACCOUNT_PREFIX,
+    FillFeeVaultAccount,
+};
+
+use crate::events::{
+    UpdateSalePhaseFillRewardEvent
+};
+
+use crate::utils::{check_signing_authority};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, sale_phase_name: String)]
+pub struct UpdateSalePhaseFillRewardInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub signing_authority: Signer<'info>,
+
+    #[account(
+    mut,
+    seeds = [
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_name.as_ref(),
+    ],
+    bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+    init_if_needed,
+    payer = payer,
+    space = FillFeeVaultAccount::space(),
+    seeds = [
+    FILL_FEE_VAULT_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    ],
+    bump,
+    )]
+    pub fill_fee_vault: Box<Account<'info, FillFeeVaultAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_update_sale_phase_fill_reward(ctx: Context<UpdateSalePhaseFillRewardInputAccounts>,
+                                            _sale_phase_detail_bump: u8, sale_phase_name: String, fill_reward: u64,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+
+    // Checks
+    check_signing_authority(sale_phase_detail.signing_authority, ctx.accounts.signing_authority.key())?;
+
+    // Update
+    let sale_phase_detail: &mut Box<Account<SogaNodeSalePhaseDetailAccount>> = &mut ctx.accounts.sale_phase_detail;
+    sale_phase_detail.last_block_timestamp = timestamp;
+    sale_phase_detail.fill_reward = fill_reward;
+
+    let fill_fee_vault: &mut Box<Account<FillFeeVaultAccount>> = &mut ctx.accounts.fill_fee_vault;
+    fill_fee_vault.last_block_timestamp = timestamp;
+
+    // Event
+    let event: UpdateSalePhaseFillRewardEvent = UpdateSalePhaseFillRewardEvent {
+        timestamp,
+        sale_phase_name,
+        fill_reward,
+        fill_fee_vault: ctx.accounts.fill_fee_vault.key(),
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
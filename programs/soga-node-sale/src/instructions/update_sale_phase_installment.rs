@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+};
+
+use crate::events::{
+    UpdateSalePhaseInstallmentEvent
+};
+
+use crate::utils::{check_signing_authority, check_installment_config};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, sale_phase_name: String)]
+pub struct UpdateSalePhaseInstallmentInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub signing_authority: Signer<'info>,
+
+    #[account(
+    mut,
+    seeds = [
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_name.as_ref(),
+    ],
+    bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_update_sale_phase_installment(ctx: Context<UpdateSalePhaseInstallmentInputAccounts>,
+                                            _sale_phase_detail_bump: u8, sale_phase_name: String, installment_deposit_bps: u16,
+                                            installment_period: i64, is_installment_deposit_refundable: bool,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+
+    // Checks
+    check_signing_authority(sale_phase_detail.signing_authority, ctx.accounts.signing_authority.key())?;
+
+    check_installment_config(installment_deposit_bps, installment_period)?;
+
+    // Update
+    let sale_phase_detail: &mut Box<Account<SogaNodeSalePhaseDetailAccount>> = &mut ctx.accounts.sale_phase_detail;
+    sale_phase_detail.last_block_timestamp = timestamp;
+    sale_phase_detail.installment_deposit_bps = installment_deposit_bps;
+    sale_phase_detail.installment_period = installment_period;
+    sale_phase_detail.is_installment_deposit_refundable = is_installment_deposit_refundable;
+
+    // Event
+    let event: UpdateSalePhaseInstallmentEvent = UpdateSalePhaseInstallmentEvent {
+        timestamp,
+        sale_phase_name,
+        installment_deposit_bps,
+        installment_period,
+        is_installment_deposit_refundable,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
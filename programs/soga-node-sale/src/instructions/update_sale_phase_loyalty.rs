@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+};
+
+use crate::events::{
+    UpdateSalePhaseLoyaltyEvent
+};
+
+use crate::error::SogaNodeSaleError;
+
+use crate::utils::{check_signing_authority, check_loyalty_config, check_loyalty_account_owner, check_loyalty_phase, check_loyalty_tier};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, sale_phase_name: String)]
+pub struct UpdateSalePhaseLoyaltyInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub signing_authority: Signer<'info>,
+
+    #[account(
+    mut,
+    seeds = [
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_name.as_ref(),
+    ],
+    bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Sets the loyalty discount of the phase. Remaining accounts are `[sale_phase_detail,
+/// sale_phase_tier_detail]` of each of `loyalty_tier_ids`, tiers of phases starting
+/// before this one whose node holders get the discount. Phases are ordered by fields added
+/// after their layout, so a phase created before them has to be grown with `realloc_account`
+/// first, only the collection of its tiers is read.
+pub fn handle_update_sale_phase_loyalty(ctx: Context<UpdateSalePhaseLoyaltyInputAccounts>,
+                                        _sale_phase_detail_bump: u8, sale_phase_name: String, loyalty_discount: u16,
+                                        loyalty_tier_ids: Vec<String>,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+
+    // Checks
+    check_signing_authority(sale_phase_detail.signing_authority, ctx.accounts.signing_authority.key())?;
+
+    check_loyalty_config(loyalty_discount, loyalty_tier_ids.len(), ctx.remaining_accounts.len())?;
+
+    let mut loyalty_collections: Vec<Pubkey> = Vec::with_capacity(loyalty_tier_ids.len());
+
+    for (loyalty_accounts, tier_id) in ctx.remaining_accounts.chunks(2).zip(loyalty_tier_ids.iter()) {
+        let loyalty_sale_phase_detail_info = &loyalty_accounts[0];
+        let sale_phase_tier_detail_info = &loyalty_accounts[1];
+
+        check_loyalty_account_owner(*loyalty_sale_phase_detail_info.owner, *ctx.program_id)?;
+        check_loyalty_account_owner(*sale_phase_tier_detail_info.owner, *ctx.program_id)?;
+
+        // the discriminators are checked on deserialization
+        let loyalty_sale_phase_detail = SogaNodeSalePhaseDetailAccount::try_deserialize(&mut &loyalty_sale_phase_detail_info.try_borrow_data()?[..])?;
+
+        check_loyalty_phase(sale_phase_detail.key(), loyalty_sale_phase_detail_info.key(), sale_phase_detail.sale_start_timestamp(), loyalty_sale_phase_detail.sale_start_timestamp())?;
+
+        let (sale_phase_tier_detail_address, _sale_phase_tier_detail_bump) = Pubkey::find_program_address(
+            &[
+                SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+                loyalty_sale_phase_detail_info.key().as_ref(),
+                tier_id.as_ref(),
+            ],
+            ctx.program_id,
+        );
+
+        check_loyalty_tier(sale_phase_tier_detail_address, sale_phase_tier_detail_info.key())?;
+
+        loyalty_collections.push(get_collection_mint_address(&sale_phase_tier_detail_info.try_borrow_data()?)?);
+    }
+
+    // Update
+    let sale_phase_detail: &mut Box<Account<SogaNodeSalePhaseDetailAccount>> = &mut ctx.accounts.sale_phase_detail;
+    sale_phase_detail.last_block_timestamp = timestamp;
+    sale_phase_detail.loyalty_discount = loyalty_discount;
+    sale_phase_detail.loyalty_collections = loyalty_collections.clone();
+
+    // Event
+    let event: UpdateSalePhaseLoyaltyEvent = UpdateSalePhaseLoyaltyEvent {
+        timestamp,
+        sale_phase_name,
+        loyalty_discount,
+        loyalty_collections,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
+
+/// `collection_mint_address` of a tier of any layout, it follows the discriminator and `last_block_timestamp`.
+fn get_collection_mint_address(data: &[u8]) -> Result<Pubkey> {
+    if data.len() < 8 + 8 + 32 || data[..8] != SogaNodeSalePhaseTierDetailAccount::DISCRIMINATOR {
+        return Err(SogaNodeSaleError::InvalidLoyaltyCollections.into());
+    }
+
+    Ok(Pubkey::try_from(&data[8 + 8..8 + 8 + 32]).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collection_of_a_tier_before_the_current_layout() {
+        let collection_mint_address: Pubkey = Pubkey::new_unique();
+
+        // discriminator, last_block_timestamp and collection_mint_address lead every tier layout
+        let mut data: Vec<u8> = SogaNodeSalePhaseTierDetailAccount::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&1700000000_i64.to_le_bytes());
+        data.extend_from_slice(collection_mint_address.as_ref());
+        data.resize(200, 0);
+
+        assert_eq!(get_collection_mint_address(&data).unwrap(), collection_mint_address);
+    }
+
+    #[test]
+    fn collection_of_another_account_fails() {
+        let mut data: Vec<u8> = SogaNodeSalePhaseDetailAccount::DISCRIMINATOR.to_vec();
+        data.resize(200, 0);
+
+        assert!(get_collection_mint_address(&data).is_err());
+        assert!(get_collection_mint_address(&SogaNodeSalePhaseTierDetailAccount::DISCRIMINATOR).is_err());
+    }
+}
\ No newline at end of file
@@ -3,7 +3,6 @@ use anchor_lang::prelude::*;
 use anchor_spl::{
     token_interface::{Mint, TokenInterface},
 };
-use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 
 use crate::states::{
     SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
@@ -28,7 +27,8 @@ pub struct UpdateSalePhasePaymentTokenInputAccounts<'info> {
 
     pub signing_authority: Signer<'info>,
 
-    pub price_feed: Account<'info, PriceUpdateV2>,
+    /// CHECK: price feed, owner and layout checked by the oracle source when a price is read
+    pub price_feed: UncheckedAccount<'info>,
 
     #[account(
     seeds = [
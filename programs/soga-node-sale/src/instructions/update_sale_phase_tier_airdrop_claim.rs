@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+    AIRDROP_CLAIM_BITMAP_ACCOUNT_PREFIX,
+    AirdropClaimBitmapAccount,
+};
+
+use crate::events::{
+    UpdateSalePhaseTierAirdropClaimEvent
+};
+
+use crate::utils::{check_signing_authority, check_airdrop_claim_started, check_reservation_quantity};
+use crate::error::SogaNodeSaleError;
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, sale_phase_name: String, tier_id: String,
+merkle_root: [u8; 32], total_claims: u32)]
+pub struct UpdateSalePhaseTierAirdropClaimInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub signing_authority: Signer<'info>,
+
+    #[account(
+    seeds = [
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_name.as_ref(),
+    ],
+    bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    tier_id.as_ref()
+    ],
+    bump = _sale_phase_tier_detail_bump,
+    )]
+    pub sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+
+    #[account(
+    init,
+    payer = payer,
+    space = AirdropClaimBitmapAccount::space(total_claims),
+    seeds = [
+    AIRDROP_CLAIM_BITMAP_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_tier_detail.key().as_ref(),
+    merkle_root.as_ref(),
+    ],
+    bump,
+    )]
+    pub airdrop_claim_bitmap: Box<Account<'info, AirdropClaimBitmapAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Posts the airdrop snapshot of a tier, `total_quantity` nodes are held out of the public
+/// supply for the `total_claims` entries. The snapshot can be replaced until the first claim.
+pub fn handle_update_sale_phase_tier_airdrop_claim(ctx: Context<UpdateSalePhaseTierAirdropClaimInputAccounts>,
+                                                   _sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8,
+                                                   sale_phase_name: String, tier_id: String,
+                                                   merkle_root: [u8; 32], total_claims: u32, total_quantity: u64,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+    let sale_phase_tier_detail: &Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &ctx.accounts.sale_phase_tier_detail;
+
+    // Checks
+    check_signing_authority(sale_phase_detail.signing_authority, ctx.accounts.signing_authority.key())?;
+
+    check_airdrop_claim_started(sale_phase_tier_detail.airdrop_claimed_quantity)?;
+
+    let total_reserved: u64 = sale_phase_tier_detail.total_reserved.checked_sub(sale_phase_tier_detail.airdrop_claim_quantity)
+        .and_then(|total_reserved| total_reserved.checked_add(total_quantity))
+        .ok_or(SogaNodeSaleError::MathOverflow)?;
+
+    let reserved_supply: u64 = sale_phase_tier_detail.minted_supply().checked_add(total_reserved)
+        .and_then(|reserved_supply| reserved_supply.checked_sub(sale_phase_tier_detail.total_reserved_claimed))
+        .ok_or(SogaNodeSaleError::MathOverflow)?;
+
+    check_reservation_quantity(reserved_supply, sale_phase_tier_detail.quantity)?;
+
+    // Update
+    let airdrop_claim_bitmap: &mut Box<Account<AirdropClaimBitmapAccount>> = &mut ctx.accounts.airdrop_claim_bitmap;
+    airdrop_claim_bitmap.last_block_timestamp = timestamp;
+    airdrop_claim_bitmap.merkle_root = merkle_root;
+    airdrop_claim_bitmap.total_claims = total_claims;
+    airdrop_claim_bitmap.total_claimed = 0;
+    airdrop_claim_bitmap.claimed = vec![0; AirdropClaimBitmapAccount::bitmap_len(total_claims)];
+
+    let sale_phase_tier_detail: &mut Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &mut ctx.accounts.sale_phase_tier_detail;
+    sale_phase_tier_detail.last_block_timestamp = timestamp;
+    sale_phase_tier_detail.airdrop_merkle_root = merkle_root;
+    sale_phase_tier_detail.airdrop_claim_quantity = total_quantity;
+    sale_phase_tier_detail.total_reserved = total_reserved;
+
+    // Event
+    let event: UpdateSalePhaseTierAirdropClaimEvent = UpdateSalePhaseTierAirdropClaimEvent {
+        timestamp,
+        sale_phase_name,
+        tier_id,
+        merkle_root,
+        total_claims,
+        total_quantity,
+        total_reserved,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
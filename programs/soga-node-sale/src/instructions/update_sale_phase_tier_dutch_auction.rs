@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+    PriceSchedule,
+};
+
+use crate::events::{
+    UpdateSalePhaseTierDutchAuctionEvent
+};
+
+use crate::utils::{check_signing_authority, check_dutch_auction};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, sale_phase_name: String, tier_id: String)]
+pub struct UpdateSalePhaseTierDutchAuctionInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub signing_authority: Signer<'info>,
+
+    #[account(
+    seeds = [
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_name.as_ref(),
+    ],
+    bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    tier_id.as_ref()
+    ],
+    bump = _sale_phase_tier_detail_bump,
+    )]
+    pub sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_update_sale_phase_tier_dutch_auction(ctx: Context<UpdateSalePhaseTierDutchAuctionInputAccounts>,
+                                                   _sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8,
+                                                   sale_phase_name: String, tier_id: String, start_timestamp: i64, end_timestamp: i64,
+                                                   start_price: u64, floor_price: u64, is_rebate: bool,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+
+    // Checks
+    check_signing_authority(sale_phase_detail.signing_authority, ctx.accounts.signing_authority.key())?;
+
+    check_dutch_auction(start_timestamp, end_timestamp, start_price, floor_price)?;
+
+    // Update
+    let sale_phase_tier_detail: &mut Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &mut ctx.accounts.sale_phase_tier_detail;
+    sale_phase_tier_detail.last_block_timestamp = timestamp;
+    sale_phase_tier_detail.price = start_price;
+    sale_phase_tier_detail.price_schedule = PriceSchedule::LinearRamp {
+        start_timestamp,
+        end_timestamp,
+        start_price,
+        end_price: floor_price,
+    };
+    sale_phase_tier_detail.is_auction_rebate = is_rebate;
+    sale_phase_tier_detail.auction_floor_price = floor_price;
+    sale_phase_tier_detail.auction_end_timestamp = end_timestamp;
+    sale_phase_tier_detail.clearing_price = 0;
+
+    // Event
+    let event: UpdateSalePhaseTierDutchAuctionEvent = UpdateSalePhaseTierDutchAuctionEvent {
+        timestamp,
+        sale_phase_name,
+        tier_id,
+        start_timestamp,
+        end_timestamp,
+        start_price,
+        floor_price,
+        is_rebate,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+    AllocationMode,
+};
+
+use crate::events::{
+    UpdateSalePhaseTierFairLaunchEvent
+};
+
+use crate::utils::{check_signing_authority, check_fair_launch, check_fair_launch_not_finalized};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, sale_phase_name: String, tier_id: String)]
+pub struct UpdateSalePhaseTierFairLaunchInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub signing_authority: Signer<'info>,
+
+    #[account(
+    seeds = [
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_name.as_ref(),
+    ],
+    bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    tier_id.as_ref()
+    ],
+    bump = _sale_phase_tier_detail_bump,
+    )]
+    pub sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_update_sale_phase_tier_fair_launch(ctx: Context<UpdateSalePhaseTierFairLaunchInputAccounts>,
+                                                 _sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8,
+                                                 sale_phase_name: String, tier_id: String, is_fair_launch: bool,
+                                                 commit_start_timestamp: i64, commit_end_timestamp: i64, allocation_mode: AllocationMode,
+                                                 allocation_end_timestamp: i64,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+
+    // Checks
+    check_signing_authority(sale_phase_detail.signing_authority, ctx.accounts.signing_authority.key())?;
+
+    check_fair_launch(is_fair_launch, commit_start_timestamp, commit_end_timestamp, allocation_end_timestamp)?;
+
+    check_fair_launch_not_finalized(ctx.accounts.sale_phase_tier_detail.is_fair_launch_finalized)?;
+
+    // Update
+    let sale_phase_tier_detail: &mut Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &mut ctx.accounts.sale_phase_tier_detail;
+    sale_phase_tier_detail.last_block_timestamp = timestamp;
+    sale_phase_tier_detail.is_fair_launch = is_fair_launch;
+    sale_phase_tier_detail.commit_start_timestamp = commit_start_timestamp;
+    sale_phase_tier_detail.commit_end_timestamp = commit_end_timestamp;
+    sale_phase_tier_detail.allocation_mode = allocation_mode;
+    sale_phase_tier_detail.allocation_end_timestamp = allocation_end_timestamp;
+
+    // Event
+    let event: UpdateSalePhaseTierFairLaunchEvent = UpdateSalePhaseTierFairLaunchEvent {
+        timestamp,
+        sale_phase_name,
+        tier_id,
+        is_fair_launch,
+        commit_start_timestamp,
+        commit_end_timestamp,
+        allocation_mode,
+        allocation_end_timestamp,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
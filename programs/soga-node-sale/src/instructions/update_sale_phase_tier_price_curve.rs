@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+    PriceCurve,
+};
+
+use crate::events::{
+    UpdateSalePhaseTierPriceCurveEvent
+};
+
+use crate::utils::{check_signing_authority, check_price_curve};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, sale_phase_name: String, tier_id: String)]
+pub struct UpdateSalePhaseTierPriceCurveInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub signing_authority: Signer<'info>,
+
+    #[account(
+    seeds = [
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_name.as_ref(),
+    ],
+    bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    tier_id.as_ref()
+    ],
+    bump = _sale_phase_tier_detail_bump,
+    )]
+    pub sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_update_sale_phase_tier_price_curve(ctx: Context<UpdateSalePhaseTierPriceCurveInputAccounts>,
+                                                 _sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8,
+                                                 sale_phase_name: String, tier_id: String, price_curve: PriceCurve,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+    let sale_phase_tier_detail: &Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &ctx.accounts.sale_phase_tier_detail;
+
+    // Checks
+    check_signing_authority(sale_phase_detail.signing_authority, ctx.accounts.signing_authority.key())?;
+
+    check_price_curve(&price_curve, sale_phase_tier_detail.price, sale_phase_tier_detail.quantity)?;
+
+    // Update
+    let sale_phase_tier_detail: &mut Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &mut ctx.accounts.sale_phase_tier_detail;
+    sale_phase_tier_detail.last_block_timestamp = timestamp;
+    sale_phase_tier_detail.price_curve = price_curve;
+
+    // Event
+    let event: UpdateSalePhaseTierPriceCurveEvent = UpdateSalePhaseTierPriceCurveEvent {
+        timestamp,
+        sale_phase_name,
+        tier_id,
+        price_curve,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
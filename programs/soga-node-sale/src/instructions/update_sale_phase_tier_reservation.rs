@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+    RESERVATION_DETAIL_ACCOUNT_PREFIX,
+    ReservationDetailAccount,
+};
+
+use crate::events::{
+    UpdateSalePhaseTierReservationEvent
+};
+
+use crate::utils::{check_signing_authority, check_reservation_claimed, check_reservation_quantity, check_public_quantity_locked};
+use crate::error::SogaNodeSaleError;
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, sale_phase_name: String, tier_id: String)]
+pub struct UpdateSalePhaseTierReservationInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub signing_authority: Signer<'info>,
+
+    /// CHECK: partner
+    pub partner: AccountInfo<'info>,
+
+    #[account(
+    seeds = [
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_name.as_ref(),
+    ],
+    bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    tier_id.as_ref()
+    ],
+    bump = _sale_phase_tier_detail_bump,
+    )]
+    pub sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+
+    #[account(
+    init_if_needed,
+    payer = payer,
+    space = ReservationDetailAccount::space(),
+    seeds = [
+    RESERVATION_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_tier_detail.key().as_ref(),
+    partner.key().as_ref(),
+    ],
+    bump,
+    )]
+    pub reservation_detail: Box<Account<'info, ReservationDetailAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Reserves `quantity` nodes of a tier for a partner, replacing an unclaimed reservation.
+/// A quantity of 0 cancels the reservation.
+pub fn handle_update_sale_phase_tier_reservation(ctx: Context<UpdateSalePhaseTierReservationInputAccounts>,
+                                                 _sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8,
+                                                 sale_phase_name: String, tier_id: String, quantity: u64, price: u64,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+    let sale_phase_tier_detail: &Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &ctx.accounts.sale_phase_tier_detail;
+    let reservation_detail: &Box<Account<ReservationDetailAccount>> = &ctx.accounts.reservation_detail;
+
+    // Checks
+    check_signing_authority(sale_phase_detail.signing_authority, ctx.accounts.signing_authority.key())?;
+
+    check_reservation_claimed(reservation_detail.is_claimed)?;
+
+    check_public_quantity_locked(sale_phase_tier_detail.is_public_quantity_locked(timestamp))?;
+
+    let total_reserved: u64 = sale_phase_tier_detail.total_reserved.checked_sub(reservation_detail.quantity)
+        .and_then(|total_reserved| total_reserved.checked_add(quantity))
+        .ok_or(SogaNodeSaleError::MathOverflow)?;
+
+    let reserved_supply: u64 = sale_phase_tier_detail.minted_supply().checked_add(total_reserved)
+        .and_then(|reserved_supply| reserved_supply.checked_sub(sale_phase_tier_detail.total_reserved_claimed))
+        .ok_or(SogaNodeSaleError::MathOverflow)?;
+
+    check_reservation_quantity(reserved_supply, sale_phase_tier_detail.quantity)?;
+
+    // Update
+    let reservation_detail: &mut Box<Account<ReservationDetailAccount>> = &mut ctx.accounts.reservation_detail;
+    reservation_detail.last_block_timestamp = timestamp;
+    reservation_detail.partner = ctx.accounts.partner.key();
+    reservation_detail.tier_id = tier_id.clone().parse().unwrap();
+    reservation_detail.quantity = quantity;
+    reservation_detail.price = price;
+
+    let sale_phase_tier_detail: &mut Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &mut ctx.accounts.sale_phase_tier_detail;
+    sale_phase_tier_detail.last_block_timestamp = timestamp;
+    sale_phase_tier_detail.total_reserved = total_reserved;
+
+    // Event
+    let event: UpdateSalePhaseTierReservationEvent = UpdateSalePhaseTierReservationEvent {
+        timestamp,
+        sale_phase_name,
+        tier_id,
+        partner: ctx.accounts.partner.key(),
+        quantity,
+        price,
+        total_reserved,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+};
+
+use crate::events::{
+    UpdateSalePhaseTierSealedBidAuctionEvent
+};
+
+use crate::utils::{check_signing_authority, check_sealed_bid_auction};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, sale_phase_name: String, tier_id: String)]
+pub struct UpdateSalePhaseTierSealedBidAuctionInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub signing_authority: Signer<'info>,
+
+    #[account(
+    seeds = [
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_name.as_ref(),
+    ],
+    bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    tier_id.as_ref()
+    ],
+    bump = _sale_phase_tier_detail_bump,
+    )]
+    pub sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_update_sale_phase_tier_sealed_bid_auction(ctx: Context<UpdateSalePhaseTierSealedBidAuctionInputAccounts>,
+                                                         _sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8,
+                                                         sale_phase_name: String, tier_id: String, is_sealed_bid_auction: bool,
+                                                         bid_end_timestamp: i64, reveal_end_timestamp: i64, reserve_price: u64, is_uniform_price: bool,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+
+    // Checks
+    check_signing_authority(sale_phase_detail.signing_authority, ctx.accounts.signing_authority.key())?;
+
+    if is_sealed_bid_auction {
+        check_sealed_bid_auction(bid_end_timestamp, reveal_end_timestamp, ctx.accounts.sale_phase_tier_detail.quantity)?;
+    }
+
+    // Update
+    let sale_phase_tier_detail: &mut Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &mut ctx.accounts.sale_phase_tier_detail;
+    sale_phase_tier_detail.last_block_timestamp = timestamp;
+    sale_phase_tier_detail.is_sealed_bid_auction = is_sealed_bid_auction;
+    sale_phase_tier_detail.bid_end_timestamp = bid_end_timestamp;
+    sale_phase_tier_detail.reveal_end_timestamp = reveal_end_timestamp;
+    sale_phase_tier_detail.reserve_price = reserve_price;
+    sale_phase_tier_detail.is_uniform_price = is_uniform_price;
+
+    // Event
+    let event: UpdateSalePhaseTierSealedBidAuctionEvent = UpdateSalePhaseTierSealedBidAuctionEvent {
+        timestamp,
+        sale_phase_name,
+        tier_id,
+        is_sealed_bid_auction,
+        bid_end_timestamp,
+        reveal_end_timestamp,
+        reserve_price,
+        is_uniform_price,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+    VolumeBracket,
+};
+
+use crate::events::{
+    UpdateSalePhaseTierVolumeBracketsEvent
+};
+
+use crate::utils::{check_signing_authority, check_volume_brackets};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8, sale_phase_name: String, tier_id: String)]
+pub struct UpdateSalePhaseTierVolumeBracketsInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub signing_authority: Signer<'info>,
+
+    #[account(
+    seeds = [
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_name.as_ref(),
+    ],
+    bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    tier_id.as_ref()
+    ],
+    bump = _sale_phase_tier_detail_bump,
+    )]
+    pub sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_update_sale_phase_tier_volume_brackets(ctx: Context<UpdateSalePhaseTierVolumeBracketsInputAccounts>,
+                                                     _sale_phase_detail_bump: u8, _sale_phase_tier_detail_bump: u8,
+                                                     sale_phase_name: String, tier_id: String, volume_brackets: Vec<VolumeBracket>, is_volume_lifetime: bool,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+
+    // Checks
+    check_signing_authority(sale_phase_detail.signing_authority, ctx.accounts.signing_authority.key())?;
+
+    check_volume_brackets(&volume_brackets)?;
+
+    // Update
+    let sale_phase_tier_detail: &mut Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &mut ctx.accounts.sale_phase_tier_detail;
+    sale_phase_tier_detail.last_block_timestamp = timestamp;
+    sale_phase_tier_detail.volume_brackets = volume_brackets.clone();
+    sale_phase_tier_detail.is_volume_lifetime = is_volume_lifetime;
+
+    // Event
+    let event: UpdateSalePhaseTierVolumeBracketsEvent = UpdateSalePhaseTierVolumeBracketsEvent {
+        timestamp,
+        sale_phase_name,
+        tier_id,
+        volume_brackets,
+        is_volume_lifetime,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,561 @@
+use anchor_lang::prelude::*;
+
+use crate::oracle::{get_quorum_oracle_price, get_expo_base, get_price_update, get_payment_token_amount, OraclePrice};
+
+use crate::pricing::{usd_to_token_amount, SOL_DECIMALS};
+
+use anchor_spl::{
+    token_2022::Token2022,
+    token_interface::{TokenAccount, TransferChecked, transfer_checked},
+};
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseTierDetailAccount,
+    SOGA_NODE_SALE_PHASE_PAYMENT_TOKEN_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhasePaymentTokenDetailAccount,
+    USER_DETAIL_ACCOUNT_PREFIX,
+    UserDetailAccount,
+    USER_TIER_DETAIL_ACCOUNT_PREFIX,
+    UserTierDetailAccount,
+    NODE_ACCOUNT_PREFIX,
+    ORDER_DETAIL_ACCOUNT_PREFIX,
+    OrderDetailAccount,
+    NodeMintMode,
+};
+
+use crate::events::{UpgradeNodeEvent};
+
+use crate::error::SogaNodeSaleError;
+
+use crate::token_extensions::burn_node;
+
+use crate::utils::{
+    check_signing_authority,
+    check_price_feed,
+    check_payment_receiver,
+    check_phase_buy,
+    check_phase_buy_with_token,
+    check_phase_tier_buy,
+    check_phase_tier_buy_with_token,
+    check_payment_token,
+    check_payment_token_mint_account,
+    check_phase_tier_is_completed,
+    check_token_quantity_out_of_range,
+    check_mint_limit_with_quantity,
+    check_quantity,
+    check_order_id,
+    check_order_token_id,
+    check_order_token_id_revoked,
+    check_order_token_id_upgraded,
+    check_order_layout,
+    check_order_voided,
+    check_installment_paid,
+    check_cap_escrow_pending,
+    check_tier_id,
+    check_mint_mode,
+    check_phase_tier_collection,
+    check_node_mint_account,
+    check_user_token_account,
+    check_upgrade_tier,
+    check_phase_tier_is_fair_launch,
+    check_phase_tier_is_sealed_bid_auction,
+    check_phase_started,
+    check_phase_ended,
+    check_hard_cap,
+    check_cap_escrow,
+    check_payment_token_program,
+};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, _source_sale_phase_tier_detail_bump: u8, _user_detail_bump: u8,
+_source_user_tier_detail_bump: u8, _source_order_detail_bump: u8, sale_phase_name: String,
+source_tier_id: String, target_tier_id: String, source_order_id: String, token_id: String, order_id: String)]
+pub struct UpgradeNodeInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub signing_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub user_payer: Signer<'info>,
+
+    /// holder of the source node
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+        SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_name.as_ref(),
+        ],
+        bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+        SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail.key().as_ref(),
+        source_tier_id.as_ref()
+        ],
+        bump = _source_sale_phase_tier_detail_bump,
+    )]
+    pub source_sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+        SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail.key().as_ref(),
+        target_tier_id.as_ref()
+        ],
+        bump,
+    )]
+    pub target_sale_phase_tier_detail: Box<Account<'info, SogaNodeSalePhaseTierDetailAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+        USER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail.key().as_ref(),
+        user.key().as_ref(),
+        ],
+        bump = _user_detail_bump,
+    )]
+    pub user_detail: Box<Account<'info, UserDetailAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+        USER_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        user_detail.key().as_ref(),
+        source_sale_phase_tier_detail.key().as_ref(),
+        ],
+        bump = _source_user_tier_detail_bump,
+    )]
+    pub source_user_tier_detail: Box<Account<'info, UserTierDetailAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserTierDetailAccount::space(),
+        seeds = [
+        USER_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        user_detail.key().as_ref(),
+        target_sale_phase_tier_detail.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub target_user_tier_detail: Box<Account<'info, UserTierDetailAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+        ORDER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail.key().as_ref(),
+        user_detail.key().as_ref(),
+        source_order_id.as_ref(),
+        ],
+        bump = _source_order_detail_bump,
+    )]
+    pub source_order_detail: Box<Account<'info, OrderDetailAccount>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = OrderDetailAccount::space(1),
+        seeds = [
+        ORDER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail.key().as_ref(),
+        user_detail.key().as_ref(),
+        order_id.as_ref(),
+        ],
+        bump,
+    )]
+    pub order_detail: Box<Account<'info, OrderDetailAccount>>,
+
+    /// required when `pay_with_token`
+    #[account(
+        seeds = [
+        SOGA_NODE_SALE_PHASE_PAYMENT_TOKEN_DETAIL_ACCOUNT_PREFIX.as_ref(),
+        sale_phase_detail.key().as_ref(),
+        sale_phase_payment_token_detail.mint.as_ref()
+        ],
+        bump,
+    )]
+    pub sale_phase_payment_token_detail: Option<Box<Account<'info, SogaNodeSalePhasePaymentTokenDetailAccount>>>,
+
+    /// not required when paying with a fixed-rate payment token without a depeg guard
+    /// CHECK: price feed, owner and layout checked by the oracle source
+    pub price_update: Option<UncheckedAccount<'info>>,
+
+    /// required when `pay_with_token` until the phase soft cap is reached
+    /// CHECK: token account owned by the sale phase detail, checked in the handler
+    #[account(mut)]
+    pub cap_escrow: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Program<'info, Token2022>,
+
+    pub system_program: Program<'info, System>,
+
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Exchanges a token id of a source tier order for a new single node order in the
+/// target tier on sale, charging the target price less the usd price paid for the source
+/// node in sol or in a payment token.
+///
+/// Remaining accounts are `[payment_receiver]`, followed by `[payment_token_mint_account,
+/// payment_token_program, payment_token_user_payer_token_account,
+/// payment_token_payment_receiver_token_account]` when `pay_with_token`, followed by
+/// `[collection_mint_account, node_mint_account, holder_token_account]` when the source
+/// token id is already minted, in which case the node is burned, followed by the registered
+/// price sources of the phase or payment token.
+pub fn handle_upgrade_node<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, UpgradeNodeInputAccounts<'info>>,
+                                              _sale_phase_detail_bump: u8, _source_sale_phase_tier_detail_bump: u8, _user_detail_bump: u8,
+                                              _source_user_tier_detail_bump: u8, _source_order_detail_bump: u8, sale_phase_name: String,
+                                              source_tier_id: String, target_tier_id: String, source_order_id: String, token_id: String, order_id: String,
+                                              pay_with_token: bool,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let source_tier_id_int: u32 = source_tier_id.clone().parse().unwrap();
+    let target_tier_id_int: u32 = target_tier_id.clone().parse().unwrap();
+    let token_id_int: u64 = token_id.clone().parse().unwrap();
+    let order_id_int: u64 = order_id.clone().parse().unwrap();
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+    let source_sale_phase_tier_detail: &Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &ctx.accounts.source_sale_phase_tier_detail;
+    let target_sale_phase_tier_detail: &Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &ctx.accounts.target_sale_phase_tier_detail;
+    let source_order_detail: &Box<Account<OrderDetailAccount>> = &ctx.accounts.source_order_detail;
+
+    let payment_receiver = &ctx.remaining_accounts[0];
+
+    // Checks
+    check_signing_authority(sale_phase_detail.signing_authority, ctx.accounts.signing_authority.key())?;
+
+    check_payment_receiver(sale_phase_detail.payment_receiver, payment_receiver.key())?;
+
+    if pay_with_token {
+        check_phase_buy_with_token(sale_phase_detail.buy_with_token_enable)?;
+        check_phase_tier_buy_with_token(target_sale_phase_tier_detail.buy_with_token_enable)?;
+    } else {
+        check_phase_buy(sale_phase_detail.buy_enable)?;
+        check_phase_tier_buy(target_sale_phase_tier_detail.buy_enable)?;
+    }
+
+    check_phase_tier_is_fair_launch(target_sale_phase_tier_detail.is_fair_launch_running())?;
+
+    check_phase_tier_is_sealed_bid_auction(target_sale_phase_tier_detail.is_sealed_bid_auction)?;
+
+    check_phase_started(sale_phase_detail.is_started(timestamp))?;
+
+    check_phase_ended(sale_phase_detail.is_ended(timestamp))?;
+
+    // the target tier is bought like any other node and has to be the tier currently on sale
+    check_tier_id(sale_phase_detail.total_completed_tiers + 1, target_tier_id_int)?;
+
+    let source_price: u64 = source_sale_phase_tier_detail.current_price(timestamp)?;
+    // the upgraded node is a new node of the target tier and pays its curve price
+    let target_price: u64 = target_sale_phase_tier_detail.quote(timestamp, 1)?[0];
+
+    check_upgrade_tier(source_price, target_price)?;
+
+    check_phase_tier_is_completed(target_sale_phase_tier_detail.is_completed)?;
+
+    check_quantity(target_sale_phase_tier_detail.mint_limit, 1)?;
+
+    check_token_quantity_out_of_range(target_sale_phase_tier_detail.minted_supply() + 1, target_sale_phase_tier_detail.public_quantity())?;
+
+    check_tier_id(source_order_detail.tier_id, source_tier_id_int)?;
+
+    check_order_token_id(source_order_detail.token_ids.contains(&token_id_int))?;
+
+    let index = source_order_detail.token_ids.iter().position(|r| r == &token_id_int).unwrap();
+
+    check_order_token_id_revoked(source_order_detail.is_token_id_revoked(index))?;
+
+    check_order_token_id_upgraded(source_order_detail.is_token_id_upgraded(index))?;
+
+    check_order_layout(source_order_detail.token_flags_len(), source_order_detail.token_ids.len())?;
+
+    check_order_voided(source_order_detail.is_voided)?;
+
+    check_installment_paid(source_order_detail.is_installment_paid())?;
+
+    // the source payment can still be refunded while it waits for the soft cap
+    check_cap_escrow_pending(source_order_detail.cap_escrow_amount > 0 && !source_order_detail.is_cap_settled)?;
+
+    let user_detail: &Box<Account<UserDetailAccount>> = &ctx.accounts.user_detail;
+
+    check_order_id(user_detail.total_orders + 1, order_id_int)?;
+
+    let target_user_tier_detail: &Box<Account<UserTierDetailAccount>> = &ctx.accounts.target_user_tier_detail;
+
+    check_mint_limit_with_quantity(target_sale_phase_tier_detail.mint_limit, target_user_tier_detail.total_mint + 1)?;
+
+
+    // Make Payment
+    // the source node is credited with the price the user paid for it, nothing is refunded when it
+    // exceeds the target price
+    let credit_in_usd: u64 = source_order_detail.paid_unit_price_in_usd();
+    let price_in_usd: u64 = target_price.saturating_sub(credit_in_usd);
+
+    check_hard_cap(sale_phase_detail.committed_payment() + price_in_usd, sale_phase_detail.hard_cap)?;
+
+    // the phase holds the payment until the soft cap is reached
+    let is_cap_escrowed: bool = !sale_phase_detail.is_soft_cap_reached();
+
+    let price_update = ctx.accounts.price_update.as_deref();
+
+    let burn_accounts_offset: usize = if pay_with_token { 5 } else { 1 };
+    let price_source_accounts_offset: usize = burn_accounts_offset + if source_order_detail.is_token_ids_minted[index] { 3 } else { 0 };
+    let price_source_accounts = &ctx.remaining_accounts[price_source_accounts_offset.min(ctx.remaining_accounts.len())..];
+
+    let mut payment_token_mint_account_key: Option<Pubkey> = None;
+
+    let price_in_lamport: u64;
+    let pyth_expo: u64;
+    let pyth_price: u64;
+
+    if pay_with_token {
+        let payment_token_mint_account = &ctx.remaining_accounts[1];
+        let payment_token_program = &ctx.remaining_accounts[2];
+        let payment_token_user_payer_token_account = &ctx.remaining_accounts[3];
+        let payment_token_payment_receiver_token_account = &ctx.remaining_accounts[4];
+
+        let sale_phase_payment_token_detail = ctx.accounts.sale_phase_payment_token_detail.as_deref().ok_or(SogaNodeSaleError::InvalidPaymentTokenMintAccount)?;
+
+        check_payment_token(sale_phase_payment_token_detail.enable)?;
+
+        check_payment_token_mint_account(sale_phase_payment_token_detail.mint, payment_token_mint_account.key())?;
+
+        let (payment_amount, price): (u64, Option<OraclePrice>) = get_payment_token_amount(price_in_usd, sale_phase_payment_token_detail, price_update, price_source_accounts)?;
+
+        pyth_expo = price.as_ref().map_or(0, get_expo_base);
+        pyth_price = price.as_ref().map_or(0, |price| price.price as u64);
+
+        price_in_lamport = payment_amount;
+
+        let payment_receiver_token: TokenAccount = TokenAccount::try_deserialize(&mut &payment_token_payment_receiver_token_account.try_borrow_data()?[..])?;
+        check_payment_receiver(sale_phase_detail.payment_receiver, payment_receiver_token.owner)?;
+
+        let cap_escrow: Option<&AccountInfo> = if is_cap_escrowed {
+            let cap_escrow = ctx.accounts.cap_escrow.as_deref().ok_or(SogaNodeSaleError::InvalidCapEscrow)?;
+            let cap_escrow_token: TokenAccount = TokenAccount::try_deserialize(&mut &cap_escrow.try_borrow_data()?[..])?;
+
+            check_cap_escrow(cap_escrow_token.owner, sale_phase_detail.key(), cap_escrow_token.mint, payment_token_mint_account.key())?;
+
+            Some(cap_escrow)
+        } else { None };
+
+        let deposit_receiver: &AccountInfo = cap_escrow.unwrap_or(payment_token_payment_receiver_token_account);
+
+        check_payment_token_program(payment_token_program.key(), *payment_token_mint_account.owner, *deposit_receiver.owner)?;
+
+        let cpi_accounts = TransferChecked {
+            from: payment_token_user_payer_token_account.to_account_info(),
+            mint: payment_token_mint_account.to_account_info(),
+            to: deposit_receiver.to_account_info(),
+            authority: ctx.accounts.user_payer.to_account_info(),
+        };
+
+        let cpi_program = payment_token_program.to_account_info();
+
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+
+        transfer_checked(cpi_context, price_in_lamport, sale_phase_payment_token_detail.decimals)?;
+
+        payment_token_mint_account_key = Some(payment_token_mint_account.key());
+    } else {
+        let price_update = get_price_update(price_update)?;
+
+        check_price_feed(sale_phase_detail.price_feed_address, price_update.key())?;
+
+        let price: OraclePrice = get_quorum_oracle_price(price_update, price_source_accounts, sale_phase_detail.price_feed_id.as_str(),
+                                                         &sale_phase_detail.oracle_config, &sale_phase_detail.price_sources, sale_phase_detail.price_source_quorum)?;
+
+        pyth_expo = get_expo_base(&price);
+        pyth_price = price.price as u64;
+
+        price_in_lamport = usd_to_token_amount(price_in_usd, SOL_DECIMALS, pyth_price, price.exponent)?;
+
+        let deposit_receiver: AccountInfo = if is_cap_escrowed { sale_phase_detail.to_account_info() } else { payment_receiver.to_account_info() };
+
+        let deposit_amount_ix = anchor_lang::solana_program::system_instruction::transfer(
+            &ctx.accounts.user_payer.key(),
+            &deposit_receiver.key(),
+            price_in_lamport,
+        );
+
+        anchor_lang::solana_program::program::invoke(
+            &deposit_amount_ix,
+            &[
+                ctx.accounts.user_payer.to_account_info(),
+                deposit_receiver,
+            ],
+        )?;
+    }
+
+
+    // Burn source node
+    let mut node_mint_account: Option<Pubkey> = None;
+
+    if source_order_detail.is_token_ids_minted[index] {
+        check_mint_mode(sale_phase_detail.mint_mode, NodeMintMode::Token2022)?;
+
+        let collection_mint_account = &ctx.remaining_accounts[burn_accounts_offset];
+        let node_mint = &ctx.remaining_accounts[burn_accounts_offset + 1];
+        let holder_token_account = &ctx.remaining_accounts[burn_accounts_offset + 2];
+
+        check_phase_tier_collection(source_sale_phase_tier_detail.collection_mint_address, collection_mint_account.key())?;
+
+        let (node_mint_address, _node_mint_bump) = Pubkey::find_program_address(
+            &[
+                NODE_ACCOUNT_PREFIX.as_ref(),
+                collection_mint_account.key().as_ref(),
+                token_id.as_ref(),
+            ],
+            ctx.program_id,
+        );
+
+        check_node_mint_account(node_mint_address, node_mint.key())?;
+
+        check_user_token_account(ctx.accounts.token_program.key(), *holder_token_account.owner)?;
+
+        let holder_token: TokenAccount = TokenAccount::try_deserialize(&mut &holder_token_account.try_borrow_data()?[..])?;
+
+        check_user_token_account(node_mint.key(), holder_token.mint)?;
+
+        check_user_token_account(ctx.accounts.user.key(), holder_token.owner)?;
+
+        let sale_phase_detail_key: Pubkey = ctx.accounts.sale_phase_detail.key();
+
+        let signer_seeds = &[
+            SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX.as_ref(),
+            sale_phase_detail_key.as_ref(),
+            source_tier_id.as_ref(),
+            &[_source_sale_phase_tier_detail_bump],
+        ];
+
+        let signer = &[&signer_seeds[..]];
+
+        burn_node(
+            &ctx.accounts.token_program.to_account_info(),
+            node_mint,
+            holder_token_account,
+            &ctx.accounts.source_sale_phase_tier_detail.to_account_info(),
+            signer,
+        )?;
+
+        node_mint_account = Some(node_mint.key());
+    }
+
+
+    // Update
+    let source_order_detail: &mut Box<Account<OrderDetailAccount>> = &mut ctx.accounts.source_order_detail;
+    source_order_detail.last_block_timestamp = timestamp;
+    source_order_detail.is_token_ids_upgraded[index] = true;
+
+    if source_order_detail.is_filled() {
+        source_order_detail.is_completed = true;
+    }
+
+    let target_sale_phase_tier_detail: &mut Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &mut ctx.accounts.target_sale_phase_tier_detail;
+
+    let order_detail: &mut Box<Account<OrderDetailAccount>> = &mut ctx.accounts.order_detail;
+    order_detail.last_block_timestamp = timestamp;
+    order_detail.tier_id = target_tier_id.clone().parse().unwrap();
+    order_detail.is_completed = false;
+    order_detail.quantity = 1;
+    order_detail.total_payment_in_usd = price_in_usd;
+    // the credited source price counts as paid for the new node, a later upgrade credits it again
+    order_detail.total_user_discount_in_usd = price_in_usd + credit_in_usd.min(target_price);
+    order_detail.total_discount_in_usd = 0;
+    order_detail.total_payment = price_in_lamport;
+    order_detail.total_user_discount = price_in_lamport;
+    order_detail.total_discount = 0;
+    order_detail.payment_token_mint_account = payment_token_mint_account_key;
+    order_detail.token_ids = vec![target_sale_phase_tier_detail.total_mint + 1];
+    order_detail.is_token_ids_minted = vec![false];
+    order_detail.is_token_ids_revoked = vec![false];
+    order_detail.is_token_ids_upgraded = vec![false];
+    order_detail.is_token_ids_reissued = vec![false];
+    order_detail.is_whitelist = false;
+    order_detail.cap_escrow_amount = if is_cap_escrowed { price_in_lamport } else { 0 };
+
+    let sale_phase_detail: &mut Box<Account<SogaNodeSalePhaseDetailAccount>> = &mut ctx.accounts.sale_phase_detail;
+    sale_phase_detail.total_mint += 1;
+    sale_phase_detail.total_upgraded += 1;
+    sale_phase_detail.total_payment += price_in_usd;
+    sale_phase_detail.latch_soft_cap();
+    sale_phase_detail.last_block_timestamp = timestamp;
+
+    let source_sale_phase_tier_detail: &mut Box<Account<SogaNodeSalePhaseTierDetailAccount>> = &mut ctx.accounts.source_sale_phase_tier_detail;
+    source_sale_phase_tier_detail.total_upgraded += 1;
+    source_sale_phase_tier_detail.last_block_timestamp = timestamp;
+
+    target_sale_phase_tier_detail.total_mint += 1;
+    target_sale_phase_tier_detail.total_payment += price_in_usd;
+    target_sale_phase_tier_detail.last_block_timestamp = timestamp;
+
+    if target_sale_phase_tier_detail.minted_supply() >= target_sale_phase_tier_detail.public_quantity() {
+        target_sale_phase_tier_detail.is_completed = true;
+        sale_phase_detail.total_completed_tiers += 1;
+    }
+
+    let user_detail: &mut Box<Account<UserDetailAccount>> = &mut ctx.accounts.user_detail;
+    user_detail.total_payment += price_in_usd;
+    user_detail.total_orders += 1;
+    user_detail.last_block_timestamp = timestamp;
+
+    let source_user_tier_detail: &mut Box<Account<UserTierDetailAccount>> = &mut ctx.accounts.source_user_tier_detail;
+    source_user_tier_detail.total_mint -= 1;
+    source_user_tier_detail.last_block_timestamp = timestamp;
+
+    let target_user_tier_detail: &mut Box<Account<UserTierDetailAccount>> = &mut ctx.accounts.target_user_tier_detail;
+    target_user_tier_detail.total_mint += 1;
+    target_user_tier_detail.total_payment += price_in_usd;
+    target_user_tier_detail.last_block_timestamp = timestamp;
+
+    if pay_with_token {
+        sale_phase_detail.total_buy_with_token += 1;
+        target_sale_phase_tier_detail.total_buy_with_token += 1;
+        user_detail.total_buy_with_token += 1;
+        target_user_tier_detail.total_buy_with_token += 1;
+    } else {
+        sale_phase_detail.total_buy += 1;
+        target_sale_phase_tier_detail.total_buy += 1;
+        user_detail.total_buy += 1;
+        target_user_tier_detail.total_buy += 1;
+    }
+
+    // Event
+    let event: UpgradeNodeEvent = UpgradeNodeEvent {
+        timestamp,
+        sale_phase_name,
+        source_tier_id,
+        target_tier_id,
+        source_order_id,
+        token_id,
+        order_id,
+        user: ctx.accounts.user.key(),
+        user_payer: ctx.accounts.user_payer.key(),
+        price_feed: price_update.map_or(Pubkey::default(), |price_update| price_update.key()),
+        payment_receiver: payment_receiver.key(),
+        payment_token_mint_account: payment_token_mint_account_key,
+        node_mint_account,
+        total_price_in_lamport: price_in_lamport,
+        total_price_in_usd: price_in_usd,
+        pyth_expo,
+        pyth_price,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX,
+    SogaNodeSalePhaseDetailAccount,
+    FILL_FEE_VAULT_ACCOUNT_PREFIX,
+    FillFeeVaultAccount,
+};
+
+use crate::events::{
+    WithdrawFillFeeVaultEvent
+};
+
+use crate::utils::{check_signing_authority, check_payment_receiver, check_value_is_zero, check_fill_fee_vault_balance};
+
+#[derive(Accounts)]
+#[instruction(_sale_phase_detail_bump: u8, sale_phase_name: String)]
+pub struct WithdrawFillFeeVaultInputAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub signing_authority: Signer<'info>,
+
+    /// CHECK: payment receiver, checked against the sale phase detail
+    #[account(mut)]
+    pub payment_receiver: AccountInfo<'info>,
+
+    #[account(
+    seeds = [
+    SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_name.as_ref(),
+    ],
+    bump = _sale_phase_detail_bump,
+    )]
+    pub sale_phase_detail: Box<Account<'info, SogaNodeSalePhaseDetailAccount>>,
+
+    #[account(
+    mut,
+    seeds = [
+    FILL_FEE_VAULT_ACCOUNT_PREFIX.as_ref(),
+    sale_phase_detail.key().as_ref(),
+    ],
+    bump,
+    )]
+    pub fill_fee_vault: Box<Account<'info, FillFeeVaultAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Withdraws unused fill rewards to the payment receiver, the vault keeps its rent.
+pub fn handle_withdraw_fill_fee_vault(ctx: Context<WithdrawFillFeeVaultInputAccounts>,
+                                      _sale_phase_detail_bump: u8, sale_phase_name: String, amount: u64,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let sale_phase_detail: &Box<Account<SogaNodeSalePhaseDetailAccount>> = &ctx.accounts.sale_phase_detail;
+
+    // Checks
+    check_signing_authority(sale_phase_detail.signing_authority, ctx.accounts.signing_authority.key())?;
+
+    check_payment_receiver(sale_phase_detail.payment_receiver, ctx.accounts.payment_receiver.key())?;
+
+    check_value_is_zero(amount as usize)?;
+
+    let fill_fee_vault_info: AccountInfo = ctx.accounts.fill_fee_vault.to_account_info();
+    let min_balance: u64 = Rent::get()?.minimum_balance(fill_fee_vault_info.data_len());
+
+    check_fill_fee_vault_balance(fill_fee_vault_info.lamports(), min_balance, amount)?;
+
+    // Make Payment
+    **fill_fee_vault_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.payment_receiver.try_borrow_mut_lamports()? += amount;
+
+    // Update
+    let fill_fee_vault: &mut Box<Account<FillFeeVaultAccount>> = &mut ctx.accounts.fill_fee_vault;
+    fill_fee_vault.last_block_timestamp = timestamp;
+    fill_fee_vault.total_withdrawn += amount;
+
+    // Event
+    let event: WithdrawFillFeeVaultEvent = WithdrawFillFeeVaultEvent {
+        timestamp,
+        sale_phase_name,
+        payment_receiver: ctx.accounts.payment_receiver.key(),
+        amount,
+        total_withdrawn: fill_fee_vault.total_withdrawn,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
\ No newline at end of file
@@ -3,12 +3,21 @@ use anchor_lang::prelude::*;
 pub mod meta;
 
 use instructions::*;
+use states::{NodeMintMode, PriceSchedule, PriceCurve, AllocationMode, VolumeBracket};
+use oracle::{OracleConfig, PriceSource};
 
 mod error;
 mod events;
 mod instructions;
 mod states;
 mod utils;
+mod token_extensions;
+mod oracle;
+mod pricing;
+mod allocation;
+mod whitelist;
+mod loyalty;
+mod merkle;
 
 declare_id!("8bN6BdztrKwNrxqq6BmRjAqM2bQT7wtMvXkY5JuR6sst");
 
@@ -29,6 +38,7 @@ pub mod soga_node_sale {
         symbol: String,
         metadata_base_uri: String,
         price_feed_id: String,
+        mint_mode: NodeMintMode,
     ) -> Result<()> {
         handle_initialize_sale_phase(
             ctx,
@@ -39,11 +49,12 @@ pub mod soga_node_sale {
             symbol,
             metadata_base_uri,
             price_feed_id,
+            mint_mode,
         )
     }
 
-    pub fn initialize_sale_phase_tier(
-        ctx: Context<InitializeSalePhaseTierInputAccounts>,
+    pub fn initialize_sale_phase_tier<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, InitializeSalePhaseTierInputAccounts<'info>>,
         _sale_phase_detail_bump: u8,
         sale_phase_name: String,
         tier_id: String,
@@ -54,6 +65,9 @@ pub mod soga_node_sale {
         collection_symbol: String,
         collection_url: String,
         whitelist_quantity: u64,
+        is_compressed: bool,
+        max_depth: u32,
+        max_buffer_size: u32,
     ) -> Result<()> {
         handle_initialize_sale_phase_tier(
             ctx,
@@ -67,6 +81,9 @@ pub mod soga_node_sale {
             collection_symbol,
             collection_url,
             whitelist_quantity,
+            is_compressed,
+            max_depth,
+            max_buffer_size,
         )
     }
 
@@ -85,6 +102,8 @@ pub mod soga_node_sale {
         is_whitelist: bool,
         allow_user_discount: bool,
         user_discount: u16,
+        max_payment_amount: u64,
+        is_installment: bool,
     ) -> Result<()> {
         handle_buy(
             ctx,
@@ -101,6 +120,8 @@ pub mod soga_node_sale {
             is_whitelist,
             allow_user_discount,
             user_discount,
+            max_payment_amount,
+            is_installment,
         )
     }
 
@@ -223,6 +244,8 @@ pub mod soga_node_sale {
         is_whitelist: bool,
         allow_user_discount: bool,
         user_discount: u16,
+        max_payment_amount: u64,
+        is_installment: bool,
     ) -> Result<()> {
         handle_buy_with_token(
             ctx,
@@ -239,6 +262,8 @@ pub mod soga_node_sale {
             is_whitelist,
             allow_user_discount,
             user_discount,
+            max_payment_amount,
+            is_installment,
         )
     }
 
@@ -293,4 +318,1013 @@ pub mod soga_node_sale {
     pub fn update_key(ctx: Context<UpdateKeyInputAccounts>, _sale_config_bump: u8, _sale_phase_name: String, _sale_phase_detail_bump: u8) -> Result<()> {
         handle_update_key(ctx, _sale_config_bump, _sale_phase_name, _sale_phase_detail_bump)
     }
-}
+
+    pub fn initialize_sale_phase_tier_token_2022(
+        ctx: Context<InitializeSalePhaseTierToken2022InputAccounts>,
+        _sale_phase_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+        price: u64,
+        quantity: u64,
+        mint_limit: u64,
+        collection_name: String,
+        collection_symbol: String,
+        collection_url: String,
+        whitelist_quantity: u64,
+    ) -> Result<()> {
+        handle_initialize_sale_phase_tier_token_2022(
+            ctx,
+            _sale_phase_detail_bump,
+            sale_phase_name,
+            tier_id,
+            price,
+            quantity,
+            mint_limit,
+            collection_name,
+            collection_symbol,
+            collection_url,
+            whitelist_quantity,
+        )
+    }
+
+    pub fn file_order_token_2022<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, FillOrderToken2022InputAccounts<'info>>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        _collection_mint_account_bump: u8,
+        _user_detail_bump: u8,
+        _order_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+        token_id: String,
+        order_id: String,
+    ) -> Result<()> {
+        handle_file_order_token_2022(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            _collection_mint_account_bump,
+            _user_detail_bump,
+            _order_detail_bump,
+            sale_phase_name,
+            tier_id,
+            token_id,
+            order_id,
+        )
+    }
+
+    pub fn file_order_compressed<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, FillOrderCompressedInputAccounts<'info>>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        _collection_mint_account_bump: u8,
+        _user_detail_bump: u8,
+        _order_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+        token_id: String,
+        order_id: String,
+    ) -> Result<()> {
+        handle_file_order_compressed(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            _collection_mint_account_bump,
+            _user_detail_bump,
+            _order_detail_bump,
+            sale_phase_name,
+            tier_id,
+            token_id,
+            order_id,
+        )
+    }
+
+    pub fn airdrop_token_2022<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, AirdropToken2022InputAccounts<'info>>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        _collection_mint_account_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+        token_id: String,
+    ) -> Result<()> {
+        handle_airdrop_token_2022(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            _collection_mint_account_bump,
+            sale_phase_name,
+            tier_id,
+            token_id,
+        )
+    }
+
+    pub fn update_sale_phase_unlock(
+        ctx: Context<UpdateSalePhaseUnlockInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        sale_phase_name: String,
+        unlock_timestamp: i64,
+    ) -> Result<()> {
+        handle_update_sale_phase_unlock(
+            ctx,
+            _sale_phase_detail_bump,
+            sale_phase_name,
+            unlock_timestamp,
+        )
+    }
+
+    pub fn update_sale_phase_tier_unlock(
+        ctx: Context<UpdateSalePhaseTierUnlockInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+        unlock_timestamp: i64,
+        is_unlocked: bool,
+    ) -> Result<()> {
+        handle_update_sale_phase_tier_unlock(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            sale_phase_name,
+            tier_id,
+            unlock_timestamp,
+            is_unlocked,
+        )
+    }
+
+    pub fn thaw_node(
+        ctx: Context<ThawNodeInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        _collection_mint_account_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+        token_id: String,
+    ) -> Result<()> {
+        handle_thaw_node(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            _collection_mint_account_bump,
+            sale_phase_name,
+            tier_id,
+            token_id,
+        )
+    }
+
+    pub fn revoke_node<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, RevokeNodeInputAccounts<'info>>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        _user_detail_bump: u8,
+        _user_tier_detail_bump: u8,
+        _order_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+        token_id: String,
+        order_id: String,
+    ) -> Result<()> {
+        handle_revoke_node(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            _user_detail_bump,
+            _user_tier_detail_bump,
+            _order_detail_bump,
+            sale_phase_name,
+            tier_id,
+            token_id,
+            order_id,
+        )
+    }
+
+    pub fn reissue_node(
+        ctx: Context<ReissueNodeInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        _collection_mint_account_bump: u8,
+        _revoked_user_detail_bump: u8,
+        _revoked_order_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+        token_id: String,
+        revoked_order_id: String,
+        order_id: String,
+    ) -> Result<()> {
+        handle_reissue_node(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            _collection_mint_account_bump,
+            _revoked_user_detail_bump,
+            _revoked_order_detail_bump,
+            sale_phase_name,
+            tier_id,
+            token_id,
+            revoked_order_id,
+            order_id,
+        )
+    }
+
+    pub fn upgrade_node<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, UpgradeNodeInputAccounts<'info>>,
+        _sale_phase_detail_bump: u8,
+        _source_sale_phase_tier_detail_bump: u8,
+        _user_detail_bump: u8,
+        _source_user_tier_detail_bump: u8,
+        _source_order_detail_bump: u8,
+        sale_phase_name: String,
+        source_tier_id: String,
+        target_tier_id: String,
+        source_order_id: String,
+        token_id: String,
+        order_id: String,
+        pay_with_token: bool,
+    ) -> Result<()> {
+        handle_upgrade_node(
+            ctx,
+            _sale_phase_detail_bump,
+            _source_sale_phase_tier_detail_bump,
+            _user_detail_bump,
+            _source_user_tier_detail_bump,
+            _source_order_detail_bump,
+            sale_phase_name,
+            source_tier_id,
+            target_tier_id,
+            source_order_id,
+            token_id,
+            order_id,
+            pay_with_token,
+        )
+    }
+
+    pub fn update_sale_phase_oracle_config(
+        ctx: Context<UpdateSalePhaseOracleConfigInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        sale_phase_name: String,
+        oracle_config: OracleConfig,
+    ) -> Result<()> {
+        handle_update_sale_phase_oracle_config(
+            ctx,
+            _sale_phase_detail_bump,
+            sale_phase_name,
+            oracle_config,
+        )
+    }
+
+    pub fn update_sale_phase_payment_token_oracle_config(
+        ctx: Context<UpdateSalePhasePaymentTokenOracleConfigInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_payment_token_detail_bump: u8,
+        sale_phase_name: String,
+        oracle_config: OracleConfig,
+    ) -> Result<()> {
+        handle_update_sale_phase_payment_token_oracle_config(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_payment_token_detail_bump,
+            sale_phase_name,
+            oracle_config,
+        )
+    }
+
+    pub fn update_sale_phase_payment_token_fixed_rate(
+        ctx: Context<UpdateSalePhasePaymentTokenFixedRateInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_payment_token_detail_bump: u8,
+        sale_phase_name: String,
+        is_fixed_rate: bool,
+        fixed_rate: u64,
+        max_depeg_bps: u16,
+    ) -> Result<()> {
+        handle_update_sale_phase_payment_token_fixed_rate(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_payment_token_detail_bump,
+            sale_phase_name,
+            is_fixed_rate,
+            fixed_rate,
+            max_depeg_bps,
+        )
+    }
+
+    pub fn initialize_oracle_price(
+        ctx: Context<InitializeOraclePriceInputAccounts>,
+        _sale_config_bump: u8,
+        name: String,
+        price: i64,
+        conf: u64,
+        exponent: i32,
+    ) -> Result<()> {
+        handle_initialize_oracle_price(
+            ctx,
+            _sale_config_bump,
+            name,
+            price,
+            conf,
+            exponent,
+        )
+    }
+
+    pub fn update_oracle_price(
+        ctx: Context<UpdateOraclePriceInputAccounts>,
+        _oracle_price_bump: u8,
+        name: String,
+        price: i64,
+        conf: u64,
+        exponent: i32,
+    ) -> Result<()> {
+        handle_update_oracle_price(
+            ctx,
+            _oracle_price_bump,
+            name,
+            price,
+            conf,
+            exponent,
+        )
+    }
+
+    pub fn update_sale_phase_price_sources(
+        ctx: Context<UpdateSalePhasePriceSourcesInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        sale_phase_name: String,
+        price_sources: Vec<PriceSource>,
+        price_source_quorum: u8,
+    ) -> Result<()> {
+        handle_update_sale_phase_price_sources(
+            ctx,
+            _sale_phase_detail_bump,
+            sale_phase_name,
+            price_sources,
+            price_source_quorum,
+        )
+    }
+
+    pub fn update_sale_phase_payment_token_price_sources(
+        ctx: Context<UpdateSalePhasePaymentTokenPriceSourcesInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_payment_token_detail_bump: u8,
+        sale_phase_name: String,
+        price_sources: Vec<PriceSource>,
+        price_source_quorum: u8,
+    ) -> Result<()> {
+        handle_update_sale_phase_payment_token_price_sources(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_payment_token_detail_bump,
+            sale_phase_name,
+            price_sources,
+            price_source_quorum,
+        )
+    }
+
+    pub fn update_sale_phase_tier_price_schedule(
+        ctx: Context<UpdateSalePhaseTierPriceScheduleInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+        price_schedule: PriceSchedule,
+    ) -> Result<()> {
+        handle_update_sale_phase_tier_price_schedule(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            sale_phase_name,
+            tier_id,
+            price_schedule,
+        )
+    }
+
+    pub fn update_sale_phase_tier_price_curve(
+        ctx: Context<UpdateSalePhaseTierPriceCurveInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+        price_curve: PriceCurve,
+    ) -> Result<()> {
+        handle_update_sale_phase_tier_price_curve(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            sale_phase_name,
+            tier_id,
+            price_curve,
+        )
+    }
+
+    pub fn quote(
+        ctx: Context<QuoteInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+        quantity: u64,
+    ) -> Result<()> {
+        handle_quote(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            sale_phase_name,
+            tier_id,
+            quantity,
+        )
+    }
+
+
+    pub fn update_sale_phase_tier_dutch_auction(
+        ctx: Context<UpdateSalePhaseTierDutchAuctionInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+        start_timestamp: i64,
+        end_timestamp: i64,
+        start_price: u64,
+        floor_price: u64,
+        is_rebate: bool,
+    ) -> Result<()> {
+        handle_update_sale_phase_tier_dutch_auction(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            sale_phase_name,
+            tier_id,
+            start_timestamp,
+            end_timestamp,
+            start_price,
+            floor_price,
+            is_rebate,
+        )
+    }
+
+    pub fn settle_auction_order<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, SettleAuctionOrderInputAccounts<'info>>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        _user_detail_bump: u8,
+        _order_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+        order_id: String,
+    ) -> Result<()> {
+        handle_settle_auction_order(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            _user_detail_bump,
+            _order_detail_bump,
+            sale_phase_name,
+            tier_id,
+            order_id,
+        )
+    }
+
+
+    pub fn update_sale_phase_tier_fair_launch(
+        ctx: Context<UpdateSalePhaseTierFairLaunchInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+        is_fair_launch: bool,
+        commit_start_timestamp: i64,
+        commit_end_timestamp: i64,
+        allocation_mode: AllocationMode,
+        allocation_end_timestamp: i64,
+    ) -> Result<()> {
+        handle_update_sale_phase_tier_fair_launch(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            sale_phase_name,
+            tier_id,
+            is_fair_launch,
+            commit_start_timestamp,
+            commit_end_timestamp,
+            allocation_mode,
+            allocation_end_timestamp,
+        )
+    }
+
+    pub fn commit<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, CommitInputAccounts<'info>>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+        quantity: u64,
+        max_payment_amount: u64,
+    ) -> Result<()> {
+        handle_commit(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            sale_phase_name,
+            tier_id,
+            quantity,
+            max_payment_amount,
+        )
+    }
+
+    pub fn commit_with_token<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, CommitWithTokenInputAccounts<'info>>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+        quantity: u64,
+        max_payment_amount: u64,
+    ) -> Result<()> {
+        handle_commit_with_token(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            sale_phase_name,
+            tier_id,
+            quantity,
+            max_payment_amount,
+        )
+    }
+
+    pub fn finalize_sale_phase_tier_fair_launch(
+        ctx: Context<FinalizeSalePhaseTierFairLaunchInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+    ) -> Result<()> {
+        handle_finalize_sale_phase_tier_fair_launch(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            sale_phase_name,
+            tier_id,
+        )
+    }
+
+    pub fn allocate_commitment<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, AllocateCommitmentInputAccounts<'info>>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+        order_id: String,
+        quantity: u64,
+    ) -> Result<()> {
+        handle_allocate_commitment(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            sale_phase_name,
+            tier_id,
+            order_id,
+            quantity,
+        )
+    }
+
+    pub fn refund_commitment<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, RefundCommitmentInputAccounts<'info>>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+    ) -> Result<()> {
+        handle_refund_commitment(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            sale_phase_name,
+            tier_id,
+        )
+    }
+
+
+    pub fn update_sale_phase_tier_sealed_bid_auction(
+        ctx: Context<UpdateSalePhaseTierSealedBidAuctionInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+        is_sealed_bid_auction: bool,
+        bid_end_timestamp: i64,
+        reveal_end_timestamp: i64,
+        reserve_price: u64,
+        is_uniform_price: bool,
+    ) -> Result<()> {
+        handle_update_sale_phase_tier_sealed_bid_auction(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            sale_phase_name,
+            tier_id,
+            is_sealed_bid_auction,
+            bid_end_timestamp,
+            reveal_end_timestamp,
+            reserve_price,
+            is_uniform_price,
+        )
+    }
+
+    pub fn place_bid(
+        ctx: Context<PlaceBidInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+        bid_hash: [u8; 32],
+        deposit: u64,
+    ) -> Result<()> {
+        handle_place_bid(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            sale_phase_name,
+            tier_id,
+            bid_hash,
+            deposit,
+        )
+    }
+
+    pub fn reveal_bid(
+        ctx: Context<RevealBidInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+        price: u64,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        handle_reveal_bid(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            sale_phase_name,
+            tier_id,
+            price,
+            salt,
+        )
+    }
+
+    pub fn settle_winning_bid<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, SettleWinningBidInputAccounts<'info>>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+        order_id: String,
+    ) -> Result<()> {
+        handle_settle_winning_bid(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            sale_phase_name,
+            tier_id,
+            order_id,
+        )
+    }
+
+    pub fn refund_bid(
+        ctx: Context<RefundBidInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+    ) -> Result<()> {
+        handle_refund_bid(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            sale_phase_name,
+            tier_id,
+        )
+    }
+
+
+    pub fn update_sale_phase_caps(
+        ctx: Context<UpdateSalePhaseCapsInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        sale_phase_name: String,
+        soft_cap: u64,
+        hard_cap: u64,
+        start_timestamp: i64,
+        end_timestamp: i64,
+    ) -> Result<()> {
+        handle_update_sale_phase_caps(
+            ctx,
+            _sale_phase_detail_bump,
+            sale_phase_name,
+            soft_cap,
+            hard_cap,
+            start_timestamp,
+            end_timestamp,
+        )
+    }
+
+    pub fn settle_order_cap_escrow<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, SettleOrderCapEscrowInputAccounts<'info>>,
+        _sale_phase_detail_bump: u8,
+        _user_detail_bump: u8,
+        _order_detail_bump: u8,
+        sale_phase_name: String,
+        order_id: String,
+    ) -> Result<()> {
+        handle_settle_order_cap_escrow(
+            ctx,
+            _sale_phase_detail_bump,
+            _user_detail_bump,
+            _order_detail_bump,
+            sale_phase_name,
+            order_id,
+        )
+    }
+
+
+    pub fn update_sale_phase_installment(
+        ctx: Context<UpdateSalePhaseInstallmentInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        sale_phase_name: String,
+        installment_deposit_bps: u16,
+        installment_period: i64,
+        is_installment_deposit_refundable: bool,
+    ) -> Result<()> {
+        handle_update_sale_phase_installment(
+            ctx,
+            _sale_phase_detail_bump,
+            sale_phase_name,
+            installment_deposit_bps,
+            installment_period,
+            is_installment_deposit_refundable,
+        )
+    }
+
+    pub fn pay_installment<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, PayInstallmentInputAccounts<'info>>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        _user_detail_bump: u8,
+        _user_tier_detail_bump: u8,
+        _order_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+        order_id: String,
+        payment_in_usd: u64,
+        max_payment_amount: u64,
+    ) -> Result<()> {
+        handle_pay_installment(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            _user_detail_bump,
+            _user_tier_detail_bump,
+            _order_detail_bump,
+            sale_phase_name,
+            tier_id,
+            order_id,
+            payment_in_usd,
+            max_payment_amount,
+        )
+    }
+
+    pub fn lapse_installment_order<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, LapseInstallmentOrderInputAccounts<'info>>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        _user_detail_bump: u8,
+        _user_tier_detail_bump: u8,
+        _order_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+        order_id: String,
+    ) -> Result<()> {
+        handle_lapse_installment_order(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            _user_detail_bump,
+            _user_tier_detail_bump,
+            _order_detail_bump,
+            sale_phase_name,
+            tier_id,
+            order_id,
+        )
+    }
+
+
+    pub fn update_sale_phase_tier_volume_brackets(
+        ctx: Context<UpdateSalePhaseTierVolumeBracketsInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+        volume_brackets: Vec<VolumeBracket>,
+        is_volume_lifetime: bool,
+    ) -> Result<()> {
+        handle_update_sale_phase_tier_volume_brackets(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            sale_phase_name,
+            tier_id,
+            volume_brackets,
+            is_volume_lifetime,
+        )
+    }
+
+
+    pub fn update_sale_phase_loyalty(
+        ctx: Context<UpdateSalePhaseLoyaltyInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        sale_phase_name: String,
+        loyalty_discount: u16,
+        loyalty_tier_ids: Vec<String>,
+    ) -> Result<()> {
+        handle_update_sale_phase_loyalty(
+            ctx,
+            _sale_phase_detail_bump,
+            sale_phase_name,
+            loyalty_discount,
+            loyalty_tier_ids,
+        )
+    }
+
+
+    pub fn update_sale_phase_tier_whitelist_pass(
+        ctx: Context<UpdateSalePhaseTierWhitelistPassInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+        whitelist_pass_mint: Option<Pubkey>,
+        is_whitelist_pass_burned: bool,
+    ) -> Result<()> {
+        handle_update_sale_phase_tier_whitelist_pass(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            sale_phase_name,
+            tier_id,
+            whitelist_pass_mint,
+            is_whitelist_pass_burned,
+        )
+    }
+
+
+    pub fn update_sale_phase_tier_whitelist(
+        ctx: Context<UpdateSalePhaseTierWhitelistInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+        whitelist_price: u64,
+        whitelist_mint_limit: u64,
+    ) -> Result<()> {
+        handle_update_sale_phase_tier_whitelist(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            sale_phase_name,
+            tier_id,
+            whitelist_price,
+            whitelist_mint_limit,
+        )
+    }
+
+
+    pub fn update_sale_phase_tier_reservation(
+        ctx: Context<UpdateSalePhaseTierReservationInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+        quantity: u64,
+        price: u64,
+    ) -> Result<()> {
+        handle_update_sale_phase_tier_reservation(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            sale_phase_name,
+            tier_id,
+            quantity,
+            price,
+        )
+    }
+
+    pub fn claim_reservation<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ClaimReservationInputAccounts<'info>>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+        order_id: String,
+        max_payment_amount: u64,
+    ) -> Result<()> {
+        handle_claim_reservation(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            sale_phase_name,
+            tier_id,
+            order_id,
+            max_payment_amount,
+        )
+    }
+
+
+    pub fn update_sale_phase_tier_airdrop_claim(
+        ctx: Context<UpdateSalePhaseTierAirdropClaimInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+        merkle_root: [u8; 32],
+        total_claims: u32,
+        total_quantity: u64,
+    ) -> Result<()> {
+        handle_update_sale_phase_tier_airdrop_claim(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            sale_phase_name,
+            tier_id,
+            merkle_root,
+            total_claims,
+            total_quantity,
+        )
+    }
+
+    pub fn claim_airdrop(
+        ctx: Context<ClaimAirdropInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        _sale_phase_tier_detail_bump: u8,
+        sale_phase_name: String,
+        tier_id: String,
+        order_id: String,
+        index: u32,
+        quantity: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        handle_claim_airdrop(
+            ctx,
+            _sale_phase_detail_bump,
+            _sale_phase_tier_detail_bump,
+            sale_phase_name,
+            tier_id,
+            order_id,
+            index,
+            quantity,
+            proof,
+        )
+    }
+
+
+    pub fn update_sale_phase_fill_reward(
+        ctx: Context<UpdateSalePhaseFillRewardInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        sale_phase_name: String,
+        fill_reward: u64,
+    ) -> Result<()> {
+        handle_update_sale_phase_fill_reward(
+            ctx,
+            _sale_phase_detail_bump,
+            sale_phase_name,
+            fill_reward,
+        )
+    }
+
+    pub fn fund_fill_fee_vault(
+        ctx: Context<FundFillFeeVaultInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        sale_phase_name: String,
+        amount: u64,
+    ) -> Result<()> {
+        handle_fund_fill_fee_vault(
+            ctx,
+            _sale_phase_detail_bump,
+            sale_phase_name,
+            amount,
+        )
+    }
+
+    pub fn withdraw_fill_fee_vault(
+        ctx: Context<WithdrawFillFeeVaultInputAccounts>,
+        _sale_phase_detail_bump: u8,
+        sale_phase_name: String,
+        amount: u64,
+    ) -> Result<()> {
+        handle_withdraw_fill_fee_vault(
+            ctx,
+            _sale_phase_detail_bump,
+            sale_phase_name,
+            amount,
+        )
+    }
+
+
+    pub fn realloc_account(ctx: Context<ReallocAccountInputAccounts>) -> Result<()> {
+        handle_realloc_account(ctx)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    metadata::{MetadataAccount, mpl_token_metadata::accounts::Metadata},
+    token_interface::TokenAccount,
+};
+
+use crate::error::SogaNodeSaleError;
+
+use crate::token_extensions::get_node_collection_mint;
+
+use crate::utils::{check_loyalty_node_program, check_loyalty_holder, check_loyalty_node_metadata};
+
+/// Collection mint of a loyalty node held by `user` in `loyalty_token_account`. Token-2022
+/// nodes are members of the token group of their tier, metaplex nodes need the metadata
+/// account of the node mint with a verified collection.
+pub fn get_loyalty_collection_mint(
+    user: Pubkey,
+    loyalty_node_mint: &AccountInfo,
+    loyalty_token_account: &AccountInfo,
+    loyalty_node_metadata: Option<&AccountInfo>,
+) -> Result<Pubkey> {
+    check_loyalty_node_program(*loyalty_node_mint.owner, *loyalty_token_account.owner)?;
+
+    let loyalty_token: TokenAccount = TokenAccount::try_deserialize(&mut &loyalty_token_account.try_borrow_data()?[..])?;
+
+    check_loyalty_holder(user, loyalty_token.owner, loyalty_node_mint.key(), loyalty_token.mint, loyalty_token.amount)?;
+
+    if *loyalty_node_mint.owner == anchor_spl::token_2022::ID {
+        return get_node_collection_mint(loyalty_node_mint);
+    }
+
+    let loyalty_node_metadata = loyalty_node_metadata.ok_or(SogaNodeSaleError::InvalidLoyaltyNode)?;
+
+    check_loyalty_node_metadata(*loyalty_node_metadata.owner, loyalty_node_metadata.key(), Metadata::find_pda(&loyalty_node_mint.key()).0)?;
+
+    let metadata: MetadataAccount = MetadataAccount::try_deserialize(&mut &loyalty_node_metadata.try_borrow_data()?[..])?;
+
+    // unverified collections can be set by anyone minting a lookalike node
+    let collection_mint: Pubkey = metadata.collection.as_ref()
+        .filter(|collection| collection.verified)
+        .map(|collection| collection.key)
+        .ok_or(SogaNodeSaleError::InvalidLoyaltyNode)?;
+
+    Ok(collection_mint)
+}
\ No newline at end of file
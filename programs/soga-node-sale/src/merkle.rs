@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Leaf of an airdrop snapshot entry, `index` is the bit of the entry in the claim bitmap.
+pub fn get_airdrop_leaf(index: u32, user: &Pubkey, tier: &Pubkey, quantity: u64) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, &index.to_le_bytes(), user.as_ref(), tier.as_ref(), &quantity.to_le_bytes()]).to_bytes()
+}
+
+/// Pairs are hashed in sorted order so proofs do not need the position of the leaf.
+pub fn get_merkle_parent(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    if left <= right {
+        hashv(&[NODE_PREFIX, left.as_ref(), right.as_ref()]).to_bytes()
+    } else {
+        hashv(&[NODE_PREFIX, right.as_ref(), left.as_ref()]).to_bytes()
+    }
+}
+
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed: [u8; 32] = proof.iter().fold(leaf, |node, sibling| get_merkle_parent(&node, sibling));
+
+    computed == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn airdrop_leaf_is_proven_against_root() {
+        let tier: Pubkey = Pubkey::new_unique();
+        let users: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+
+        let leaves: Vec<[u8; 32]> = users.iter().enumerate()
+            .map(|(index, user)| get_airdrop_leaf(index as u32, user, &tier, index as u64 + 1))
+            .collect();
+
+        // three leaves, the last one is promoted to the second level
+        let node: [u8; 32] = get_merkle_parent(&leaves[0], &leaves[1]);
+        let root: [u8; 32] = get_merkle_parent(&node, &leaves[2]);
+
+        assert!(verify_merkle_proof(&[leaves[1], leaves[2]], &root, leaves[0]));
+        assert!(verify_merkle_proof(&[leaves[0], leaves[2]], &root, leaves[1]));
+        assert!(verify_merkle_proof(&[node], &root, leaves[2]));
+
+        // quantity and index are part of the leaf
+        assert!(!verify_merkle_proof(&[leaves[1], leaves[2]], &root, get_airdrop_leaf(0, &users[0], &tier, 5)));
+        assert!(!verify_merkle_proof(&[leaves[1], leaves[2]], &root, get_airdrop_leaf(1, &users[0], &tier, 1)));
+
+        // an inner node can not be claimed as a leaf
+        assert!(!verify_merkle_proof(&[leaves[2]], &root, get_airdrop_leaf(0, &users[0], &tier, 1)));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,423 @@
+use anchor_lang::prelude::*;
+
+use pyth_solana_receiver_sdk::price_update::{PriceUpdateV2, get_feed_id_from_hex};
+
+use crate::error::SogaNodeSaleError;
+
+use crate::pricing::{usd_to_token_amount, fixed_rate_to_token_amount, peg_deviation_bps};
+
+use crate::states::{SogaNodeSalePhasePaymentTokenDetailAccount, OraclePriceAccount};
+
+use crate::utils::{check_price_feed, check_stablecoin_peg};
+
+/// max price age in seconds used when `max_price_age` is not configured
+pub const DEFAULT_MAX_PRICE_AGE: u64 = 120;
+
+/// switchboard on-demand program owning pull feed accounts
+pub mod switchboard_program {
+    use anchor_lang::declare_id;
+
+    declare_id!("SBondMDrcV3K4kxZR1HNVT7osZxAHVHgYXL5Ze1oMUv");
+}
+
+/// anchor discriminator of a switchboard on-demand `PullFeedAccountData`
+const SWITCHBOARD_PULL_FEED_DISCRIMINATOR: [u8; 8] = [196, 27, 108, 196, 10, 215, 219, 40];
+
+/// offsets in a switchboard on-demand `PullFeedAccountData`, discriminator included
+const SWITCHBOARD_LAST_UPDATE_TIMESTAMP_OFFSET: usize = 2216;
+const SWITCHBOARD_RESULT_VALUE_OFFSET: usize = 2264;
+const SWITCHBOARD_RESULT_STD_DEV_OFFSET: usize = 2280;
+
+/// switchboard results have 18 decimals, they are rescaled to this exponent
+const SWITCHBOARD_EXPONENT: i32 = -8;
+const SWITCHBOARD_DECIMALS: u32 = 18;
+
+/// where the price of a phase or payment token is read from
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum OracleSource {
+    /// pyth pull oracle `PriceUpdateV2` account
+    #[default]
+    Pyth,
+
+    /// switchboard on-demand pull feed account
+    Switchboard,
+
+    /// `OraclePriceAccount` maintained by an admin of this program
+    Manual,
+}
+
+/// max price sources registered on a phase or payment token next to its price feed
+pub const MAX_PRICE_SOURCES: usize = 4;
+
+/// additional price feed of a phase or payment token
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct PriceSource {
+    pub address: Pubkey,
+
+    /// pyth feed id, unused by other sources
+    pub feed_id: String,
+
+    pub source: OracleSource,
+}
+
+impl PriceSource {
+    pub fn space() -> usize {
+        32 // address
+            + 70 // feed_id
+            + 1 // source
+    }
+}
+
+/// price read from any oracle source, `price * 10^exponent` usd
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct OraclePrice {
+    pub price: i64,
+
+    pub conf: u64,
+
+    pub exponent: i32,
+
+    pub publish_time: i64,
+}
+
+/// oracle settings of a phase or payment token, 0 disables a setting
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct OracleConfig {
+    /// max price age in seconds, falls back to `DEFAULT_MAX_PRICE_AGE`
+    pub max_price_age: u64,
+
+    /// max confidence interval in basis points of the price
+    pub max_confidence_ratio: u16,
+
+    /// min price in feed units
+    pub min_price: u64,
+
+    /// max price in feed units
+    pub max_price: u64,
+
+    /// oracle the price feed account belongs to
+    pub source: OracleSource,
+}
+
+impl OracleConfig {
+    pub fn space() -> usize {
+        8 // max_price_age
+            + 2 // max_confidence_ratio
+            + 8 // min_price
+            + 8 // max_price
+            + 1 // source
+    }
+}
+
+/// Reads the price of `feed_id` from `price_feed` with the configured oracle
+/// source and rejects it when stale, non positive, too uncertain or outside the
+/// configured bounds. `feed_id` is only used by pyth.
+pub fn get_oracle_price(price_feed: &AccountInfo, feed_id: &str, oracle_config: &OracleConfig) -> Result<OraclePrice> {
+    let timestamp: i64 = Clock::get()?.unix_timestamp;
+
+    let max_price_age: u64 = if oracle_config.max_price_age == 0 { DEFAULT_MAX_PRICE_AGE } else { oracle_config.max_price_age };
+
+    let price: OraclePrice = match oracle_config.source {
+        OracleSource::Pyth => get_pyth_price(price_feed, feed_id, max_price_age)?,
+        OracleSource::Switchboard => get_switchboard_price(price_feed)?,
+        OracleSource::Manual => get_manual_price(price_feed)?,
+    };
+
+    // manual prices are fixed until the admin updates them, they only expire when an age is configured
+    let check_age: bool = oracle_config.source != OracleSource::Manual || oracle_config.max_price_age != 0;
+
+    if check_age && price.publish_time.saturating_add(max_price_age as i64) < timestamp {
+        return Err(SogaNodeSaleError::OraclePriceTooOld.into());
+    }
+
+    if price.price <= 0 {
+        return Err(SogaNodeSaleError::InvalidOraclePrice.into());
+    }
+
+    let price_value: u64 = price.price as u64;
+
+    if oracle_config.max_confidence_ratio != 0
+        && (price.conf as u128) * 10000 > (oracle_config.max_confidence_ratio as u128) * (price_value as u128) {
+        return Err(SogaNodeSaleError::OracleConfidenceTooWide.into());
+    }
+
+    if (oracle_config.min_price != 0 && price_value < oracle_config.min_price)
+        || (oracle_config.max_price != 0 && price_value > oracle_config.max_price) {
+        return Err(SogaNodeSaleError::OraclePriceOutOfRange.into());
+    }
+
+    Ok(price)
+}
+
+fn get_pyth_price(price_feed: &AccountInfo, feed_id: &str, max_price_age: u64) -> Result<OraclePrice> {
+    check_oracle_owner(pyth_solana_receiver_sdk::ID, *price_feed.owner)?;
+
+    let price_update: PriceUpdateV2 = PriceUpdateV2::try_deserialize(&mut &price_feed.try_borrow_data()?[..])?;
+
+    let feed_id: [u8; 32] = get_feed_id_from_hex(feed_id)?;
+
+    let price = price_update.get_price_no_older_than(&Clock::get()?, max_price_age, &feed_id)?;
+
+    Ok(OraclePrice {
+        price: price.price,
+        conf: price.conf,
+        exponent: price.exponent,
+        publish_time: price.publish_time,
+    })
+}
+
+fn get_switchboard_price(price_feed: &AccountInfo) -> Result<OraclePrice> {
+    check_oracle_owner(switchboard_program::ID, *price_feed.owner)?;
+
+    read_switchboard_price(&price_feed.try_borrow_data()?)
+}
+
+/// reads the current result of a pull feed, other switchboard accounts are rejected by their discriminator
+fn read_switchboard_price(data: &[u8]) -> Result<OraclePrice> {
+    if data.len() < SWITCHBOARD_RESULT_STD_DEV_OFFSET + 16 || data[..8] != SWITCHBOARD_PULL_FEED_DISCRIMINATOR {
+        return Err(SogaNodeSaleError::InvalidOracleAccount.into());
+    }
+
+    let read_i128 = |offset: usize| i128::from_le_bytes(data[offset..offset + 16].try_into().unwrap());
+
+    let publish_time: i64 = i64::from_le_bytes(data[SWITCHBOARD_LAST_UPDATE_TIMESTAMP_OFFSET..SWITCHBOARD_LAST_UPDATE_TIMESTAMP_OFFSET + 8].try_into().unwrap());
+
+    let scale: i128 = 10_i128.pow(SWITCHBOARD_DECIMALS - SWITCHBOARD_EXPONENT.unsigned_abs());
+
+    let price: i64 = i64::try_from(read_i128(SWITCHBOARD_RESULT_VALUE_OFFSET) / scale).map_err(|_| SogaNodeSaleError::InvalidOraclePrice)?;
+    let conf: u64 = u64::try_from(read_i128(SWITCHBOARD_RESULT_STD_DEV_OFFSET).unsigned_abs() / scale as u128).map_err(|_| SogaNodeSaleError::InvalidOraclePrice)?;
+
+    Ok(OraclePrice {
+        price,
+        conf,
+        exponent: SWITCHBOARD_EXPONENT,
+        publish_time,
+    })
+}
+
+fn get_manual_price(price_feed: &AccountInfo) -> Result<OraclePrice> {
+    check_oracle_owner(crate::ID, *price_feed.owner)?;
+
+    let oracle_price: OraclePriceAccount = OraclePriceAccount::try_deserialize(&mut &price_feed.try_borrow_data()?[..])?;
+
+    Ok(OraclePrice {
+        price: oracle_price.price,
+        conf: oracle_price.conf,
+        exponent: oracle_price.exponent,
+        publish_time: oracle_price.publish_time,
+    })
+}
+
+fn check_oracle_owner(expected_owner: Pubkey, owner: Pubkey) -> Result<()> {
+    if expected_owner != owner {
+        return Err(SogaNodeSaleError::InvalidOracleAccount.into());
+    }
+
+    Ok(())
+}
+
+/// `10^|exponent|` as reported in events
+pub fn get_expo_base(price: &OraclePrice) -> u64 {
+    10_u64.pow(price.exponent.unsigned_abs())
+}
+
+/// Reads the price feed and the registered price sources, passed in `source_accounts`
+/// in registration order, and returns the median of the valid prices. Stale or
+/// invalid prices are skipped, fewer than `quorum` valid prices fail the read.
+/// Without registered sources only the price feed is read.
+pub fn get_quorum_oracle_price(price_feed: &AccountInfo, source_accounts: &[AccountInfo], feed_id: &str, oracle_config: &OracleConfig,
+                               price_sources: &[PriceSource], quorum: u8) -> Result<OraclePrice> {
+    if price_sources.is_empty() {
+        return get_oracle_price(price_feed, feed_id, oracle_config);
+    }
+
+    if source_accounts.len() < price_sources.len() {
+        return Err(SogaNodeSaleError::InvalidPriceFeedAddress.into());
+    }
+
+    let mut prices: Vec<OraclePrice> = Vec::with_capacity(price_sources.len() + 1);
+
+    if let Ok(price) = get_oracle_price(price_feed, feed_id, oracle_config) {
+        prices.push(price);
+    }
+
+    for (price_source, source_account) in price_sources.iter().zip(source_accounts) {
+        check_price_feed(price_source.address, source_account.key())?;
+
+        let source_oracle_config: OracleConfig = OracleConfig { source: price_source.source, ..*oracle_config };
+
+        if let Ok(price) = get_oracle_price(source_account, price_source.feed_id.as_str(), &source_oracle_config) {
+            prices.push(price);
+        }
+    }
+
+    if prices.len() < quorum.max(1) as usize {
+        return Err(SogaNodeSaleError::OracleQuorumNotReached.into());
+    }
+
+    get_median_price(&prices)
+}
+
+/// Median of `prices` rescaled to their smallest exponent. For an even count the
+/// two middle prices are averaged, keeping the wider confidence and older publish time.
+pub fn get_median_price(prices: &[OraclePrice]) -> Result<OraclePrice> {
+    let exponent: i32 = prices.iter().map(|price| price.exponent).min().ok_or(SogaNodeSaleError::OracleQuorumNotReached)?;
+
+    let mut rescaled: Vec<OraclePrice> = prices
+        .iter()
+        .map(|price| {
+            let scale: i64 = 10_i64.checked_pow((price.exponent - exponent) as u32).ok_or(SogaNodeSaleError::MathOverflow)?;
+
+            Ok(OraclePrice {
+                price: price.price.checked_mul(scale).ok_or(SogaNodeSaleError::MathOverflow)?,
+                conf: price.conf.checked_mul(scale as u64).ok_or(SogaNodeSaleError::MathOverflow)?,
+                exponent,
+                publish_time: price.publish_time,
+            })
+        })
+        .collect::<Result<Vec<OraclePrice>>>()?;
+
+    rescaled.sort_by_key(|price| price.price);
+
+    let middle: usize = rescaled.len() / 2;
+
+    if rescaled.len() % 2 == 1 {
+        return Ok(rescaled[middle]);
+    }
+
+    let low: OraclePrice = rescaled[middle - 1];
+    let high: OraclePrice = rescaled[middle];
+
+    Ok(OraclePrice {
+        price: ((low.price as i128 + high.price as i128) / 2) as i64,
+        conf: low.conf.max(high.conf),
+        exponent,
+        publish_time: low.publish_time.min(high.publish_time),
+    })
+}
+
+/// price update account of an instruction, required unless a fixed-rate token skips the oracle
+pub fn get_price_update<'a, 'info>(price_update: Option<&'a AccountInfo<'info>>) -> Result<&'a AccountInfo<'info>> {
+    price_update.ok_or(SogaNodeSaleError::PriceUpdateRequired.into())
+}
+
+/// Converts `price_in_usd` to base units of a payment token. Fixed-rate tokens
+/// only read the oracle when a depeg guard is configured. Returns the amount and
+/// the oracle price when it was read.
+pub fn get_payment_token_amount(price_in_usd: u64, payment_token_detail: &SogaNodeSalePhasePaymentTokenDetailAccount,
+                                price_update: Option<&AccountInfo>, source_accounts: &[AccountInfo]) -> Result<(u64, Option<OraclePrice>)> {
+    if payment_token_detail.is_fixed_rate && payment_token_detail.max_depeg_bps == 0 {
+        return Ok((fixed_rate_to_token_amount(price_in_usd, payment_token_detail.fixed_rate)?, None));
+    }
+
+    let price_update = get_price_update(price_update)?;
+
+    check_price_feed(payment_token_detail.price_feed_address, price_update.key())?;
+
+    let price: OraclePrice = get_quorum_oracle_price(price_update, source_accounts, payment_token_detail.price_feed_id.as_str(), &payment_token_detail.oracle_config,
+                                                     &payment_token_detail.price_sources, payment_token_detail.price_source_quorum)?;
+
+    if !payment_token_detail.is_fixed_rate {
+        return Ok((usd_to_token_amount(price_in_usd, payment_token_detail.decimals, price.price as u64, price.exponent)?, Some(price)));
+    }
+
+    // depeg guard, the oracle only decides whether the fixed rate is still usable
+    let oracle_rate: u64 = usd_to_token_amount(1, payment_token_detail.decimals, price.price as u64, price.exponent)?;
+
+    check_stablecoin_peg(peg_deviation_bps(payment_token_detail.fixed_rate, oracle_rate)?, payment_token_detail.max_depeg_bps)?;
+
+    Ok((fixed_rate_to_token_amount(price_in_usd, payment_token_detail.fixed_rate)?, Some(price)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn oracle_price(price: i64, conf: u64, exponent: i32, publish_time: i64) -> OraclePrice {
+        OraclePrice { price, conf, exponent, publish_time }
+    }
+
+    /// pull feed account of 3208 bytes laid out as `PullFeedAccountData`, with a sol/usd result of
+    /// 150.12345678 and a std dev of 0.05
+    fn switchboard_pull_feed_data() -> Vec<u8> {
+        let mut data: Vec<u8> = vec![0; 3208];
+        data[..8].copy_from_slice(&SWITCHBOARD_PULL_FEED_DISCRIMINATOR);
+
+        // submissions of 32 oracles, 64 bytes each, the last field is the submitted value
+        for index in 0..32 {
+            let offset: usize = 8 + index * 64;
+            data[offset..offset + 32].copy_from_slice(&[index as u8 + 1; 32]);
+            data[offset + 48..offset + 64].copy_from_slice(&150_100_000_000_000_000_000_i128.to_le_bytes());
+        }
+
+        // authority, queue and feed hash
+        data[2056..2152].copy_from_slice(&[7; 96]);
+
+        data[SWITCHBOARD_LAST_UPDATE_TIMESTAMP_OFFSET..SWITCHBOARD_LAST_UPDATE_TIMESTAMP_OFFSET + 8].copy_from_slice(&1_718_000_000_i64.to_le_bytes());
+        data[SWITCHBOARD_RESULT_VALUE_OFFSET..SWITCHBOARD_RESULT_VALUE_OFFSET + 16].copy_from_slice(&150_123_456_780_000_000_000_i128.to_le_bytes());
+        data[SWITCHBOARD_RESULT_STD_DEV_OFFSET..SWITCHBOARD_RESULT_STD_DEV_OFFSET + 16].copy_from_slice(&50_000_000_000_000_000_i128.to_le_bytes());
+
+        // mean of the result
+        data[2296..2312].copy_from_slice(&150_120_000_000_000_000_000_i128.to_le_bytes());
+
+        data
+    }
+
+    #[test]
+    fn switchboard_pull_feed_result() {
+        assert_eq!(read_switchboard_price(&switchboard_pull_feed_data()).unwrap(), oracle_price(15012345678, 5000000, -8, 1_718_000_000));
+    }
+
+    #[test]
+    fn switchboard_account_with_another_discriminator_fails() {
+        let mut data: Vec<u8> = switchboard_pull_feed_data();
+        // discriminator of a switchboard `OracleAccountData`
+        data[..8].copy_from_slice(&[128, 30, 16, 241, 170, 73, 55, 54]);
+
+        assert!(read_switchboard_price(&data).is_err());
+    }
+
+    #[test]
+    fn truncated_switchboard_account_fails() {
+        let data: Vec<u8> = switchboard_pull_feed_data();
+
+        assert!(read_switchboard_price(&data[..SWITCHBOARD_RESULT_STD_DEV_OFFSET]).is_err());
+    }
+
+    #[test]
+    fn median_of_odd_count() {
+        let prices = [
+            oracle_price(15100000000, 100, -8, 10),
+            oracle_price(9000000000, 50, -8, 11),
+            oracle_price(15000000000, 200, -8, 12),
+        ];
+
+        assert_eq!(get_median_price(&prices).unwrap(), oracle_price(15000000000, 200, -8, 12));
+    }
+
+    #[test]
+    fn median_of_even_count() {
+        let prices = [
+            oracle_price(15000000000, 100, -8, 10),
+            oracle_price(15200000000, 300, -8, 12),
+        ];
+
+        assert_eq!(get_median_price(&prices).unwrap(), oracle_price(15100000000, 300, -8, 10));
+    }
+
+    #[test]
+    fn median_rescales_exponents() {
+        let prices = [
+            oracle_price(150, 1, 0, 10),
+            oracle_price(15050000000, 100, -8, 11),
+            oracle_price(1510000, 10, -4, 12),
+        ];
+
+        assert_eq!(get_median_price(&prices).unwrap(), oracle_price(15050000000, 100, -8, 11));
+    }
+
+    #[test]
+    fn median_of_nothing_fails() {
+        assert!(get_median_price(&[]).is_err());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,423 @@
+use anchor_lang::prelude::*;
+
+use crate::error::SogaNodeSaleError;
+
+use crate::states::{PriceSchedule, PriceCurve, VolumeBracket};
+
+pub const BPS_DENOMINATOR: u64 = 10000;
+
+pub const SOL_DECIMALS: u8 = 9;
+
+/// fixed point scale of exponential curve multipliers
+const CURVE_SCALE: u128 = 1_000_000_000_000;
+
+// Rounding policy: amounts the buyer pays are rounded up so the sale never
+// receives less than the usd price, discounts and commissions taken out of a
+// payment are rounded down. All intermediate math is done in u128.
+
+/// Converts `price_in_usd` to the smallest unit of a token with `decimals`,
+/// given an oracle price of `oracle_price * 10^oracle_exponent` usd per token.
+/// The result is rounded up.
+pub fn usd_to_token_amount(price_in_usd: u64, decimals: u8, oracle_price: u64, oracle_exponent: i32) -> Result<u64> {
+    if oracle_price == 0 {
+        return Err(SogaNodeSaleError::InvalidOraclePrice.into());
+    }
+
+    let decimal_base: u128 = 10_u128.checked_pow(decimals as u32).ok_or(SogaNodeSaleError::MathOverflow)?;
+    let expo_base: u128 = 10_u128.checked_pow(oracle_exponent.unsigned_abs()).ok_or(SogaNodeSaleError::MathOverflow)?;
+
+    let (numerator, denominator) = if oracle_exponent < 0 {
+        (
+            (price_in_usd as u128).checked_mul(decimal_base).and_then(|v| v.checked_mul(expo_base)),
+            Some(oracle_price as u128),
+        )
+    } else {
+        (
+            (price_in_usd as u128).checked_mul(decimal_base),
+            (oracle_price as u128).checked_mul(expo_base),
+        )
+    };
+
+    let amount: u128 = numerator
+        .zip(denominator)
+        .filter(|(_, denominator)| *denominator != 0)
+        .map(|(numerator, denominator)| numerator.div_ceil(denominator))
+        .ok_or(SogaNodeSaleError::MathOverflow)?;
+
+    Ok(u64::try_from(amount).map_err(|_| SogaNodeSaleError::MathOverflow)?)
+}
+
+/// Usd value of `amount` in the smallest unit of a token with `decimals`, given an
+/// oracle price of `oracle_price * 10^oracle_exponent` usd per token. The result is
+/// rounded down, it is only used to count payments toward the phase caps.
+pub fn token_amount_to_usd(amount: u64, decimals: u8, oracle_price: u64, oracle_exponent: i32) -> Result<u64> {
+    let decimal_base: u128 = 10_u128.checked_pow(decimals as u32).ok_or(SogaNodeSaleError::MathOverflow)?;
+    let expo_base: u128 = 10_u128.checked_pow(oracle_exponent.unsigned_abs()).ok_or(SogaNodeSaleError::MathOverflow)?;
+
+    let (numerator, denominator) = if oracle_exponent < 0 {
+        (
+            (amount as u128).checked_mul(oracle_price as u128),
+            decimal_base.checked_mul(expo_base),
+        )
+    } else {
+        (
+            (amount as u128).checked_mul(oracle_price as u128).and_then(|v| v.checked_mul(expo_base)),
+            Some(decimal_base),
+        )
+    };
+
+    let value: u128 = numerator
+        .zip(denominator)
+        .map(|(numerator, denominator)| numerator / denominator)
+        .ok_or(SogaNodeSaleError::MathOverflow)?;
+
+    Ok(u64::try_from(value).map_err(|_| SogaNodeSaleError::MathOverflow)?)
+}
+
+/// `bps` basis points of `amount`, rounded down.
+pub fn bps_of(amount: u64, bps: u16) -> Result<u64> {
+    let value: u128 = (amount as u128) * (bps as u128) / (BPS_DENOMINATOR as u128);
+
+    Ok(u64::try_from(value).map_err(|_| SogaNodeSaleError::MathOverflow)?)
+}
+
+/// Amount of a fixed-rate token with `fixed_rate` base units per usd.
+pub fn fixed_rate_to_token_amount(price_in_usd: u64, fixed_rate: u64) -> Result<u64> {
+    Ok(price_in_usd.checked_mul(fixed_rate).ok_or(SogaNodeSaleError::MathOverflow)?)
+}
+
+/// Deviation in basis points of `oracle_rate` from `fixed_rate`, both in base
+/// units per usd. Rounded up so a guard never accepts a wider deviation.
+pub fn peg_deviation_bps(fixed_rate: u64, oracle_rate: u64) -> Result<u64> {
+    let difference: u128 = (fixed_rate as u128).abs_diff(oracle_rate as u128);
+
+    let deviation: u128 = (fixed_rate != 0)
+        .then(|| (difference * (BPS_DENOMINATOR as u128)).div_ceil(fixed_rate as u128))
+        .ok_or(SogaNodeSaleError::InvalidFixedRate)?;
+
+    Ok(u64::try_from(deviation).map_err(|_| SogaNodeSaleError::MathOverflow)?)
+}
+
+/// Usd price of `schedule` at `timestamp`, `base_price` before the schedule starts.
+/// Linear ramps are rounded up.
+pub fn get_scheduled_price(schedule: &PriceSchedule, base_price: u64, timestamp: i64) -> Result<u64> {
+    match schedule {
+        PriceSchedule::None => Ok(base_price),
+        PriceSchedule::Steps(steps) => Ok(steps
+            .iter()
+            .rev()
+            .find(|step| step.start_timestamp <= timestamp)
+            .map_or(base_price, |step| step.price)),
+        PriceSchedule::LinearRamp { start_timestamp, end_timestamp, start_price, end_price } => {
+            if timestamp < *start_timestamp {
+                return Ok(base_price);
+            }
+
+            if timestamp >= *end_timestamp {
+                return Ok(*end_price);
+            }
+
+            let elapsed: i128 = (timestamp - start_timestamp) as i128;
+            let duration: i128 = (end_timestamp - start_timestamp) as i128;
+            let numerator: i128 = (*end_price as i128 - *start_price as i128) * elapsed;
+
+            let mut change: i128 = numerator.div_euclid(duration);
+
+            if numerator.rem_euclid(duration) != 0 {
+                change += 1;
+            }
+
+            Ok(u64::try_from(*start_price as i128 + change).map_err(|_| SogaNodeSaleError::MathOverflow)?)
+        }
+    }
+}
+
+/// Usd price of each of the next `quantity` nodes of a tier with `total_mint`
+/// nodes minted, rounded up per node.
+pub fn get_curve_unit_prices(curve: &PriceCurve, base_price: u64, total_mint: u64, quantity: u64) -> Result<Vec<u64>> {
+    (total_mint..total_mint.checked_add(quantity).ok_or(SogaNodeSaleError::MathOverflow)?)
+        .map(|index| {
+            let price: Option<u128> = match curve {
+                PriceCurve::None => Some(base_price as u128),
+                PriceCurve::Linear { increment } => (*increment as u128)
+                    .checked_mul(index as u128)
+                    .and_then(|value| value.checked_add(base_price as u128)),
+                PriceCurve::Exponential { growth_bps } => get_growth_multiplier(*growth_bps, index)
+                    .and_then(|multiplier| multiplier.checked_mul(base_price as u128))
+                    .map(|value| value.div_ceil(CURVE_SCALE)),
+            };
+
+            price
+                .and_then(|price| u64::try_from(price).ok())
+                .ok_or(SogaNodeSaleError::MathOverflow.into())
+        })
+        .collect()
+}
+
+/// Sum of usd unit prices.
+pub fn get_total_price(unit_prices: &[u64]) -> Result<u64> {
+    Ok(unit_prices
+        .iter()
+        .try_fold(0_u64, |total, price| total.checked_add(*price))
+        .ok_or(SogaNodeSaleError::MathOverflow)?)
+}
+
+/// `(1 + growth_bps / 10000)^exponent` scaled by `CURVE_SCALE`
+fn get_growth_multiplier(growth_bps: u16, exponent: u64) -> Option<u128> {
+    let mut multiplier: u128 = CURVE_SCALE;
+    let mut base: u128 = CURVE_SCALE * (BPS_DENOMINATOR as u128 + growth_bps as u128) / BPS_DENOMINATOR as u128;
+    let mut exponent: u64 = exponent;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            multiplier = multiplier.checked_mul(base)? / CURVE_SCALE;
+        }
+
+        exponent >>= 1;
+
+        if exponent > 0 {
+            base = base.checked_mul(base)? / CURVE_SCALE;
+        }
+    }
+
+    Some(multiplier)
+}
+
+/// Part of `receiver_amount` escrowed by a rebate auction, the share of the
+/// payment above the floor price. Rounded down.
+pub fn get_auction_escrow_amount(receiver_amount: u64, price_in_usd: u64, floor_price_in_usd: u64) -> Result<u64> {
+    if price_in_usd <= floor_price_in_usd {
+        return Ok(0);
+    }
+
+    let amount: u128 = (receiver_amount as u128) * ((price_in_usd - floor_price_in_usd) as u128) / (price_in_usd as u128);
+
+    Ok(u64::try_from(amount).map_err(|_| SogaNodeSaleError::MathOverflow)?)
+}
+
+/// Part of `escrow_amount` rebated to the buyer, the share of the payment above
+/// the clearing price. Rounded down, the rest goes to the payment receiver.
+pub fn get_auction_rebate_amount(escrow_amount: u64, price_in_usd: u64, floor_price_in_usd: u64, clearing_price_in_usd: u64) -> Result<u64> {
+    if price_in_usd <= floor_price_in_usd || price_in_usd <= clearing_price_in_usd {
+        return Ok(0);
+    }
+
+    let clearing_price_in_usd: u64 = clearing_price_in_usd.max(floor_price_in_usd);
+
+    let amount: u128 = (escrow_amount as u128) * ((price_in_usd - clearing_price_in_usd) as u128) / ((price_in_usd - floor_price_in_usd) as u128);
+
+    Ok(u64::try_from(amount).map_err(|_| SogaNodeSaleError::MathOverflow)?)
+}
+
+/// Part of `price_in_usd` of an installment order paid so far, in proportion of
+/// `installment_paid_in_usd` to `installment_owed_in_usd`. Rounded down, the full
+/// price once the order is paid.
+pub fn get_paid_price_in_usd(price_in_usd: u64, installment_paid_in_usd: u64, installment_owed_in_usd: u64) -> Result<u64> {
+    if installment_paid_in_usd >= installment_owed_in_usd {
+        return Ok(price_in_usd);
+    }
+
+    let amount: u128 = (price_in_usd as u128) * (installment_paid_in_usd as u128) / (installment_owed_in_usd as u128);
+
+    Ok(u64::try_from(amount).map_err(|_| SogaNodeSaleError::MathOverflow)?)
+}
+
+/// Discount of the highest bracket reached by `quantity` nodes, 0 below the
+/// first bracket. Brackets are ordered by `min_quantity`.
+pub fn get_volume_discount(brackets: &[VolumeBracket], quantity: u64) -> u16 {
+    brackets.iter()
+        .rev()
+        .find(|bracket| quantity >= bracket.min_quantity)
+        .map_or(0, |bracket| bracket.discount)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sol_amount_is_rounded_up() {
+        // 100 usd at 150.12345678 usd per sol = 666118421.0975... lamports
+        assert_eq!(usd_to_token_amount(100, SOL_DECIMALS, 15012345678, -8).unwrap(), 666118422);
+
+        // the previous u64 formula truncated the per sol price before multiplying
+        let truncated: u64 = 1_000_000_000 * 100_000_000 / 15012345678 * 100;
+        assert_eq!(truncated, 666118400);
+    }
+
+    #[test]
+    fn sol_amount_exact() {
+        // 1000 usd at 200 usd per sol = 5 sol
+        assert_eq!(usd_to_token_amount(1000, SOL_DECIMALS, 20000000000, -8).unwrap(), 5_000_000_000);
+    }
+
+    #[test]
+    fn six_decimal_token_amount() {
+        // 100 usd at 0.9999 usd per token = 100.010001000100... tokens
+        assert_eq!(usd_to_token_amount(100, 6, 99990000, -8).unwrap(), 100010002);
+
+        // 250 usd at 1 usd per token
+        assert_eq!(usd_to_token_amount(250, 6, 100000000, -8).unwrap(), 250_000_000);
+    }
+
+    #[test]
+    fn nine_decimal_token_amount() {
+        // 3 usd at 2.5 usd per token = 1.2 tokens
+        assert_eq!(usd_to_token_amount(3, 9, 250000000, -8).unwrap(), 1_200_000_000);
+
+        // 1 usd at 3 usd per token = 0.333333333.. tokens
+        assert_eq!(usd_to_token_amount(1, 9, 300000, -5).unwrap(), 333333334);
+    }
+
+    #[test]
+    fn non_negative_exponent() {
+        // 30 usd at 2 * 10^1 usd per token = 1.5 tokens
+        assert_eq!(usd_to_token_amount(30, 6, 2, 1).unwrap(), 1_500_000);
+
+        // 7 usd at 3 usd per token with exponent 0
+        assert_eq!(usd_to_token_amount(7, 0, 3, 0).unwrap(), 3);
+    }
+
+    #[test]
+    fn invalid_inputs_are_rejected() {
+        assert!(usd_to_token_amount(100, SOL_DECIMALS, 0, -8).is_err());
+        assert!(usd_to_token_amount(u64::MAX, SOL_DECIMALS, 1, -8).is_err());
+        assert!(usd_to_token_amount(1, SOL_DECIMALS, 1, -40).is_err());
+    }
+
+    #[test]
+    fn usd_value_is_rounded_down() {
+        // 5 sol at 200 usd per sol
+        assert_eq!(token_amount_to_usd(5_000_000_000, SOL_DECIMALS, 20000000000, -8).unwrap(), 1000);
+
+        // 666118422 lamports at 150.12345678 usd per sol = 100.0000000... usd
+        assert_eq!(token_amount_to_usd(666118422, SOL_DECIMALS, 15012345678, -8).unwrap(), 100);
+
+        // 1.5 tokens at 2 * 10^1 usd per token
+        assert_eq!(token_amount_to_usd(1_500_000, 6, 2, 1).unwrap(), 30);
+
+        assert!(token_amount_to_usd(1, SOL_DECIMALS, 1, -40).is_err());
+    }
+
+    #[test]
+    fn paid_price_of_installments() {
+        // a 20% deposit of an order discounted from 1000 to 900 usd
+        assert_eq!(get_paid_price_in_usd(1000, 180, 900).unwrap(), 200);
+
+        // 333 of 900 usd paid = 370 usd of the price
+        assert_eq!(get_paid_price_in_usd(1000, 333, 900).unwrap(), 370);
+
+        assert_eq!(get_paid_price_in_usd(1000, 900, 900).unwrap(), 1000);
+        assert_eq!(get_paid_price_in_usd(1000, 0, 0).unwrap(), 1000);
+    }
+
+    #[test]
+    fn fixed_rate_amount() {
+        // 250 usd of a 6 decimal stablecoin pegged 1:1
+        assert_eq!(fixed_rate_to_token_amount(250, 1_000_000).unwrap(), 250_000_000);
+        assert!(fixed_rate_to_token_amount(u64::MAX, 2).is_err());
+    }
+
+    #[test]
+    fn peg_deviation() {
+        // 0.9999 usd per token is 1000100.01 base units per usd
+        let oracle_rate: u64 = usd_to_token_amount(1, 6, 99990000, -8).unwrap();
+        assert_eq!(oracle_rate, 1000101);
+        assert_eq!(peg_deviation_bps(1_000_000, oracle_rate).unwrap(), 2);
+
+        // 1.05 usd per token
+        let oracle_rate: u64 = usd_to_token_amount(1, 6, 105000000, -8).unwrap();
+        assert_eq!(peg_deviation_bps(1_000_000, oracle_rate).unwrap(), 477);
+
+        assert_eq!(peg_deviation_bps(1_000_000, 1_000_000).unwrap(), 0);
+        assert!(peg_deviation_bps(0, 1_000_000).is_err());
+    }
+
+    #[test]
+    fn scheduled_price_steps() {
+        use crate::states::PriceStep;
+
+        let schedule = PriceSchedule::Steps(vec![
+            PriceStep { start_timestamp: 100, price: 500 },
+            PriceStep { start_timestamp: 200, price: 600 },
+        ]);
+
+        assert_eq!(get_scheduled_price(&schedule, 400, 99).unwrap(), 400);
+        assert_eq!(get_scheduled_price(&schedule, 400, 100).unwrap(), 500);
+        assert_eq!(get_scheduled_price(&schedule, 400, 199).unwrap(), 500);
+        assert_eq!(get_scheduled_price(&schedule, 400, 200).unwrap(), 600);
+        assert_eq!(get_scheduled_price(&PriceSchedule::None, 400, 200).unwrap(), 400);
+    }
+
+    #[test]
+    fn scheduled_price_linear_ramp() {
+        let schedule = PriceSchedule::LinearRamp { start_timestamp: 100, end_timestamp: 400, start_price: 1000, end_price: 2000 };
+
+        assert_eq!(get_scheduled_price(&schedule, 900, 99).unwrap(), 900);
+        assert_eq!(get_scheduled_price(&schedule, 900, 100).unwrap(), 1000);
+        assert_eq!(get_scheduled_price(&schedule, 900, 101).unwrap(), 1004);
+        assert_eq!(get_scheduled_price(&schedule, 900, 250).unwrap(), 1500);
+        assert_eq!(get_scheduled_price(&schedule, 900, 400).unwrap(), 2000);
+        assert_eq!(get_scheduled_price(&schedule, 900, 1000).unwrap(), 2000);
+
+        let schedule = PriceSchedule::LinearRamp { start_timestamp: 100, end_timestamp: 400, start_price: 2000, end_price: 1000 };
+
+        assert_eq!(get_scheduled_price(&schedule, 900, 101).unwrap(), 1997);
+        assert_eq!(get_scheduled_price(&schedule, 900, 250).unwrap(), 1500);
+    }
+
+    #[test]
+    fn curve_unit_prices() {
+        assert_eq!(get_curve_unit_prices(&PriceCurve::None, 100, 5, 3).unwrap(), vec![100, 100, 100]);
+        assert_eq!(get_curve_unit_prices(&PriceCurve::Linear { increment: 10 }, 100, 5, 3).unwrap(), vec![150, 160, 170]);
+        assert_eq!(get_curve_unit_prices(&PriceCurve::Exponential { growth_bps: 1000 }, 100, 0, 4).unwrap(), vec![100, 110, 121, 134]);
+        assert_eq!(get_curve_unit_prices(&PriceCurve::Exponential { growth_bps: 100 }, 1000, 100, 1).unwrap(), vec![2705]);
+        assert!(get_curve_unit_prices(&PriceCurve::Exponential { growth_bps: 10000 }, 1000, 200, 1).is_err());
+        assert!(get_curve_unit_prices(&PriceCurve::None, 100, 5, 0).unwrap().is_empty());
+
+        assert_eq!(get_total_price(&[150, 160, 170]).unwrap(), 480);
+        assert!(get_total_price(&[u64::MAX, 1]).is_err());
+    }
+
+    #[test]
+    fn auction_escrow_and_rebate() {
+        // 2 nodes bought at 1000 usd with a 400 usd floor, 1_000_000 paid to the receiver
+        let escrow: u64 = get_auction_escrow_amount(1_000_000, 2000, 800).unwrap();
+        assert_eq!(escrow, 600_000);
+
+        // clearing at 700 usd per node
+        assert_eq!(get_auction_rebate_amount(escrow, 2000, 800, 1400).unwrap(), 300_000);
+
+        // clearing at the floor rebates the whole escrow, at the paid price nothing
+        assert_eq!(get_auction_rebate_amount(escrow, 2000, 800, 800).unwrap(), 600_000);
+        assert_eq!(get_auction_rebate_amount(escrow, 2000, 800, 2000).unwrap(), 0);
+
+        // paid at the floor
+        assert_eq!(get_auction_escrow_amount(1_000_000, 800, 800).unwrap(), 0);
+        assert_eq!(get_auction_rebate_amount(0, 800, 800, 800).unwrap(), 0);
+    }
+
+    #[test]
+    fn volume_discount_brackets() {
+        let brackets: Vec<VolumeBracket> = vec![
+            VolumeBracket { min_quantity: 5, discount: 300 },
+            VolumeBracket { min_quantity: 10, discount: 500 },
+        ];
+
+        assert_eq!(get_volume_discount(&brackets, 4), 0);
+        assert_eq!(get_volume_discount(&brackets, 5), 300);
+        assert_eq!(get_volume_discount(&brackets, 9), 300);
+        assert_eq!(get_volume_discount(&brackets, 10), 500);
+        assert_eq!(get_volume_discount(&brackets, 100), 500);
+        assert_eq!(get_volume_discount(&[], 100), 0);
+    }
+
+    #[test]
+    fn bps_of_is_rounded_down() {
+        assert_eq!(bps_of(666118422, 10000).unwrap(), 666118422);
+        assert_eq!(bps_of(666118422, 500).unwrap(), 33305921);
+        assert_eq!(bps_of(999, 333).unwrap(), 33);
+        assert_eq!(bps_of(u64::MAX, 10000).unwrap(), u64::MAX);
+        assert_eq!(bps_of(u64::MAX, 0).unwrap(), 0);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+pub const AIRDROP_CLAIM_BITMAP_ACCOUNT_PREFIX: &str = "AIRDROP_CLAIM";
+
+/// claimed entries of an airdrop snapshot, one bit per entry
+#[account]
+pub struct AirdropClaimBitmapAccount {
+    /// timestamp when account updated
+    pub last_block_timestamp: i64,
+
+    pub merkle_root: [u8; 32],
+
+    pub total_claims: u32,
+
+    pub total_claimed: u32,
+
+    pub claimed: Vec<u8>,
+}
+
+impl AirdropClaimBitmapAccount {
+    pub fn space(total_claims: u32) -> usize {
+        8 // default
+            + 8 // last_block_timestamp
+            + 32 // merkle_root
+            + 4 // total_claims
+            + 4 // total_claimed
+            + 4 + Self::bitmap_len(total_claims) // claimed
+    }
+
+    pub fn bitmap_len(total_claims: u32) -> usize {
+        (total_claims as usize).div_ceil(8)
+    }
+
+    pub fn is_claimed(&self, index: u32) -> bool {
+        self.claimed[index as usize / 8] & (1 << (index % 8)) != 0
+    }
+
+    pub fn set_claimed(&mut self, index: u32) {
+        self.claimed[index as usize / 8] |= 1 << (index % 8);
+    }
+}
\ No newline at end of file
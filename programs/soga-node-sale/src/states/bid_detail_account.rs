@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+pub const BID_DETAIL_ACCOUNT_PREFIX: &str = "BID";
+
+/// sealed bid of a user for one node of an auctioned tier
+#[account]
+pub struct BidDetailAccount {
+    /// timestamp when account updated
+    pub last_block_timestamp: i64,
+
+    pub user: Pubkey,
+
+    pub tier_id: u32,
+
+    /// hash of the bid price, the salt and the user
+    pub bid_hash: [u8; 32],
+
+    /// lamports escrowed with the bid, at least the bid price
+    pub deposit: u64,
+
+    pub is_revealed: bool,
+
+    /// bid price in lamports per node, set when revealed
+    pub price: u64,
+
+    pub is_settled: bool,
+
+    /// order created for a winning bid
+    pub order_id: u64,
+}
+
+impl BidDetailAccount {
+    pub fn space() -> usize {
+        8 // default
+            + 8 // last_block_timestamp
+            + 32 // user
+            + 4 // tier_id
+            + 32 // bid_hash
+            + 8 // deposit
+            + 1 // is_revealed
+            + 8 // price
+            + 1 // is_settled
+            + 8 // order_id
+    }
+}
\ No newline at end of file
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+pub const COMMITMENT_DETAIL_ACCOUNT_PREFIX: &str = "COMMITMENT";
+
+pub const MAX_TICKET_RANGES: usize = 8;
+
+/// positions `start..start + quantity` of committed nodes among all commitments of a tier
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TicketRange {
+    pub start: u64,
+
+    pub quantity: u64,
+}
+
+/// deposit of a user against a fair launch tier
+#[account]
+pub struct CommitmentDetailAccount {
+    /// timestamp when account updated
+    pub last_block_timestamp: i64,
+
+    pub user: Pubkey,
+
+    pub tier_id: u32,
+
+    /// nodes requested
+    pub quantity: u64,
+
+    pub total_payment_in_usd: u64,
+
+    /// deposit in lamports or payment token units
+    pub total_payment: u64,
+
+    pub payment_token_mint_account: Option<Pubkey>,
+
+    pub is_allocated: bool,
+
+    pub allocated_quantity: u64,
+
+    /// order created for the allocated nodes
+    pub order_id: u64,
+
+    /// part of the deposit not spent on allocated nodes
+    pub refund_amount: u64,
+
+    pub is_refunded: bool,
+
+    /// positions of the committed nodes, adjacent commits share a range
+    pub ticket_ranges: Vec<TicketRange>,
+}
+
+impl CommitmentDetailAccount {
+    pub fn space() -> usize {
+        8 // default
+            + 8 // last_block_timestamp
+            + 32 // user
+            + 4 // tier_id
+            + 8 // quantity
+            + 8 // total_payment_in_usd
+            + 8 // total_payment
+            + 33 // payment_token_mint_account
+            + 1 // is_allocated
+            + 8 // allocated_quantity
+            + 8 // order_id
+            + 8 // refund_amount
+            + 1 // is_refunded
+            + 4 + MAX_TICKET_RANGES * (8 + 8) // ticket_ranges
+    }
+
+    /// nodes committed at position `start` extend the last range
+    pub fn is_adjacent(&self, start: u64) -> bool {
+        self.ticket_ranges.last().is_some_and(|ticket_range| ticket_range.start + ticket_range.quantity == start)
+    }
+
+    /// adds the nodes committed at position `start`, merged into the last range when adjacent
+    pub fn add_tickets(&mut self, start: u64, quantity: u64) {
+        if self.is_adjacent(start) {
+            self.ticket_ranges.last_mut().unwrap().quantity += quantity;
+        } else {
+            self.ticket_ranges.push(TicketRange { start, quantity });
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+pub const FILL_FEE_VAULT_ACCOUNT_PREFIX: &str = "FILL_FEE_VAULT";
+
+/// lamports of the phase paying the fill reward of permissionless fills
+#[account]
+pub struct FillFeeVaultAccount {
+    /// timestamp when account updated
+    pub last_block_timestamp: i64,
+
+    pub total_funded: u64,
+
+    pub total_rewarded: u64,
+
+    pub total_withdrawn: u64,
+}
+
+impl FillFeeVaultAccount {
+    pub fn space() -> usize {
+        8 // default
+            + 8 // last_block_timestamp
+            + 8 // total_funded
+            + 8 // total_rewarded
+            + 8 // total_withdrawn
+    }
+}
\ No newline at end of file
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+pub const LOYALTY_DETAIL_ACCOUNT_PREFIX: &str = "LOYALTY";
+
+/// use of a loyalty node in a phase, each node grants the loyalty discount on one order
+#[account]
+pub struct LoyaltyDetailAccount {
+    /// timestamp when account updated
+    pub last_block_timestamp: i64,
+
+    pub is_used: bool,
+
+    /// user of the discounted order
+    pub user: Pubkey,
+
+    pub order_id: u64,
+}
+
+impl LoyaltyDetailAccount {
+    pub fn space() -> usize {
+        8 // default
+            + 8 // last_block_timestamp
+            + 1 // is_used
+            + 32 // user
+            + 8 // order_id
+    }
+}
\ No newline at end of file
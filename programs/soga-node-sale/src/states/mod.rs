@@ -7,6 +7,16 @@ pub use user_tier_detail_account::*;
 pub use order_detail_account::*;
 pub use collection_account::*;
 pub use node_account::*;
+pub use oracle_price_account::*;
+pub use commitment_detail_account::*;
+pub use bid_detail_account::*;
+pub use reservation_detail_account::*;
+pub use airdrop_claim_bitmap_account::*;
+pub use fill_fee_vault_account::*;
+pub use loyalty_detail_account::*;
+
+/// bumped with every layout change of an existing account, see `realloc_account`
+pub const ACCOUNT_LAYOUT_VERSION: u8 = 4;
 
 
 pub mod soga_node_sale_config_account;
@@ -18,3 +28,10 @@ pub mod user_tier_detail_account;
 pub mod order_detail_account;
 pub mod collection_account;
 pub mod node_account;
+pub mod oracle_price_account;
+pub mod commitment_detail_account;
+pub mod bid_detail_account;
+pub mod reservation_detail_account;
+pub mod airdrop_claim_bitmap_account;
+pub mod fill_fee_vault_account;
+pub mod loyalty_detail_account;
\ No newline at end of file
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+pub const ORACLE_PRICE_ACCOUNT_PREFIX: &str = "ORACLE_PRICE";
+
+/// admin controlled price used by the manual oracle source
+#[account]
+pub struct OraclePriceAccount {
+    /// timestamp when account updated
+    pub last_block_timestamp: i64,
+
+    /// authority allowed to update the price
+    pub authority: Pubkey,
+
+    pub name: String,
+
+    /// price in usd is `price * 10^exponent`
+    pub price: i64,
+
+    pub conf: u64,
+
+    pub exponent: i32,
+
+    /// timestamp when price updated
+    pub publish_time: i64,
+}
+
+impl OraclePriceAccount {
+    pub fn space() -> usize {
+        8 // default
+            + 8 // last_block_timestamp
+            + 32 // authority
+            + 20 // name
+            + 8 // price
+            + 8 // conf
+            + 4 // exponent
+            + 8 // publish_time
+    }
+}
\ No newline at end of file
@@ -32,6 +32,64 @@ pub struct OrderDetailAccount {
     pub payment_token_mint_account: Option<Pubkey>,
 
     pub is_whitelist: bool,
+
+    pub is_token_ids_revoked: Vec<bool>,
+
+    /// payment escrowed by a rebate dutch auction, in lamports or payment token units
+    pub auction_escrow_amount: u64,
+
+    pub is_auction_settled: bool,
+
+    /// payment escrowed until the phase soft cap is reached and installment orders are paid in full,
+    /// in lamports or payment token units
+    pub cap_escrow_amount: u64,
+
+    pub is_cap_settled: bool,
+
+    /// refunded because the phase missed its soft cap, the order can not be filled
+    pub is_voided: bool,
+
+    pub is_installment: bool,
+
+    /// price after the user discount owed by an installment order
+    pub installment_owed_in_usd: u64,
+
+    pub installment_paid_in_usd: u64,
+
+    /// the balance has to be paid before this timestamp or the order lapses
+    pub installment_deadline: i64,
+
+    pub is_installment_lapsed: bool,
+
+    /// volume discount in basis points of the tier bracket reached by the order
+    pub volume_discount: u16,
+
+    pub total_volume_discount_in_usd: u64,
+
+    pub total_volume_discount: u64,
+
+    /// loyalty discount in basis points of a node held from an earlier phase
+    pub loyalty_discount: u16,
+
+    pub total_loyalty_discount_in_usd: u64,
+
+    pub total_loyalty_discount: u64,
+
+    /// referral shares held with `cap_escrow_amount` are paid to these receivers when the escrow is
+    /// released, wallets of sol orders and token accounts of payment token orders
+    pub full_discount_receiver: Pubkey,
+
+    pub full_discount_escrow_amount: u64,
+
+    pub half_discount_receiver: Pubkey,
+
+    pub half_discount_escrow_amount: u64,
+
+    /// exchanged for a node of a higher tier by `upgrade_node`
+    pub is_token_ids_upgraded: Vec<bool>,
+
+    /// minted again to a new order by `reissue_node` after being revoked
+    pub is_token_ids_reissued: Vec<bool>,
 }
 
 impl OrderDetailAccount {
@@ -41,7 +99,7 @@ impl OrderDetailAccount {
             + 4 // tier_id
             + 1 // is_completed
             + 8 + (quantity as usize * 8)  // token_ids
-            + 8 + (quantity as usize * 1)  // is_token_ids_minted
+            + 8 + quantity as usize  // is_token_ids_minted
             + 8 // quantity
             + 8 // total_payment_in_usd
             + 8 // total_discount_in_usd
@@ -50,5 +108,71 @@ impl OrderDetailAccount {
             + 8 // total_discount
             + 8 // total_user_discount
             + 33 // payment_token_mint_account
+            + 1 // is_whitelist
+            + 4 + quantity as usize // is_token_ids_revoked
+            + 8 // auction_escrow_amount
+            + 1 // is_auction_settled
+            + 8 // cap_escrow_amount
+            + 1 // is_cap_settled
+            + 1 // is_voided
+            + 1 // is_installment
+            + 8 // installment_owed_in_usd
+            + 8 // installment_paid_in_usd
+            + 8 // installment_deadline
+            + 1 // is_installment_lapsed
+            + 2 // volume_discount
+            + 8 // total_volume_discount_in_usd
+            + 8 // total_volume_discount
+            + 2 // loyalty_discount
+            + 8 // total_loyalty_discount_in_usd
+            + 8 // total_loyalty_discount
+            + 32 // full_discount_receiver
+            + 8 // full_discount_escrow_amount
+            + 32 // half_discount_receiver
+            + 8 // half_discount_escrow_amount
+            + 4 + quantity as usize // is_token_ids_upgraded
+            + 4 + quantity as usize // is_token_ids_reissued
+    }
+
+    /// every token id is either minted, revoked or upgraded
+    pub fn is_filled(&self) -> bool {
+        self.is_token_ids_minted.iter()
+            .enumerate()
+            .all(|(index, is_minted)| *is_minted || self.is_token_id_revoked(index) || self.is_token_id_upgraded(index))
+    }
+
+    /// orders created before node revocation carry no revoked flags until `realloc_account` adds them
+    pub fn is_token_id_revoked(&self, index: usize) -> bool {
+        self.is_token_ids_revoked.get(index).copied().unwrap_or(false)
+    }
+
+    /// orders created before node upgrades carry no upgraded or reissued flags until `realloc_account`
+    /// adds them
+    pub fn is_token_id_upgraded(&self, index: usize) -> bool {
+        self.is_token_ids_upgraded.get(index).copied().unwrap_or(false)
+    }
+
+    pub fn is_token_id_reissued(&self, index: usize) -> bool {
+        self.is_token_ids_reissued.get(index).copied().unwrap_or(false)
+    }
+
+    /// token id flags present for every token id, fewer than the token ids on an outdated layout
+    pub fn token_flags_len(&self) -> usize {
+        self.is_token_ids_revoked.len()
+            .min(self.is_token_ids_upgraded.len())
+            .min(self.is_token_ids_reissued.len())
+    }
+
+    /// usd price paid for each token id after the user discount
+    pub fn paid_unit_price_in_usd(&self) -> u64 {
+        self.total_user_discount_in_usd.checked_div(self.quantity).unwrap_or(0)
+    }
+
+    pub fn is_installment_paid(&self) -> bool {
+        !self.is_installment || self.installment_paid_in_usd >= self.installment_owed_in_usd
+    }
+
+    pub fn is_installment_overdue(&self, timestamp: i64) -> bool {
+        !self.is_installment_paid() && timestamp >= self.installment_deadline
     }
 }
\ No newline at end of file
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+pub const RESERVATION_DETAIL_ACCOUNT_PREFIX: &str = "RESERVATION";
+
+/// nodes of a tier reserved for a partner
+#[account]
+pub struct ReservationDetailAccount {
+    /// timestamp when account updated
+    pub last_block_timestamp: i64,
+
+    pub partner: Pubkey,
+
+    pub tier_id: u32,
+
+    pub quantity: u64,
+
+    /// usd per node paid in sol on claim, 0 means the nodes are free
+    pub price: u64,
+
+    pub is_claimed: bool,
+
+    /// order created on claim
+    pub order_id: u64,
+}
+
+impl ReservationDetailAccount {
+    pub fn space() -> usize {
+        8 // default
+            + 8 // last_block_timestamp
+            + 32 // partner
+            + 4 // tier_id
+            + 8 // quantity
+            + 8 // price
+            + 1 // is_claimed
+            + 8 // order_id
+    }
+}
\ No newline at end of file
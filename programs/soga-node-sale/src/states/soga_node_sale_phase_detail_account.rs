@@ -1,7 +1,22 @@
 use anchor_lang::prelude::*;
 
+use crate::oracle::{OracleConfig, PriceSource, MAX_PRICE_SOURCES};
+
 pub const SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX: &str = "PHASE";
 
+pub const MAX_LOYALTY_COLLECTIONS: usize = 8;
+
+/// how node licenses of a phase are minted
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum NodeMintMode {
+    /// spl mint with metaplex metadata, master edition and a frozen token account
+    Metaplex,
+
+    /// token-2022 mint with metadata and group member extensions, non-transferable
+    /// unless an unlock timestamp is set
+    Token2022,
+}
+
 #[account]
 pub struct SogaNodeSalePhaseDetailAccount {
     /// timestamp when account updated
@@ -46,6 +61,69 @@ pub struct SogaNodeSalePhaseDetailAccount {
     pub metadata_base_uri: String,
 
     pub total_whitelist_mint: u64,
+
+    pub mint_mode: NodeMintMode,
+
+    /// frozen node token accounts can be thawed after this timestamp, 0 means no unlock
+    pub unlock_timestamp: i64,
+
+    pub total_revoked: u64,
+
+    pub total_upgraded: u64,
+
+    /// oracle settings of the sol price feed
+    pub oracle_config: OracleConfig,
+
+    /// price sources read next to the price feed, the median of the valid prices is used
+    pub price_sources: Vec<PriceSource>,
+
+    /// min valid prices out of the price feed and price sources
+    pub price_source_quorum: u8,
+
+    /// usd payments are escrowed until `total_payment` reaches the soft cap and refunded
+    /// when the phase ends without reaching it, 0 means no soft cap
+    pub soft_cap: u64,
+
+    /// max `total_payment` in usd, 0 means no hard cap
+    pub hard_cap: u64,
+
+    /// buys are rejected after this timestamp, 0 means no end
+    pub end_timestamp: i64,
+
+    /// share of the order price paid upfront by installment orders, 0 means installments are disabled
+    pub installment_deposit_bps: u16,
+
+    /// seconds after the buy until an installment order has to be paid in full
+    pub installment_period: i64,
+
+    /// lapsed installment payments are refunded to the user instead of forfeited to the payment receiver
+    pub is_installment_deposit_refundable: bool,
+
+    /// discount in basis points of buyers holding a node of a loyalty collection
+    pub loyalty_discount: u16,
+
+    /// collection mints of tiers of earlier phases
+    pub loyalty_collections: Vec<Pubkey>,
+
+    /// lamports paid from the fill fee vault to whoever fills a node of an order, 0 means no reward
+    pub fill_reward: u64,
+
+    /// revoked nodes minted again by reissue_node
+    pub total_reissued: u64,
+
+    /// usd price of open installment orders not yet paid and counted in `total_payment`
+    pub total_installment_pending: u64,
+
+    /// start of the sale of phases without a `start_timestamp`, 0 for phases initialized before
+    /// the field was added
+    pub initialize_timestamp: i64,
+
+    /// set the first time `total_payment` reaches the soft cap, lapsed installment orders lowering
+    /// `total_payment` afterwards do not miss the soft cap again
+    pub is_soft_cap_reached: bool,
+
+    /// buys are rejected before this timestamp, 0 means the phase starts when it is initialized
+    pub start_timestamp: i64,
 }
 
 impl SogaNodeSalePhaseDetailAccount {
@@ -72,5 +150,60 @@ impl SogaNodeSalePhaseDetailAccount {
             + 20 // symbol
             + 100 // metadata_base_uri
             + 8 // total_whitelist_mint
+            + 1 // mint_mode
+            + 8 // unlock_timestamp
+            + 8 // total_revoked
+            + 8 // total_upgraded
+            + OracleConfig::space() // oracle_config
+            + 4 + MAX_PRICE_SOURCES * PriceSource::space() // price_sources
+            + 1 // price_source_quorum
+            + 8 // soft_cap
+            + 8 // hard_cap
+            + 8 // end_timestamp
+            + 2 // installment_deposit_bps
+            + 8 // installment_period
+            + 1 // is_installment_deposit_refundable
+            + 2 // loyalty_discount
+            + 4 + MAX_LOYALTY_COLLECTIONS * 32 // loyalty_collections
+            + 8 // fill_reward
+            + 8 // total_reissued
+            + 8 // total_installment_pending
+            + 8 // initialize_timestamp
+            + 1 // is_soft_cap_reached
+            + 8 // start_timestamp
+    }
+
+    pub fn is_started(&self, timestamp: i64) -> bool {
+        timestamp >= self.start_timestamp
+    }
+
+    /// phases are ordered by the start of their sale, loyalty tiers are taken from phases starting
+    /// before this one
+    pub fn sale_start_timestamp(&self) -> i64 {
+        if self.start_timestamp != 0 { self.start_timestamp } else { self.initialize_timestamp }
+    }
+
+    pub fn is_ended(&self, timestamp: i64) -> bool {
+        self.end_timestamp != 0 && timestamp >= self.end_timestamp
+    }
+
+    /// `total_payment` including the balance still owed by open installment orders,
+    /// checked against the hard cap
+    pub fn committed_payment(&self) -> u64 {
+        self.total_payment + self.total_installment_pending
+    }
+
+    pub fn is_soft_cap_reached(&self) -> bool {
+        self.is_soft_cap_reached || self.total_payment >= self.soft_cap
+    }
+
+    /// latches the soft cap, called whenever `total_payment` changes
+    pub fn latch_soft_cap(&mut self) {
+        self.is_soft_cap_reached = self.is_soft_cap_reached();
+    }
+
+    /// escrowed payments are refunded and their orders voided
+    pub fn is_soft_cap_missed(&self, timestamp: i64) -> bool {
+        !self.is_soft_cap_reached() && self.is_ended(timestamp)
     }
 }
\ No newline at end of file
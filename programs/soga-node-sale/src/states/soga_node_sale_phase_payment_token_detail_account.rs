@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::oracle::{OracleConfig, PriceSource, MAX_PRICE_SOURCES};
+
 pub const SOGA_NODE_SALE_PHASE_PAYMENT_TOKEN_DETAIL_ACCOUNT_PREFIX: &str = "PHASE_PAYMENT_TOKEN";
 
 #[account]
@@ -16,6 +18,24 @@ pub struct SogaNodeSalePhasePaymentTokenDetailAccount {
     pub enable: bool,
 
     pub decimals: u8,
+
+    /// oracle settings of the payment token price feed
+    pub oracle_config: OracleConfig,
+
+    /// price the token at `fixed_rate` instead of the oracle
+    pub is_fixed_rate: bool,
+
+    /// base units of the token per usd
+    pub fixed_rate: u64,
+
+    /// max deviation in basis points of the oracle price from the fixed rate, 0 skips the oracle
+    pub max_depeg_bps: u16,
+
+    /// price sources read next to the price feed, the median of the valid prices is used
+    pub price_sources: Vec<PriceSource>,
+
+    /// min valid prices out of the price feed and price sources
+    pub price_source_quorum: u8,
 }
 
 impl SogaNodeSalePhasePaymentTokenDetailAccount {
@@ -27,5 +47,11 @@ impl SogaNodeSalePhasePaymentTokenDetailAccount {
             + 70 // price_feed_id
             + 1 // enable
             + 1 // enable
+            + OracleConfig::space() // oracle_config
+            + 1 // is_fixed_rate
+            + 8 // fixed_rate
+            + 2 // max_depeg_bps
+            + 4 + MAX_PRICE_SOURCES * PriceSource::space() // price_sources
+            + 1 // price_source_quorum
     }
 }
\ No newline at end of file
@@ -1,7 +1,105 @@
 use anchor_lang::prelude::*;
 
+use crate::pricing::{get_scheduled_price, get_curve_unit_prices, get_volume_discount};
+
 pub const SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX: &str = "PHASE_TIER";
 
+pub const MAX_PRICE_STEPS: usize = 8;
+
+pub const MAX_WINNING_BIDS: usize = 16;
+
+pub const MAX_VOLUME_BRACKETS: usize = 8;
+
+pub const MAX_CURVE_GROWTH_BPS: u16 = 10000;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PriceStep {
+    pub start_timestamp: i64,
+
+    pub price: u64,
+}
+
+/// usd price of a tier over time, `price` of the tier applies before the schedule starts
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum PriceSchedule {
+    /// `price` of the tier
+    None,
+
+    /// price of the last step started, ordered by `start_timestamp`
+    Steps(Vec<PriceStep>),
+
+    /// price moving linearly from `start_price` to `end_price`
+    LinearRamp {
+        start_timestamp: i64,
+        end_timestamp: i64,
+        start_price: u64,
+        end_price: u64,
+    },
+}
+
+/// price increase of a tier with every node minted in it
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PriceCurve {
+    /// flat price
+    None,
+
+    /// `increment` usd added per node minted
+    Linear {
+        increment: u64,
+    },
+
+    /// price grows by `growth_bps` basis points per node minted
+    Exponential {
+        growth_bps: u16,
+    },
+}
+
+/// revealed bid of a sealed bid auction, in lamports per node
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct WinningBid {
+    pub user: Pubkey,
+
+    pub price: u64,
+}
+
+/// discount in basis points of orders from `min_quantity` nodes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct VolumeBracket {
+    pub min_quantity: u64,
+
+    pub discount: u16,
+}
+
+/// how nodes are allocated to commitments of an oversubscribed fair launch
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AllocationMode {
+    /// every commitment gets its share of the supply, rounding is carried between adjacent commitments
+    ProRata,
+
+    /// the supply is drawn among all committed nodes
+    Lottery,
+}
+
+impl AllocationMode {
+    pub fn space() -> usize {
+        1 // variant
+    }
+}
+
+impl PriceCurve {
+    pub fn space() -> usize {
+        1 // variant
+            + 8 // increment, larger than growth_bps
+    }
+}
+
+impl PriceSchedule {
+    pub fn space() -> usize {
+        1 // variant
+            + 4 + MAX_PRICE_STEPS * (8 + 8) // steps, larger than linear ramp
+    }
+}
+
 #[account]
 pub struct SogaNodeSalePhaseTierDetailAccount {
     /// timestamp when account updated
@@ -40,6 +138,117 @@ pub struct SogaNodeSalePhaseTierDetailAccount {
     pub whitelist_quantity: u64,
 
     pub total_whitelist_mint: u64,
+
+    /// nodes are minted as compressed nfts into `merkle_tree_address`
+    pub is_compressed: bool,
+
+    pub merkle_tree_address: Pubkey,
+
+    /// overrides the phase unlock timestamp when not 0
+    pub unlock_timestamp: i64,
+
+    /// early unlock by signing authority
+    pub is_unlocked: bool,
+
+    pub total_revoked: u64,
+
+    /// token ids exchanged for a license of a higher tier
+    pub total_upgraded: u64,
+
+    pub price_schedule: PriceSchedule,
+
+    pub price_curve: PriceCurve,
+
+    /// part of each dutch auction payment above the floor price is escrowed and
+    /// rebated down to the clearing price after the auction
+    pub is_auction_rebate: bool,
+
+    pub auction_floor_price: u64,
+
+    pub auction_end_timestamp: i64,
+
+    /// lowest usd unit price paid in the tier
+    pub clearing_price: u64,
+
+    /// nodes are committed to during the commit window and allocated after it closes
+    pub is_fair_launch: bool,
+
+    pub commit_start_timestamp: i64,
+
+    pub commit_end_timestamp: i64,
+
+    pub allocation_mode: AllocationMode,
+
+    /// nodes requested by all commitments
+    pub total_committed: u64,
+
+    /// nodes available to commitments, set when the fair launch is finalized
+    pub fair_launch_supply: u64,
+
+    pub is_fair_launch_finalized: bool,
+
+    pub lottery_seed: [u8; 32],
+
+    pub total_allocated: u64,
+
+    /// nodes are auctioned to the highest revealed sealed bids
+    pub is_sealed_bid_auction: bool,
+
+    pub bid_end_timestamp: i64,
+
+    pub reveal_end_timestamp: i64,
+
+    /// lowest bid accepted, in lamports per node
+    pub reserve_price: u64,
+
+    /// winners pay the lowest winning bid instead of their own bid
+    pub is_uniform_price: bool,
+
+    pub total_bids: u64,
+
+    /// highest revealed bids ordered by price, at most `quantity`
+    pub winning_bids: Vec<WinningBid>,
+
+    /// ordered by `min_quantity`
+    pub volume_brackets: Vec<VolumeBracket>,
+
+    /// brackets count the nodes the user already minted in the tier with the order quantity
+    pub is_volume_lifetime: bool,
+
+    /// whitelist buys consume one pass token of this mint per node, None keeps the signed whitelist flag
+    pub whitelist_pass_mint: Option<Pubkey>,
+
+    /// passes are burned instead of transferred to an escrow owned by the tier
+    pub is_whitelist_pass_burned: bool,
+
+    /// usd price of whitelist buys, 0 means whitelist buys pay the public price
+    pub whitelist_price: u64,
+
+    /// max nodes a user buys on the whitelist, 0 means only `mint_limit` applies
+    pub whitelist_mint_limit: u64,
+
+    /// nodes reserved for partners and airdrop claims, excluded from the public supply until claimed
+    pub total_reserved: u64,
+
+    pub total_reserved_claimed: u64,
+
+    /// root of the (index, user, tier, quantity) airdrop snapshot users claim from
+    pub airdrop_merkle_root: [u8; 32],
+
+    /// nodes in the airdrop snapshot, part of `total_reserved`
+    pub airdrop_claim_quantity: u64,
+
+    pub airdrop_claimed_quantity: u64,
+
+    /// revoked nodes minted again by reissue_node
+    pub total_reissued: u64,
+
+    /// commitments are allocated until this timestamp, unallocated ones are refunded in full after it,
+    /// 0 keeps commitments allocatable without a deadline
+    pub allocation_end_timestamp: i64,
+
+    /// allocations of commitments refunded after `allocation_end_timestamp`, released to public buys
+    pub total_forfeited: u64,
 }
 
 impl SogaNodeSalePhaseTierDetailAccount {
@@ -62,5 +271,143 @@ impl SogaNodeSalePhaseTierDetailAccount {
             + 1 // airdrop_enable
             + 8 // whitelist_quantity
             + 8 // total_whitelist_mint
+            + 1 // is_compressed
+            + 32 // merkle_tree_address
+            + 8 // unlock_timestamp
+            + 1 // is_unlocked
+            + 8 // total_revoked
+            + 8 // total_upgraded
+            + PriceSchedule::space() // price_schedule
+            + PriceCurve::space() // price_curve
+            + 1 // is_auction_rebate
+            + 8 // auction_floor_price
+            + 8 // auction_end_timestamp
+            + 8 // clearing_price
+            + 1 // is_fair_launch
+            + 8 // commit_start_timestamp
+            + 8 // commit_end_timestamp
+            + AllocationMode::space() // allocation_mode
+            + 8 // total_committed
+            + 8 // fair_launch_supply
+            + 1 // is_fair_launch_finalized
+            + 32 // lottery_seed
+            + 8 // total_allocated
+            + 1 // is_sealed_bid_auction
+            + 8 // bid_end_timestamp
+            + 8 // reveal_end_timestamp
+            + 8 // reserve_price
+            + 1 // is_uniform_price
+            + 8 // total_bids
+            + 4 + MAX_WINNING_BIDS * (32 + 8) // winning_bids
+            + 4 + MAX_VOLUME_BRACKETS * (8 + 2) // volume_brackets
+            + 1 // is_volume_lifetime
+            + 33 // whitelist_pass_mint
+            + 1 // is_whitelist_pass_burned
+            + 8 // whitelist_price
+            + 8 // whitelist_mint_limit
+            + 8 // total_reserved
+            + 8 // total_reserved_claimed
+            + 32 // airdrop_merkle_root
+            + 8 // airdrop_claim_quantity
+            + 8 // airdrop_claimed_quantity
+            + 8 // total_reissued
+            + 8 // allocation_end_timestamp
+            + 8 // total_forfeited
+    }
+
+    /// usd price of a node at `timestamp`
+    pub fn current_price(&self, timestamp: i64) -> Result<u64> {
+        get_scheduled_price(&self.price_schedule, self.price, timestamp)
+    }
+
+    /// usd price of each of the next `quantity` nodes at `timestamp`
+    pub fn quote(&self, timestamp: i64, quantity: u64) -> Result<Vec<u64>> {
+        get_curve_unit_prices(&self.price_curve, self.current_price(timestamp)?, self.total_mint, quantity)
+    }
+
+    /// usd price of each of the next `quantity` whitelist nodes, flat at `whitelist_price` when set
+    pub fn quote_whitelist(&self, timestamp: i64, quantity: u64) -> Result<Vec<u64>> {
+        if self.whitelist_price == 0 {
+            return self.quote(timestamp, quantity);
+        }
+
+        Ok(vec![self.whitelist_price; quantity as usize])
+    }
+
+    /// reserved nodes that are not claimed yet
+    pub fn unclaimed_reserved(&self) -> u64 {
+        self.total_reserved.saturating_sub(self.total_reserved_claimed)
+    }
+
+    /// nodes held by users, `total_mint` keeps counting revoked and upgraded token ids so token ids are
+    /// never reused
+    pub fn minted_supply(&self) -> u64 {
+        self.total_mint - self.total_revoked - self.total_upgraded + self.total_reissued
+    }
+
+    /// nodes of a finalized fair launch owed to commitments that are not allocated yet
+    pub fn unallocated_fair_launch(&self) -> u64 {
+        if !self.is_fair_launch_finalized {
+            return 0;
+        }
+
+        self.total_committed.min(self.fair_launch_supply)
+            .saturating_sub(self.total_allocated + self.total_forfeited)
+    }
+
+    /// supply of the tier outside of unclaimed reservations, airdrop claims and unallocated commitments
+    pub fn public_quantity(&self) -> u64 {
+        self.quantity.saturating_sub(self.unclaimed_reserved()).saturating_sub(self.unallocated_fair_launch())
+    }
+
+    /// payments are escrowed while the rebate auction runs
+    pub fn is_auction_running(&self, timestamp: i64) -> bool {
+        self.is_auction_rebate && timestamp < self.auction_end_timestamp
+    }
+
+    /// the auction ends when the tier sells out or the price reaches the floor
+    pub fn is_auction_ended(&self, timestamp: i64) -> bool {
+        self.is_completed || timestamp >= self.auction_end_timestamp
+    }
+
+    /// final usd unit price of an ended auction, remaining nodes sell at the floor price
+    pub fn auction_clearing_price(&self) -> u64 {
+        if self.is_completed { self.clearing_price } else { self.auction_floor_price }
+    }
+
+    /// commitments are accepted from `commit_start_timestamp` until `commit_end_timestamp`
+    pub fn is_commit_open(&self, timestamp: i64) -> bool {
+        self.is_fair_launch && timestamp >= self.commit_start_timestamp && timestamp < self.commit_end_timestamp
+    }
+
+    pub fn is_allocation_ended(&self, timestamp: i64) -> bool {
+        self.allocation_end_timestamp != 0 && timestamp >= self.allocation_end_timestamp
+    }
+
+    /// nodes left by an undersubscribed fair launch are bought like any other tier once it is finalized
+    pub fn is_fair_launch_running(&self) -> bool {
+        self.is_fair_launch && !self.is_fair_launch_finalized
+    }
+
+    /// the fair launch supply and the winning bid slots are sized from the public quantity
+    /// when the fair launch is finalized and when bids are revealed
+    pub fn is_public_quantity_locked(&self, timestamp: i64) -> bool {
+        self.is_fair_launch_finalized || (self.is_sealed_bid_auction && timestamp >= self.bid_end_timestamp)
+    }
+
+    /// lowest price among the winning bids
+    pub fn lowest_winning_bid(&self) -> u64 {
+        self.winning_bids.last().map_or(0, |winning_bid| winning_bid.price)
+    }
+
+    pub fn is_winning_bid(&self, user: &Pubkey) -> bool {
+        self.winning_bids.iter().any(|winning_bid| winning_bid.user == *user)
+    }
+
+    /// volume discount in basis points of an order of `quantity` nodes by a user who minted `total_mint` nodes in the tier
+    pub fn volume_discount(&self, quantity: u64, total_mint: u64) -> u16 {
+        let volume: u64 = if self.is_volume_lifetime { total_mint + quantity } else { quantity };
+
+        get_volume_discount(&self.volume_brackets, volume)
     }
 }
\ No newline at end of file
@@ -20,6 +20,9 @@ pub struct UserTierDetailAccount {
     pub total_discount: u64,
 
     pub total_whitelist_mint: u64,
+
+    /// usd paid for whitelist buys
+    pub total_whitelist_payment: u64,
 }
 
 impl UserTierDetailAccount {
@@ -33,5 +36,6 @@ impl UserTierDetailAccount {
             + 8 // total_airdrop
             + 8 // total_payment
             + 8 // total_discount
+            + 8 // total_whitelist_payment
     }
 }
\ No newline at end of file
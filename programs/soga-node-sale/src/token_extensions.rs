@@ -0,0 +1,235 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::system_program::{create_account, CreateAccount};
+
+use anchor_spl::{
+    token_2022::{Burn, burn, ThawAccount, thaw_account},
+    token_interface::TokenAccount,
+};
+
+use spl_token_2022::{
+    extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions, group_member_pointer, group_pointer, metadata_pointer},
+    instruction::{initialize_mint2, initialize_permanent_delegate},
+    state::Mint,
+};
+use spl_token_group_interface::{instruction::{initialize_group, initialize_member}, state::TokenGroupMember};
+use spl_token_metadata_interface::{instruction::initialize as initialize_token_metadata, state::TokenMetadata};
+
+/// Creates a token-2022 collection mint that acts as the token group of a tier.
+///
+/// The mint carries the group pointer, metadata pointer, token group and token
+/// metadata extensions, all pointing to the mint itself. `authority` (the tier
+/// account) becomes mint, freeze, metadata update and group update authority.
+pub fn create_collection_group_mint<'info>(
+    payer: &AccountInfo<'info>,
+    collection_mint: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    collection_mint_signer: &[&[&[u8]]],
+    authority_signer: &[&[&[u8]]],
+    name: String,
+    symbol: String,
+    uri: String,
+    max_size: u32,
+) -> Result<()> {
+    let metadata = TokenMetadata {
+        name: name.clone(),
+        symbol: symbol.clone(),
+        uri: uri.clone(),
+        ..Default::default()
+    };
+
+    let space = ExtensionType::try_calculate_account_len::<Mint>(&[
+        ExtensionType::GroupPointer,
+        ExtensionType::MetadataPointer,
+    ])?;
+
+    let space_with_variable_extensions = ExtensionType::try_calculate_account_len::<Mint>(&[
+        ExtensionType::GroupPointer,
+        ExtensionType::MetadataPointer,
+        ExtensionType::TokenGroup,
+    ])? + metadata.tlv_size_of()?;
+
+    create_mint_account(payer, collection_mint, token_program, system_program, collection_mint_signer, space, space_with_variable_extensions)?;
+
+    invoke(
+        &group_pointer::instruction::initialize(token_program.key, collection_mint.key, Some(authority.key()), Some(collection_mint.key()))?,
+        &[collection_mint.clone(), token_program.clone()],
+    )?;
+
+    invoke(
+        &metadata_pointer::instruction::initialize(token_program.key, collection_mint.key, Some(authority.key()), Some(collection_mint.key()))?,
+        &[collection_mint.clone(), token_program.clone()],
+    )?;
+
+    invoke(
+        &initialize_mint2(token_program.key, collection_mint.key, authority.key, Some(authority.key), 0)?,
+        &[collection_mint.clone(), token_program.clone()],
+    )?;
+
+    invoke_signed(
+        &initialize_token_metadata(token_program.key, collection_mint.key, authority.key, collection_mint.key, authority.key, name, symbol, uri),
+        &[collection_mint.clone(), authority.clone(), token_program.clone()],
+        authority_signer,
+    )?;
+
+    invoke_signed(
+        &initialize_group(token_program.key, collection_mint.key, collection_mint.key, authority.key, Some(authority.key()), max_size),
+        &[collection_mint.clone(), authority.clone(), token_program.clone()],
+        authority_signer,
+    )?;
+
+    Ok(())
+}
+
+/// Creates a token-2022 node mint as a member of the tier collection group.
+///
+/// The mint carries the metadata pointer, token metadata, group member pointer,
+/// token group member and permanent delegate extensions. The tier is the
+/// permanent delegate so revoked licenses can be burned. The tier is also the
+/// freeze authority, a lock-up is enforced by freezing the holder token account
+/// so that `thaw_node` can lift it once the unlock schedule allows.
+pub fn create_node_member_mint<'info>(
+    payer: &AccountInfo<'info>,
+    node_mint: &AccountInfo<'info>,
+    collection_mint: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    node_mint_signer: &[&[&[u8]]],
+    authority_signer: &[&[&[u8]]],
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Result<()> {
+    let metadata = TokenMetadata {
+        name: name.clone(),
+        symbol: symbol.clone(),
+        uri: uri.clone(),
+        ..Default::default()
+    };
+
+    let mut extension_types = vec![
+        ExtensionType::MetadataPointer,
+        ExtensionType::GroupMemberPointer,
+        ExtensionType::PermanentDelegate,
+    ];
+
+    let space = ExtensionType::try_calculate_account_len::<Mint>(&extension_types)?;
+
+    extension_types.push(ExtensionType::TokenGroupMember);
+
+    let space_with_variable_extensions = ExtensionType::try_calculate_account_len::<Mint>(&extension_types)? + metadata.tlv_size_of()?;
+
+    create_mint_account(payer, node_mint, token_program, system_program, node_mint_signer, space, space_with_variable_extensions)?;
+
+    invoke(
+        &metadata_pointer::instruction::initialize(token_program.key, node_mint.key, Some(authority.key()), Some(node_mint.key()))?,
+        &[node_mint.clone(), token_program.clone()],
+    )?;
+
+    invoke(
+        &group_member_pointer::instruction::initialize(token_program.key, node_mint.key, Some(authority.key()), Some(node_mint.key()))?,
+        &[node_mint.clone(), token_program.clone()],
+    )?;
+
+    invoke(
+        &initialize_permanent_delegate(token_program.key, node_mint.key, authority.key)?,
+        &[node_mint.clone(), token_program.clone()],
+    )?;
+
+    invoke(
+        &initialize_mint2(token_program.key, node_mint.key, authority.key, Some(authority.key), 0)?,
+        &[node_mint.clone(), token_program.clone()],
+    )?;
+
+    invoke_signed(
+        &initialize_token_metadata(token_program.key, node_mint.key, authority.key, node_mint.key, authority.key, name, symbol, uri),
+        &[node_mint.clone(), authority.clone(), token_program.clone()],
+        authority_signer,
+    )?;
+
+    invoke_signed(
+        &initialize_member(token_program.key, node_mint.key, node_mint.key, authority.key, collection_mint.key, authority.key),
+        &[node_mint.clone(), collection_mint.clone(), authority.clone(), token_program.clone()],
+        authority_signer,
+    )?;
+
+    Ok(())
+}
+
+/// Collection mint of the token group a node mint is a member of. Members are
+/// initialized with the tier account as group update authority, so the group
+/// of a token-2022 mint can not be forged.
+pub fn get_node_collection_mint(node_mint: &AccountInfo) -> Result<Pubkey> {
+    let data = node_mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&data)?;
+    let member = mint.get_extension::<TokenGroupMember>()?;
+
+    Ok(member.group)
+}
+
+/// Burns a node held in `holder_token_account` as permanent delegate of the node
+/// mint, thawing the token account first since frozen accounts can not be burned.
+pub fn burn_node<'info>(
+    token_program: &AccountInfo<'info>,
+    node_mint: &AccountInfo<'info>,
+    holder_token_account: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    authority_signer: &[&[&[u8]]],
+) -> Result<()> {
+    let holder_token: TokenAccount = TokenAccount::try_deserialize(&mut &holder_token_account.try_borrow_data()?[..])?;
+
+    if holder_token.is_frozen() {
+        let thaw_account_cpi_context = CpiContext::new_with_signer(
+            token_program.clone(),
+            ThawAccount {
+                account: holder_token_account.clone(),
+                mint: node_mint.clone(),
+                authority: authority.clone(),
+            },
+            authority_signer,
+        );
+
+        thaw_account(thaw_account_cpi_context)?;
+    }
+
+    let burn_cpi_context = CpiContext::new_with_signer(
+        token_program.clone(),
+        Burn {
+            mint: node_mint.clone(),
+            from: holder_token_account.clone(),
+            authority: authority.clone(),
+        },
+        authority_signer,
+    );
+
+    burn(burn_cpi_context, holder_token.amount)
+}
+
+/// Allocates `space` bytes for a mint owned by the token program, funded for
+/// `space_with_variable_extensions` so that the token program can realloc the
+/// account when the metadata and group extensions are initialized.
+fn create_mint_account<'info>(
+    payer: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    mint_signer: &[&[&[u8]]],
+    space: usize,
+    space_with_variable_extensions: usize,
+) -> Result<()> {
+    let lamports = Rent::get()?.minimum_balance(space_with_variable_extensions);
+
+    let create_account_cpi_context = CpiContext::new_with_signer(
+        system_program.clone(),
+        CreateAccount {
+            from: payer.clone(),
+            to: mint.clone(),
+        },
+        mint_signer,
+    );
+
+    create_account(create_account_cpi_context, lamports, space as u64, token_program.key)
+}
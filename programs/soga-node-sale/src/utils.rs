@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
 use crate::error::SogaNodeSaleError;
+use crate::states::{NodeMintMode, PriceSchedule, PriceCurve, MAX_TICKET_RANGES, MAX_CURVE_GROWTH_BPS, MAX_PRICE_STEPS, MAX_WINNING_BIDS, VolumeBracket, MAX_VOLUME_BRACKETS, MAX_LOYALTY_COLLECTIONS};
+use crate::oracle::MAX_PRICE_SOURCES;
+use crate::pricing::get_curve_unit_prices;
 
 pub fn check_main_signing_authority(main_signing_authority_from_account: Pubkey, main_signing_authority_from_input_accounts: Pubkey) -> Result<()> {
     if main_signing_authority_from_account != main_signing_authority_from_input_accounts {
@@ -206,6 +209,58 @@ pub fn check_invalid_user_discount(value: u16) -> Result<()> {
     Ok(())
 }
 
+pub fn check_max_payment_amount(payment_amount: u64, max_payment_amount: u64) -> Result<()> {
+    if payment_amount > max_payment_amount {
+        return Err(SogaNodeSaleError::PaymentAmountExceedsMax.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_fixed_rate(is_fixed_rate: bool, fixed_rate: u64) -> Result<()> {
+    if is_fixed_rate && fixed_rate == 0 {
+        return Err(SogaNodeSaleError::InvalidFixedRate.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_stablecoin_peg(deviation_bps: u64, max_depeg_bps: u16) -> Result<()> {
+    if deviation_bps > max_depeg_bps as u64 {
+        return Err(SogaNodeSaleError::StablecoinDepegged.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_price_sources(total_price_sources: usize, quorum: u8) -> Result<()> {
+    if total_price_sources > MAX_PRICE_SOURCES {
+        return Err(SogaNodeSaleError::TooManyPriceSources.into());
+    }
+
+    // the price feed counts towards the quorum next to the registered sources
+    if quorum as usize > total_price_sources + 1 {
+        return Err(SogaNodeSaleError::InvalidPriceSourceQuorum.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_price_schedule(price_schedule: &PriceSchedule) -> Result<()> {
+    let is_valid: bool = match price_schedule {
+        PriceSchedule::None => true,
+        PriceSchedule::Steps(steps) => steps.len() <= MAX_PRICE_STEPS
+            && steps.windows(2).all(|pair| pair[0].start_timestamp < pair[1].start_timestamp),
+        PriceSchedule::LinearRamp { start_timestamp, end_timestamp, .. } => start_timestamp < end_timestamp,
+    };
+
+    if !is_valid {
+        return Err(SogaNodeSaleError::InvalidPriceSchedule.into());
+    }
+
+    Ok(())
+}
+
 pub fn check_payment_token_mint_account(value_from_account: Pubkey, valur_from_param: Pubkey) -> Result<()> {
     if value_from_account != valur_from_param {
         return Err(SogaNodeSaleError::InvalidPaymentTokenMintAccount.into());
@@ -254,3 +309,782 @@ pub fn check_order_id(value_from_account: u64, valur_from_param: u64) -> Result<
     Ok(())
 }
 
+pub fn check_mint_mode(value_from_account: NodeMintMode, value_from_instruction: NodeMintMode) -> Result<()> {
+    if value_from_account != value_from_instruction {
+        return Err(SogaNodeSaleError::InvalidMintMode.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_phase_tier_is_compressed(value: bool) -> Result<()> {
+    if value {
+        return Err(SogaNodeSaleError::PhaseTierIsCompressed.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_phase_tier_is_not_compressed(value: bool) -> Result<()> {
+    if !value {
+        return Err(SogaNodeSaleError::PhaseTierIsNotCompressed.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_merkle_tree(value_from_account: Pubkey, value_from_input_accounts: Pubkey) -> Result<()> {
+    if value_from_account != value_from_input_accounts {
+        return Err(SogaNodeSaleError::InvalidMerkleTreeAddress.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_program_address(value_from_program: Pubkey, value_from_input_accounts: Pubkey) -> Result<()> {
+    if value_from_program != value_from_input_accounts {
+        return Err(SogaNodeSaleError::InvalidProgramAddress.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_node_is_locked(is_unlocked: bool, unlock_timestamp: i64, timestamp: i64) -> Result<()> {
+    if is_node_locked(is_unlocked, unlock_timestamp, timestamp) {
+        return Err(SogaNodeSaleError::NodeIsLocked.into());
+    }
+
+    Ok(())
+}
+
+pub fn is_node_locked(is_unlocked: bool, unlock_timestamp: i64, timestamp: i64) -> bool {
+    !is_unlocked && (unlock_timestamp == 0 || timestamp < unlock_timestamp)
+}
+
+pub fn get_unlock_timestamp(phase_unlock_timestamp: i64, tier_unlock_timestamp: i64) -> i64 {
+    if tier_unlock_timestamp != 0 {
+        return tier_unlock_timestamp;
+    }
+
+    phase_unlock_timestamp
+}
+
+pub fn check_order_token_id_revoked(value: bool) -> Result<()> {
+    if value {
+        return Err(SogaNodeSaleError::OrderTokenIdRevoked.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_order_token_id_not_revoked(value: bool) -> Result<()> {
+    if !value {
+        return Err(SogaNodeSaleError::NodeIsNotRevoked.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_order_token_id_reissued(value: bool) -> Result<()> {
+    if value {
+        return Err(SogaNodeSaleError::OrderTokenIdReissued.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_order_token_id_upgraded(value: bool) -> Result<()> {
+    if value {
+        return Err(SogaNodeSaleError::OrderTokenIdUpgraded.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_node_mint_account(value_from_seeds: Pubkey, value_from_input_accounts: Pubkey) -> Result<()> {
+    if value_from_seeds != value_from_input_accounts {
+        return Err(SogaNodeSaleError::InvalidNodeMintAccount.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_user_token_account(value_from_seeds: Pubkey, value_from_input_accounts: Pubkey) -> Result<()> {
+    if value_from_seeds != value_from_input_accounts {
+        return Err(SogaNodeSaleError::InvalidUserTokenAccount.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_node_is_revoked(supply: u64) -> Result<()> {
+    if supply != 0 {
+        return Err(SogaNodeSaleError::NodeIsNotRevoked.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_upgrade_tier(source_tier_price: u64, target_tier_price: u64) -> Result<()> {
+    if target_tier_price <= source_tier_price {
+        return Err(SogaNodeSaleError::InvalidUpgradeTier.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_dutch_auction(start_timestamp: i64, end_timestamp: i64, start_price: u64, floor_price: u64) -> Result<()> {
+    if start_timestamp >= end_timestamp || floor_price == 0 || floor_price > start_price {
+        return Err(SogaNodeSaleError::InvalidDutchAuction.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_auction_escrow(owner: Pubkey, sale_phase_tier_detail: Pubkey, mint: Pubkey, payment_token_mint: Pubkey) -> Result<()> {
+    if owner != sale_phase_tier_detail || mint != payment_token_mint {
+        return Err(SogaNodeSaleError::InvalidAuctionEscrow.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_auction_ended(is_auction_ended: bool) -> Result<()> {
+    if !is_auction_ended {
+        return Err(SogaNodeSaleError::AuctionNotEnded.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_auction_order_settled(is_auction_settled: bool) -> Result<()> {
+    if is_auction_settled {
+        return Err(SogaNodeSaleError::AuctionOrderSettled.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_fair_launch(is_fair_launch: bool, commit_start_timestamp: i64, commit_end_timestamp: i64, allocation_end_timestamp: i64) -> Result<()> {
+    if is_fair_launch && (commit_start_timestamp >= commit_end_timestamp || commit_end_timestamp >= allocation_end_timestamp) {
+        return Err(SogaNodeSaleError::InvalidFairLaunch.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_fair_launch_enabled(is_fair_launch: bool) -> Result<()> {
+    if !is_fair_launch {
+        return Err(SogaNodeSaleError::InvalidFairLaunch.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_phase_tier_is_fair_launch(is_fair_launch: bool) -> Result<()> {
+    if is_fair_launch {
+        return Err(SogaNodeSaleError::PhaseTierIsFairLaunch.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_commit_window_open(is_commit_open: bool) -> Result<()> {
+    if !is_commit_open {
+        return Err(SogaNodeSaleError::CommitWindowClosed.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_commit_window_ended(commit_end_timestamp: i64, timestamp: i64) -> Result<()> {
+    if timestamp < commit_end_timestamp {
+        return Err(SogaNodeSaleError::CommitWindowNotEnded.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_fair_launch_finalized(is_fair_launch_finalized: bool) -> Result<()> {
+    if !is_fair_launch_finalized {
+        return Err(SogaNodeSaleError::FairLaunchNotFinalized.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_fair_launch_not_finalized(is_fair_launch_finalized: bool) -> Result<()> {
+    if is_fair_launch_finalized {
+        return Err(SogaNodeSaleError::FairLaunchFinalized.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_commitment_allocated(is_allocated: bool) -> Result<()> {
+    if is_allocated {
+        return Err(SogaNodeSaleError::CommitmentAllocated.into());
+    }
+
+    Ok(())
+}
+
+/// unallocated commitments are refundable once the allocation window ended
+pub fn check_commitment_refundable(is_allocated: bool, is_allocation_ended: bool, is_refunded: bool) -> Result<()> {
+    if !is_allocated && !is_allocation_ended {
+        return Err(SogaNodeSaleError::CommitmentNotAllocated.into());
+    }
+
+    if is_refunded {
+        return Err(SogaNodeSaleError::CommitmentRefunded.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_allocation_quantity(allocated_quantity: u64, quantity_from_param: u64) -> Result<()> {
+    if allocated_quantity != quantity_from_param {
+        return Err(SogaNodeSaleError::InvalidAllocationQuantity.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_commitment_escrow(owner: Pubkey, sale_phase_tier_detail: Pubkey, mint: Pubkey, payment_token_mint: Pubkey) -> Result<()> {
+    if owner != sale_phase_tier_detail || mint != payment_token_mint {
+        return Err(SogaNodeSaleError::InvalidCommitmentEscrow.into());
+    }
+
+    Ok(())
+}
+
+/// a commitment is paid in a single currency
+pub fn check_commitment_payment_token(committed_quantity: u64, value_from_account: Option<Pubkey>, value_from_input_accounts: Option<Pubkey>) -> Result<()> {
+    if committed_quantity != 0 && value_from_account != value_from_input_accounts {
+        return Err(SogaNodeSaleError::InvalidPaymentTokenMintAccount.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_sealed_bid_auction(bid_end_timestamp: i64, reveal_end_timestamp: i64, quantity: u64) -> Result<()> {
+    if bid_end_timestamp >= reveal_end_timestamp || quantity > MAX_WINNING_BIDS as u64 {
+        return Err(SogaNodeSaleError::InvalidSealedBidAuction.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_phase_tier_is_sealed_bid_auction(is_sealed_bid_auction: bool) -> Result<()> {
+    if is_sealed_bid_auction {
+        return Err(SogaNodeSaleError::PhaseTierIsSealedBidAuction.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_bidding_open(is_sealed_bid_auction: bool, bid_end_timestamp: i64, timestamp: i64) -> Result<()> {
+    if !is_sealed_bid_auction || timestamp >= bid_end_timestamp {
+        return Err(SogaNodeSaleError::BiddingClosed.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_reveal_open(bid_end_timestamp: i64, reveal_end_timestamp: i64, timestamp: i64) -> Result<()> {
+    if timestamp < bid_end_timestamp || timestamp >= reveal_end_timestamp {
+        return Err(SogaNodeSaleError::RevealClosed.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_reveal_ended(reveal_end_timestamp: i64, timestamp: i64) -> Result<()> {
+    if timestamp < reveal_end_timestamp {
+        return Err(SogaNodeSaleError::RevealNotEnded.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_bid_hash(bid_hash_from_account: [u8; 32], bid_hash_from_reveal: [u8; 32]) -> Result<()> {
+    if bid_hash_from_account != bid_hash_from_reveal {
+        return Err(SogaNodeSaleError::InvalidBidReveal.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_bid_price(price: u64, reserve_price: u64, deposit: u64) -> Result<()> {
+    if price < reserve_price || price > deposit {
+        return Err(SogaNodeSaleError::InvalidBidPrice.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_bid_revealed(is_revealed: bool) -> Result<()> {
+    if is_revealed {
+        return Err(SogaNodeSaleError::BidRevealed.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_bid_settled(is_settled: bool) -> Result<()> {
+    if is_settled {
+        return Err(SogaNodeSaleError::BidSettled.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_bid_winning(is_winning_bid: bool) -> Result<()> {
+    if !is_winning_bid {
+        return Err(SogaNodeSaleError::BidNotWinning.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_bid_not_winning(is_winning_bid: bool) -> Result<()> {
+    if is_winning_bid {
+        return Err(SogaNodeSaleError::BidWinning.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_phase_caps(soft_cap: u64, hard_cap: u64, start_timestamp: i64, end_timestamp: i64) -> Result<()> {
+    if (soft_cap != 0 && end_timestamp == 0) || (hard_cap != 0 && hard_cap < soft_cap) || (end_timestamp != 0 && start_timestamp >= end_timestamp) {
+        return Err(SogaNodeSaleError::InvalidPhaseCaps.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_phase_started(is_started: bool) -> Result<()> {
+    if !is_started {
+        return Err(SogaNodeSaleError::PhaseNotStarted.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_hard_cap(total_payment: u64, hard_cap: u64) -> Result<()> {
+    if hard_cap != 0 && total_payment > hard_cap {
+        return Err(SogaNodeSaleError::HardCapExceeded.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_phase_ended(is_ended: bool) -> Result<()> {
+    if is_ended {
+        return Err(SogaNodeSaleError::PhaseEnded.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_soft_cap_reached(is_soft_cap_reached: bool) -> Result<()> {
+    if !is_soft_cap_reached {
+        return Err(SogaNodeSaleError::SoftCapPending.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_cap_escrow_settled(is_cap_settled: bool) -> Result<()> {
+    if is_cap_settled {
+        return Err(SogaNodeSaleError::CapEscrowSettled.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_order_voided(is_voided: bool) -> Result<()> {
+    if is_voided {
+        return Err(SogaNodeSaleError::OrderVoided.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_cap_escrow(owner: Pubkey, sale_phase_detail: Pubkey, mint: Pubkey, payment_token_mint: Pubkey) -> Result<()> {
+    if owner != sale_phase_detail || mint != payment_token_mint {
+        return Err(SogaNodeSaleError::InvalidCapEscrow.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_installment_config(installment_deposit_bps: u16, installment_period: i64) -> Result<()> {
+    if installment_deposit_bps > 10000 || (installment_deposit_bps != 0 && installment_period <= 0) {
+        return Err(SogaNodeSaleError::InvalidInstallmentConfig.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_installment_enabled(installment_deposit_bps: u16) -> Result<()> {
+    if installment_deposit_bps == 0 {
+        return Err(SogaNodeSaleError::InstallmentDisabled.into());
+    }
+
+    Ok(())
+}
+
+/// installment orders are not combined with referral discounts or running dutch auctions
+pub fn check_installment(allow_full_discount: bool, allow_half_discount: bool, is_auction_running: bool) -> Result<()> {
+    if allow_full_discount || allow_half_discount || is_auction_running {
+        return Err(SogaNodeSaleError::InvalidInstallment.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_installment_order(is_installment: bool) -> Result<()> {
+    if !is_installment {
+        return Err(SogaNodeSaleError::NotInstallmentOrder.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_installment_payment(payment_in_usd: u64, installment_paid_in_usd: u64, installment_owed_in_usd: u64) -> Result<()> {
+    if payment_in_usd == 0 || installment_paid_in_usd + payment_in_usd > installment_owed_in_usd {
+        return Err(SogaNodeSaleError::InvalidInstallmentPayment.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_installment_overdue(is_installment_overdue: bool) -> Result<()> {
+    if is_installment_overdue {
+        return Err(SogaNodeSaleError::InstallmentOverdue.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_installment_not_overdue(is_installment_overdue: bool) -> Result<()> {
+    if !is_installment_overdue {
+        return Err(SogaNodeSaleError::InstallmentNotOverdue.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_installment_paid(is_installment_paid: bool) -> Result<()> {
+    if !is_installment_paid {
+        return Err(SogaNodeSaleError::InstallmentNotPaid.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_volume_brackets(volume_brackets: &[VolumeBracket]) -> Result<()> {
+    let is_valid: bool = volume_brackets.len() <= MAX_VOLUME_BRACKETS
+        && volume_brackets.iter().all(|bracket| bracket.min_quantity > 0 && bracket.discount < 10000)
+        && volume_brackets.windows(2).all(|pair| pair[0].min_quantity < pair[1].min_quantity);
+
+    if !is_valid {
+        return Err(SogaNodeSaleError::InvalidVolumeBrackets.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_loyalty_config(loyalty_discount: u16, total_loyalty_collections: usize, total_loyalty_accounts: usize) -> Result<()> {
+    if loyalty_discount >= 10000 || total_loyalty_collections > MAX_LOYALTY_COLLECTIONS || total_loyalty_accounts != 2 * total_loyalty_collections {
+        return Err(SogaNodeSaleError::InvalidLoyaltyCollections.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_loyalty_account_owner(owner: Pubkey, program_id: Pubkey) -> Result<()> {
+    if owner != program_id {
+        return Err(SogaNodeSaleError::InvalidLoyaltyCollections.into());
+    }
+
+    Ok(())
+}
+
+/// loyalty nodes are token-2022 or metaplex node mints, compressed nodes are not accepted
+pub fn check_loyalty_node_program(node_mint_owner: Pubkey, token_account_owner: Pubkey) -> Result<()> {
+    if node_mint_owner != token_account_owner || (node_mint_owner != anchor_spl::token::ID && node_mint_owner != anchor_spl::token_2022::ID) {
+        return Err(SogaNodeSaleError::InvalidLoyaltyNode.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_loyalty_collection(is_loyalty_collection: bool) -> Result<()> {
+    if !is_loyalty_collection {
+        return Err(SogaNodeSaleError::InvalidLoyaltyNode.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_loyalty_holder(user: Pubkey, holder: Pubkey, node_mint: Pubkey, token_account_mint: Pubkey, amount: u64) -> Result<()> {
+    if user != holder || node_mint != token_account_mint || amount == 0 {
+        return Err(SogaNodeSaleError::InvalidLoyaltyNode.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_whitelist_pass_mint(value_from_account: Pubkey, value_from_input_accounts: Pubkey) -> Result<()> {
+    if value_from_account != value_from_input_accounts {
+        return Err(SogaNodeSaleError::InvalidWhitelistPass.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_whitelist_pass_token_program(mint_owner: Pubkey, token_program: Pubkey) -> Result<()> {
+    if mint_owner != token_program || (token_program != anchor_spl::token::ID && token_program != anchor_spl::token_2022::ID) {
+        return Err(SogaNodeSaleError::InvalidWhitelistPass.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_whitelist_pass_token_account(whitelist_pass_mint: Pubkey, token_account_mint: Pubkey) -> Result<()> {
+    if whitelist_pass_mint != token_account_mint {
+        return Err(SogaNodeSaleError::InvalidWhitelistPass.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_whitelist_pass_escrow(owner: Pubkey, sale_phase_tier_detail: Pubkey, mint: Pubkey, whitelist_pass_mint: Pubkey) -> Result<()> {
+    if owner != sale_phase_tier_detail || mint != whitelist_pass_mint {
+        return Err(SogaNodeSaleError::InvalidWhitelistPassEscrow.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_whitelist_mint_limit(whitelist_mint_limit: u64, total_whitelist_mint: u64) -> Result<()> {
+    if whitelist_mint_limit != 0 && total_whitelist_mint > whitelist_mint_limit {
+        return Err(SogaNodeSaleError::WhitelistMintLimitExceeded.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_reservation_quantity(total_reserved_supply: u64, quantity: u64) -> Result<()> {
+    if total_reserved_supply > quantity {
+        return Err(SogaNodeSaleError::ReservationExceedsSupply.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_reservation_claimed(is_claimed: bool) -> Result<()> {
+    if is_claimed {
+        return Err(SogaNodeSaleError::ReservationClaimed.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_airdrop_claim_started(airdrop_claimed_quantity: u64) -> Result<()> {
+    if airdrop_claimed_quantity > 0 {
+        return Err(SogaNodeSaleError::AirdropClaimStarted.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_airdrop_claim_index(index: u32, total_claims: u32) -> Result<()> {
+    if index >= total_claims {
+        return Err(SogaNodeSaleError::InvalidAirdropClaim.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_airdrop_proof(is_valid: bool) -> Result<()> {
+    if !is_valid {
+        return Err(SogaNodeSaleError::InvalidAirdropClaim.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_airdrop_claim_quantity(airdrop_claimed_quantity: u64, airdrop_claim_quantity: u64) -> Result<()> {
+    if airdrop_claimed_quantity > airdrop_claim_quantity {
+        return Err(SogaNodeSaleError::AirdropClaimExceedsQuantity.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_airdrop_claimed(is_claimed: bool) -> Result<()> {
+    if is_claimed {
+        return Err(SogaNodeSaleError::AirdropClaimed.into());
+    }
+
+    Ok(())
+}
+
+/// Reward of a fill, nothing is paid when the vault can not pay it without dropping below `min_balance`.
+pub fn get_fill_reward(fill_reward: u64, vault_balance: u64, min_balance: u64) -> u64 {
+    if vault_balance < min_balance.saturating_add(fill_reward) {
+        return 0;
+    }
+
+    fill_reward
+}
+
+pub fn check_fill_fee_vault_balance(vault_balance: u64, min_balance: u64, amount: u64) -> Result<()> {
+    if vault_balance < min_balance.saturating_add(amount) {
+        return Err(SogaNodeSaleError::InsufficientFillFeeVaultBalance.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_realloc_account_owner(owner: Pubkey, program_id: Pubkey) -> Result<()> {
+    if owner != program_id {
+        return Err(SogaNodeSaleError::InvalidReallocAccount.into());
+    }
+
+    Ok(())
+}
+
+/// revoking token ids of an order created before node revocation requires `realloc_account` first
+pub fn check_order_layout(token_flags: usize, token_ids: usize) -> Result<()> {
+    if token_flags < token_ids {
+        return Err(SogaNodeSaleError::OrderLayoutOutdated.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_phase_caps_locked(is_locked: bool) -> Result<()> {
+    if is_locked {
+        return Err(SogaNodeSaleError::PhaseCapsLocked.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_cap_escrow_empty(cap_escrow_amount: u64) -> Result<()> {
+    if cap_escrow_amount == 0 {
+        return Err(SogaNodeSaleError::CapEscrowEmpty.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_order_token_ids_minted(is_minted: bool) -> Result<()> {
+    if is_minted {
+        return Err(SogaNodeSaleError::OrderTokenIdsMinted.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_cap_escrow_pending(is_cap_escrow_pending: bool) -> Result<()> {
+    if is_cap_escrow_pending {
+        return Err(SogaNodeSaleError::CapEscrowPending.into());
+    }
+
+    Ok(())
+}
+
+/// the curve price of the last node of the tier has to fit in a u64
+pub fn check_price_curve(price_curve: &PriceCurve, base_price: u64, quantity: u64) -> Result<()> {
+    let is_valid: bool = match price_curve {
+        PriceCurve::None => true,
+        PriceCurve::Linear { increment } => *increment > 0,
+        PriceCurve::Exponential { growth_bps } => *growth_bps > 0 && *growth_bps <= MAX_CURVE_GROWTH_BPS,
+    } && get_curve_unit_prices(price_curve, base_price, quantity.saturating_sub(1), 1).is_ok();
+
+    if !is_valid {
+        return Err(SogaNodeSaleError::InvalidPriceCurve.into());
+    }
+
+    Ok(())
+}
+
+/// the payment token program is the owner of the payment token mint and of the escrow it moves
+pub fn check_payment_token_program(payment_token_program: Pubkey, mint_owner: Pubkey, escrow_owner: Pubkey) -> Result<()> {
+    let is_token_program: bool = payment_token_program == anchor_spl::token::ID || payment_token_program == anchor_spl::token_2022::ID;
+
+    if !is_token_program || payment_token_program != mint_owner || payment_token_program != escrow_owner {
+        return Err(SogaNodeSaleError::InvalidPaymentTokenProgram.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_allocation_ended(is_allocation_ended: bool) -> Result<()> {
+    if is_allocation_ended {
+        return Err(SogaNodeSaleError::AllocationEnded.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_ticket_ranges(ticket_ranges: usize, is_adjacent: bool) -> Result<()> {
+    if ticket_ranges >= MAX_TICKET_RANGES && !is_adjacent {
+        return Err(SogaNodeSaleError::TooManyCommits.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_referral_receiver(value_from_order: Pubkey, value_from_input_accounts: Pubkey) -> Result<()> {
+    if value_from_order != value_from_input_accounts {
+        return Err(SogaNodeSaleError::InvalidReferralReceiver.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_loyalty_node_metadata(owner: Pubkey, node_metadata: Pubkey, node_metadata_from_seeds: Pubkey) -> Result<()> {
+    if owner != anchor_spl::metadata::ID || node_metadata != node_metadata_from_seeds {
+        return Err(SogaNodeSaleError::InvalidLoyaltyNode.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_loyalty_node_used(is_used: bool) -> Result<()> {
+    if is_used {
+        return Err(SogaNodeSaleError::LoyaltyNodeUsed.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_loyalty_phase(sale_phase_detail: Pubkey, loyalty_sale_phase_detail: Pubkey, start_timestamp: i64, loyalty_start_timestamp: i64) -> Result<()> {
+    if sale_phase_detail == loyalty_sale_phase_detail || loyalty_start_timestamp > start_timestamp {
+        return Err(SogaNodeSaleError::InvalidLoyaltyCollections.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_loyalty_tier(sale_phase_tier_detail_from_seeds: Pubkey, sale_phase_tier_detail: Pubkey) -> Result<()> {
+    if sale_phase_tier_detail_from_seeds != sale_phase_tier_detail {
+        return Err(SogaNodeSaleError::InvalidLoyaltyCollections.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_public_quantity_locked(is_public_quantity_locked: bool) -> Result<()> {
+    if is_public_quantity_locked {
+        return Err(SogaNodeSaleError::PublicQuantityLocked.into());
+    }
+
+    Ok(())
+}
\ No newline at end of file
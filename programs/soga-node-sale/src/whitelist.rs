@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    token_interface::{Mint, TokenAccount, Burn, burn, TransferChecked, transfer_checked},
+};
+
+use crate::error::SogaNodeSaleError;
+
+use crate::utils::{check_whitelist_pass_token_account, check_whitelist_pass_escrow, check_whitelist_pass_token_program};
+
+/// Consumes one whole pass token per node from `whitelist_pass_token_account`, burned
+/// when `whitelist_pass_escrow` is None and transferred to the escrow owned by the tier
+/// otherwise. Returns the amount of pass tokens consumed.
+pub fn consume_whitelist_pass<'info>(
+    whitelist_pass_mint: &AccountInfo<'info>,
+    whitelist_pass_token_account: &AccountInfo<'info>,
+    whitelist_pass_escrow: Option<&AccountInfo<'info>>,
+    whitelist_pass_token_program: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    sale_phase_tier_detail: Pubkey,
+    quantity: u64,
+) -> Result<u64> {
+    check_whitelist_pass_token_program(*whitelist_pass_mint.owner, whitelist_pass_token_program.key())?;
+
+    let decimals: u8 = Mint::try_deserialize(&mut &whitelist_pass_mint.try_borrow_data()?[..])?.decimals;
+
+    let whitelist_pass_token: TokenAccount = TokenAccount::try_deserialize(&mut &whitelist_pass_token_account.try_borrow_data()?[..])?;
+    check_whitelist_pass_token_account(whitelist_pass_mint.key(), whitelist_pass_token.mint)?;
+
+    let amount: u64 = 10u64.checked_pow(decimals as u32)
+        .and_then(|unit| unit.checked_mul(quantity))
+        .ok_or(SogaNodeSaleError::MathOverflow)?;
+
+    match whitelist_pass_escrow {
+        None => {
+            let cpi_accounts = Burn {
+                mint: whitelist_pass_mint.clone(),
+                from: whitelist_pass_token_account.clone(),
+                authority: authority.clone(),
+            };
+            let cpi_context = CpiContext::new(whitelist_pass_token_program.clone(), cpi_accounts);
+            burn(cpi_context, amount)?;
+        }
+        Some(whitelist_pass_escrow) => {
+            let whitelist_pass_escrow_token: TokenAccount = TokenAccount::try_deserialize(&mut &whitelist_pass_escrow.try_borrow_data()?[..])?;
+            check_whitelist_pass_escrow(whitelist_pass_escrow_token.owner, sale_phase_tier_detail, whitelist_pass_escrow_token.mint, whitelist_pass_mint.key())?;
+
+            let cpi_accounts = TransferChecked {
+                from: whitelist_pass_token_account.clone(),
+                mint: whitelist_pass_mint.clone(),
+                to: whitelist_pass_escrow.clone(),
+                authority: authority.clone(),
+            };
+            let cpi_context = CpiContext::new(whitelist_pass_token_program.clone(), cpi_accounts);
+            transfer_checked(cpi_context, amount, decimals)?;
+        }
+    }
+
+    Ok(amount)
+}
\ No newline at end of file
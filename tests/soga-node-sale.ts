@@ -5,6 +5,7 @@ import {
     LAMPORTS_PER_SOL,
     PublicKey,
     SYSVAR_RENT_PUBKEY,
+    SYSVAR_SLOT_HASHES_PUBKEY,
     SystemProgram,
     Transaction,
     ComputeBudgetProgram
@@ -21,8 +22,11 @@ import {
     mintToChecked,
     createAssociatedTokenAccountIdempotentInstruction,
     TOKEN_PROGRAM_ID,
+    TOKEN_2022_PROGRAM_ID,
+    getAccount,
 } from '@solana/spl-token'
 import {assert} from "chai";
+import {createHash} from "crypto";
 
 interface InitializeSalePhaseEvent {
     timestamp: BN,
@@ -345,6 +349,12 @@ const SOGA_NODE_SALE_PHASE_PAYMENT_TOKEN_ACCOUNT_PREFIX: string = "PHASE_PAYMENT
 const ORDER_DETAIL_ACCOUNT_PREFIX: string = "ORDER";
 const COLLECTION_ACCOUNT_PREFIX: string = "COLLECTION";
 const NODE_ACCOUNT_PREFIX: string = "NODE";
+const FILL_FEE_VAULT_ACCOUNT_PREFIX: string = "FILL_FEE_VAULT";
+const ORACLE_PRICE_ACCOUNT_PREFIX: string = "ORACLE_PRICE";
+const COMMITMENT_DETAIL_ACCOUNT_PREFIX: string = "COMMITMENT";
+const BID_DETAIL_ACCOUNT_PREFIX: string = "BID";
+const RESERVATION_DETAIL_ACCOUNT_PREFIX: string = "RESERVATION";
+const AIRDROP_CLAIM_BITMAP_ACCOUNT_PREFIX: string = "AIRDROP_CLAIM";
 
 const mainSigningAuthorityPubKey: PublicKey = anchor.AnchorProvider.env().wallet.publicKey;
 const signingAuthorityKeypair: Keypair = Keypair.generate();
@@ -374,7 +384,12 @@ const priceFeedIdUsdc: string = "eaa020c61cc479712813461ce153894a96a6c00b21ed0cf
 const pythReceiver: PublicKey = new PublicKey("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
 const pythPriceFeed: PublicKey = new PublicKey("pythWSnswVUd12oZpeFP8e9CVaEqJg25g1Vtc2biRsT");
 
+const bubblegumProgram: PublicKey = new PublicKey("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY");
+const splAccountCompressionProgram: PublicKey = new PublicKey("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+const splNoopProgram: PublicKey = new PublicKey("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+
 let paymentTokenMintAccount: PublicKey;
+let whitelistPassMintAccount: PublicKey;
 
 let userAPaymentTokenAccount: PublicKey;
 let userBPaymentTokenAccount: PublicKey;
@@ -389,8 +404,28 @@ let sogaNodeSaleConfigBump: number;
 let sogaNodeSalePhaseOnePDA: PublicKey;
 let sogaNodeSalePhaseOneBump: number;
 
+let sogaNodeSalePhaseTwoPDA: PublicKey;
+let sogaNodeSalePhaseTwoBump: number;
+
+let sogaNodeSalePhaseThreePDA: PublicKey;
+let sogaNodeSalePhaseThreeBump: number;
+
+let sogaNodeSalePhaseFourPDA: PublicKey;
+let sogaNodeSalePhaseFourBump: number;
+
+let sogaNodeSalePhaseFivePDA: PublicKey;
+let sogaNodeSalePhaseFiveBump: number;
+
+const merkleTreeKeypair: Keypair = Keypair.generate();
+
+const bidSaltUserA: Buffer = Buffer.alloc(32, 1);
+const bidSaltUserB: Buffer = Buffer.alloc(32, 2);
+
 let phaseOne = "one";
 let phaseTwo = "two";
+let phaseThree = "three";
+let phaseFour = "four";
+let phaseFive = "five";
 
 let collection_name: string = "Name";
 let collection_symbol: string = "Symbol";
@@ -524,6 +559,18 @@ describe("soga_node_sale", () => {
         [sogaNodeSalePhaseOnePDA, sogaNodeSalePhaseOneBump] = getSogaNodeSalePhaseDetailAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX, phaseOne);
         console.log("soga node sale phase detail account pda: ", sogaNodeSalePhaseOnePDA.toBase58());
         console.log("soga node sale phase detail account bump: ", sogaNodeSalePhaseOneBump);
+
+        [sogaNodeSalePhaseTwoPDA, sogaNodeSalePhaseTwoBump] = getSogaNodeSalePhaseDetailAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX, phaseTwo);
+        console.log("soga node sale phase two detail account pda: ", sogaNodeSalePhaseTwoPDA.toBase58());
+
+        [sogaNodeSalePhaseThreePDA, sogaNodeSalePhaseThreeBump] = getSogaNodeSalePhaseDetailAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX, phaseThree);
+        console.log("soga node sale phase three detail account pda: ", sogaNodeSalePhaseThreePDA.toBase58());
+
+        [sogaNodeSalePhaseFourPDA, sogaNodeSalePhaseFourBump] = getSogaNodeSalePhaseDetailAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX, phaseFour);
+        console.log("soga node sale phase four detail account pda: ", sogaNodeSalePhaseFourPDA.toBase58());
+
+        [sogaNodeSalePhaseFivePDA, sogaNodeSalePhaseFiveBump] = getSogaNodeSalePhaseDetailAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_DETAIL_ACCOUNT_PREFIX, phaseFive);
+        console.log("soga node sale phase five detail account pda: ", sogaNodeSalePhaseFivePDA.toBase58());
     })
 
     it("initialize", async () => {
@@ -542,7 +589,7 @@ describe("soga_node_sale", () => {
 
     it("Initialize Sale Phase One", async () => {
 
-        const tx = await program.methods.initializeSalePhase(sogaNodeSaleConfigBump, phaseOne, 5, nft_name, nft_symbol, nft_url, priceFeedIdSol)
+        const tx = await program.methods.initializeSalePhase(sogaNodeSaleConfigBump, phaseOne, 5, nft_name, nft_symbol, nft_url, priceFeedIdSol, {metaplex: {}})
             .accounts({
                 payer: mainSigningAuthorityPubKey,
                 mainSigningAuthority: mainSigningAuthorityPubKey,
@@ -723,7 +770,7 @@ describe("soga_node_sale", () => {
 
         const tx = await program.methods.initializeSalePhaseTier(sogaNodeSalePhaseOneBump, phaseOne, tierId.toString(),
             new BN(100), new BN(10), new BN(5),
-            collection_name, collection_symbol, collection_url, new BN(5))
+            collection_name, collection_symbol, collection_url, new BN(5), false, 0, 0)
             .accounts({
                 payer: mainSigningAuthorityPubKey,
                 signingAuthority: signingAuthorityKeypair.publicKey,
@@ -831,7 +878,7 @@ describe("soga_node_sale", () => {
 
         const tx = await program.methods.initializeSalePhaseTier(sogaNodeSalePhaseOneBump, phaseOne, tierId.toString(),
             new BN(200), new BN(10), new BN(5),
-            collection_name, collection_symbol, collection_url, new BN(4))
+            collection_name, collection_symbol, collection_url, new BN(4), false, 0, 0)
             .accounts({
                 payer: mainSigningAuthorityPubKey,
                 signingAuthority: signingAuthorityKeypair.publicKey,
@@ -891,7 +938,7 @@ describe("soga_node_sale", () => {
         const [orderPda] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseOnePDA, userDetailPda, orderId.toString());
 
         const tx = await program.methods.buy(sogaNodeSalePhaseOneBump, nodeSalePhaseTierBump,
-            phaseOne, tierId.toString(), orderId.toString(), new BN(2), true, 2000, true, 1000, false, false, 0)
+            phaseOne, tierId.toString(), orderId.toString(), new BN(2), true, 2000, true, 1000, false, false, 0, new BN("18446744073709551615"), false)
             .accounts({
                 payer: mainSigningAuthorityPubKey,
                 signingAuthority: signingAuthorityKeypair.publicKey,
@@ -958,7 +1005,7 @@ describe("soga_node_sale", () => {
 
 
         const tx = await program.methods.buy(sogaNodeSalePhaseOneBump, nodeSalePhaseTierBump,
-            phaseOne, tierId.toString(), orderId.toString(), new BN(2), true, 2000, true, 1000, true, true, 1000)
+            phaseOne, tierId.toString(), orderId.toString(), new BN(2), true, 2000, true, 1000, true, true, 1000, new BN("18446744073709551615"), false)
             .accounts({
                 payer: mainSigningAuthorityPubKey,
                 signingAuthority: signingAuthorityKeypair.publicKey,
@@ -1054,7 +1101,7 @@ describe("soga_node_sale", () => {
         );
 
         const tx = await program.methods.buyWithToken(sogaNodeSalePhaseOneBump, nodeSalePhaseTierBump,
-            phaseOne, tierId.toString(), orderId.toString(), new BN(1), true, 2000, true, 1000, true, true, 1000)
+            phaseOne, tierId.toString(), orderId.toString(), new BN(1), true, 2000, true, 1000, true, true, 1000, new BN("18446744073709551615"), false)
             .accounts({
                 payer: mainSigningAuthorityPubKey,
                 signingAuthority: signingAuthorityKeypair.publicKey,
@@ -1194,7 +1241,7 @@ describe("soga_node_sale", () => {
         );
 
         const tx = await program.methods.buyWithToken(sogaNodeSalePhaseOneBump, nodeSalePhaseTierBump,
-            phaseOne, tierId.toString(), orderId.toString(), new BN(3), true, 2000, true, 1000, false, false, 0)
+            phaseOne, tierId.toString(), orderId.toString(), new BN(3), true, 2000, true, 1000, false, false, 0, new BN("18446744073709551615"), false)
             .accounts({
                 payer: mainSigningAuthorityPubKey,
                 signingAuthority: signingAuthorityKeypair.publicKey,
@@ -1435,7 +1482,6 @@ describe("soga_node_sale", () => {
             phaseOne, tierId.toString(), tokenId.toString(), orderId.toString())
             .accounts({
                 payer: mainSigningAuthorityPubKey,
-                signingAuthority: signingAuthorityKeypair.publicKey,
                 user: userAKeypair.publicKey,
                 salePhaseDetail: sogaNodeSalePhaseOnePDA,
                 salePhaseTierDetail: nodeSalePhaseTierPda,
@@ -1473,7 +1519,6 @@ describe("soga_node_sale", () => {
             ])
 
             .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
-            .signers([signingAuthorityKeypair])
             .rpc({skipPreflight: true});
 
         console.log("Your transaction signature", tx);
@@ -1540,7 +1585,6 @@ describe("soga_node_sale", () => {
             phaseOne, tierId.toString(), tokenId.toString(), orderId.toString())
             .accounts({
                 payer: mainSigningAuthorityPubKey,
-                signingAuthority: signingAuthorityKeypair.publicKey,
                 user: userAKeypair.publicKey,
                 salePhaseDetail: sogaNodeSalePhaseOnePDA,
                 salePhaseTierDetail: nodeSalePhaseTierPda,
@@ -1578,7 +1622,6 @@ describe("soga_node_sale", () => {
             ])
 
             .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
-            .signers([signingAuthorityKeypair])
             .rpc({skipPreflight: true});
 
         console.log("Your transaction signature", tx);
@@ -1645,7 +1688,6 @@ describe("soga_node_sale", () => {
             phaseOne, tierId.toString(), tokenId.toString(), orderId.toString())
             .accounts({
                 payer: mainSigningAuthorityPubKey,
-                signingAuthority: signingAuthorityKeypair.publicKey,
                 user: userAKeypair.publicKey,
                 salePhaseDetail: sogaNodeSalePhaseOnePDA,
                 salePhaseTierDetail: nodeSalePhaseTierPda,
@@ -1683,7 +1725,6 @@ describe("soga_node_sale", () => {
             ])
 
             .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
-            .signers([signingAuthorityKeypair])
             .rpc({skipPreflight: true});
 
         console.log("Your transaction signature", tx);
@@ -1844,7 +1885,6 @@ describe("soga_node_sale", () => {
             phaseOne, tierId.toString(), tokenId.toString(), orderId.toString())
             .accounts({
                 payer: mainSigningAuthorityPubKey,
-                signingAuthority: signingAuthorityKeypair.publicKey,
                 user: kolKeypair.publicKey,
                 salePhaseDetail: sogaNodeSalePhaseOnePDA,
                 salePhaseTierDetail: nodeSalePhaseTierPda,
@@ -1882,7 +1922,6 @@ describe("soga_node_sale", () => {
             ])
 
             .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
-            .signers([signingAuthorityKeypair])
             .rpc({skipPreflight: true});
 
         console.log("Your transaction signature", tx);
@@ -1949,7 +1988,6 @@ describe("soga_node_sale", () => {
             phaseOne, tierId.toString(), tokenId.toString(), orderId.toString())
             .accounts({
                 payer: mainSigningAuthorityPubKey,
-                signingAuthority: signingAuthorityKeypair.publicKey,
                 user: kolKeypair.publicKey,
                 salePhaseDetail: sogaNodeSalePhaseOnePDA,
                 salePhaseTierDetail: nodeSalePhaseTierPda,
@@ -1987,7 +2025,6 @@ describe("soga_node_sale", () => {
             ])
 
             .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
-            .signers([signingAuthorityKeypair])
             .rpc({skipPreflight: true});
 
         console.log("Your transaction signature", tx);
@@ -1998,153 +2035,4938 @@ describe("soga_node_sale", () => {
 
     });
 
-    it("Remove Events", async () => {
-        await delay(2000);
+    it("Unlock Sale Phase One Tier One", async () => {
 
-        await program.removeEventListener(initializeSalePhaseEventListener);
-        await program.removeEventListener(initializeSalePhaseTierEventListener);
-        await program.removeEventListener(initializeSalePhasePaymentTokenEventListener);
-        await program.removeEventListener(updateSalePhaseEventListener);
-        await program.removeEventListener(updateSalePhaseTierEventListener);
-        await program.removeEventListener(updateSalePhasePaymentTokenEventListener);
-        await program.removeEventListener(airdropEventListener);
-        await program.removeEventListener(buyEventListener);
-        await program.removeEventListener(buyWithTokenEventListener);
-        await program.removeEventListener(fillOrderEventListener);
-        await program.removeEventListener(createOrderReceiptEventListener);
+        const tierId: number = 1;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseOnePDA);
+
+        const tx = await program.methods.updateSalePhaseTierUnlock(sogaNodeSalePhaseOneBump, nodeSalePhaseTierBump,
+            phaseOne, tierId.toString(), new BN(0), true)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseOnePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        const salePhaseTierData = await program.account.sogaNodeSalePhaseTierDetailAccount.fetch(nodeSalePhaseTierPda.toBase58());
+        assert(salePhaseTierData.isUnlocked);
     });
 
-});
+    it("Initialize Sale Phase Two", async () => {
 
+        const tx = await program.methods.initializeSalePhase(sogaNodeSaleConfigBump, phaseTwo, 1, nft_name, nft_symbol, nft_url, priceFeedIdSol, {metaplex: {}})
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                mainSigningAuthority: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                saleConfig: sogaNodeSaleConfigPDA,
+                salePhaseDetail: sogaNodeSalePhaseTwoPDA,
+                priceFeed: priceFeedSolAddress,
+                paymentReceiver: priceReceiverKeypair.publicKey,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc();
+        console.log("Your transaction signature", tx);
 
-function delay(ms: number) {
-    return new Promise(resolve => setTimeout(resolve, ms));
-}
+        await delay(delayTimeCount);
 
-function getMetadataPda(mint: PublicKey): PublicKey {
-    const [metadataPda] = PublicKey.findProgramAddressSync(
-        [
-            Buffer.from('metadata'),
-            MPL_TOKEN_METADATA_PROGRAM_ID.toBuffer(),
-            mint.toBuffer(),
-        ], MPL_TOKEN_METADATA_PROGRAM_ID);
+        const salePhaseData = await program.account.sogaNodeSalePhaseDetailAccount.fetch(sogaNodeSalePhaseTwoPDA.toBase58());
 
-    return metadataPda;
-}
+        assert(salePhaseData.totalPayment.toString() === "0");
+        assert(salePhaseData.totalTiers.toString() === "1");
+        assert(!salePhaseData.isSoftCapReached);
+    });
 
-function getMasterPda(mint: PublicKey): PublicKey {
-    const [masterPda] = PublicKey.findProgramAddressSync(
-        [
-            Buffer.from('metadata'),
-            MPL_TOKEN_METADATA_PROGRAM_ID.toBuffer(),
-            mint.toBuffer(),
-            Buffer.from('edition')
-        ],
-        MPL_TOKEN_METADATA_PROGRAM_ID
-    );
+    it("Update Sale Phase Two Caps", async () => {
 
-    return masterPda;
-}
+        // caps are set before the first payment, the first buy is escrowed and reaches the soft cap
+        const softCap = new BN(1);
+        const endTimestamp = new BN(Math.floor(Date.now() / 1000) + 3600);
 
-function getSogaNodeSaleConfigAccountPdaAndBump(programAddress: PublicKey, prefix: string): [PublicKey, number] {
-    return PublicKey.findProgramAddressSync(
-        [Buffer.from(prefix)],
-        programAddress
-    )
-}
+        const tx = await program.methods.updateSalePhaseCaps(sogaNodeSalePhaseTwoBump, phaseTwo, softCap, new BN(0), new BN(0), endTimestamp)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseTwoPDA,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc();
 
-function getSogaNodeSalePhaseDetailAccountPdaAndBump(programAddress: PublicKey, prefix: string, salePhaseName: string): [PublicKey, number] {
-    return PublicKey.findProgramAddressSync(
-        [
-            Buffer.from(prefix),
-            Buffer.from(salePhaseName)
-        ],
-        programAddress
-    )
-}
+        console.log("Your transaction signature", tx);
 
-function getSogaNodeSalePhaseDetailTierAccountPdaAndBump(programAddress: PublicKey, prefix: string, tier: string,
-                                                         sogaNodeSalePhaseDetailPda: PublicKey): [PublicKey, number] {
-    return PublicKey.findProgramAddressSync(
-        [
-            Buffer.from(prefix),
-            sogaNodeSalePhaseDetailPda.toBuffer(),
-            Buffer.from(tier),
-        ],
-        programAddress
-    )
-}
+        await delay(delayTimeCount);
 
-function getSogaNodeSalePhaseDetailTierCollectionAccountPdaAndBump(programAddress: PublicKey, prefix: string,
-                                                                   sogaNodeSalePhaseTierDetailPda: PublicKey): [PublicKey, number] {
-    return PublicKey.findProgramAddressSync(
-        [
-            Buffer.from(prefix),
-            sogaNodeSalePhaseTierDetailPda.toBuffer()
-        ],
-        programAddress
-    )
-}
+        const updatedSalePhaseData = await program.account.sogaNodeSalePhaseDetailAccount.fetch(sogaNodeSalePhaseTwoPDA.toBase58());
 
-function getUserAccountPdaAndBump(programAddress: PublicKey, prefix: string,
-                                  sogaNodeSalePhaseDetailPda: PublicKey, userAddress: PublicKey): [PublicKey, number] {
-    return PublicKey.findProgramAddressSync(
-        [
-            Buffer.from(prefix),
-            sogaNodeSalePhaseDetailPda.toBuffer(),
-            userAddress.toBuffer()
-        ],
-        programAddress
-    )
-}
+        assert(updatedSalePhaseData.softCap.toString() === softCap.toString());
+        assert(updatedSalePhaseData.hardCap.toString() === "0");
+        assert(updatedSalePhaseData.endTimestamp.toString() === endTimestamp.toString());
+    });
 
-function getUserTierAccountPdaAndBump(programAddress: PublicKey, prefix: string,
-                                      userDetailPdaAddress: PublicKey,
-                                      sogaNodeSalePhaseTierDetailPda: PublicKey): [PublicKey, number] {
-    return PublicKey.findProgramAddressSync(
-        [
-            Buffer.from(prefix),
-            userDetailPdaAddress.toBuffer(),
-            sogaNodeSalePhaseTierDetailPda.toBuffer()
-        ],
-        programAddress
-    )
-}
+    it("Initialize Sale Phase Two Tier One", async () => {
 
-function getNodeMintAccount(programAddress: PublicKey, prefix: string,
-                            collectionMintAccount: PublicKey, tokenId: string): [PublicKey, number] {
-    return PublicKey.findProgramAddressSync(
-        [
-            Buffer.from(prefix),
-            collectionMintAccount.toBuffer(),
-            Buffer.from(tokenId)
-        ],
-        programAddress
-    )
-}
+        const tierId = 1;
 
-function getSogaNodeSalePhasePaymentTokenDetailAccountPdaAndBump(programAddress: PublicKey, prefix: string, salePhaseName: string,
-                                                                 sogaNodeSalePhaseDetailPda: PublicKey, mintAccount: PublicKey): [PublicKey, number] {
-    return PublicKey.findProgramAddressSync(
-        [
-            Buffer.from(prefix),
-            sogaNodeSalePhaseDetailPda.toBuffer(),
-            mintAccount.toBuffer(),
-        ],
-        programAddress
-    )
-}
+        const [nodeSalePhaseTierPda] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseTwoPDA);
 
-function getOrderDetailAccountPdaAndBump(programAddress: PublicKey, prefix: string,
-                                         sogaNodeSalePhaseDetailPda: PublicKey,
-                                         userDetail: PublicKey, orderId: string): [PublicKey, number] {
-    return PublicKey.findProgramAddressSync(
-        [
-            Buffer.from(prefix),
-            sogaNodeSalePhaseDetailPda.toBuffer(),
-            userDetail.toBuffer(),
-            Buffer.from(orderId)
-        ],
-        programAddress
-    )
-}
\ No newline at end of file
+        const [nodeSalePhaseTierCollectionPda] = getSogaNodeSalePhaseDetailTierCollectionAccountPdaAndBump(program.programId, COLLECTION_ACCOUNT_PREFIX,
+            nodeSalePhaseTierPda);
+
+        const nodeSalePhaseTierCollectionMasterPda = getMasterPda(nodeSalePhaseTierCollectionPda);
+
+        const nodeSalePhaseTierCollectionMetadataPda = getMetadataPda(nodeSalePhaseTierCollectionPda);
+
+        const nodeSalePhaseTierCollectionTokenAccount = await getAssociatedTokenAddress(nodeSalePhaseTierCollectionPda, nodeSalePhaseTierPda, true, TOKEN_PROGRAM_ID);
+
+        const tx = await program.methods.initializeSalePhaseTier(sogaNodeSalePhaseTwoBump, phaseTwo, tierId.toString(),
+            new BN(100), new BN(10), new BN(5),
+            collection_name, collection_symbol, collection_url, new BN(0), false, 0, 0)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseTwoPDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                collectionMintAccount: nodeSalePhaseTierCollectionPda,
+                collectionMasterEdition: nodeSalePhaseTierCollectionMasterPda,
+                collectionMetadata: nodeSalePhaseTierCollectionMetadataPda,
+                collectionTokenAccount: nodeSalePhaseTierCollectionTokenAccount,
+                tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc({skipPreflight: true});
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const salePhaseTierData = await program.account.sogaNodeSalePhaseTierDetailAccount.fetch(nodeSalePhaseTierPda.toBase58());
+
+        assert(salePhaseTierData.collectionMintAddress.toBase58() === nodeSalePhaseTierCollectionPda.toBase58());
+        assert(salePhaseTierData.quantity.toString() === (new BN(10)).toString());
+    });
+
+    it("Buy Node One Sale Phase Two Tier One with cap escrow", async () => {
+
+        const tierId: number = 1;
+        const orderId: number = 1;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseTwoPDA);
+
+        const [userDetailPda] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseTwoPDA, userBKeypair.publicKey);
+
+        const [userPhaseTierDetailPda] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX, userDetailPda, nodeSalePhaseTierPda);
+
+        const [orderPda] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseTwoPDA, userDetailPda, orderId.toString());
+
+        const salePhaseBalanceBefore = await connection.getBalance(sogaNodeSalePhaseTwoPDA);
+        const fullReceiverBalanceBefore = await connection.getBalance(fullReceiverKeypair.publicKey);
+        const halfReceiverBalanceBefore = await connection.getBalance(halfReceiverKeypair.publicKey);
+
+        const tx = await program.methods.buy(sogaNodeSalePhaseTwoBump, nodeSalePhaseTierBump,
+            phaseTwo, tierId.toString(), orderId.toString(), new BN(1), true, 2000, true, 1000, false, false, 0, new BN("18446744073709551615"), false)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                userPayer: userBKeypair.publicKey,
+                user: userBKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseTwoPDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                orderDetail: orderPda,
+                priceUpdate: priceFeedSolAddress,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            }).remainingAccounts([
+                {
+                    pubkey: priceReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: fullReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: halfReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                }
+            ]).preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
+            .signers([signingAuthorityKeypair, userBKeypair])
+            .rpc({skipPreflight: true});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const orderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+        console.log(orderData);
+
+        // the payment and the referral shares are held by the sale phase detail
+        assert(!orderData.isCapSettled);
+        assert(orderData.capEscrowAmount.gt(new BN(0)));
+        assert(orderData.fullDiscountEscrowAmount.gt(new BN(0)));
+        assert(orderData.halfDiscountEscrowAmount.gt(new BN(0)));
+
+        assert((await connection.getBalance(sogaNodeSalePhaseTwoPDA)) - salePhaseBalanceBefore === orderData.capEscrowAmount.toNumber());
+        assert((await connection.getBalance(fullReceiverKeypair.publicKey)) === fullReceiverBalanceBefore);
+        assert((await connection.getBalance(halfReceiverKeypair.publicKey)) === halfReceiverBalanceBefore);
+
+        const salePhaseData = await program.account.sogaNodeSalePhaseDetailAccount.fetch(sogaNodeSalePhaseTwoPDA.toBase58());
+        assert(salePhaseData.totalPayment.gte(salePhaseData.softCap));
+        assert(salePhaseData.isSoftCapReached);
+    });
+
+    it("Update Sale Phase Two Caps fails once the phase has payments", async () => {
+
+        const salePhaseData = await program.account.sogaNodeSalePhaseDetailAccount.fetch(sogaNodeSalePhaseTwoPDA.toBase58());
+
+        // a higher soft cap would turn the escrowed payments into refunds
+        await expectError(program.methods.updateSalePhaseCaps(sogaNodeSalePhaseTwoBump, phaseTwo, salePhaseData.totalPayment.add(new BN(1000)), new BN(0),
+            salePhaseData.startTimestamp, salePhaseData.endTimestamp)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseTwoPDA,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc(), "PhaseCapsLocked");
+
+        const updatedSalePhaseData = await program.account.sogaNodeSalePhaseDetailAccount.fetch(sogaNodeSalePhaseTwoPDA.toBase58());
+        assert(updatedSalePhaseData.softCap.toString() === salePhaseData.softCap.toString());
+    });
+
+    it("Settle Order Cap Escrow User B Order One", async () => {
+
+        const orderId: number = 1;
+
+        const [userDetailPda, userDetailBump] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseTwoPDA, userBKeypair.publicKey);
+
+        const [orderPda, orderBump] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseTwoPDA, userDetailPda, orderId.toString());
+
+        const orderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+
+        const receiverBalanceBefore = await connection.getBalance(priceReceiverKeypair.publicKey);
+        const fullReceiverBalanceBefore = await connection.getBalance(fullReceiverKeypair.publicKey);
+        const halfReceiverBalanceBefore = await connection.getBalance(halfReceiverKeypair.publicKey);
+
+        // anyone can settle the escrow of an order
+        const tx = await program.methods.settleOrderCapEscrow(sogaNodeSalePhaseTwoBump, userDetailBump, orderBump, phaseTwo, orderId.toString())
+            .accounts({
+                payer: feePayerKeypair.publicKey,
+                user: userBKeypair.publicKey,
+                paymentReceiver: priceReceiverKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseTwoPDA,
+                userDetail: userDetailPda,
+                orderDetail: orderPda,
+                fullDiscountReceiver: fullReceiverKeypair.publicKey,
+                halfDiscountReceiver: halfReceiverKeypair.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers([feePayerKeypair])
+            .rpc({skipPreflight: true});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const settledOrderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+        assert(settledOrderData.isCapSettled);
+
+        const fullDiscountAmount = orderData.fullDiscountEscrowAmount.toNumber();
+        const halfDiscountAmount = orderData.halfDiscountEscrowAmount.toNumber();
+        const receiverAmount = orderData.capEscrowAmount.toNumber() - fullDiscountAmount - halfDiscountAmount;
+
+        assert((await connection.getBalance(priceReceiverKeypair.publicKey)) - receiverBalanceBefore === receiverAmount);
+        assert((await connection.getBalance(fullReceiverKeypair.publicKey)) - fullReceiverBalanceBefore === fullDiscountAmount);
+        assert((await connection.getBalance(halfReceiverKeypair.publicKey)) - halfReceiverBalanceBefore === halfDiscountAmount);
+    });
+
+    it("Update Sale Phase Two Installment", async () => {
+
+        const tx = await program.methods.updateSalePhaseInstallment(sogaNodeSalePhaseTwoBump, phaseTwo, 5000, new BN(20), true)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseTwoPDA,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const salePhaseData = await program.account.sogaNodeSalePhaseDetailAccount.fetch(sogaNodeSalePhaseTwoPDA.toBase58());
+
+        assert(salePhaseData.installmentDepositBps === 5000);
+        assert(salePhaseData.installmentPeriod.toString() === "20");
+        assert(salePhaseData.isInstallmentDepositRefundable);
+    });
+
+    it("Buy installment Node One Sale Phase Two Tier One", async () => {
+
+        const tierId: number = 1;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseTwoPDA);
+
+        const [userDetailPda] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseTwoPDA, userBKeypair.publicKey);
+
+        const [userPhaseTierDetailPda] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX, userDetailPda, nodeSalePhaseTierPda);
+
+        // order two is paid in full, order three lapses
+        for (const orderId of [2, 3]) {
+            const [orderPda] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseTwoPDA, userDetailPda, orderId.toString());
+
+            const tx = await program.methods.buy(sogaNodeSalePhaseTwoBump, nodeSalePhaseTierBump,
+                phaseTwo, tierId.toString(), orderId.toString(), new BN(1), false, 0, false, 0, false, false, 0, new BN("18446744073709551615"), true)
+                .accounts({
+                    payer: mainSigningAuthorityPubKey,
+                    signingAuthority: signingAuthorityKeypair.publicKey,
+                    userPayer: userBKeypair.publicKey,
+                    user: userBKeypair.publicKey,
+                    salePhaseDetail: sogaNodeSalePhaseTwoPDA,
+                    salePhaseTierDetail: nodeSalePhaseTierPda,
+                    userDetail: userDetailPda,
+                    userTierDetail: userPhaseTierDetailPda,
+                    orderDetail: orderPda,
+                    priceUpdate: priceFeedSolAddress,
+                    systemProgram: SystemProgram.programId,
+                    rent: SYSVAR_RENT_PUBKEY
+                }).remainingAccounts([
+                    {
+                        pubkey: priceReceiverKeypair.publicKey,
+                        isWritable: true,
+                        isSigner: false
+                    },
+                    {
+                        pubkey: fullReceiverKeypair.publicKey,
+                        isWritable: true,
+                        isSigner: false
+                    },
+                    {
+                        pubkey: halfReceiverKeypair.publicKey,
+                        isWritable: true,
+                        isSigner: false
+                    }
+                ]).preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
+                .signers([signingAuthorityKeypair, userBKeypair])
+                .rpc({skipPreflight: true});
+
+            console.log("Your transaction signature", tx);
+
+            await delay(delayTimeCount);
+
+            const orderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+            console.log(orderData);
+
+            assert(orderData.isInstallment);
+            assert(orderData.capEscrowAmount.gt(new BN(0)));
+            assert(orderData.installmentPaidInUsd.lt(orderData.installmentOwedInUsd));
+        }
+    });
+
+    it("Lapse Installment User B Order Three fails before its deadline", async () => {
+
+        const tierId: number = 1;
+        const orderId: number = 3;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseTwoPDA);
+
+        const [userDetailPda, userDetailBump] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseTwoPDA, userBKeypair.publicKey);
+
+        const [userPhaseTierDetailPda, userPhaseTierDetailBump] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX, userDetailPda, nodeSalePhaseTierPda);
+
+        const [orderPda, orderBump] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseTwoPDA, userDetailPda, orderId.toString());
+
+        await expectError(program.methods.lapseInstallmentOrder(sogaNodeSalePhaseTwoBump, nodeSalePhaseTierBump, userDetailBump, userPhaseTierDetailBump, orderBump,
+            phaseTwo, tierId.toString(), orderId.toString())
+            .accounts({
+                payer: feePayerKeypair.publicKey,
+                user: userBKeypair.publicKey,
+                paymentReceiver: priceReceiverKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseTwoPDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                orderDetail: orderPda,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers([feePayerKeypair])
+            .rpc(), "InstallmentNotOverdue");
+
+        const orderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+        assert(!orderData.isInstallmentLapsed);
+    });
+
+    it("Pay Installment User B Order Two", async () => {
+
+        const tierId: number = 1;
+        const orderId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseTwoPDA);
+
+        const [userDetailPda, userDetailBump] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseTwoPDA, userBKeypair.publicKey);
+
+        const [userPhaseTierDetailPda, userPhaseTierDetailBump] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX, userDetailPda, nodeSalePhaseTierPda);
+
+        const [orderPda, orderBump] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseTwoPDA, userDetailPda, orderId.toString());
+
+        const orderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+        const salePhaseBalanceBefore = await connection.getBalance(sogaNodeSalePhaseTwoPDA);
+
+        const paymentInUsd = orderData.installmentOwedInUsd.sub(orderData.installmentPaidInUsd);
+
+        const tx = await program.methods.payInstallment(sogaNodeSalePhaseTwoBump, nodeSalePhaseTierBump, userDetailBump, userPhaseTierDetailBump, orderBump,
+            phaseTwo, tierId.toString(), orderId.toString(), paymentInUsd, new BN("18446744073709551615"))
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                userPayer: userBKeypair.publicKey,
+                user: userBKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseTwoPDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                orderDetail: orderPda,
+                salePhasePaymentTokenDetail: null,
+                priceUpdate: priceFeedSolAddress,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers([signingAuthorityKeypair, userBKeypair])
+            .rpc({skipPreflight: true});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const paidOrderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+
+        // the balance is held in the escrow until the order is settled
+        const paymentAmount = paidOrderData.capEscrowAmount.sub(orderData.capEscrowAmount);
+
+        assert(paidOrderData.installmentPaidInUsd.toString() === paidOrderData.installmentOwedInUsd.toString());
+        assert(paymentAmount.gt(new BN(0)));
+        assert((await connection.getBalance(sogaNodeSalePhaseTwoPDA)) - salePhaseBalanceBefore === paymentAmount.toNumber());
+    });
+
+    it("Settle Order Cap Escrow User B Order Two", async () => {
+
+        const orderId: number = 2;
+
+        const [userDetailPda, userDetailBump] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseTwoPDA, userBKeypair.publicKey);
+
+        const [orderPda, orderBump] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseTwoPDA, userDetailPda, orderId.toString());
+
+        const orderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+
+        const receiverBalanceBefore = await connection.getBalance(priceReceiverKeypair.publicKey);
+        const salePhaseBalanceBefore = await connection.getBalance(sogaNodeSalePhaseTwoPDA);
+
+        const tx = await program.methods.settleOrderCapEscrow(sogaNodeSalePhaseTwoBump, userDetailBump, orderBump, phaseTwo, orderId.toString())
+            .accounts({
+                payer: feePayerKeypair.publicKey,
+                user: userBKeypair.publicKey,
+                paymentReceiver: priceReceiverKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseTwoPDA,
+                userDetail: userDetailPda,
+                orderDetail: orderPda,
+                fullDiscountReceiver: null,
+                halfDiscountReceiver: null,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers([feePayerKeypair])
+            .rpc({skipPreflight: true});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const settledOrderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+        assert(settledOrderData.isCapSettled);
+
+        assert((await connection.getBalance(priceReceiverKeypair.publicKey)) - receiverBalanceBefore === orderData.capEscrowAmount.toNumber());
+        assert(salePhaseBalanceBefore - (await connection.getBalance(sogaNodeSalePhaseTwoPDA)) === orderData.capEscrowAmount.toNumber());
+    });
+
+    it("Lapse Installment User B Order Three", async () => {
+
+        const tierId: number = 1;
+        const orderId: number = 3;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseTwoPDA);
+
+        const [userDetailPda, userDetailBump] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseTwoPDA, userBKeypair.publicKey);
+
+        const [userPhaseTierDetailPda, userPhaseTierDetailBump] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX, userDetailPda, nodeSalePhaseTierPda);
+
+        const [orderPda, orderBump] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseTwoPDA, userDetailPda, orderId.toString());
+
+        const orderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+        const salePhaseTierData = await program.account.sogaNodeSalePhaseTierDetailAccount.fetch(nodeSalePhaseTierPda.toBase58());
+
+        // wait for the installment deadline
+        await delay(20 * delayTimeCount);
+
+        const userBalanceBefore = await connection.getBalance(userBKeypair.publicKey);
+
+        // anyone can lapse an overdue order, the deposit is refundable in this phase
+        const tx = await program.methods.lapseInstallmentOrder(sogaNodeSalePhaseTwoBump, nodeSalePhaseTierBump, userDetailBump, userPhaseTierDetailBump, orderBump,
+            phaseTwo, tierId.toString(), orderId.toString())
+            .accounts({
+                payer: feePayerKeypair.publicKey,
+                user: userBKeypair.publicKey,
+                paymentReceiver: priceReceiverKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseTwoPDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                orderDetail: orderPda,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers([feePayerKeypair])
+            .rpc({skipPreflight: true});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const lapsedOrderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+
+        assert(lapsedOrderData.isCapSettled);
+        assert(lapsedOrderData.isVoided);
+        assert(lapsedOrderData.isInstallmentLapsed);
+
+        assert((await connection.getBalance(userBKeypair.publicKey)) - userBalanceBefore === orderData.capEscrowAmount.toNumber());
+
+        // the lapse returns the token id to the tier supply and leaves the latched soft cap and the tier sequence alone
+        const salePhaseData = await program.account.sogaNodeSalePhaseDetailAccount.fetch(sogaNodeSalePhaseTwoPDA.toBase58());
+        const lapsedSalePhaseTierData = await program.account.sogaNodeSalePhaseTierDetailAccount.fetch(nodeSalePhaseTierPda.toBase58());
+
+        assert(salePhaseData.isSoftCapReached);
+        assert(salePhaseData.totalCompletedTiers === 0);
+        assert(lapsedSalePhaseTierData.totalRevoked.sub(salePhaseTierData.totalRevoked).toString() === "1");
+    });
+
+    it("Initialize Sale Phase Three with token-2022", async () => {
+
+        const tx = await program.methods.initializeSalePhase(sogaNodeSaleConfigBump, phaseThree, 2, nft_name, nft_symbol, nft_url, priceFeedIdSol, {token2022: {}})
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                mainSigningAuthority: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                saleConfig: sogaNodeSaleConfigPDA,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                priceFeed: priceFeedSolAddress,
+                paymentReceiver: priceReceiverKeypair.publicKey,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc();
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const salePhaseData = await program.account.sogaNodeSalePhaseDetailAccount.fetch(sogaNodeSalePhaseThreePDA.toBase58());
+
+        assert(salePhaseData.mintMode.token2022 !== undefined);
+        assert(salePhaseData.totalTiers.toString() === "2");
+    });
+
+    it("Initialize Sale Phase Three Tiers with token-2022", async () => {
+
+        // tier one sells out in this phase so that its nodes can be upgraded to tier two
+        for (const [tierId, price, quantity, mintLimit] of [[1, 100, 3, 3], [2, 200, 10, 5]]) {
+
+            const [nodeSalePhaseTierPda] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+                tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+            const [nodeSalePhaseTierCollectionPda] = getSogaNodeSalePhaseDetailTierCollectionAccountPdaAndBump(program.programId, COLLECTION_ACCOUNT_PREFIX,
+                nodeSalePhaseTierPda);
+
+            const tx = await program.methods.initializeSalePhaseTierToken2022(sogaNodeSalePhaseThreeBump, phaseThree, tierId.toString(),
+                new BN(price), new BN(quantity), new BN(mintLimit),
+                collection_name, collection_symbol, collection_url, new BN(0))
+                .accounts({
+                    payer: mainSigningAuthorityPubKey,
+                    signingAuthority: signingAuthorityKeypair.publicKey,
+                    salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                    salePhaseTierDetail: nodeSalePhaseTierPda,
+                    collectionMintAccount: nodeSalePhaseTierCollectionPda,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                    rent: SYSVAR_RENT_PUBKEY
+                })
+                .signers([signingAuthorityKeypair])
+                .rpc();
+            console.log("Your transaction signature", tx);
+
+            await delay(delayTimeCount);
+
+            const salePhaseTierData = await program.account.sogaNodeSalePhaseTierDetailAccount.fetch(nodeSalePhaseTierPda.toBase58());
+
+            assert(salePhaseTierData.collectionMintAddress.toBase58() === nodeSalePhaseTierCollectionPda.toBase58());
+            assert(salePhaseTierData.quantity.toString() === quantity.toString());
+
+            const collectionMintAccountInfo = await connection.getAccountInfo(nodeSalePhaseTierCollectionPda);
+            assert(collectionMintAccountInfo.owner.toBase58() === TOKEN_2022_PROGRAM_ID.toBase58());
+        }
+
+        const salePhaseData = await program.account.sogaNodeSalePhaseDetailAccount.fetch(sogaNodeSalePhaseThreePDA.toBase58());
+        assert(salePhaseData.totalInitializeTiers === 2);
+    });
+
+    it("Initialize Sale Phase One Tier Three with token-2022 fails in a metaplex phase", async () => {
+
+        const tierId = 3;
+
+        const [nodeSalePhaseTierPda] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseOnePDA);
+
+        const [nodeSalePhaseTierCollectionPda] = getSogaNodeSalePhaseDetailTierCollectionAccountPdaAndBump(program.programId, COLLECTION_ACCOUNT_PREFIX,
+            nodeSalePhaseTierPda);
+
+        await expectError(program.methods.initializeSalePhaseTierToken2022(sogaNodeSalePhaseOneBump, phaseOne, tierId.toString(),
+            new BN(100), new BN(10), new BN(5),
+            collection_name, collection_symbol, collection_url, new BN(0))
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseOnePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                collectionMintAccount: nodeSalePhaseTierCollectionPda,
+                tokenProgram: TOKEN_2022_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc(), "InvalidMintMode");
+
+        const salePhaseData = await program.account.sogaNodeSalePhaseDetailAccount.fetch(sogaNodeSalePhaseOnePDA.toBase58());
+        assert(salePhaseData.totalInitializeTiers === 2);
+    });
+
+    it("Buy Node One Sale Phase Three Tier One", async () => {
+
+        const tierId: number = 1;
+        const orderId: number = 1;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const [userDetailPda] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userAKeypair.publicKey);
+
+        const [userPhaseTierDetailPda] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX, userDetailPda, nodeSalePhaseTierPda);
+
+        const [orderPda] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userDetailPda, orderId.toString());
+
+        const tx = await program.methods.buy(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump,
+            phaseThree, tierId.toString(), orderId.toString(), new BN(2), true, 2000, true, 1000, false, false, 0, new BN("18446744073709551615"), false)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                userPayer: userAKeypair.publicKey,
+                user: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                orderDetail: orderPda,
+                priceUpdate: priceFeedSolAddress,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            }).remainingAccounts([
+                {
+                    pubkey: priceReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: fullReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: halfReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                }
+            ]).preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
+            .signers([signingAuthorityKeypair, userAKeypair])
+            .rpc({skipPreflight: true});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const orderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+
+        assert(orderData.tokenIds.map((tokenId) => tokenId.toString()).join() === "1,2");
+        assert(!orderData.isCompleted);
+    });
+
+    it("Fill Order User A Order One Token One Sale Phase Three with token-2022", async () => {
+
+        const tierId: number = 1;
+        const tokenId: number = 1;
+        const orderId: number = 1;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const [nodeSalePhaseTierCollectionPda, nodeSalePhaseTierCollectionBump] = getSogaNodeSalePhaseDetailTierCollectionAccountPdaAndBump(program.programId, COLLECTION_ACCOUNT_PREFIX,
+            nodeSalePhaseTierPda);
+
+        const [userDetailPda, userDetailBump] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX,
+            sogaNodeSalePhaseThreePDA, userAKeypair.publicKey);
+
+        const [orderPda, orderBump] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userDetailPda, orderId.toString());
+
+        const [nodeMintAccountPda] = getNodeMintAccount(program.programId, NODE_ACCOUNT_PREFIX,
+            nodeSalePhaseTierCollectionPda, tokenId.toString());
+
+        const nodeUserTokenAccount = await getAssociatedTokenAddress(nodeMintAccountPda, userAKeypair.publicKey, true, TOKEN_2022_PROGRAM_ID);
+
+        const tx = await program.methods.fileOrderToken2022(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, nodeSalePhaseTierCollectionBump,
+            userDetailBump, orderBump,
+            phaseThree, tierId.toString(), tokenId.toString(), orderId.toString())
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                user: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                orderDetail: orderPda,
+                collectionMintAccount: nodeSalePhaseTierCollectionPda,
+                nodeMintAccount: nodeMintAccountPda,
+                userTokenAccount: nodeUserTokenAccount,
+                tokenProgram: TOKEN_2022_PROGRAM_ID,
+                associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            })
+            .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
+            .rpc({skipPreflight: true});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const orderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+        assert(orderData.isTokenIdsMinted[0]);
+        assert(!orderData.isTokenIdsMinted[1]);
+
+        // the tier has no unlock schedule, the node stays frozen in the holder token account
+        const nodeUserToken = await getAccount(connection, nodeUserTokenAccount, undefined, TOKEN_2022_PROGRAM_ID);
+        assert(nodeUserToken.amount.toString() === "1");
+        assert(nodeUserToken.isFrozen);
+    });
+
+    it("Fill Order User A Order One Token One Sale Phase Three fails once the token id is filled", async () => {
+
+        const tierId: number = 1;
+        const tokenId: number = 1;
+        const orderId: number = 1;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const [nodeSalePhaseTierCollectionPda, nodeSalePhaseTierCollectionBump] = getSogaNodeSalePhaseDetailTierCollectionAccountPdaAndBump(program.programId, COLLECTION_ACCOUNT_PREFIX,
+            nodeSalePhaseTierPda);
+
+        const [userDetailPda, userDetailBump] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX,
+            sogaNodeSalePhaseThreePDA, userAKeypair.publicKey);
+
+        const [orderPda, orderBump] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userDetailPda, orderId.toString());
+
+        const [nodeMintAccountPda] = getNodeMintAccount(program.programId, NODE_ACCOUNT_PREFIX,
+            nodeSalePhaseTierCollectionPda, tokenId.toString());
+
+        const nodeUserTokenAccount = await getAssociatedTokenAddress(nodeMintAccountPda, userAKeypair.publicKey, true, TOKEN_2022_PROGRAM_ID);
+
+        await expectError(program.methods.fileOrderToken2022(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, nodeSalePhaseTierCollectionBump,
+            userDetailBump, orderBump,
+            phaseThree, tierId.toString(), tokenId.toString(), orderId.toString())
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                user: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                orderDetail: orderPda,
+                collectionMintAccount: nodeSalePhaseTierCollectionPda,
+                nodeMintAccount: nodeMintAccountPda,
+                userTokenAccount: nodeUserTokenAccount,
+                tokenProgram: TOKEN_2022_PROGRAM_ID,
+                associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            })
+            .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
+            .rpc(), "OrderTokenIdFilled");
+    });
+
+    it("Airdrop token-2022 Node Sale Phase Three Tier One fails with a token id out of sequence", async () => {
+
+        const tierId: number = 1;
+        const tokenId: number = 4;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const [nodeSalePhaseTierCollectionPda, nodeSalePhaseTierCollectionBump] = getSogaNodeSalePhaseDetailTierCollectionAccountPdaAndBump(program.programId, COLLECTION_ACCOUNT_PREFIX,
+            nodeSalePhaseTierPda);
+
+        const [userDetailPda] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX,
+            sogaNodeSalePhaseThreePDA, userBKeypair.publicKey);
+
+        const [userPhaseTierDetailPda] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX,
+            userDetailPda, nodeSalePhaseTierPda);
+
+        const [nodeMintAccountPda] = getNodeMintAccount(program.programId, NODE_ACCOUNT_PREFIX,
+            nodeSalePhaseTierCollectionPda, tokenId.toString());
+
+        const nodeUserTokenAccount = await getAssociatedTokenAddress(nodeMintAccountPda, userBKeypair.publicKey, true, TOKEN_2022_PROGRAM_ID);
+
+        // token id 3 is the next one of the tier
+        await expectError(program.methods.airdropToken2022(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, nodeSalePhaseTierCollectionBump,
+            phaseThree, tierId.toString(), tokenId.toString())
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                user: userBKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                collectionMintAccount: nodeSalePhaseTierCollectionPda,
+                nodeMintAccount: nodeMintAccountPda,
+                userTokenAccount: nodeUserTokenAccount,
+                tokenProgram: TOKEN_2022_PROGRAM_ID,
+                associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            })
+            .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
+            .signers([signingAuthorityKeypair])
+            .rpc(), "InvalidTokenId");
+    });
+
+    it("Airdrop token-2022 Node Three Sale Phase Three Tier One", async () => {
+
+        const tierId: number = 1;
+        const tokenId: number = 3;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const [nodeSalePhaseTierCollectionPda, nodeSalePhaseTierCollectionBump] = getSogaNodeSalePhaseDetailTierCollectionAccountPdaAndBump(program.programId, COLLECTION_ACCOUNT_PREFIX,
+            nodeSalePhaseTierPda);
+
+        const [userDetailPda] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX,
+            sogaNodeSalePhaseThreePDA, userBKeypair.publicKey);
+
+        const [userPhaseTierDetailPda] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX,
+            userDetailPda, nodeSalePhaseTierPda);
+
+        const [nodeMintAccountPda] = getNodeMintAccount(program.programId, NODE_ACCOUNT_PREFIX,
+            nodeSalePhaseTierCollectionPda, tokenId.toString());
+
+        const nodeUserTokenAccount = await getAssociatedTokenAddress(nodeMintAccountPda, userBKeypair.publicKey, true, TOKEN_2022_PROGRAM_ID);
+
+        const tx = await program.methods.airdropToken2022(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, nodeSalePhaseTierCollectionBump,
+            phaseThree, tierId.toString(), tokenId.toString())
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                user: userBKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                collectionMintAccount: nodeSalePhaseTierCollectionPda,
+                nodeMintAccount: nodeMintAccountPda,
+                userTokenAccount: nodeUserTokenAccount,
+                tokenProgram: TOKEN_2022_PROGRAM_ID,
+                associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            })
+            .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
+            .signers([signingAuthorityKeypair])
+            .rpc({skipPreflight: true});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const nodeUserToken = await getAccount(connection, nodeUserTokenAccount, undefined, TOKEN_2022_PROGRAM_ID);
+        assert(nodeUserToken.amount.toString() === "1");
+
+        // the airdrop sells out tier one, tier two is on sale
+        const salePhaseTierData = await program.account.sogaNodeSalePhaseTierDetailAccount.fetch(nodeSalePhaseTierPda.toBase58());
+        assert(salePhaseTierData.isCompleted);
+
+        const salePhaseData = await program.account.sogaNodeSalePhaseDetailAccount.fetch(sogaNodeSalePhaseThreePDA.toBase58());
+        assert(salePhaseData.totalCompletedTiers === 1);
+    });
+
+    it("Realloc Account Sale Phase Three keeps the current layout", async () => {
+
+        const accountInfo = await connection.getAccountInfo(sogaNodeSalePhaseThreePDA);
+
+        const tx = await program.methods.reallocAccount()
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                account: sogaNodeSalePhaseThreePDA,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const reallocAccountInfo = await connection.getAccountInfo(sogaNodeSalePhaseThreePDA);
+        assert(reallocAccountInfo.data.length === accountInfo.data.length);
+
+        const salePhaseData = await program.account.sogaNodeSalePhaseDetailAccount.fetch(sogaNodeSalePhaseThreePDA.toBase58());
+        assert(salePhaseData.totalCompletedTiers === 1);
+    });
+
+    it("Realloc Account fails for an account of another program", async () => {
+
+        await expectError(program.methods.reallocAccount()
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                account: priceReceiverKeypair.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc(), "InvalidReallocAccount");
+    });
+
+    it("Initialize Sale Phase Four", async () => {
+
+        const tx = await program.methods.initializeSalePhase(sogaNodeSaleConfigBump, phaseFour, 1, nft_name, nft_symbol, nft_url, priceFeedIdSol, {metaplex: {}})
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                mainSigningAuthority: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                saleConfig: sogaNodeSaleConfigPDA,
+                salePhaseDetail: sogaNodeSalePhaseFourPDA,
+                priceFeed: priceFeedSolAddress,
+                paymentReceiver: priceReceiverKeypair.publicKey,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc();
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+    });
+
+    it("Initialize Sale Phase Four Tier One compressed fails with another bubblegum program", async () => {
+
+        const tierId = 1;
+
+        const [nodeSalePhaseTierPda] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFourPDA);
+
+        const [nodeSalePhaseTierCollectionPda] = getSogaNodeSalePhaseDetailTierCollectionAccountPdaAndBump(program.programId, COLLECTION_ACCOUNT_PREFIX,
+            nodeSalePhaseTierPda);
+
+        const nodeSalePhaseTierCollectionTokenAccount = await getAssociatedTokenAddress(nodeSalePhaseTierCollectionPda, nodeSalePhaseTierPda, true, TOKEN_PROGRAM_ID);
+
+        await expectError(program.methods.initializeSalePhaseTier(sogaNodeSalePhaseFourBump, phaseFour, tierId.toString(),
+            new BN(100), new BN(10), new BN(5),
+            collection_name, collection_symbol, collection_url, new BN(0), true, 14, 64)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFourPDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                collectionMintAccount: nodeSalePhaseTierCollectionPda,
+                collectionMasterEdition: getMasterPda(nodeSalePhaseTierCollectionPda),
+                collectionMetadata: getMetadataPda(nodeSalePhaseTierCollectionPda),
+                collectionTokenAccount: nodeSalePhaseTierCollectionTokenAccount,
+                tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            }).remainingAccounts([
+                {
+                    pubkey: merkleTreeKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: getTreeConfigPda(merkleTreeKeypair.publicKey),
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: splNoopProgram,
+                    isWritable: false,
+                    isSigner: false
+                },
+                {
+                    pubkey: splNoopProgram,
+                    isWritable: false,
+                    isSigner: false
+                },
+                {
+                    pubkey: splAccountCompressionProgram,
+                    isWritable: false,
+                    isSigner: false
+                }
+            ])
+            .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
+            .signers([signingAuthorityKeypair])
+            .rpc(), "InvalidProgramAddress");
+    });
+
+    it("Initialize Sale Phase Four Tier One compressed", async () => {
+
+        const tierId = 1;
+        const maxDepth = 14;
+        const maxBufferSize = 64;
+
+        const [nodeSalePhaseTierPda] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFourPDA);
+
+        const [nodeSalePhaseTierCollectionPda] = getSogaNodeSalePhaseDetailTierCollectionAccountPdaAndBump(program.programId, COLLECTION_ACCOUNT_PREFIX,
+            nodeSalePhaseTierPda);
+
+        const nodeSalePhaseTierCollectionTokenAccount = await getAssociatedTokenAddress(nodeSalePhaseTierCollectionPda, nodeSalePhaseTierPda, true, TOKEN_PROGRAM_ID);
+
+        // the merkle tree account is allocated by the client and owned by the account compression program
+        const merkleTreeSpace = getMerkleTreeAccountSize(maxDepth, maxBufferSize);
+
+        const allocateMerkleTreeIx = SystemProgram.createAccount({
+            fromPubkey: mainSigningAuthorityPubKey,
+            newAccountPubkey: merkleTreeKeypair.publicKey,
+            lamports: await connection.getMinimumBalanceForRentExemption(merkleTreeSpace),
+            space: merkleTreeSpace,
+            programId: splAccountCompressionProgram,
+        });
+
+        const tx = await program.methods.initializeSalePhaseTier(sogaNodeSalePhaseFourBump, phaseFour, tierId.toString(),
+            new BN(100), new BN(10), new BN(5),
+            collection_name, collection_symbol, collection_url, new BN(0), true, maxDepth, maxBufferSize)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFourPDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                collectionMintAccount: nodeSalePhaseTierCollectionPda,
+                collectionMasterEdition: getMasterPda(nodeSalePhaseTierCollectionPda),
+                collectionMetadata: getMetadataPda(nodeSalePhaseTierCollectionPda),
+                collectionTokenAccount: nodeSalePhaseTierCollectionTokenAccount,
+                tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            }).remainingAccounts([
+                {
+                    pubkey: merkleTreeKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: getTreeConfigPda(merkleTreeKeypair.publicKey),
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: bubblegumProgram,
+                    isWritable: false,
+                    isSigner: false
+                },
+                {
+                    pubkey: splNoopProgram,
+                    isWritable: false,
+                    isSigner: false
+                },
+                {
+                    pubkey: splAccountCompressionProgram,
+                    isWritable: false,
+                    isSigner: false
+                }
+            ])
+            .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000}), allocateMerkleTreeIx])
+            .signers([signingAuthorityKeypair, merkleTreeKeypair])
+            .rpc({skipPreflight: true});
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const salePhaseTierData = await program.account.sogaNodeSalePhaseTierDetailAccount.fetch(nodeSalePhaseTierPda.toBase58());
+
+        assert(salePhaseTierData.isCompressed);
+        assert(salePhaseTierData.merkleTreeAddress.toBase58() === merkleTreeKeypair.publicKey.toBase58());
+    });
+
+    it("Buy Node One Sale Phase Four Tier One", async () => {
+
+        const tierId: number = 1;
+        const orderId: number = 1;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFourPDA);
+
+        const [userDetailPda] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseFourPDA, userAKeypair.publicKey);
+
+        const [userPhaseTierDetailPda] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX, userDetailPda, nodeSalePhaseTierPda);
+
+        const [orderPda] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseFourPDA, userDetailPda, orderId.toString());
+
+        const tx = await program.methods.buy(sogaNodeSalePhaseFourBump, nodeSalePhaseTierBump,
+            phaseFour, tierId.toString(), orderId.toString(), new BN(1), true, 2000, true, 1000, false, false, 0, new BN("18446744073709551615"), false)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                userPayer: userAKeypair.publicKey,
+                user: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFourPDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                orderDetail: orderPda,
+                priceUpdate: priceFeedSolAddress,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            }).remainingAccounts([
+                {
+                    pubkey: priceReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: fullReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: halfReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                }
+            ]).preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
+            .signers([signingAuthorityKeypair, userAKeypair])
+            .rpc({skipPreflight: true});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+    });
+
+    it("Fill Order User A Order One Sale Phase Four compressed", async () => {
+
+        const tierId: number = 1;
+        const tokenId: number = 1;
+        const orderId: number = 1;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFourPDA);
+
+        const [nodeSalePhaseTierCollectionPda, nodeSalePhaseTierCollectionBump] = getSogaNodeSalePhaseDetailTierCollectionAccountPdaAndBump(program.programId, COLLECTION_ACCOUNT_PREFIX,
+            nodeSalePhaseTierPda);
+
+        const [userDetailPda, userDetailBump] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX,
+            sogaNodeSalePhaseFourPDA, userAKeypair.publicKey);
+
+        const [orderPda, orderBump] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseFourPDA, userDetailPda, orderId.toString());
+
+        const tx = await program.methods.fileOrderCompressed(sogaNodeSalePhaseFourBump, nodeSalePhaseTierBump, nodeSalePhaseTierCollectionBump,
+            userDetailBump, orderBump,
+            phaseFour, tierId.toString(), tokenId.toString(), orderId.toString())
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                user: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFourPDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                orderDetail: orderPda,
+                collectionMintAccount: nodeSalePhaseTierCollectionPda,
+                collectionMetadata: getMetadataPda(nodeSalePhaseTierCollectionPda),
+                collectionMasterEdition: getMasterPda(nodeSalePhaseTierCollectionPda),
+                merkleTree: merkleTreeKeypair.publicKey,
+                treeConfig: getTreeConfigPda(merkleTreeKeypair.publicKey),
+                bubblegumSigner: getBubblegumSignerPda(),
+                bubblegumProgram: bubblegumProgram,
+                logWrapper: splNoopProgram,
+                compressionProgram: splAccountCompressionProgram,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
+            .rpc({skipPreflight: true});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const orderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+        assert(orderData.isTokenIdsMinted[0]);
+        assert(orderData.isCompleted);
+    });
+
+    it("Fill Order Compressed User A Order One Sale Phase One fails for a tier without a merkle tree", async () => {
+
+        const tierId: number = 1;
+        const tokenId: number = 2;
+        const orderId: number = 1;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseOnePDA);
+
+        const [nodeSalePhaseTierCollectionPda, nodeSalePhaseTierCollectionBump] = getSogaNodeSalePhaseDetailTierCollectionAccountPdaAndBump(program.programId, COLLECTION_ACCOUNT_PREFIX,
+            nodeSalePhaseTierPda);
+
+        const [userDetailPda, userDetailBump] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX,
+            sogaNodeSalePhaseOnePDA, userAKeypair.publicKey);
+
+        const [orderPda, orderBump] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseOnePDA, userDetailPda, orderId.toString());
+
+        await expectError(program.methods.fileOrderCompressed(sogaNodeSalePhaseOneBump, nodeSalePhaseTierBump, nodeSalePhaseTierCollectionBump,
+            userDetailBump, orderBump,
+            phaseOne, tierId.toString(), tokenId.toString(), orderId.toString())
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                user: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseOnePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                orderDetail: orderPda,
+                collectionMintAccount: nodeSalePhaseTierCollectionPda,
+                collectionMetadata: getMetadataPda(nodeSalePhaseTierCollectionPda),
+                collectionMasterEdition: getMasterPda(nodeSalePhaseTierCollectionPda),
+                merkleTree: merkleTreeKeypair.publicKey,
+                treeConfig: getTreeConfigPda(merkleTreeKeypair.publicKey),
+                bubblegumSigner: getBubblegumSignerPda(),
+                bubblegumProgram: bubblegumProgram,
+                logWrapper: splNoopProgram,
+                compressionProgram: splAccountCompressionProgram,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
+            .rpc(), "PhaseTierIsNotCompressed");
+    });
+
+    it("Revoke Node One User A Order One Sale Phase Three", async () => {
+
+        const tierId: number = 1;
+        const tokenId: number = 1;
+        const orderId: number = 1;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const [nodeSalePhaseTierCollectionPda] = getSogaNodeSalePhaseDetailTierCollectionAccountPdaAndBump(program.programId, COLLECTION_ACCOUNT_PREFIX,
+            nodeSalePhaseTierPda);
+
+        const [userDetailPda, userDetailBump] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX,
+            sogaNodeSalePhaseThreePDA, userAKeypair.publicKey);
+
+        const [userPhaseTierDetailPda, userPhaseTierDetailBump] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX,
+            userDetailPda, nodeSalePhaseTierPda);
+
+        const [orderPda, orderBump] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userDetailPda, orderId.toString());
+
+        const [nodeMintAccountPda] = getNodeMintAccount(program.programId, NODE_ACCOUNT_PREFIX,
+            nodeSalePhaseTierCollectionPda, tokenId.toString());
+
+        const nodeUserTokenAccount = await getAssociatedTokenAddress(nodeMintAccountPda, userAKeypair.publicKey, true, TOKEN_2022_PROGRAM_ID);
+
+        // the minted node is frozen, it is thawed and burned through the permanent delegate
+        const tx = await program.methods.revokeNode(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, userDetailBump, userPhaseTierDetailBump, orderBump,
+            phaseThree, tierId.toString(), tokenId.toString(), orderId.toString())
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                user: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                orderDetail: orderPda,
+                tokenProgram: TOKEN_2022_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            }).remainingAccounts([
+                {
+                    pubkey: nodeSalePhaseTierCollectionPda,
+                    isWritable: false,
+                    isSigner: false
+                },
+                {
+                    pubkey: nodeMintAccountPda,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: nodeUserTokenAccount,
+                    isWritable: true,
+                    isSigner: false
+                }
+            ])
+            .signers([signingAuthorityKeypair])
+            .rpc({skipPreflight: true});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const orderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+        assert(orderData.isTokenIdsRevoked[0]);
+        assert(!orderData.isTokenIdsRevoked[1]);
+
+        const nodeUserToken = await getAccount(connection, nodeUserTokenAccount, undefined, TOKEN_2022_PROGRAM_ID);
+        assert(nodeUserToken.amount.toString() === "0");
+
+        const salePhaseTierData = await program.account.sogaNodeSalePhaseTierDetailAccount.fetch(nodeSalePhaseTierPda.toBase58());
+        assert(salePhaseTierData.totalRevoked.toString() === "1");
+    });
+
+    it("Revoke Node One User A Order One Sale Phase Three fails once the token id is revoked", async () => {
+
+        const tierId: number = 1;
+        const tokenId: number = 1;
+        const orderId: number = 1;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const [userDetailPda, userDetailBump] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX,
+            sogaNodeSalePhaseThreePDA, userAKeypair.publicKey);
+
+        const [userPhaseTierDetailPda, userPhaseTierDetailBump] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX,
+            userDetailPda, nodeSalePhaseTierPda);
+
+        const [orderPda, orderBump] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userDetailPda, orderId.toString());
+
+        await expectError(program.methods.revokeNode(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, userDetailBump, userPhaseTierDetailBump, orderBump,
+            phaseThree, tierId.toString(), tokenId.toString(), orderId.toString())
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                user: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                orderDetail: orderPda,
+                tokenProgram: TOKEN_2022_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc(), "OrderTokenIdRevoked");
+    });
+
+    it("Reissue Node One Sale Phase Three to User B", async () => {
+
+        const tierId: number = 1;
+        const tokenId: number = 1;
+        const revokedOrderId: number = 1;
+        const orderId: number = 1;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const [nodeSalePhaseTierCollectionPda, nodeSalePhaseTierCollectionBump] = getSogaNodeSalePhaseDetailTierCollectionAccountPdaAndBump(program.programId, COLLECTION_ACCOUNT_PREFIX,
+            nodeSalePhaseTierPda);
+
+        const [userDetailPda] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX,
+            sogaNodeSalePhaseThreePDA, userBKeypair.publicKey);
+
+        const [userPhaseTierDetailPda] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX,
+            userDetailPda, nodeSalePhaseTierPda);
+
+        const [orderPda] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userDetailPda, orderId.toString());
+
+        const [revokedUserDetailPda, revokedUserDetailBump] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX,
+            sogaNodeSalePhaseThreePDA, userAKeypair.publicKey);
+
+        const [revokedOrderPda, revokedOrderBump] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA,
+            revokedUserDetailPda, revokedOrderId.toString());
+
+        const [nodeMintAccountPda] = getNodeMintAccount(program.programId, NODE_ACCOUNT_PREFIX,
+            nodeSalePhaseTierCollectionPda, tokenId.toString());
+
+        const nodeUserTokenAccount = await getAssociatedTokenAddress(nodeMintAccountPda, userBKeypair.publicKey, true, TOKEN_2022_PROGRAM_ID);
+
+        const tx = await program.methods.reissueNode(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, nodeSalePhaseTierCollectionBump,
+            revokedUserDetailBump, revokedOrderBump,
+            phaseThree, tierId.toString(), tokenId.toString(), revokedOrderId.toString(), orderId.toString())
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                user: userBKeypair.publicKey,
+                revokedUser: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                revokedUserDetail: revokedUserDetailPda,
+                revokedOrderDetail: revokedOrderPda,
+                orderDetail: orderPda,
+                collectionMintAccount: nodeSalePhaseTierCollectionPda,
+                nodeMintAccount: nodeMintAccountPda,
+                userTokenAccount: nodeUserTokenAccount,
+                tokenProgram: TOKEN_2022_PROGRAM_ID,
+                associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc({skipPreflight: true});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const revokedOrderData = await program.account.orderDetailAccount.fetch(revokedOrderPda.toBase58());
+        assert(revokedOrderData.isTokenIdsReissued[0]);
+
+        // the new order holds the reissued node so that it can be revoked again
+        const orderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+        assert(orderData.tokenIds[0].toString() === tokenId.toString());
+        assert(orderData.isTokenIdsMinted[0]);
+        assert(!orderData.isTokenIdsRevoked[0]);
+
+        const nodeUserToken = await getAccount(connection, nodeUserTokenAccount, undefined, TOKEN_2022_PROGRAM_ID);
+        assert(nodeUserToken.amount.toString() === "1");
+
+        const salePhaseTierData = await program.account.sogaNodeSalePhaseTierDetailAccount.fetch(nodeSalePhaseTierPda.toBase58());
+        assert(salePhaseTierData.totalReissued.toString() === "1");
+    });
+
+    it("Reissue Node One Sale Phase Three fails once its revocation is reissued", async () => {
+
+        const tierId: number = 1;
+        const tokenId: number = 1;
+        const revokedOrderId: number = 1;
+        const orderId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const [nodeSalePhaseTierCollectionPda, nodeSalePhaseTierCollectionBump] = getSogaNodeSalePhaseDetailTierCollectionAccountPdaAndBump(program.programId, COLLECTION_ACCOUNT_PREFIX,
+            nodeSalePhaseTierPda);
+
+        const [userDetailPda] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX,
+            sogaNodeSalePhaseThreePDA, userBKeypair.publicKey);
+
+        const [userPhaseTierDetailPda] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX,
+            userDetailPda, nodeSalePhaseTierPda);
+
+        const [orderPda] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userDetailPda, orderId.toString());
+
+        const [revokedUserDetailPda, revokedUserDetailBump] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX,
+            sogaNodeSalePhaseThreePDA, userAKeypair.publicKey);
+
+        const [revokedOrderPda, revokedOrderBump] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA,
+            revokedUserDetailPda, revokedOrderId.toString());
+
+        const [nodeMintAccountPda] = getNodeMintAccount(program.programId, NODE_ACCOUNT_PREFIX,
+            nodeSalePhaseTierCollectionPda, tokenId.toString());
+
+        const nodeUserTokenAccount = await getAssociatedTokenAddress(nodeMintAccountPda, userBKeypair.publicKey, true, TOKEN_2022_PROGRAM_ID);
+
+        await expectError(program.methods.reissueNode(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, nodeSalePhaseTierCollectionBump,
+            revokedUserDetailBump, revokedOrderBump,
+            phaseThree, tierId.toString(), tokenId.toString(), revokedOrderId.toString(), orderId.toString())
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                user: userBKeypair.publicKey,
+                revokedUser: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                revokedUserDetail: revokedUserDetailPda,
+                revokedOrderDetail: revokedOrderPda,
+                orderDetail: orderPda,
+                collectionMintAccount: nodeSalePhaseTierCollectionPda,
+                nodeMintAccount: nodeMintAccountPda,
+                userTokenAccount: nodeUserTokenAccount,
+                tokenProgram: TOKEN_2022_PROGRAM_ID,
+                associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc(), "OrderTokenIdReissued");
+    });
+
+    it("Upgrade Node One User A Order One Sale Phase Three fails once the token id is revoked", async () => {
+
+        const sourceTierId: number = 1;
+        const targetTierId: number = 2;
+        const tokenId: number = 1;
+        const sourceOrderId: number = 1;
+        const orderId: number = 2;
+
+        const [sourceNodeSalePhaseTierPda, sourceNodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            sourceTierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const [targetNodeSalePhaseTierPda] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            targetTierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const [userDetailPda, userDetailBump] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX,
+            sogaNodeSalePhaseThreePDA, userAKeypair.publicKey);
+
+        const [sourceUserPhaseTierDetailPda, sourceUserPhaseTierDetailBump] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX,
+            userDetailPda, sourceNodeSalePhaseTierPda);
+
+        const [targetUserPhaseTierDetailPda] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX,
+            userDetailPda, targetNodeSalePhaseTierPda);
+
+        const [sourceOrderPda, sourceOrderBump] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA,
+            userDetailPda, sourceOrderId.toString());
+
+        const [orderPda] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userDetailPda, orderId.toString());
+
+        // token id one was revoked, only its reissued order can upgrade it
+        await expectError(program.methods.upgradeNode(sogaNodeSalePhaseThreeBump, sourceNodeSalePhaseTierBump, userDetailBump, sourceUserPhaseTierDetailBump, sourceOrderBump,
+            phaseThree, sourceTierId.toString(), targetTierId.toString(), sourceOrderId.toString(), tokenId.toString(), orderId.toString(), false)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                userPayer: userAKeypair.publicKey,
+                user: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                sourceSalePhaseTierDetail: sourceNodeSalePhaseTierPda,
+                targetSalePhaseTierDetail: targetNodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                sourceUserTierDetail: sourceUserPhaseTierDetailPda,
+                targetUserTierDetail: targetUserPhaseTierDetailPda,
+                sourceOrderDetail: sourceOrderPda,
+                orderDetail: orderPda,
+                priceUpdate: priceFeedSolAddress,
+                tokenProgram: TOKEN_2022_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            }).remainingAccounts([
+                {
+                    pubkey: priceReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                }
+            ]).preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
+            .signers([signingAuthorityKeypair, userAKeypair])
+            .rpc(), "OrderTokenIdRevoked");
+    });
+
+    it("Fill Order User A Order One Token Two Sale Phase Three with token-2022", async () => {
+
+        const tierId: number = 1;
+        const tokenId: number = 2;
+        const orderId: number = 1;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const [nodeSalePhaseTierCollectionPda, nodeSalePhaseTierCollectionBump] = getSogaNodeSalePhaseDetailTierCollectionAccountPdaAndBump(program.programId, COLLECTION_ACCOUNT_PREFIX,
+            nodeSalePhaseTierPda);
+
+        const [userDetailPda, userDetailBump] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX,
+            sogaNodeSalePhaseThreePDA, userAKeypair.publicKey);
+
+        const [orderPda, orderBump] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userDetailPda, orderId.toString());
+
+        const [nodeMintAccountPda] = getNodeMintAccount(program.programId, NODE_ACCOUNT_PREFIX,
+            nodeSalePhaseTierCollectionPda, tokenId.toString());
+
+        const nodeUserTokenAccount = await getAssociatedTokenAddress(nodeMintAccountPda, userAKeypair.publicKey, true, TOKEN_2022_PROGRAM_ID);
+
+        const tx = await program.methods.fileOrderToken2022(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, nodeSalePhaseTierCollectionBump,
+            userDetailBump, orderBump,
+            phaseThree, tierId.toString(), tokenId.toString(), orderId.toString())
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                user: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                orderDetail: orderPda,
+                collectionMintAccount: nodeSalePhaseTierCollectionPda,
+                nodeMintAccount: nodeMintAccountPda,
+                userTokenAccount: nodeUserTokenAccount,
+                tokenProgram: TOKEN_2022_PROGRAM_ID,
+                associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            })
+            .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
+            .rpc({skipPreflight: true});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const orderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+        assert(orderData.isTokenIdsMinted[1]);
+    });
+
+    it("Upgrade Node Two User A Order One Sale Phase Three to Tier Two", async () => {
+
+        const sourceTierId: number = 1;
+        const targetTierId: number = 2;
+        const tokenId: number = 2;
+        const sourceOrderId: number = 1;
+        const orderId: number = 2;
+
+        const [sourceNodeSalePhaseTierPda, sourceNodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            sourceTierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const [targetNodeSalePhaseTierPda] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            targetTierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const [sourceNodeSalePhaseTierCollectionPda] = getSogaNodeSalePhaseDetailTierCollectionAccountPdaAndBump(program.programId, COLLECTION_ACCOUNT_PREFIX,
+            sourceNodeSalePhaseTierPda);
+
+        const [userDetailPda, userDetailBump] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX,
+            sogaNodeSalePhaseThreePDA, userAKeypair.publicKey);
+
+        const [sourceUserPhaseTierDetailPda, sourceUserPhaseTierDetailBump] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX,
+            userDetailPda, sourceNodeSalePhaseTierPda);
+
+        const [targetUserPhaseTierDetailPda] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX,
+            userDetailPda, targetNodeSalePhaseTierPda);
+
+        const [sourceOrderPda, sourceOrderBump] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA,
+            userDetailPda, sourceOrderId.toString());
+
+        const [orderPda] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userDetailPda, orderId.toString());
+
+        const [nodeMintAccountPda] = getNodeMintAccount(program.programId, NODE_ACCOUNT_PREFIX,
+            sourceNodeSalePhaseTierCollectionPda, tokenId.toString());
+
+        const nodeUserTokenAccount = await getAssociatedTokenAddress(nodeMintAccountPda, userAKeypair.publicKey, true, TOKEN_2022_PROGRAM_ID);
+
+        const sourceOrderData = await program.account.orderDetailAccount.fetch(sourceOrderPda.toBase58());
+
+        // the minted source node is burned from the holder token account
+        const tx = await program.methods.upgradeNode(sogaNodeSalePhaseThreeBump, sourceNodeSalePhaseTierBump, userDetailBump, sourceUserPhaseTierDetailBump, sourceOrderBump,
+            phaseThree, sourceTierId.toString(), targetTierId.toString(), sourceOrderId.toString(), tokenId.toString(), orderId.toString(), false)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                userPayer: userAKeypair.publicKey,
+                user: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                sourceSalePhaseTierDetail: sourceNodeSalePhaseTierPda,
+                targetSalePhaseTierDetail: targetNodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                sourceUserTierDetail: sourceUserPhaseTierDetailPda,
+                targetUserTierDetail: targetUserPhaseTierDetailPda,
+                sourceOrderDetail: sourceOrderPda,
+                orderDetail: orderPda,
+                priceUpdate: priceFeedSolAddress,
+                tokenProgram: TOKEN_2022_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            }).remainingAccounts([
+                {
+                    pubkey: priceReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: sourceNodeSalePhaseTierCollectionPda,
+                    isWritable: false,
+                    isSigner: false
+                },
+                {
+                    pubkey: nodeMintAccountPda,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: nodeUserTokenAccount,
+                    isWritable: true,
+                    isSigner: false
+                }
+            ]).preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
+            .signers([signingAuthorityKeypair, userAKeypair])
+            .rpc({skipPreflight: true});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const updatedSourceOrderData = await program.account.orderDetailAccount.fetch(sourceOrderPda.toBase58());
+        assert(updatedSourceOrderData.isTokenIdsUpgraded[1]);
+        assert(!updatedSourceOrderData.isTokenIdsRevoked[1]);
+
+        const nodeUserToken = await getAccount(connection, nodeUserTokenAccount, undefined, TOKEN_2022_PROGRAM_ID);
+        assert(nodeUserToken.amount.toString() === "0");
+
+        // the new order is credited with the usd price paid for the source node
+        const orderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+        const creditInUsd = sourceOrderData.totalUserDiscountInUsd.div(sourceOrderData.quantity);
+
+        assert(orderData.tierId === targetTierId);
+        assert(orderData.quantity.toString() === "1");
+        assert(orderData.totalPaymentInUsd.add(creditInUsd).toString() === orderData.totalUserDiscountInUsd.toString());
+
+        const sourceSalePhaseTierData = await program.account.sogaNodeSalePhaseTierDetailAccount.fetch(sourceNodeSalePhaseTierPda.toBase58());
+        assert(sourceSalePhaseTierData.totalUpgraded.toString() === "1");
+        assert(sourceSalePhaseTierData.totalRevoked.toString() === "1");
+    });
+
+    it("Update Sale Phase Three Oracle Config fails with another signing authority", async () => {
+
+        await expectError(program.methods.updateSalePhaseOracleConfig(sogaNodeSalePhaseThreeBump, phaseThree, {
+            maxPriceAge: new BN(0),
+            maxConfidenceRatio: 0,
+            minPrice: new BN(0),
+            maxPrice: new BN(0),
+            source: {pyth: {}}
+        })
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([userAKeypair])
+            .rpc(), "InvalidSigningAuthority");
+    });
+
+    it("Update Sale Phase Three Oracle Config with a max price below the feed", async () => {
+
+        const tx = await program.methods.updateSalePhaseOracleConfig(sogaNodeSalePhaseThreeBump, phaseThree, {
+            maxPriceAge: new BN(0),
+            maxConfidenceRatio: 0,
+            minPrice: new BN(0),
+            maxPrice: new BN(1),
+            source: {pyth: {}}
+        })
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const salePhaseData = await program.account.sogaNodeSalePhaseDetailAccount.fetch(sogaNodeSalePhaseThreePDA.toBase58());
+        assert(salePhaseData.oracleConfig.maxPrice.toString() === "1");
+    });
+
+    it("Buy Node One Sale Phase Three Tier Two fails with the oracle price out of range", async () => {
+
+        const tierId: number = 2;
+        const orderId: number = 3;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const [userDetailPda] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userAKeypair.publicKey);
+
+        const [userPhaseTierDetailPda] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX, userDetailPda, nodeSalePhaseTierPda);
+
+        const [orderPda] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userDetailPda, orderId.toString());
+
+        await expectError(program.methods.buy(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump,
+            phaseThree, tierId.toString(), orderId.toString(), new BN(1), true, 2000, true, 1000, false, false, 0, new BN("18446744073709551615"), false)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                userPayer: userAKeypair.publicKey,
+                user: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                orderDetail: orderPda,
+                priceUpdate: priceFeedSolAddress,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            }).remainingAccounts([
+                {
+                    pubkey: priceReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: fullReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: halfReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                }
+            ]).preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
+            .signers([signingAuthorityKeypair, userAKeypair])
+            .rpc(), "OraclePriceOutOfRange");
+    });
+
+    it("Update Sale Phase Three Oracle Config back to the defaults", async () => {
+
+        const tx = await program.methods.updateSalePhaseOracleConfig(sogaNodeSalePhaseThreeBump, phaseThree, {
+            maxPriceAge: new BN(0),
+            maxConfidenceRatio: 0,
+            minPrice: new BN(0),
+            maxPrice: new BN(0),
+            source: {pyth: {}}
+        })
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+    });
+
+    it("Update Sale Phase One payment token usdt Oracle Config", async () => {
+
+        const [salePhasePaymentTokenDetailPda, salePhasePaymentTokenDetailBump] = getSogaNodeSalePhasePaymentTokenDetailAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_PAYMENT_TOKEN_ACCOUNT_PREFIX,
+            phaseOne, sogaNodeSalePhaseOnePDA, paymentTokenMintAccount);
+
+        const tx = await program.methods.updateSalePhasePaymentTokenOracleConfig(sogaNodeSalePhaseOneBump, salePhasePaymentTokenDetailBump, phaseOne, {
+            maxPriceAge: new BN(0),
+            maxConfidenceRatio: 10000,
+            minPrice: new BN(0),
+            maxPrice: new BN(0),
+            source: {pyth: {}}
+        })
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseOnePDA,
+                salePhasePaymentTokenDetail: salePhasePaymentTokenDetailPda,
+                paymentTokenMintAccount: paymentTokenMintAccount,
+                paymentTokenProgram: TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const salePhasePaymentTokenData = await program.account.sogaNodeSalePhasePaymentTokenDetailAccount.fetch(salePhasePaymentTokenDetailPda.toBase58());
+        assert(salePhasePaymentTokenData.oracleConfig.maxConfidenceRatio === 10000);
+    });
+
+    it("Update Sale Phase One payment token usdt Oracle Config fails with another signing authority", async () => {
+
+        const [salePhasePaymentTokenDetailPda, salePhasePaymentTokenDetailBump] = getSogaNodeSalePhasePaymentTokenDetailAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_PAYMENT_TOKEN_ACCOUNT_PREFIX,
+            phaseOne, sogaNodeSalePhaseOnePDA, paymentTokenMintAccount);
+
+        await expectError(program.methods.updateSalePhasePaymentTokenOracleConfig(sogaNodeSalePhaseOneBump, salePhasePaymentTokenDetailBump, phaseOne, {
+            maxPriceAge: new BN(0),
+            maxConfidenceRatio: 0,
+            minPrice: new BN(0),
+            maxPrice: new BN(0),
+            source: {pyth: {}}
+        })
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseOnePDA,
+                salePhasePaymentTokenDetail: salePhasePaymentTokenDetailPda,
+                paymentTokenMintAccount: paymentTokenMintAccount,
+                paymentTokenProgram: TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([userAKeypair])
+            .rpc(), "InvalidSigningAuthority");
+    });
+
+    it("Buy Node Two Sale Phase Three Tier Two fails above the max payment amount", async () => {
+
+        const tierId: number = 2;
+        const orderId: number = 3;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const [userDetailPda] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userAKeypair.publicKey);
+
+        const [userPhaseTierDetailPda] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX, userDetailPda, nodeSalePhaseTierPda);
+
+        const [orderPda] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userDetailPda, orderId.toString());
+
+        await expectError(program.methods.buy(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump,
+            phaseThree, tierId.toString(), orderId.toString(), new BN(1), true, 2000, true, 1000, false, false, 0, new BN(1), false)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                userPayer: userAKeypair.publicKey,
+                user: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                orderDetail: orderPda,
+                priceUpdate: priceFeedSolAddress,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            }).remainingAccounts([
+                {
+                    pubkey: priceReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: fullReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: halfReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                }
+            ]).preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
+            .signers([signingAuthorityKeypair, userAKeypair])
+            .rpc(), "PaymentAmountExceedsMax");
+    });
+
+    it("Buy Node Two Sale Phase Three Tier Two within the max payment amount", async () => {
+
+        const tierId: number = 2;
+        const orderId: number = 3;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const [userDetailPda] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userAKeypair.publicKey);
+
+        const [userPhaseTierDetailPda] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX, userDetailPda, nodeSalePhaseTierPda);
+
+        const [orderPda] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userDetailPda, orderId.toString());
+
+        // the buyer never pays more than its balance before the buy
+        const maxPaymentAmount = await connection.getBalance(userAKeypair.publicKey);
+
+        const tx = await program.methods.buy(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump,
+            phaseThree, tierId.toString(), orderId.toString(), new BN(1), true, 2000, true, 1000, false, false, 0, new BN(maxPaymentAmount), false)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                userPayer: userAKeypair.publicKey,
+                user: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                orderDetail: orderPda,
+                priceUpdate: priceFeedSolAddress,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            }).remainingAccounts([
+                {
+                    pubkey: priceReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: fullReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: halfReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                }
+            ]).preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
+            .signers([signingAuthorityKeypair, userAKeypair])
+            .rpc({skipPreflight: true});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const orderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+        assert(orderData.quantity.toString() === "1");
+        assert(orderData.totalUserDiscount.lte(new BN(maxPaymentAmount)));
+    });
+
+    it("Update Sale Phase One payment token usdt Fixed Rate fails without a rate", async () => {
+
+        const [salePhasePaymentTokenDetailPda, salePhasePaymentTokenDetailBump] = getSogaNodeSalePhasePaymentTokenDetailAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_PAYMENT_TOKEN_ACCOUNT_PREFIX,
+            phaseOne, sogaNodeSalePhaseOnePDA, paymentTokenMintAccount);
+
+        await expectError(program.methods.updateSalePhasePaymentTokenFixedRate(sogaNodeSalePhaseOneBump, salePhasePaymentTokenDetailBump, phaseOne, true, new BN(0), 0)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseOnePDA,
+                salePhasePaymentTokenDetail: salePhasePaymentTokenDetailPda,
+                paymentTokenMintAccount: paymentTokenMintAccount,
+                paymentTokenProgram: TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc(), "InvalidFixedRate");
+    });
+
+    it("Update Sale Phase One payment token usdt Fixed Rate", async () => {
+
+        const [salePhasePaymentTokenDetailPda, salePhasePaymentTokenDetailBump] = getSogaNodeSalePhasePaymentTokenDetailAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_PAYMENT_TOKEN_ACCOUNT_PREFIX,
+            phaseOne, sogaNodeSalePhaseOnePDA, paymentTokenMintAccount);
+
+        // one usdt per usd with a 9 decimals mint, the oracle is only read as a 1% depeg guard
+        const tx = await program.methods.updateSalePhasePaymentTokenFixedRate(sogaNodeSalePhaseOneBump, salePhasePaymentTokenDetailBump, phaseOne, true, new BN(1_000_000_000), 100)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseOnePDA,
+                salePhasePaymentTokenDetail: salePhasePaymentTokenDetailPda,
+                paymentTokenMintAccount: paymentTokenMintAccount,
+                paymentTokenProgram: TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const salePhasePaymentTokenData = await program.account.sogaNodeSalePhasePaymentTokenDetailAccount.fetch(salePhasePaymentTokenDetailPda.toBase58());
+        assert(salePhasePaymentTokenData.isFixedRate);
+        assert(salePhasePaymentTokenData.fixedRate.toString() === "1000000000");
+        assert(salePhasePaymentTokenData.maxDepegBps === 100);
+    });
+
+    it("Initialize Oracle Price fails with another main signing authority", async () => {
+
+        const oracleName: string = "SOL";
+
+        const [oraclePricePda] = getOraclePriceAccountPdaAndBump(program.programId, ORACLE_PRICE_ACCOUNT_PREFIX, oracleName);
+
+        await expectError(program.methods.initializeOraclePrice(sogaNodeSaleConfigBump, oracleName, new BN(15_000_000_000), new BN(1_000_000), -8)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                mainSigningAuthority: userAKeypair.publicKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                saleConfig: sogaNodeSaleConfigPDA,
+                oraclePrice: oraclePricePda,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            })
+            .signers([userAKeypair])
+            .rpc(), "InvalidMainSigningAuthority");
+    });
+
+    it("Initialize Oracle Price", async () => {
+
+        const oracleName: string = "SOL";
+
+        const [oraclePricePda] = getOraclePriceAccountPdaAndBump(program.programId, ORACLE_PRICE_ACCOUNT_PREFIX, oracleName);
+
+        const tx = await program.methods.initializeOraclePrice(sogaNodeSaleConfigBump, oracleName, new BN(15_000_000_000), new BN(1_000_000), -8)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                mainSigningAuthority: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                saleConfig: sogaNodeSaleConfigPDA,
+                oraclePrice: oraclePricePda,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            })
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const oraclePriceData = await program.account.oraclePriceAccount.fetch(oraclePricePda.toBase58());
+        assert(oraclePriceData.authority.toBase58() === signingAuthorityKeypair.publicKey.toBase58());
+        assert(oraclePriceData.price.toString() === "15000000000");
+        assert(oraclePriceData.exponent === -8);
+    });
+
+    it("Update Oracle Price", async () => {
+
+        const oracleName: string = "SOL";
+
+        const [oraclePricePda, oraclePriceBump] = getOraclePriceAccountPdaAndBump(program.programId, ORACLE_PRICE_ACCOUNT_PREFIX, oracleName);
+
+        const tx = await program.methods.updateOraclePrice(oraclePriceBump, oracleName, new BN(16_000_000_000), new BN(1_000_000), -8)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                oraclePrice: oraclePricePda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const oraclePriceData = await program.account.oraclePriceAccount.fetch(oraclePricePda.toBase58());
+        assert(oraclePriceData.price.toString() === "16000000000");
+    });
+
+    it("Update Oracle Price fails with another signing authority", async () => {
+
+        const oracleName: string = "SOL";
+
+        const [oraclePricePda, oraclePriceBump] = getOraclePriceAccountPdaAndBump(program.programId, ORACLE_PRICE_ACCOUNT_PREFIX, oracleName);
+
+        await expectError(program.methods.updateOraclePrice(oraclePriceBump, oracleName, new BN(1), new BN(1_000_000), -8)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: userAKeypair.publicKey,
+                oraclePrice: oraclePricePda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([userAKeypair])
+            .rpc(), "InvalidSigningAuthority");
+    });
+
+    it("Update Sale Phase Three Price Sources fails with more sources than allowed", async () => {
+
+        const [oraclePricePda] = getOraclePriceAccountPdaAndBump(program.programId, ORACLE_PRICE_ACCOUNT_PREFIX, "SOL");
+
+        await expectError(program.methods.updateSalePhasePriceSources(sogaNodeSalePhaseThreeBump, phaseThree, Array(5).fill({
+            address: oraclePricePda,
+            feedId: "",
+            source: {manual: {}}
+        }), 2)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc(), "TooManyPriceSources");
+    });
+
+    it("Update Sale Phase Three Price Sources with the manual oracle price", async () => {
+
+        const [oraclePricePda] = getOraclePriceAccountPdaAndBump(program.programId, ORACLE_PRICE_ACCOUNT_PREFIX, "SOL");
+
+        const tx = await program.methods.updateSalePhasePriceSources(sogaNodeSalePhaseThreeBump, phaseThree, [
+            {
+                address: oraclePricePda,
+                feedId: "",
+                source: {manual: {}}
+            }
+        ], 2)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const salePhaseData = await program.account.sogaNodeSalePhaseDetailAccount.fetch(sogaNodeSalePhaseThreePDA.toBase58());
+        assert(salePhaseData.priceSources.length === 1);
+        assert(salePhaseData.priceSources[0].address.toBase58() === oraclePricePda.toBase58());
+        assert(salePhaseData.priceSourceQuorum === 2);
+    });
+
+    it("Buy Node Three Sale Phase Three Tier Two fails without its price source account", async () => {
+
+        const tierId: number = 2;
+        const orderId: number = 4;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const [userDetailPda] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userAKeypair.publicKey);
+
+        const [userPhaseTierDetailPda] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX, userDetailPda, nodeSalePhaseTierPda);
+
+        const [orderPda] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userDetailPda, orderId.toString());
+
+        await expectError(program.methods.buy(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump,
+            phaseThree, tierId.toString(), orderId.toString(), new BN(1), true, 2000, true, 1000, false, false, 0, new BN("18446744073709551615"), false)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                userPayer: userAKeypair.publicKey,
+                user: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                orderDetail: orderPda,
+                priceUpdate: priceFeedSolAddress,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            }).remainingAccounts([
+                {
+                    pubkey: priceReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: fullReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: halfReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                }
+            ]).preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
+            .signers([signingAuthorityKeypair, userAKeypair])
+            .rpc(), "InvalidPriceFeedAddress");
+    });
+
+    it("Buy Node Three Sale Phase Three Tier Two with the median of its price sources", async () => {
+
+        const tierId: number = 2;
+        const orderId: number = 4;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const [userDetailPda] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userAKeypair.publicKey);
+
+        const [userPhaseTierDetailPda] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX, userDetailPda, nodeSalePhaseTierPda);
+
+        const [orderPda] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userDetailPda, orderId.toString());
+
+        const [oraclePricePda] = getOraclePriceAccountPdaAndBump(program.programId, ORACLE_PRICE_ACCOUNT_PREFIX, "SOL");
+
+        const tx = await program.methods.buy(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump,
+            phaseThree, tierId.toString(), orderId.toString(), new BN(1), true, 2000, true, 1000, false, false, 0, new BN("18446744073709551615"), false)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                userPayer: userAKeypair.publicKey,
+                user: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                orderDetail: orderPda,
+                priceUpdate: priceFeedSolAddress,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            }).remainingAccounts([
+                {
+                    pubkey: priceReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: fullReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: halfReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: oraclePricePda,
+                    isWritable: false,
+                    isSigner: false
+                }
+            ]).preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
+            .signers([signingAuthorityKeypair, userAKeypair])
+            .rpc({skipPreflight: true});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const orderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+        assert(orderData.quantity.toString() === "1");
+    });
+
+    it("Update Sale Phase Three Price Sources back to the price feed only", async () => {
+
+        const tx = await program.methods.updateSalePhasePriceSources(sogaNodeSalePhaseThreeBump, phaseThree, [], 0)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+    });
+
+    it("Update Sale Phase One payment token usdt Price Sources fails with a quorum above the sources", async () => {
+
+        const [salePhasePaymentTokenDetailPda, salePhasePaymentTokenDetailBump] = getSogaNodeSalePhasePaymentTokenDetailAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_PAYMENT_TOKEN_ACCOUNT_PREFIX,
+            phaseOne, sogaNodeSalePhaseOnePDA, paymentTokenMintAccount);
+
+        const [oraclePricePda] = getOraclePriceAccountPdaAndBump(program.programId, ORACLE_PRICE_ACCOUNT_PREFIX, "SOL");
+
+        await expectError(program.methods.updateSalePhasePaymentTokenPriceSources(sogaNodeSalePhaseOneBump, salePhasePaymentTokenDetailBump, phaseOne, [
+            {
+                address: oraclePricePda,
+                feedId: "",
+                source: {manual: {}}
+            }
+        ], 3)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseOnePDA,
+                salePhasePaymentTokenDetail: salePhasePaymentTokenDetailPda,
+                paymentTokenMintAccount: paymentTokenMintAccount,
+                paymentTokenProgram: TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc(), "InvalidPriceSourceQuorum");
+    });
+
+    it("Update Sale Phase One payment token usdt Price Sources", async () => {
+
+        const [salePhasePaymentTokenDetailPda, salePhasePaymentTokenDetailBump] = getSogaNodeSalePhasePaymentTokenDetailAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_PAYMENT_TOKEN_ACCOUNT_PREFIX,
+            phaseOne, sogaNodeSalePhaseOnePDA, paymentTokenMintAccount);
+
+        const [oraclePricePda] = getOraclePriceAccountPdaAndBump(program.programId, ORACLE_PRICE_ACCOUNT_PREFIX, "SOL");
+
+        const tx = await program.methods.updateSalePhasePaymentTokenPriceSources(sogaNodeSalePhaseOneBump, salePhasePaymentTokenDetailBump, phaseOne, [
+            {
+                address: oraclePricePda,
+                feedId: "",
+                source: {manual: {}}
+            }
+        ], 1)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseOnePDA,
+                salePhasePaymentTokenDetail: salePhasePaymentTokenDetailPda,
+                paymentTokenMintAccount: paymentTokenMintAccount,
+                paymentTokenProgram: TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const salePhasePaymentTokenData = await program.account.sogaNodeSalePhasePaymentTokenDetailAccount.fetch(salePhasePaymentTokenDetailPda.toBase58());
+        assert(salePhasePaymentTokenData.priceSources.length === 1);
+        assert(salePhasePaymentTokenData.priceSourceQuorum === 1);
+    });
+
+    it("Update Sale Phase Three Tier Two Price Schedule fails with a ramp ending before it starts", async () => {
+
+        const tierId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const timestamp: number = Math.floor(Date.now() / 1000);
+
+        await expectError(program.methods.updateSalePhaseTierPriceSchedule(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, phaseThree, tierId.toString(), {
+            linearRamp: {
+                startTimestamp: new BN(timestamp),
+                endTimestamp: new BN(timestamp - 3600),
+                startPrice: new BN(250),
+                endPrice: new BN(300)
+            }
+        })
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc(), "InvalidPriceSchedule");
+    });
+
+    it("Update Sale Phase Three Tier Two Price Schedule with an ended ramp", async () => {
+
+        const tierId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const timestamp: number = Math.floor(Date.now() / 1000);
+
+        const tx = await program.methods.updateSalePhaseTierPriceSchedule(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, phaseThree, tierId.toString(), {
+            linearRamp: {
+                startTimestamp: new BN(timestamp - 7200),
+                endTimestamp: new BN(timestamp - 3600),
+                startPrice: new BN(250),
+                endPrice: new BN(300)
+            }
+        })
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const salePhaseTierData = await program.account.sogaNodeSalePhaseTierDetailAccount.fetch(nodeSalePhaseTierPda.toBase58());
+        assert(salePhaseTierData.priceSchedule.linearRamp.endPrice.toString() === "300");
+    });
+
+    it("Buy Node Four Sale Phase Three Tier Two at the scheduled price", async () => {
+
+        const tierId: number = 2;
+        const orderId: number = 5;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const [userDetailPda] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userAKeypair.publicKey);
+
+        const [userPhaseTierDetailPda] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX, userDetailPda, nodeSalePhaseTierPda);
+
+        const [orderPda] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userDetailPda, orderId.toString());
+
+        const tx = await program.methods.buy(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump,
+            phaseThree, tierId.toString(), orderId.toString(), new BN(1), true, 2000, true, 1000, false, false, 0, new BN("18446744073709551615"), false)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                userPayer: userAKeypair.publicKey,
+                user: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                orderDetail: orderPda,
+                priceUpdate: priceFeedSolAddress,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            }).remainingAccounts([
+                {
+                    pubkey: priceReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: fullReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: halfReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                }
+            ]).preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
+            .signers([signingAuthorityKeypair, userAKeypair])
+            .rpc({skipPreflight: true});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        // the ramp ended, the end price applies instead of the tier price
+        const orderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+        assert(orderData.totalPaymentInUsd.toString() === "300");
+    });
+
+    it("Update Sale Phase Three Tier Two Price Schedule back to the tier price", async () => {
+
+        const tierId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const tx = await program.methods.updateSalePhaseTierPriceSchedule(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, phaseThree, tierId.toString(), {none: {}})
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+    });
+
+    it("Update Sale Phase Three Tier Two Price Curve fails without an increment", async () => {
+
+        const tierId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        await expectError(program.methods.updateSalePhaseTierPriceCurve(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, phaseThree, tierId.toString(), {linear: {increment: new BN(0)}})
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc(), "InvalidPriceCurve");
+    });
+
+    it("Update Sale Phase Three Tier Two Price Curve", async () => {
+
+        const tierId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const tx = await program.methods.updateSalePhaseTierPriceCurve(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, phaseThree, tierId.toString(), {linear: {increment: new BN(10)}})
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const salePhaseTierData = await program.account.sogaNodeSalePhaseTierDetailAccount.fetch(nodeSalePhaseTierPda.toBase58());
+        assert(salePhaseTierData.priceCurve.linear.increment.toString() === "10");
+    });
+
+    it("Quote Sale Phase Three Tier Two", async () => {
+
+        const tierId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const simulation = await program.methods.quote(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, phaseThree, tierId.toString(), new BN(2))
+            .accounts({
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda
+            })
+            .simulate();
+
+        // every next node costs the increment more than the one before
+        const quoteEvent = simulation.events.find((event) => event.name === "QuoteEvent");
+        const unitPrices: BN[] = quoteEvent.data.unitPricesInUsd as BN[];
+
+        assert(unitPrices.length === 2);
+        assert(unitPrices[1].sub(unitPrices[0]).toString() === "10");
+        assert((quoteEvent.data.totalPriceInUsd as BN).toString() === unitPrices[0].add(unitPrices[1]).toString());
+    });
+
+    it("Quote Sale Phase Three Tier Two fails without a quantity", async () => {
+
+        const tierId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        await expectError(program.methods.quote(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, phaseThree, tierId.toString(), new BN(0))
+            .accounts({
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda
+            })
+            .rpc(), "ValueIsZero");
+    });
+
+    it("Update Sale Phase Three Tier Two Price Curve back to a flat price", async () => {
+
+        const tierId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const tx = await program.methods.updateSalePhaseTierPriceCurve(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, phaseThree, tierId.toString(), {none: {}})
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+    });
+
+    it("Update Sale Phase Three Tier Two Dutch Auction fails with a floor above the start price", async () => {
+
+        const tierId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const timestamp: number = Math.floor(Date.now() / 1000);
+
+        await expectError(program.methods.updateSalePhaseTierDutchAuction(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, phaseThree, tierId.toString(),
+            new BN(timestamp), new BN(timestamp + 20), new BN(300), new BN(400), true)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc(), "InvalidDutchAuction");
+    });
+
+    it("Update Sale Phase Three Tier Two Dutch Auction with rebates", async () => {
+
+        const tierId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const timestamp: number = Math.floor(Date.now() / 1000);
+
+        const tx = await program.methods.updateSalePhaseTierDutchAuction(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, phaseThree, tierId.toString(),
+            new BN(timestamp - 5), new BN(timestamp + 20), new BN(400), new BN(300), true)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const salePhaseTierData = await program.account.sogaNodeSalePhaseTierDetailAccount.fetch(nodeSalePhaseTierPda.toBase58());
+        assert(salePhaseTierData.isAuctionRebate);
+        assert(salePhaseTierData.auctionFloorPrice.toString() === "300");
+    });
+
+    it("Buy Node Five Sale Phase Three Tier Two in the dutch auction", async () => {
+
+        const tierId: number = 2;
+        const orderId: number = 6;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const [userDetailPda] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userAKeypair.publicKey);
+
+        const [userPhaseTierDetailPda] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX, userDetailPda, nodeSalePhaseTierPda);
+
+        const [orderPda] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userDetailPda, orderId.toString());
+
+        const tx = await program.methods.buy(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump,
+            phaseThree, tierId.toString(), orderId.toString(), new BN(1), true, 2000, true, 1000, false, false, 0, new BN("18446744073709551615"), false)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                userPayer: userAKeypair.publicKey,
+                user: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                orderDetail: orderPda,
+                priceUpdate: priceFeedSolAddress,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            }).remainingAccounts([
+                {
+                    pubkey: priceReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: fullReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: halfReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                }
+            ]).preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
+            .signers([signingAuthorityKeypair, userAKeypair])
+            .rpc({skipPreflight: true});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        // the payment above the floor price is escrowed until the auction ends
+        const orderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+        assert(orderData.auctionEscrowAmount.gtn(0));
+        assert(!orderData.isAuctionSettled);
+    });
+
+    it("Settle Auction Order User A Order Six fails before the auction ends", async () => {
+
+        const tierId: number = 2;
+        const orderId: number = 6;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const [userDetailPda, userDetailBump] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userAKeypair.publicKey);
+
+        const [orderPda, orderBump] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userDetailPda, orderId.toString());
+
+        await expectError(program.methods.settleAuctionOrder(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, userDetailBump, orderBump,
+            phaseThree, tierId.toString(), orderId.toString())
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                user: userAKeypair.publicKey,
+                paymentReceiver: priceReceiverKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                orderDetail: orderPda,
+                systemProgram: SystemProgram.programId
+            })
+            .rpc(), "AuctionNotEnded");
+    });
+
+    it("Settle Auction Order User A Order Six", async () => {
+
+        const tierId: number = 2;
+        const orderId: number = 6;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const [userDetailPda, userDetailBump] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userAKeypair.publicKey);
+
+        const [orderPda, orderBump] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userDetailPda, orderId.toString());
+
+        // wait for the auction to reach its floor price
+        await delay(20 * delayTimeCount);
+
+        const userBalance = await connection.getBalance(userAKeypair.publicKey);
+
+        const tx = await program.methods.settleAuctionOrder(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, userDetailBump, orderBump,
+            phaseThree, tierId.toString(), orderId.toString())
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                user: userAKeypair.publicKey,
+                paymentReceiver: priceReceiverKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                orderDetail: orderPda,
+                systemProgram: SystemProgram.programId
+            })
+            .rpc({skipPreflight: true});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const orderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+        assert(orderData.isAuctionSettled);
+
+        // nobody bought at a higher price, the whole escrow above the floor is rebated
+        const updatedUserBalance = await connection.getBalance(userAKeypair.publicKey);
+        assert(updatedUserBalance > userBalance);
+    });
+
+    it("Initialize Sale Phase Five", async () => {
+
+        const tx = await program.methods.initializeSalePhase(sogaNodeSaleConfigBump, phaseFive, 3, nft_name, nft_symbol, nft_url, priceFeedIdSol, {token2022: {}})
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                mainSigningAuthority: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                saleConfig: sogaNodeSaleConfigPDA,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                priceFeed: priceFeedSolAddress,
+                paymentReceiver: priceReceiverKeypair.publicKey,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc();
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+    });
+
+    it("Initialize Sale Phase Five Tiers", async () => {
+
+        // tier one runs a fair launch, tier two a sealed bid auction and tier three sells to whitelist pass holders
+        for (const [tierId, price, quantity, mintLimit, whitelistQuantity] of [[1, 100, 4, 3, 0], [2, 100, 2, 2, 0], [3, 100, 10, 5, 4]]) {
+
+            const [nodeSalePhaseTierPda] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+                tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+            const [nodeSalePhaseTierCollectionPda] = getSogaNodeSalePhaseDetailTierCollectionAccountPdaAndBump(program.programId, COLLECTION_ACCOUNT_PREFIX,
+                nodeSalePhaseTierPda);
+
+            const tx = await program.methods.initializeSalePhaseTierToken2022(sogaNodeSalePhaseFiveBump, phaseFive, tierId.toString(),
+                new BN(price), new BN(quantity), new BN(mintLimit),
+                collection_name, collection_symbol, collection_url, new BN(whitelistQuantity))
+                .accounts({
+                    payer: mainSigningAuthorityPubKey,
+                    signingAuthority: signingAuthorityKeypair.publicKey,
+                    salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                    salePhaseTierDetail: nodeSalePhaseTierPda,
+                    collectionMintAccount: nodeSalePhaseTierCollectionPda,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                    rent: SYSVAR_RENT_PUBKEY
+                })
+                .signers([signingAuthorityKeypair])
+                .rpc();
+            console.log("Your transaction signature", tx);
+
+            await delay(delayTimeCount);
+        }
+    });
+
+    it("initialize Sale Phase Five payment token usdt", async () => {
+
+        const [salePhasePaymentTokenDetailPda] = getSogaNodeSalePhasePaymentTokenDetailAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_PAYMENT_TOKEN_ACCOUNT_PREFIX,
+            phaseFive, sogaNodeSalePhaseFivePDA, paymentTokenMintAccount);
+
+        const tx = await program.methods.initializeSalePhaseTokenPayment(sogaNodeSalePhaseFiveBump, phaseFive, priceFeedIdUsdt)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                priceFeed: priceFeedUsdtAddress,
+                salePhasePaymentTokenDetail: salePhasePaymentTokenDetailPda,
+                paymentTokenMintAccount: paymentTokenMintAccount,
+                paymentTokenProgram: TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc();
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+    });
+
+    it("Update Sale Phase Five Tier One Fair Launch fails with a commit window ending before it starts", async () => {
+
+        const tierId: number = 1;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        const timestamp: number = Math.floor(Date.now() / 1000);
+
+        await expectError(program.methods.updateSalePhaseTierFairLaunch(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump, phaseFive, tierId.toString(),
+            true, new BN(timestamp), new BN(timestamp - 10), {proRata: {}}, new BN(timestamp + 600))
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc(), "InvalidFairLaunch");
+    });
+
+    it("Update Sale Phase Five Tier One Fair Launch", async () => {
+
+        const tierId: number = 1;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        const timestamp: number = Math.floor(Date.now() / 1000);
+
+        const tx = await program.methods.updateSalePhaseTierFairLaunch(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump, phaseFive, tierId.toString(),
+            true, new BN(timestamp - 5), new BN(timestamp + 30), {proRata: {}}, new BN(timestamp + 600))
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const salePhaseTierData = await program.account.sogaNodeSalePhaseTierDetailAccount.fetch(nodeSalePhaseTierPda.toBase58());
+        assert(salePhaseTierData.isFairLaunch);
+        assert(salePhaseTierData.allocationMode.proRata !== undefined);
+    });
+
+    it("Commit User A Sale Phase Five Tier One fails above the max payment amount", async () => {
+
+        const tierId: number = 1;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        const [commitmentPda] = getCommitmentDetailAccountPdaAndBump(program.programId, COMMITMENT_DETAIL_ACCOUNT_PREFIX, nodeSalePhaseTierPda, userAKeypair.publicKey);
+
+        await expectError(program.methods.commit(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump, phaseFive, tierId.toString(), new BN(3), new BN(1))
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                userPayer: userAKeypair.publicKey,
+                user: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                commitmentDetail: commitmentPda,
+                priceUpdate: priceFeedSolAddress,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair, userAKeypair])
+            .rpc(), "PaymentAmountExceedsMax");
+    });
+
+    it("Commit User A Sale Phase Five Tier One", async () => {
+
+        const tierId: number = 1;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        const [commitmentPda] = getCommitmentDetailAccountPdaAndBump(program.programId, COMMITMENT_DETAIL_ACCOUNT_PREFIX, nodeSalePhaseTierPda, userAKeypair.publicKey);
+
+        const tx = await program.methods.commit(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump, phaseFive, tierId.toString(), new BN(3), new BN("18446744073709551615"))
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                userPayer: userAKeypair.publicKey,
+                user: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                commitmentDetail: commitmentPda,
+                priceUpdate: priceFeedSolAddress,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair, userAKeypair])
+            .rpc({skipPreflight: true});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const commitmentData = await program.account.commitmentDetailAccount.fetch(commitmentPda.toBase58());
+        assert(commitmentData.quantity.toString() === "3");
+        assert(commitmentData.paymentTokenMintAccount === null);
+
+        const salePhaseTierData = await program.account.sogaNodeSalePhaseTierDetailAccount.fetch(nodeSalePhaseTierPda.toBase58());
+        assert(salePhaseTierData.totalCommitted.toString() === "3");
+    });
+
+    it("Commit with token User B Sale Phase Five Tier One fails above the max payment amount", async () => {
+
+        const tierId: number = 1;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        const [commitmentPda] = getCommitmentDetailAccountPdaAndBump(program.programId, COMMITMENT_DETAIL_ACCOUNT_PREFIX, nodeSalePhaseTierPda, userBKeypair.publicKey);
+
+        const [salePhasePaymentTokenDetailPda] = getSogaNodeSalePhasePaymentTokenDetailAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_PAYMENT_TOKEN_ACCOUNT_PREFIX,
+            phaseFive, sogaNodeSalePhaseFivePDA, paymentTokenMintAccount);
+
+        // the tier account owns the escrow holding token deposits until they are allocated
+        const commitmentEscrow = await getAssociatedTokenAddress(paymentTokenMintAccount, nodeSalePhaseTierPda, true);
+
+        await expectError(program.methods.commitWithToken(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump, phaseFive, tierId.toString(), new BN(3), new BN(1))
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                userPayer: userBKeypair.publicKey,
+                user: userBKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                commitmentDetail: commitmentPda,
+                priceUpdate: priceFeedUsdtAddress,
+                systemProgram: SystemProgram.programId
+            }).remainingAccounts([
+                {
+                    pubkey: salePhasePaymentTokenDetailPda,
+                    isWritable: false,
+                    isSigner: false
+                },
+                {
+                    pubkey: paymentTokenMintAccount,
+                    isWritable: false,
+                    isSigner: false
+                },
+                {
+                    pubkey: TOKEN_PROGRAM_ID,
+                    isWritable: false,
+                    isSigner: false
+                },
+                {
+                    pubkey: userBPaymentTokenAccount,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: commitmentEscrow,
+                    isWritable: true,
+                    isSigner: false
+                }
+            ]).preInstructions([
+                createAssociatedTokenAccountIdempotentInstruction(mainSigningAuthorityPubKey, commitmentEscrow, nodeSalePhaseTierPda, paymentTokenMintAccount)
+            ])
+            .signers([signingAuthorityKeypair, userBKeypair])
+            .rpc(), "PaymentAmountExceedsMax");
+    });
+
+    it("Commit with token User B Sale Phase Five Tier One", async () => {
+
+        const tierId: number = 1;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        const [commitmentPda] = getCommitmentDetailAccountPdaAndBump(program.programId, COMMITMENT_DETAIL_ACCOUNT_PREFIX, nodeSalePhaseTierPda, userBKeypair.publicKey);
+
+        const [salePhasePaymentTokenDetailPda] = getSogaNodeSalePhasePaymentTokenDetailAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_PAYMENT_TOKEN_ACCOUNT_PREFIX,
+            phaseFive, sogaNodeSalePhaseFivePDA, paymentTokenMintAccount);
+
+        // the tier account owns the escrow holding token deposits until they are allocated
+        const commitmentEscrow = await getAssociatedTokenAddress(paymentTokenMintAccount, nodeSalePhaseTierPda, true);
+
+        const tx = await program.methods.commitWithToken(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump, phaseFive, tierId.toString(), new BN(3), new BN("18446744073709551615"))
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                userPayer: userBKeypair.publicKey,
+                user: userBKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                commitmentDetail: commitmentPda,
+                priceUpdate: priceFeedUsdtAddress,
+                systemProgram: SystemProgram.programId
+            }).remainingAccounts([
+                {
+                    pubkey: salePhasePaymentTokenDetailPda,
+                    isWritable: false,
+                    isSigner: false
+                },
+                {
+                    pubkey: paymentTokenMintAccount,
+                    isWritable: false,
+                    isSigner: false
+                },
+                {
+                    pubkey: TOKEN_PROGRAM_ID,
+                    isWritable: false,
+                    isSigner: false
+                },
+                {
+                    pubkey: userBPaymentTokenAccount,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: commitmentEscrow,
+                    isWritable: true,
+                    isSigner: false
+                }
+            ]).preInstructions([
+                createAssociatedTokenAccountIdempotentInstruction(mainSigningAuthorityPubKey, commitmentEscrow, nodeSalePhaseTierPda, paymentTokenMintAccount)
+            ])
+            .signers([signingAuthorityKeypair, userBKeypair])
+            .rpc({skipPreflight: true});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const commitmentData = await program.account.commitmentDetailAccount.fetch(commitmentPda.toBase58());
+        assert(commitmentData.quantity.toString() === "3");
+        assert(commitmentData.paymentTokenMintAccount.toBase58() === paymentTokenMintAccount.toBase58());
+
+        const commitmentEscrowToken = await getAccount(connection, commitmentEscrow);
+        assert(commitmentEscrowToken.amount.toString() === commitmentData.totalPayment.toString());
+    });
+
+    it("Finalize Sale Phase Five Tier One Fair Launch fails before the commit window ends", async () => {
+
+        const tierId: number = 1;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        await expectError(program.methods.finalizeSalePhaseTierFairLaunch(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump, phaseFive, tierId.toString())
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                recentSlotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc(), "CommitWindowNotEnded");
+    });
+
+    it("Finalize Sale Phase Five Tier One Fair Launch", async () => {
+
+        const tierId: number = 1;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        const salePhaseTierData = await program.account.sogaNodeSalePhaseTierDetailAccount.fetch(nodeSalePhaseTierPda.toBase58());
+
+        // wait for the commit window to close
+        await delay(Math.max(0, salePhaseTierData.commitEndTimestamp.toNumber() * 1000 - Date.now()) + 2 * delayTimeCount);
+
+        const tx = await program.methods.finalizeSalePhaseTierFairLaunch(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump, phaseFive, tierId.toString())
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                recentSlotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        // six nodes are committed for a supply of four
+        const updatedSalePhaseTierData = await program.account.sogaNodeSalePhaseTierDetailAccount.fetch(nodeSalePhaseTierPda.toBase58());
+        assert(updatedSalePhaseTierData.isFairLaunchFinalized);
+        assert(updatedSalePhaseTierData.fairLaunchSupply.toString() === "4");
+    });
+
+    it("Allocate Commitment User B Sale Phase Five Tier One fails with another quantity than its share", async () => {
+
+        const orderId: number = 1;
+
+        const tierId: number = 1;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        const [commitmentPda] = getCommitmentDetailAccountPdaAndBump(program.programId, COMMITMENT_DETAIL_ACCOUNT_PREFIX, nodeSalePhaseTierPda, userBKeypair.publicKey);
+
+        const [userDetailPda] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseFivePDA, userBKeypair.publicKey);
+
+        const [userPhaseTierDetailPda] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX, userDetailPda, nodeSalePhaseTierPda);
+
+        const [orderPda] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseFivePDA, userDetailPda, orderId.toString());
+
+        await expectError(program.methods.allocateCommitment(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump, phaseFive, tierId.toString(), orderId.toString(), new BN(3))
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                user: userBKeypair.publicKey,
+                paymentReceiver: priceReceiverKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                commitmentDetail: commitmentPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                orderDetail: orderPda,
+                systemProgram: SystemProgram.programId
+            })
+            .rpc(), "InvalidAllocationQuantity");
+    });
+
+    it("Allocate Commitment User A Sale Phase Five Tier One", async () => {
+
+        const orderId: number = 1;
+
+        const tierId: number = 1;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        const [commitmentPda] = getCommitmentDetailAccountPdaAndBump(program.programId, COMMITMENT_DETAIL_ACCOUNT_PREFIX, nodeSalePhaseTierPda, userAKeypair.publicKey);
+
+        const [userDetailPda] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseFivePDA, userAKeypair.publicKey);
+
+        const [userPhaseTierDetailPda] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX, userDetailPda, nodeSalePhaseTierPda);
+
+        const [orderPda] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseFivePDA, userDetailPda, orderId.toString());
+
+        const tx = await program.methods.allocateCommitment(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump, phaseFive, tierId.toString(), orderId.toString(), new BN(2))
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                user: userAKeypair.publicKey,
+                paymentReceiver: priceReceiverKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                commitmentDetail: commitmentPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                orderDetail: orderPda,
+                systemProgram: SystemProgram.programId
+            })
+            .rpc({skipPreflight: true});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        // the first three of six committed nodes get two of the four nodes
+        const orderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+        assert(orderData.quantity.toString() === "2");
+        assert(orderData.tokenIds.map((tokenId) => tokenId.toString()).join() === "1,2");
+
+        const commitmentData = await program.account.commitmentDetailAccount.fetch(commitmentPda.toBase58());
+        assert(commitmentData.isAllocated);
+        assert(commitmentData.refundAmount.gtn(0));
+    });
+
+    it("Refund Commitment User B Sale Phase Five Tier One fails before it is allocated", async () => {
+
+        const tierId: number = 1;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        const [commitmentPda] = getCommitmentDetailAccountPdaAndBump(program.programId, COMMITMENT_DETAIL_ACCOUNT_PREFIX, nodeSalePhaseTierPda, userBKeypair.publicKey);
+
+        await expectError(program.methods.refundCommitment(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump, phaseFive, tierId.toString())
+            .accounts({
+                user: userBKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                commitmentDetail: commitmentPda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([userBKeypair])
+            .rpc(), "CommitmentNotAllocated");
+    });
+
+    it("Refund Commitment User A Sale Phase Five Tier One", async () => {
+
+        const tierId: number = 1;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        const [commitmentPda] = getCommitmentDetailAccountPdaAndBump(program.programId, COMMITMENT_DETAIL_ACCOUNT_PREFIX, nodeSalePhaseTierPda, userAKeypair.publicKey);
+
+        const commitmentData = await program.account.commitmentDetailAccount.fetch(commitmentPda.toBase58());
+        const userBalance = await connection.getBalance(userAKeypair.publicKey);
+
+        const tx = await program.methods.refundCommitment(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump, phaseFive, tierId.toString())
+            .accounts({
+                user: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                commitmentDetail: commitmentPda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([userAKeypair])
+            .rpc({skipPreflight: true});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const updatedCommitmentData = await program.account.commitmentDetailAccount.fetch(commitmentPda.toBase58());
+        assert(updatedCommitmentData.isRefunded);
+
+        // the deposit of the node that was not allocated is refunded, less the transaction fee
+        const updatedUserBalance = await connection.getBalance(userAKeypair.publicKey);
+        assert(updatedUserBalance > userBalance + commitmentData.refundAmount.toNumber() - LAMPORTS_PER_SOL / 100);
+    });
+
+    it("Update Sale Phase Five Tier Two Sealed Bid Auction fails with a reveal window ending before bidding", async () => {
+
+        const tierId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        const timestamp: number = Math.floor(Date.now() / 1000);
+
+        await expectError(program.methods.updateSalePhaseTierSealedBidAuction(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump, phaseFive, tierId.toString(),
+            true, new BN(timestamp + 20), new BN(timestamp + 10), new BN(LAMPORTS_PER_SOL / 10), false)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc(), "InvalidSealedBidAuction");
+    });
+
+    it("Update Sale Phase Five Tier Two Sealed Bid Auction", async () => {
+
+        const tierId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        const timestamp: number = Math.floor(Date.now() / 1000);
+
+        const tx = await program.methods.updateSalePhaseTierSealedBidAuction(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump, phaseFive, tierId.toString(),
+            true, new BN(timestamp + 15), new BN(timestamp + 30), new BN(LAMPORTS_PER_SOL / 10), false)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const salePhaseTierData = await program.account.sogaNodeSalePhaseTierDetailAccount.fetch(nodeSalePhaseTierPda.toBase58());
+        assert(salePhaseTierData.isSealedBidAuction);
+        assert(salePhaseTierData.reservePrice.toString() === (LAMPORTS_PER_SOL / 10).toString());
+    });
+
+    it("Place Bid User A Sale Phase Five Tier One fails outside of a sealed bid auction", async () => {
+
+        const tierId: number = 1;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        const [bidPda] = getBidDetailAccountPdaAndBump(program.programId, BID_DETAIL_ACCOUNT_PREFIX, nodeSalePhaseTierPda, userAKeypair.publicKey);
+
+        await expectError(program.methods.placeBid(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump, phaseFive, tierId.toString(),
+            getBidHash(new BN(4 * LAMPORTS_PER_SOL / 10), bidSaltUserA, userAKeypair.publicKey), new BN(LAMPORTS_PER_SOL / 2))
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                user: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                bidDetail: bidPda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair, userAKeypair])
+            .rpc(), "BiddingClosed");
+    });
+
+    it("Place Bid User A Sale Phase Five Tier Two", async () => {
+
+        const tierId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        const [bidPda] = getBidDetailAccountPdaAndBump(program.programId, BID_DETAIL_ACCOUNT_PREFIX, nodeSalePhaseTierPda, userAKeypair.publicKey);
+
+        // the deposit covers the sealed price, the difference is refunded on settlement
+        const tx = await program.methods.placeBid(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump, phaseFive, tierId.toString(),
+            getBidHash(new BN(4 * LAMPORTS_PER_SOL / 10), bidSaltUserA, userAKeypair.publicKey), new BN(LAMPORTS_PER_SOL / 2))
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                user: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                bidDetail: bidPda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair, userAKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const bidData = await program.account.bidDetailAccount.fetch(bidPda.toBase58());
+        assert(bidData.deposit.toString() === (LAMPORTS_PER_SOL / 2).toString());
+        assert(!bidData.isRevealed);
+    });
+
+    it("Place Bid User B Sale Phase Five Tier Two", async () => {
+
+        const tierId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        const [bidPda] = getBidDetailAccountPdaAndBump(program.programId, BID_DETAIL_ACCOUNT_PREFIX, nodeSalePhaseTierPda, userBKeypair.publicKey);
+
+        const tx = await program.methods.placeBid(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump, phaseFive, tierId.toString(),
+            getBidHash(new BN(2 * LAMPORTS_PER_SOL / 10), bidSaltUserB, userBKeypair.publicKey), new BN(2 * LAMPORTS_PER_SOL / 10))
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                user: userBKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                bidDetail: bidPda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair, userBKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const salePhaseTierData = await program.account.sogaNodeSalePhaseTierDetailAccount.fetch(nodeSalePhaseTierPda.toBase58());
+        assert(salePhaseTierData.totalBids.toString() === "2");
+    });
+
+    it("Reveal Bid User A Sale Phase Five Tier Two", async () => {
+
+        const tierId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        const [bidPda] = getBidDetailAccountPdaAndBump(program.programId, BID_DETAIL_ACCOUNT_PREFIX, nodeSalePhaseTierPda, userAKeypair.publicKey);
+
+        const salePhaseTierData = await program.account.sogaNodeSalePhaseTierDetailAccount.fetch(nodeSalePhaseTierPda.toBase58());
+
+        // wait for the bidding to end
+        await delay(Math.max(0, salePhaseTierData.bidEndTimestamp.toNumber() * 1000 - Date.now()) + 2 * delayTimeCount);
+
+        const tx = await program.methods.revealBid(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump, phaseFive, tierId.toString(),
+            new BN(4 * LAMPORTS_PER_SOL / 10), Array.from(bidSaltUserA))
+            .accounts({
+                user: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                bidDetail: bidPda
+            })
+            .signers([userAKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const bidData = await program.account.bidDetailAccount.fetch(bidPda.toBase58());
+        assert(bidData.isRevealed);
+
+        const updatedSalePhaseTierData = await program.account.sogaNodeSalePhaseTierDetailAccount.fetch(nodeSalePhaseTierPda.toBase58());
+        assert(updatedSalePhaseTierData.winningBids[0].user.toBase58() === userAKeypair.publicKey.toBase58());
+    });
+
+    it("Reveal Bid User B Sale Phase Five Tier Two fails with another salt", async () => {
+
+        const tierId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        const [bidPda] = getBidDetailAccountPdaAndBump(program.programId, BID_DETAIL_ACCOUNT_PREFIX, nodeSalePhaseTierPda, userBKeypair.publicKey);
+
+        await expectError(program.methods.revealBid(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump, phaseFive, tierId.toString(),
+            new BN(2 * LAMPORTS_PER_SOL / 10), Array.from(bidSaltUserA))
+            .accounts({
+                user: userBKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                bidDetail: bidPda
+            })
+            .signers([userBKeypair])
+            .rpc(), "InvalidBidReveal");
+    });
+
+    it("Refund Bid User A Sale Phase Five Tier Two fails for a winning bid", async () => {
+
+        const tierId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        const [bidPda] = getBidDetailAccountPdaAndBump(program.programId, BID_DETAIL_ACCOUNT_PREFIX, nodeSalePhaseTierPda, userAKeypair.publicKey);
+
+        const salePhaseTierData = await program.account.sogaNodeSalePhaseTierDetailAccount.fetch(nodeSalePhaseTierPda.toBase58());
+
+        // wait for the reveal window to end
+        await delay(Math.max(0, salePhaseTierData.revealEndTimestamp.toNumber() * 1000 - Date.now()) + 2 * delayTimeCount);
+
+        await expectError(program.methods.refundBid(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump, phaseFive, tierId.toString())
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                user: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                bidDetail: bidPda
+            })
+            .rpc(), "BidWinning");
+    });
+
+    it("Settle Winning Bid User B Sale Phase Five Tier Two fails for an unrevealed bid", async () => {
+
+        const orderId: number = 1;
+
+        const tierId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        const [bidPda] = getBidDetailAccountPdaAndBump(program.programId, BID_DETAIL_ACCOUNT_PREFIX, nodeSalePhaseTierPda, userBKeypair.publicKey);
+
+        const [userDetailPda] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseFivePDA, userBKeypair.publicKey);
+
+        const [userPhaseTierDetailPda] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX, userDetailPda, nodeSalePhaseTierPda);
+
+        const [orderPda] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseFivePDA, userDetailPda, orderId.toString());
+
+        await expectError(program.methods.settleWinningBid(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump, phaseFive, tierId.toString(), orderId.toString())
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                user: userBKeypair.publicKey,
+                paymentReceiver: priceReceiverKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                bidDetail: bidPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                orderDetail: orderPda,
+                priceUpdate: priceFeedSolAddress,
+                systemProgram: SystemProgram.programId
+            })
+            .rpc(), "BidNotWinning");
+    });
+
+    it("Settle Winning Bid User A Sale Phase Five Tier Two", async () => {
+
+        // the fair launch allocation is the first order of user A in this phase
+        const orderId: number = 2;
+
+        const tierId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        const [bidPda] = getBidDetailAccountPdaAndBump(program.programId, BID_DETAIL_ACCOUNT_PREFIX, nodeSalePhaseTierPda, userAKeypair.publicKey);
+
+        const [userDetailPda] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseFivePDA, userAKeypair.publicKey);
+
+        const [userPhaseTierDetailPda] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX, userDetailPda, nodeSalePhaseTierPda);
+
+        const [orderPda] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseFivePDA, userDetailPda, orderId.toString());
+
+        const tx = await program.methods.settleWinningBid(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump, phaseFive, tierId.toString(), orderId.toString())
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                user: userAKeypair.publicKey,
+                paymentReceiver: priceReceiverKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                bidDetail: bidPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                orderDetail: orderPda,
+                priceUpdate: priceFeedSolAddress,
+                systemProgram: SystemProgram.programId
+            })
+            .rpc({skipPreflight: true});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        // pay as bid, the order is paid at the revealed price of the bid
+        const orderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+        assert(orderData.quantity.toString() === "1");
+        assert(orderData.totalPayment.toString() === (4 * LAMPORTS_PER_SOL / 10).toString());
+
+        const bidData = await program.account.bidDetailAccount.fetch(bidPda.toBase58());
+        assert(bidData.isSettled);
+        assert(bidData.orderId.toString() === orderId.toString());
+    });
+
+    it("Refund Bid User B Sale Phase Five Tier Two for an unrevealed bid", async () => {
+
+        const tierId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        const [bidPda] = getBidDetailAccountPdaAndBump(program.programId, BID_DETAIL_ACCOUNT_PREFIX, nodeSalePhaseTierPda, userBKeypair.publicKey);
+
+        const userBalance = await connection.getBalance(userBKeypair.publicKey);
+
+        const tx = await program.methods.refundBid(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump, phaseFive, tierId.toString())
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                user: userBKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                bidDetail: bidPda
+            })
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const bidData = await program.account.bidDetailAccount.fetch(bidPda.toBase58());
+        assert(bidData.isSettled);
+
+        const updatedUserBalance = await connection.getBalance(userBKeypair.publicKey);
+        assert(updatedUserBalance === userBalance + 2 * LAMPORTS_PER_SOL / 10);
+    });
+
+    it("Update Sale Phase Three Tier Two Volume Brackets fails with brackets out of order", async () => {
+
+        const tierId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        await expectError(program.methods.updateSalePhaseTierVolumeBrackets(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, phaseThree, tierId.toString(), [
+            {
+                minQuantity: new BN(4),
+                discount: 2000
+            },
+            {
+                minQuantity: new BN(2),
+                discount: 1000
+            }
+        ], false)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc(), "InvalidVolumeBrackets");
+    });
+
+    it("Update Sale Phase Three Tier Two Volume Brackets", async () => {
+
+        const tierId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const tx = await program.methods.updateSalePhaseTierVolumeBrackets(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, phaseThree, tierId.toString(), [
+            {
+                minQuantity: new BN(2),
+                discount: 1000
+            },
+            {
+                minQuantity: new BN(4),
+                discount: 2000
+            }
+        ], false)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const salePhaseTierData = await program.account.sogaNodeSalePhaseTierDetailAccount.fetch(nodeSalePhaseTierPda.toBase58());
+        assert(salePhaseTierData.volumeBrackets.length === 2);
+        assert(!salePhaseTierData.isVolumeLifetime);
+    });
+
+    it("Buy Node Two User B Sale Phase Three Tier Two with a volume discount", async () => {
+
+        const tierId: number = 2;
+        const orderId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const [userDetailPda] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userBKeypair.publicKey);
+
+        const [userPhaseTierDetailPda] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX, userDetailPda, nodeSalePhaseTierPda);
+
+        const [orderPda] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userDetailPda, orderId.toString());
+
+        const tx = await program.methods.buy(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump,
+            phaseThree, tierId.toString(), orderId.toString(), new BN(2), true, 2000, true, 1000, false, false, 0, new BN("18446744073709551615"), false)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                userPayer: userBKeypair.publicKey,
+                user: userBKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                orderDetail: orderPda,
+                priceUpdate: priceFeedSolAddress,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            }).remainingAccounts([
+                {
+                    pubkey: priceReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: fullReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: halfReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                }
+            ]).preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
+            .signers([signingAuthorityKeypair, userBKeypair])
+            .rpc({skipPreflight: true});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        // two nodes at the auction floor of 300 reach the first bracket, 10% off
+        const orderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+        assert(orderData.quantity.toString() === "2");
+        assert(orderData.totalVolumeDiscountInUsd.toString() === "60");
+    });
+
+    it("create whitelist pass mint account", async () => {
+        whitelistPassMintAccount = await createMint(anchor.AnchorProvider.env().connection, signingAuthorityKeypair, signingAuthorityKeypair.publicKey, signingAuthorityKeypair.publicKey, 0);
+        console.log("whitelist pass mint account: ", whitelistPassMintAccount.toBase58());
+        await delay(delayTimeCount);
+
+        for (const [user, amount] of [[userAKeypair, 2], [userBKeypair, 1]] as [Keypair, number][]) {
+            const whitelistPassTokenAccount = await createAssociatedTokenAccount(
+                anchor.AnchorProvider.env().connection, // connection
+                signingAuthorityKeypair, // fee payer
+                whitelistPassMintAccount, // mint
+                user.publicKey // owner,
+            );
+
+            const tx = await mintToChecked(
+                anchor.AnchorProvider.env().connection, // connection
+                signingAuthorityKeypair, // fee payer
+                whitelistPassMintAccount, // mint
+                whitelistPassTokenAccount, // receiver
+                signingAuthorityKeypair, // mint authority
+                amount, // amount
+                0 // decimals
+            );
+            console.log("mint whitelist pass tx: ", tx);
+        }
+    });
+
+    it("Update Sale Phase Five Tier Three Whitelist Pass fails with another signing authority", async () => {
+
+        const tierId: number = 3;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        await expectError(program.methods.updateSalePhaseTierWhitelistPass(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump, phaseFive, tierId.toString(), whitelistPassMintAccount, true)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([userAKeypair])
+            .rpc(), "InvalidSigningAuthority");
+    });
+
+    it("Update Sale Phase Five Tier Three Whitelist Pass", async () => {
+
+        const tierId: number = 3;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        const tx = await program.methods.updateSalePhaseTierWhitelistPass(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump, phaseFive, tierId.toString(), whitelistPassMintAccount, true)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const salePhaseTierData = await program.account.sogaNodeSalePhaseTierDetailAccount.fetch(nodeSalePhaseTierPda.toBase58());
+        assert(salePhaseTierData.whitelistPassMint.toBase58() === whitelistPassMintAccount.toBase58());
+        assert(salePhaseTierData.isWhitelistPassBurned);
+    });
+
+    it("Buy Node Three User A Sale Phase Five Tier Three fails with another pass mint", async () => {
+
+        const tierId: number = 3;
+        const orderId: number = 3;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        const [userDetailPda] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseFivePDA, userAKeypair.publicKey);
+
+        const [userPhaseTierDetailPda] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX, userDetailPda, nodeSalePhaseTierPda);
+
+        const [orderPda] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseFivePDA, userDetailPda, orderId.toString());
+
+        await expectError(program.methods.buy(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump,
+            phaseFive, tierId.toString(), orderId.toString(), new BN(1), true, 2000, true, 1000, false, false, 0, new BN("18446744073709551615"), false)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                userPayer: userAKeypair.publicKey,
+                user: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                orderDetail: orderPda,
+                priceUpdate: priceFeedSolAddress,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY,
+                whitelistPassMint: paymentTokenMintAccount,
+                whitelistPassTokenAccount: userAPaymentTokenAccount,
+                whitelistPassTokenProgram: TOKEN_PROGRAM_ID
+            }).remainingAccounts([
+                {
+                    pubkey: priceReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: fullReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: halfReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                }
+            ]).preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
+            .signers([signingAuthorityKeypair, userAKeypair])
+            .rpc(), "InvalidWhitelistPass");
+    });
+
+    it("Buy Node Three User A Sale Phase Five Tier Three with a whitelist pass", async () => {
+
+        const tierId: number = 3;
+        const orderId: number = 3;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        const [userDetailPda] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseFivePDA, userAKeypair.publicKey);
+
+        const [userPhaseTierDetailPda] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX, userDetailPda, nodeSalePhaseTierPda);
+
+        const [orderPda] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseFivePDA, userDetailPda, orderId.toString());
+
+        const whitelistPassTokenAccount = await getAssociatedTokenAddress(whitelistPassMintAccount, userAKeypair.publicKey);
+
+        const tx = await program.methods.buy(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump,
+            phaseFive, tierId.toString(), orderId.toString(), new BN(1), true, 2000, true, 1000, false, false, 0, new BN("18446744073709551615"), false)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                userPayer: userAKeypair.publicKey,
+                user: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                orderDetail: orderPda,
+                priceUpdate: priceFeedSolAddress,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY,
+                whitelistPassMint: whitelistPassMintAccount,
+                whitelistPassTokenAccount: whitelistPassTokenAccount,
+                whitelistPassTokenProgram: TOKEN_PROGRAM_ID
+            }).remainingAccounts([
+                {
+                    pubkey: priceReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: fullReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: halfReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                }
+            ]).preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
+            .signers([signingAuthorityKeypair, userAKeypair])
+            .rpc({skipPreflight: true});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        // the pass is burned and the node counts against the whitelist quantity
+        const whitelistPassTokenAccountData = await getAccount(connection, whitelistPassTokenAccount);
+        assert(whitelistPassTokenAccountData.amount.toString() === "1");
+
+        const salePhaseTierData = await program.account.sogaNodeSalePhaseTierDetailAccount.fetch(nodeSalePhaseTierPda.toBase58());
+        assert(salePhaseTierData.totalWhitelistMint.toString() === "1");
+    });
+
+    it("Update Sale Phase Five Tier Three Whitelist fails with another signing authority", async () => {
+
+        const tierId: number = 3;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        await expectError(program.methods.updateSalePhaseTierWhitelist(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump, phaseFive, tierId.toString(), new BN(50), new BN(1))
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([userAKeypair])
+            .rpc(), "InvalidSigningAuthority");
+    });
+
+    it("Update Sale Phase Five Tier Three Whitelist", async () => {
+
+        const tierId: number = 3;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        const tx = await program.methods.updateSalePhaseTierWhitelist(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump, phaseFive, tierId.toString(), new BN(50), new BN(1))
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const salePhaseTierData = await program.account.sogaNodeSalePhaseTierDetailAccount.fetch(nodeSalePhaseTierPda.toBase58());
+        assert(salePhaseTierData.whitelistPrice.toString() === "50");
+        assert(salePhaseTierData.whitelistMintLimit.toString() === "1");
+    });
+
+    it("Buy Node Four User A Sale Phase Five Tier Three fails above the whitelist mint limit", async () => {
+
+        const tierId: number = 3;
+        const orderId: number = 4;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        const [userDetailPda] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseFivePDA, userAKeypair.publicKey);
+
+        const [userPhaseTierDetailPda] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX, userDetailPda, nodeSalePhaseTierPda);
+
+        const [orderPda] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseFivePDA, userDetailPda, orderId.toString());
+
+        const whitelistPassTokenAccount = await getAssociatedTokenAddress(whitelistPassMintAccount, userAKeypair.publicKey);
+
+        await expectError(program.methods.buy(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump,
+            phaseFive, tierId.toString(), orderId.toString(), new BN(1), true, 2000, true, 1000, false, false, 0, new BN("18446744073709551615"), false)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                userPayer: userAKeypair.publicKey,
+                user: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                orderDetail: orderPda,
+                priceUpdate: priceFeedSolAddress,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY,
+                whitelistPassMint: whitelistPassMintAccount,
+                whitelistPassTokenAccount: whitelistPassTokenAccount,
+                whitelistPassTokenProgram: TOKEN_PROGRAM_ID
+            }).remainingAccounts([
+                {
+                    pubkey: priceReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: fullReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: halfReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                }
+            ]).preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
+            .signers([signingAuthorityKeypair, userAKeypair])
+            .rpc(), "WhitelistMintLimitExceeded");
+    });
+
+    it("Buy Node One User B Sale Phase Five Tier Three at the whitelist price", async () => {
+
+        const tierId: number = 3;
+        const orderId: number = 1;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseFivePDA);
+
+        const [userDetailPda] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseFivePDA, userBKeypair.publicKey);
+
+        const [userPhaseTierDetailPda] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX, userDetailPda, nodeSalePhaseTierPda);
+
+        const [orderPda] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseFivePDA, userDetailPda, orderId.toString());
+
+        const whitelistPassTokenAccount = await getAssociatedTokenAddress(whitelistPassMintAccount, userBKeypair.publicKey);
+
+        const tx = await program.methods.buy(sogaNodeSalePhaseFiveBump, nodeSalePhaseTierBump,
+            phaseFive, tierId.toString(), orderId.toString(), new BN(1), true, 2000, true, 1000, false, false, 0, new BN("18446744073709551615"), false)
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                userPayer: userBKeypair.publicKey,
+                user: userBKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseFivePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                orderDetail: orderPda,
+                priceUpdate: priceFeedSolAddress,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY,
+                whitelistPassMint: whitelistPassMintAccount,
+                whitelistPassTokenAccount: whitelistPassTokenAccount,
+                whitelistPassTokenProgram: TOKEN_PROGRAM_ID
+            }).remainingAccounts([
+                {
+                    pubkey: priceReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: fullReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: halfReceiverKeypair.publicKey,
+                    isWritable: true,
+                    isSigner: false
+                }
+            ]).preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
+            .signers([signingAuthorityKeypair, userBKeypair])
+            .rpc({skipPreflight: true});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const orderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+        assert(orderData.totalPaymentInUsd.toString() === "50");
+    });
+
+    it("Update Sale Phase Three Tier Two Reservation fails above the tier supply", async () => {
+
+        const tierId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const [reservationPda] = getReservationDetailAccountPdaAndBump(program.programId, RESERVATION_DETAIL_ACCOUNT_PREFIX, nodeSalePhaseTierPda, userBKeypair.publicKey);
+
+        await expectError(program.methods.updateSalePhaseTierReservation(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, phaseThree, tierId.toString(), new BN(10), new BN(100))
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                partner: userBKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                reservationDetail: reservationPda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc(), "ReservationExceedsSupply");
+    });
+
+    it("Update Sale Phase Three Tier Two Reservation for User B", async () => {
+
+        const tierId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const [reservationPda] = getReservationDetailAccountPdaAndBump(program.programId, RESERVATION_DETAIL_ACCOUNT_PREFIX, nodeSalePhaseTierPda, userBKeypair.publicKey);
+
+        const tx = await program.methods.updateSalePhaseTierReservation(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, phaseThree, tierId.toString(), new BN(1), new BN(100))
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                partner: userBKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                reservationDetail: reservationPda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const reservationData = await program.account.reservationDetailAccount.fetch(reservationPda.toBase58());
+        assert(reservationData.quantity.toString() === "1");
+        assert(!reservationData.isClaimed);
+
+        const salePhaseTierData = await program.account.sogaNodeSalePhaseTierDetailAccount.fetch(nodeSalePhaseTierPda.toBase58());
+        assert(salePhaseTierData.totalReserved.toString() === "1");
+    });
+
+    it("Claim Reservation User B Sale Phase Three Tier Two", async () => {
+
+        const tierId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const [reservationPda] = getReservationDetailAccountPdaAndBump(program.programId, RESERVATION_DETAIL_ACCOUNT_PREFIX, nodeSalePhaseTierPda, userBKeypair.publicKey);
+
+        const orderId: number = 3;
+
+        const [userDetailPda] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userBKeypair.publicKey);
+
+        const [userPhaseTierDetailPda] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX, userDetailPda, nodeSalePhaseTierPda);
+
+        const [orderPda] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userDetailPda, orderId.toString());
+
+        const tx = await program.methods.claimReservation(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, phaseThree, tierId.toString(), orderId.toString(), new BN("18446744073709551615"))
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                partner: userBKeypair.publicKey,
+                paymentReceiver: priceReceiverKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                reservationDetail: reservationPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                orderDetail: orderPda,
+                priceUpdate: priceFeedSolAddress,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([userBKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const reservationData = await program.account.reservationDetailAccount.fetch(reservationPda.toBase58());
+        assert(reservationData.isClaimed);
+        assert(reservationData.orderId.toString() === orderId.toString());
+
+        const orderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+        assert(orderData.quantity.toString() === "1");
+        assert(orderData.totalPaymentInUsd.toString() === "100");
+    });
+
+    it("Claim Reservation User B Sale Phase Three Tier Two fails once it is claimed", async () => {
+
+        const tierId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        const [reservationPda] = getReservationDetailAccountPdaAndBump(program.programId, RESERVATION_DETAIL_ACCOUNT_PREFIX, nodeSalePhaseTierPda, userBKeypair.publicKey);
+
+        const orderId: number = 4;
+
+        const [userDetailPda] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userBKeypair.publicKey);
+
+        const [userPhaseTierDetailPda] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX, userDetailPda, nodeSalePhaseTierPda);
+
+        const [orderPda] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userDetailPda, orderId.toString());
+
+        await expectError(program.methods.claimReservation(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, phaseThree, tierId.toString(), orderId.toString(), new BN("18446744073709551615"))
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                partner: userBKeypair.publicKey,
+                paymentReceiver: priceReceiverKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                reservationDetail: reservationPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                orderDetail: orderPda,
+                priceUpdate: priceFeedSolAddress,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([userBKeypair])
+            .rpc(), "ReservationClaimed");
+    });
+
+    it("Update Sale Phase Three Tier Two Airdrop Claim fails above the tier supply", async () => {
+
+        const tierId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        // snapshot of one node for user A at index 0 and one node for user B at index 1
+        const userALeaf: Buffer = getAirdropLeaf(0, userAKeypair.publicKey, nodeSalePhaseTierPda, new BN(1));
+        const userBLeaf: Buffer = getAirdropLeaf(1, userBKeypair.publicKey, nodeSalePhaseTierPda, new BN(1));
+        const merkleRoot: Buffer = getMerkleParent(userALeaf, userBLeaf);
+
+        const [airdropClaimBitmapPda] = getAirdropClaimBitmapAccountPdaAndBump(program.programId, AIRDROP_CLAIM_BITMAP_ACCOUNT_PREFIX, nodeSalePhaseTierPda, merkleRoot);
+
+        await expectError(program.methods.updateSalePhaseTierAirdropClaim(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, phaseThree, tierId.toString(), Array.from(merkleRoot), 2, new BN(3))
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                airdropClaimBitmap: airdropClaimBitmapPda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc(), "ReservationExceedsSupply");
+    });
+
+    it("Update Sale Phase Three Tier Two Airdrop Claim", async () => {
+
+        const tierId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        // snapshot of one node for user A at index 0 and one node for user B at index 1
+        const userALeaf: Buffer = getAirdropLeaf(0, userAKeypair.publicKey, nodeSalePhaseTierPda, new BN(1));
+        const userBLeaf: Buffer = getAirdropLeaf(1, userBKeypair.publicKey, nodeSalePhaseTierPda, new BN(1));
+        const merkleRoot: Buffer = getMerkleParent(userALeaf, userBLeaf);
+
+        const [airdropClaimBitmapPda] = getAirdropClaimBitmapAccountPdaAndBump(program.programId, AIRDROP_CLAIM_BITMAP_ACCOUNT_PREFIX, nodeSalePhaseTierPda, merkleRoot);
+
+        const tx = await program.methods.updateSalePhaseTierAirdropClaim(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, phaseThree, tierId.toString(), Array.from(merkleRoot), 2, new BN(2))
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                airdropClaimBitmap: airdropClaimBitmapPda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const airdropClaimBitmapData = await program.account.airdropClaimBitmapAccount.fetch(airdropClaimBitmapPda.toBase58());
+        assert(Buffer.from(airdropClaimBitmapData.merkleRoot).equals(merkleRoot));
+        assert(airdropClaimBitmapData.totalClaims === 2);
+
+        const salePhaseTierData = await program.account.sogaNodeSalePhaseTierDetailAccount.fetch(nodeSalePhaseTierPda.toBase58());
+        assert(salePhaseTierData.airdropClaimQuantity.toString() === "2");
+    });
+
+    it("Claim Airdrop User B Sale Phase Three Tier Two fails with the entry of user A", async () => {
+
+        const tierId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        // snapshot of one node for user A at index 0 and one node for user B at index 1
+        const userALeaf: Buffer = getAirdropLeaf(0, userAKeypair.publicKey, nodeSalePhaseTierPda, new BN(1));
+        const userBLeaf: Buffer = getAirdropLeaf(1, userBKeypair.publicKey, nodeSalePhaseTierPda, new BN(1));
+        const merkleRoot: Buffer = getMerkleParent(userALeaf, userBLeaf);
+
+        const [airdropClaimBitmapPda] = getAirdropClaimBitmapAccountPdaAndBump(program.programId, AIRDROP_CLAIM_BITMAP_ACCOUNT_PREFIX, nodeSalePhaseTierPda, merkleRoot);
+
+        const orderId: number = 4;
+
+        const [userDetailPda] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userBKeypair.publicKey);
+
+        const [userPhaseTierDetailPda] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX, userDetailPda, nodeSalePhaseTierPda);
+
+        const [orderPda] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userDetailPda, orderId.toString());
+
+        await expectError(program.methods.claimAirdrop(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, phaseThree, tierId.toString(), orderId.toString(), 0, new BN(1), [Array.from(userALeaf)])
+            .accounts({
+                user: userBKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                airdropClaimBitmap: airdropClaimBitmapPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                orderDetail: orderPda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([userBKeypair])
+            .rpc(), "InvalidAirdropClaim");
+    });
+
+    it("Claim Airdrop User A Sale Phase Three Tier Two", async () => {
+
+        const tierId: number = 2;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseThreePDA);
+
+        // snapshot of one node for user A at index 0 and one node for user B at index 1
+        const userALeaf: Buffer = getAirdropLeaf(0, userAKeypair.publicKey, nodeSalePhaseTierPda, new BN(1));
+        const userBLeaf: Buffer = getAirdropLeaf(1, userBKeypair.publicKey, nodeSalePhaseTierPda, new BN(1));
+        const merkleRoot: Buffer = getMerkleParent(userALeaf, userBLeaf);
+
+        const [airdropClaimBitmapPda] = getAirdropClaimBitmapAccountPdaAndBump(program.programId, AIRDROP_CLAIM_BITMAP_ACCOUNT_PREFIX, nodeSalePhaseTierPda, merkleRoot);
+
+        const orderId: number = 7;
+
+        const [userDetailPda] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userAKeypair.publicKey);
+
+        const [userPhaseTierDetailPda] = getUserTierAccountPdaAndBump(program.programId, USER_TIER_DETAIL_ACCOUNT_PREFIX, userDetailPda, nodeSalePhaseTierPda);
+
+        const [orderPda] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseThreePDA, userDetailPda, orderId.toString());
+
+        const tx = await program.methods.claimAirdrop(sogaNodeSalePhaseThreeBump, nodeSalePhaseTierBump, phaseThree, tierId.toString(), orderId.toString(), 0, new BN(1), [Array.from(userBLeaf)])
+            .accounts({
+                user: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseThreePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                airdropClaimBitmap: airdropClaimBitmapPda,
+                userDetail: userDetailPda,
+                userTierDetail: userPhaseTierDetailPda,
+                orderDetail: orderPda,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([userAKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const airdropClaimBitmapData = await program.account.airdropClaimBitmapAccount.fetch(airdropClaimBitmapPda.toBase58());
+        assert(airdropClaimBitmapData.totalClaimed === 1);
+
+        const orderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+        assert(orderData.quantity.toString() === "1");
+        assert(orderData.totalPaymentInUsd.toString() === "0");
+    });
+
+    it("Update Sale Phase Two Loyalty", async () => {
+
+        const tierId = 1;
+
+        const [nodeSalePhaseTierPda] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseOnePDA);
+
+        const [nodeSalePhaseTierCollectionPda] = getSogaNodeSalePhaseDetailTierCollectionAccountPdaAndBump(program.programId, COLLECTION_ACCOUNT_PREFIX,
+            nodeSalePhaseTierPda);
+
+        // phase one starts before phase two, its tier one holders get the discount
+        const tx = await program.methods.updateSalePhaseLoyalty(sogaNodeSalePhaseTwoBump, phaseTwo, 500, [tierId.toString()])
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseTwoPDA,
+                systemProgram: SystemProgram.programId,
+            }).remainingAccounts([
+                {
+                    pubkey: sogaNodeSalePhaseOnePDA,
+                    isWritable: false,
+                    isSigner: false
+                },
+                {
+                    pubkey: nodeSalePhaseTierPda,
+                    isWritable: false,
+                    isSigner: false
+                }
+            ])
+            .signers([signingAuthorityKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const salePhaseData = await program.account.sogaNodeSalePhaseDetailAccount.fetch(sogaNodeSalePhaseTwoPDA.toBase58());
+
+        assert(salePhaseData.loyaltyDiscount === 500);
+        assert(salePhaseData.loyaltyCollections.length === 1);
+        assert(salePhaseData.loyaltyCollections[0].toBase58() === nodeSalePhaseTierCollectionPda.toBase58());
+    });
+
+    it("Update Sale Phase One Loyalty fails with a tier of a later phase", async () => {
+
+        const tierId = 1;
+
+        const [nodeSalePhaseTierPda] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseTwoPDA);
+
+        await expectError(program.methods.updateSalePhaseLoyalty(sogaNodeSalePhaseOneBump, phaseOne, 500, [tierId.toString()])
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseOnePDA,
+                systemProgram: SystemProgram.programId,
+            }).remainingAccounts([
+                {
+                    pubkey: sogaNodeSalePhaseTwoPDA,
+                    isWritable: false,
+                    isSigner: false
+                },
+                {
+                    pubkey: nodeSalePhaseTierPda,
+                    isWritable: false,
+                    isSigner: false
+                }
+            ])
+            .signers([signingAuthorityKeypair])
+            .rpc(), "InvalidLoyaltyCollections");
+
+        const salePhaseData = await program.account.sogaNodeSalePhaseDetailAccount.fetch(sogaNodeSalePhaseOnePDA.toBase58());
+        assert(salePhaseData.loyaltyCollections.length === 0);
+    });
+
+    it("Update Sale Phase One Fill Reward", async () => {
+
+        const [fillFeeVaultPda] = getFillFeeVaultAccountPdaAndBump(program.programId, FILL_FEE_VAULT_ACCOUNT_PREFIX, sogaNodeSalePhaseOnePDA);
+
+        const tx = await program.methods.updateSalePhaseFillReward(sogaNodeSalePhaseOneBump, phaseOne, new BN(0.001 * LAMPORTS_PER_SOL))
+            .accounts({
+                payer: mainSigningAuthorityPubKey,
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseOnePDA,
+                fillFeeVault: fillFeeVaultPda,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const salePhaseData = await program.account.sogaNodeSalePhaseDetailAccount.fetch(sogaNodeSalePhaseOnePDA.toBase58());
+        assert(salePhaseData.fillReward.toString() === (0.001 * LAMPORTS_PER_SOL).toString());
+    });
+
+    it("Fund Fill Fee Vault Phase One", async () => {
+
+        const [fillFeeVaultPda] = getFillFeeVaultAccountPdaAndBump(program.programId, FILL_FEE_VAULT_ACCOUNT_PREFIX, sogaNodeSalePhaseOnePDA);
+
+        const fillFeeVaultBalanceBefore = await connection.getBalance(fillFeeVaultPda);
+
+        const tx = await program.methods.fundFillFeeVault(sogaNodeSalePhaseOneBump, phaseOne, new BN(0.01 * LAMPORTS_PER_SOL))
+            .accounts({
+                funder: agencyKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseOnePDA,
+                fillFeeVault: fillFeeVaultPda,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers([agencyKeypair])
+            .rpc();
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const fillFeeVaultData = await program.account.fillFeeVaultAccount.fetch(fillFeeVaultPda.toBase58());
+
+        assert(fillFeeVaultData.totalFunded.toString() === (0.01 * LAMPORTS_PER_SOL).toString());
+        assert((await connection.getBalance(fillFeeVaultPda)) - fillFeeVaultBalanceBefore === 0.01 * LAMPORTS_PER_SOL);
+    });
+
+    it("Fill Order User A Order Three by a permissionless filler", async () => {
+
+        const tierId: number = 2;
+        const orderId = 3;
+
+        const [nodeSalePhaseTierPda, nodeSalePhaseTierBump] = getSogaNodeSalePhaseDetailTierAccountPdaAndBump(program.programId, SOGA_NODE_SALE_PHASE_TIER_DETAIL_ACCOUNT_PREFIX,
+            tierId.toString(), sogaNodeSalePhaseOnePDA);
+
+        const [nodeSalePhaseTierCollectionPda, nodeSalePhaseTierCollectionBump] = getSogaNodeSalePhaseDetailTierCollectionAccountPdaAndBump(program.programId, COLLECTION_ACCOUNT_PREFIX,
+            nodeSalePhaseTierPda);
+
+        const nodeSalePhaseTierCollectionMasterPda = getMasterPda(nodeSalePhaseTierCollectionPda);
+
+        const nodeSalePhaseTierCollectionMetadataPda = getMetadataPda(nodeSalePhaseTierCollectionPda);
+
+        const [userDetailPda, userDetailBump] = getUserAccountPdaAndBump(program.programId, USER_DETAIL_ACCOUNT_PREFIX,
+            sogaNodeSalePhaseOnePDA, userAKeypair.publicKey);
+
+        const [orderPda, orderBump] = getOrderDetailAccountPdaAndBump(program.programId, ORDER_DETAIL_ACCOUNT_PREFIX, sogaNodeSalePhaseOnePDA, userDetailPda, orderId.toString());
+
+        const orderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+        const tokenId = orderData.tokenIds[0].toString();
+
+        const [nodeMintAccountPda] = getNodeMintAccount(program.programId, NODE_ACCOUNT_PREFIX,
+            nodeSalePhaseTierCollectionPda, tokenId);
+        console.log("Node Mint Account Pda: ", nodeMintAccountPda.toBase58());
+
+        const nodeMintAccountMasterPda = getMasterPda(nodeMintAccountPda);
+
+        const nodeMintAccountMetadataPda = getMetadataPda(nodeMintAccountPda)
+
+        const nodeUserTokenAccount = await getAssociatedTokenAddress(nodeMintAccountPda, userAKeypair.publicKey, true, TOKEN_PROGRAM_ID);
+
+        const [fillFeeVaultPda] = getFillFeeVaultAccountPdaAndBump(program.programId, FILL_FEE_VAULT_ACCOUNT_PREFIX, sogaNodeSalePhaseOnePDA);
+
+        const salePhaseData = await program.account.sogaNodeSalePhaseDetailAccount.fetch(sogaNodeSalePhaseOnePDA.toBase58());
+        const fillFeeVaultData = await program.account.fillFeeVaultAccount.fetch(fillFeeVaultPda.toBase58());
+        const fillFeeVaultBalanceBefore = await connection.getBalance(fillFeeVaultPda);
+
+        // the filler is neither the signing authority nor the user
+        const tx = await program.methods.fileOrder(sogaNodeSalePhaseOneBump, nodeSalePhaseTierBump, nodeSalePhaseTierCollectionBump,
+            userDetailBump, orderBump,
+            phaseOne, tierId.toString(), tokenId, orderId.toString())
+            .accounts({
+                payer: feePayerKeypair.publicKey,
+                user: userAKeypair.publicKey,
+                salePhaseDetail: sogaNodeSalePhaseOnePDA,
+                salePhaseTierDetail: nodeSalePhaseTierPda,
+                userDetail: userDetailPda,
+                orderDetail: orderPda,
+                collectionMintAccount: nodeSalePhaseTierCollectionPda,
+                nodeMintAccount: nodeMintAccountPda,
+                userTokenAccount: nodeUserTokenAccount,
+                tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                fillFeeVault: fillFeeVaultPda,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            }).remainingAccounts([
+                {
+                    pubkey: nodeSalePhaseTierCollectionMetadataPda,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: nodeSalePhaseTierCollectionMasterPda,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: nodeMintAccountMetadataPda,
+                    isWritable: true,
+                    isSigner: false
+                },
+                {
+                    pubkey: nodeMintAccountMasterPda,
+                    isWritable: true,
+                    isSigner: false
+                }
+            ])
+
+            .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({units: 1400_000})])
+            .signers([feePayerKeypair])
+            .rpc({skipPreflight: true});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const filledOrderData = await program.account.orderDetailAccount.fetch(orderPda.toBase58());
+        console.log(filledOrderData);
+
+        assert(filledOrderData.isTokenIdsMinted[0]);
+
+        // the filler is paid the fill reward from the fill fee vault
+        const filledFillFeeVaultData = await program.account.fillFeeVaultAccount.fetch(fillFeeVaultPda.toBase58());
+
+        assert(filledFillFeeVaultData.totalRewarded.sub(fillFeeVaultData.totalRewarded).toString() === salePhaseData.fillReward.toString());
+        assert(fillFeeVaultBalanceBefore - (await connection.getBalance(fillFeeVaultPda)) === salePhaseData.fillReward.toNumber());
+
+        const nodeUserTokenAccountData = await getAccount(connection, nodeUserTokenAccount);
+        assert(nodeUserTokenAccountData.amount.toString() === "1");
+    });
+
+    it("Remove Events", async () => {
+        await delay(2000);
+
+        await program.removeEventListener(initializeSalePhaseEventListener);
+        await program.removeEventListener(initializeSalePhaseTierEventListener);
+        await program.removeEventListener(initializeSalePhasePaymentTokenEventListener);
+        await program.removeEventListener(updateSalePhaseEventListener);
+        await program.removeEventListener(updateSalePhaseTierEventListener);
+        await program.removeEventListener(updateSalePhasePaymentTokenEventListener);
+        await program.removeEventListener(airdropEventListener);
+        await program.removeEventListener(buyEventListener);
+        await program.removeEventListener(buyWithTokenEventListener);
+        await program.removeEventListener(fillOrderEventListener);
+        await program.removeEventListener(createOrderReceiptEventListener);
+    });
+
+});
+
+
+function delay(ms: number) {
+    return new Promise(resolve => setTimeout(resolve, ms));
+}
+
+async function expectError(transaction: Promise<string>, code: string) {
+    try {
+        await transaction;
+    } catch (e) {
+        assert(e.error?.errorCode?.code === code, e.toString());
+        return;
+    }
+
+    assert(false, `expected ${code}`);
+}
+
+function getMetadataPda(mint: PublicKey): PublicKey {
+    const [metadataPda] = PublicKey.findProgramAddressSync(
+        [
+            Buffer.from('metadata'),
+            MPL_TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+            mint.toBuffer(),
+        ], MPL_TOKEN_METADATA_PROGRAM_ID);
+
+    return metadataPda;
+}
+
+function getMasterPda(mint: PublicKey): PublicKey {
+    const [masterPda] = PublicKey.findProgramAddressSync(
+        [
+            Buffer.from('metadata'),
+            MPL_TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+            mint.toBuffer(),
+            Buffer.from('edition')
+        ],
+        MPL_TOKEN_METADATA_PROGRAM_ID
+    );
+
+    return masterPda;
+}
+
+function getSogaNodeSaleConfigAccountPdaAndBump(programAddress: PublicKey, prefix: string): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from(prefix)],
+        programAddress
+    )
+}
+
+function getSogaNodeSalePhaseDetailAccountPdaAndBump(programAddress: PublicKey, prefix: string, salePhaseName: string): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(prefix),
+            Buffer.from(salePhaseName)
+        ],
+        programAddress
+    )
+}
+
+function getSogaNodeSalePhaseDetailTierAccountPdaAndBump(programAddress: PublicKey, prefix: string, tier: string,
+                                                         sogaNodeSalePhaseDetailPda: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(prefix),
+            sogaNodeSalePhaseDetailPda.toBuffer(),
+            Buffer.from(tier),
+        ],
+        programAddress
+    )
+}
+
+function getSogaNodeSalePhaseDetailTierCollectionAccountPdaAndBump(programAddress: PublicKey, prefix: string,
+                                                                   sogaNodeSalePhaseTierDetailPda: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(prefix),
+            sogaNodeSalePhaseTierDetailPda.toBuffer()
+        ],
+        programAddress
+    )
+}
+
+function getUserAccountPdaAndBump(programAddress: PublicKey, prefix: string,
+                                  sogaNodeSalePhaseDetailPda: PublicKey, userAddress: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(prefix),
+            sogaNodeSalePhaseDetailPda.toBuffer(),
+            userAddress.toBuffer()
+        ],
+        programAddress
+    )
+}
+
+function getUserTierAccountPdaAndBump(programAddress: PublicKey, prefix: string,
+                                      userDetailPdaAddress: PublicKey,
+                                      sogaNodeSalePhaseTierDetailPda: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(prefix),
+            userDetailPdaAddress.toBuffer(),
+            sogaNodeSalePhaseTierDetailPda.toBuffer()
+        ],
+        programAddress
+    )
+}
+
+function getNodeMintAccount(programAddress: PublicKey, prefix: string,
+                            collectionMintAccount: PublicKey, tokenId: string): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(prefix),
+            collectionMintAccount.toBuffer(),
+            Buffer.from(tokenId)
+        ],
+        programAddress
+    )
+}
+
+function getSogaNodeSalePhasePaymentTokenDetailAccountPdaAndBump(programAddress: PublicKey, prefix: string, salePhaseName: string,
+                                                                 sogaNodeSalePhaseDetailPda: PublicKey, mintAccount: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(prefix),
+            sogaNodeSalePhaseDetailPda.toBuffer(),
+            mintAccount.toBuffer(),
+        ],
+        programAddress
+    )
+}
+
+function getOrderDetailAccountPdaAndBump(programAddress: PublicKey, prefix: string,
+                                         sogaNodeSalePhaseDetailPda: PublicKey,
+                                         userDetail: PublicKey, orderId: string): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(prefix),
+            sogaNodeSalePhaseDetailPda.toBuffer(),
+            userDetail.toBuffer(),
+            Buffer.from(orderId)
+        ],
+        programAddress
+    )
+}
+
+function getTreeConfigPda(merkleTree: PublicKey): PublicKey {
+    const [treeConfigPda] = PublicKey.findProgramAddressSync(
+        [
+            merkleTree.toBuffer()
+        ],
+        bubblegumProgram
+    );
+
+    return treeConfigPda;
+}
+
+function getBubblegumSignerPda(): PublicKey {
+    const [bubblegumSignerPda] = PublicKey.findProgramAddressSync(
+        [
+            Buffer.from('collection_cpi')
+        ],
+        bubblegumProgram
+    );
+
+    return bubblegumSignerPda;
+}
+
+// concurrent merkle tree header, sequence number, active index and buffer size, then the change log
+// buffer and the rightmost path, both sized 32 * maxDepth + 40, without a canopy
+function getMerkleTreeAccountSize(maxDepth: number, maxBufferSize: number): number {
+    return 56 + 24 + (maxBufferSize + 1) * (32 * maxDepth + 40);
+}
+
+function getFillFeeVaultAccountPdaAndBump(programAddress: PublicKey, prefix: string,
+                                          sogaNodeSalePhaseDetailPda: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(prefix),
+            sogaNodeSalePhaseDetailPda.toBuffer()
+        ],
+        programAddress
+    )
+}
+
+function getOraclePriceAccountPdaAndBump(programAddress: PublicKey, prefix: string, name: string): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(prefix),
+            Buffer.from(name)
+        ],
+        programAddress
+    )
+}
+
+function getCommitmentDetailAccountPdaAndBump(programAddress: PublicKey, prefix: string,
+                                              sogaNodeSalePhaseTierDetailPda: PublicKey, user: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(prefix),
+            sogaNodeSalePhaseTierDetailPda.toBuffer(),
+            user.toBuffer()
+        ],
+        programAddress
+    )
+}
+
+function getBidDetailAccountPdaAndBump(programAddress: PublicKey, prefix: string,
+                                       sogaNodeSalePhaseTierDetailPda: PublicKey, user: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(prefix),
+            sogaNodeSalePhaseTierDetailPda.toBuffer(),
+            user.toBuffer()
+        ],
+        programAddress
+    )
+}
+
+function getReservationDetailAccountPdaAndBump(programAddress: PublicKey, prefix: string,
+                                               sogaNodeSalePhaseTierDetailPda: PublicKey, partner: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(prefix),
+            sogaNodeSalePhaseTierDetailPda.toBuffer(),
+            partner.toBuffer()
+        ],
+        programAddress
+    )
+}
+
+function getBidHash(price: BN, salt: Buffer, user: PublicKey): number[] {
+    return Array.from(createHash("sha256")
+        .update(price.toArrayLike(Buffer, "le", 8))
+        .update(salt)
+        .update(user.toBuffer())
+        .digest());
+}
+
+function getAirdropClaimBitmapAccountPdaAndBump(programAddress: PublicKey, prefix: string,
+                                                sogaNodeSalePhaseTierDetailPda: PublicKey, merkleRoot: Buffer): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(prefix),
+            sogaNodeSalePhaseTierDetailPda.toBuffer(),
+            merkleRoot
+        ],
+        programAddress
+    )
+}
+
+function getAirdropLeaf(index: number, user: PublicKey, sogaNodeSalePhaseTierDetailPda: PublicKey, quantity: BN): Buffer {
+    const indexBuffer: Buffer = Buffer.alloc(4);
+    indexBuffer.writeUInt32LE(index);
+
+    return createHash("sha256")
+        .update(Buffer.from([0]))
+        .update(indexBuffer)
+        .update(user.toBuffer())
+        .update(sogaNodeSalePhaseTierDetailPda.toBuffer())
+        .update(quantity.toArrayLike(Buffer, "le", 8))
+        .digest();
+}
+
+// pairs are hashed in sorted order like the program does
+function getMerkleParent(left: Buffer, right: Buffer): Buffer {
+    const [first, second] = Buffer.compare(left, right) <= 0 ? [left, right] : [right, left];
+
+    return createHash("sha256")
+        .update(Buffer.from([1]))
+        .update(first)
+        .update(second)
+        .digest();
+}